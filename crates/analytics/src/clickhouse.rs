@@ -35,7 +35,7 @@ pub struct ClickhouseClient {
 #[derive(Clone, Debug, serde::Deserialize)]
 pub struct ClickhouseConfig {
     username: String,
-    password: Option<String>,
+    pub password: Option<String>,
     host: String,
     database_name: String,
 }
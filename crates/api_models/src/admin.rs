@@ -464,6 +464,18 @@ pub struct PaymentLinkConfig {
     )]
     pub merchant_logo: Option<String>,
     pub color_scheme: Option<PaymentLinkColorSchema>,
+    /// The smallest amount a customer can enter on a link accepting a customer entered amount, in
+    /// the lowest denomination of the currency
+    #[schema(example = 100)]
+    pub min_amount: Option<i64>,
+    /// The largest amount a customer can enter on a link accepting a customer entered amount, in
+    /// the lowest denomination of the currency
+    #[schema(example = 100000)]
+    pub max_amount: Option<i64>,
+    /// The custom domain the payment link is served from, defaults to the custom domain of the
+    /// merchant if any
+    #[schema(max_length = 255, example = "pay.example.com")]
+    pub custom_domain: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq)]
@@ -506,6 +518,88 @@ pub struct WebhookDetails {
     pub payment_failed_enabled: Option<bool>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct EmailNotificationSettings {
+    /// If this property is true, a receipt is emailed to the customer once their payment succeeds
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub send_customer_receipts: bool,
+
+    /// The email addresses at which the merchant alerts are sent
+    #[serde(default)]
+    #[schema(value_type = Vec<String>, example = json!(["alerts@example.com"]))]
+    pub alert_recipients: Vec<pii::Email>,
+
+    /// The merchant alerts which are sent to the alert recipients
+    #[serde(default)]
+    pub enabled_alerts: Vec<MerchantAlertType>,
+
+    /// Number of refunds within a day above which the high refund volume alert is sent
+    #[schema(example = 50)]
+    pub refund_volume_threshold: Option<u32>,
+
+    /// Custom content of the emails, the default content is used when not provided
+    pub templates: Option<EmailTemplates>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantAlertType {
+    /// Sent when a dispute is opened against a payment
+    DisputeOpened,
+    /// Sent when the number of refunds within a day exceeds the refund volume threshold
+    HighRefundVolume,
+    /// Sent when the webhook endpoint fails to accept the outgoing webhooks
+    WebhookEndpointFailing,
+    /// Sent at the reminder offsets of the dispute settings before the evidence of a dispute is due
+    DisputeDeadlineApproaching,
+}
+
+/// Custom content of the emails. The placeholders `{{merchant_name}}`, `{{profile_name}}`,
+/// `{{payment_id}}`, `{{amount}}` and `{{currency}}` are replaced with the details of the payment.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct EmailTemplates {
+    /// Subject of the customer receipt email
+    #[schema(max_length = 255, example = "Your receipt from {{merchant_name}}")]
+    pub receipt_subject: Option<String>,
+
+    /// HTML body of the customer receipt email
+    #[schema(example = "<p>We received your payment of {{amount}} {{currency}}.</p>")]
+    pub receipt_body: Option<String>,
+
+    /// Text added at the end of the default customer receipt and merchant alert emails
+    #[schema(max_length = 1024, example = "Reach us at support@example.com")]
+    pub footer: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeSettings {
+    /// Number of hours before the evidence of a dispute is due at which the merchant is reminded
+    /// of the deadline, with a webhook and the dispute deadline approaching alert email
+    #[serde(default)]
+    #[schema(example = json!([72, 24]))]
+    pub reminder_offsets_in_hours: Vec<u16>,
+
+    /// Disputes of a lower amount which have no evidence attached are accepted automatically
+    /// shortly before their evidence is due
+    pub auto_accept_threshold: Option<DisputeAutoAcceptThreshold>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeAutoAcceptThreshold {
+    /// The amount in the lowest denomination of the currency
+    #[schema(example = 1000)]
+    pub amount: i64,
+
+    /// The currency of the amount, disputes in other currencies are never accepted automatically
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct MerchantAccountDeleteResponse {
     /// The identifier for the Merchant Account
@@ -1042,6 +1136,16 @@ pub struct BusinessProfileCreate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Number of hours after authorization at which payments with manual capture are captured automatically
+    #[schema(example = 24)]
+    pub delayed_capture_hours: Option<u16>,
+
+    /// Settings of the customer receipt and merchant alert emails
+    pub email_settings: Option<EmailNotificationSettings>,
+
+    /// Settings of the dispute deadline reminders and of the automatic acceptance of disputes
+    pub dispute_settings: Option<DisputeSettings>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -1106,6 +1210,16 @@ pub struct BusinessProfileResponse {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Number of hours after authorization at which payments with manual capture are captured automatically
+    #[schema(example = 24)]
+    pub delayed_capture_hours: Option<i32>,
+
+    /// Settings of the customer receipt and merchant alert emails
+    pub email_settings: Option<EmailNotificationSettings>,
+
+    /// Settings of the dispute deadline reminders and of the automatic acceptance of disputes
+    pub dispute_settings: Option<DisputeSettings>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1163,4 +1277,14 @@ pub struct BusinessProfileUpdate {
 
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
+
+    /// Number of hours after authorization at which payments with manual capture are captured automatically
+    #[schema(example = 24)]
+    pub delayed_capture_hours: Option<u16>,
+
+    /// Settings of the customer receipt and merchant alert emails
+    pub email_settings: Option<EmailNotificationSettings>,
+
+    /// Settings of the dispute deadline reminders and of the automatic acceptance of disputes
+    pub dispute_settings: Option<DisputeSettings>,
 }
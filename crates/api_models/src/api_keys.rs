@@ -1,3 +1,4 @@
+use common_enums::{ApiKeyResource, ApiKeyVerb};
 use common_utils::custom_serde;
 use masking::StrongSecret;
 use serde::{Deserialize, Serialize};
@@ -23,6 +24,19 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// Restricts the API Key to the listed resources and operations. An API Key without scopes
+    /// has full access to the merchant account.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// Binds the API Key to a business profile of the merchant account, which is used as the
+    /// profile for requests made with the key.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// Restricts the API Key to requests made from the listed IP addresses or CIDR ranges.
+    #[schema(example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +74,21 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The resources and operations the API Key is restricted to, if any.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The business profile the API Key is bound to, if any.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The IP addresses or CIDR ranges the API Key is restricted to, if any.
+    #[schema(example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The API Key this API Key was issued to replace, if it was issued by rotating an API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub rotated_from: Option<String>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,12 +131,31 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
+
+    /// The resources and operations the API Key is restricted to, if any.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The business profile the API Key is bound to, if any.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The IP addresses or CIDR ranges the API Key is restricted to, if any.
+    #[schema(example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    /// The API Key this API Key was issued to replace, if it was issued by rotating an API Key.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub rotated_from: Option<String>,
+
+    /// The API Key issued to replace this API Key, if it has been rotated. A rotated API Key
+    /// remains valid until its expiration date.
+    #[schema(max_length = 64, example = "5hEEqkgJUyuxgSKGArHA4mWSnX")]
+    pub rotated_to: Option<String>,
+
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -130,6 +178,35 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// Replaces the resources and operations the API Key is restricted to.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// Replaces the IP addresses or CIDR ranges the API Key is restricted to.
+    #[schema(example = json!(["203.0.113.7", "198.51.100.0/24"]))]
+    pub ip_allowlist: Option<Vec<String>>,
+
+    #[serde(skip_deserializing)]
+    pub key_id: String,
+
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+}
+
+/// The request body for rotating an API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// The number of seconds for which the API Key being rotated remains valid alongside the new
+    /// API Key. Defaults to the overlap configured for the server, and must not exceed the
+    /// maximum overlap configured for the server.
+    #[schema(example = 86400)]
+    pub overlap_in_secs: Option<u32>,
+
+    /// An expiration date for the new API Key. Defaults to the API Key being rotated's validity
+    /// period counted from now, or no expiration if the API Key being rotated does not expire.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: Option<ApiKeyExpiration>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
@@ -152,6 +229,19 @@ pub struct RevokeApiKeyResponse {
     pub revoked: bool,
 }
 
+/// The operations an API Key is allowed to perform on a resource.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyScope {
+    /// The resource the API Key is granted access to.
+    #[schema(value_type = ApiKeyResource, example = "payments")]
+    pub resource: ApiKeyResource,
+
+    /// The operations allowed on the resource.
+    #[schema(value_type = Vec<ApiKeyVerb>, example = json!(["read"]))]
+    pub verbs: Vec<ApiKeyVerb>,
+}
+
 /// The constraints that are applicable when listing API Keys associated with a merchant account.
 #[derive(Clone, Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -0,0 +1,66 @@
+use common_utils::custom_serde;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{AuditAction, AuditActorType, AuditResourceType};
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogListConstraints {
+    /// The type of the resource that was changed
+    pub resource_type: Option<AuditResourceType>,
+    /// The identifier of the resource that was changed
+    pub resource_id: Option<String>,
+    /// The kind of credential that made the change
+    pub actor_type: Option<AuditActorType>,
+    /// The identifier of the API key or user that made the change
+    pub actor_id: Option<String>,
+    /// The action that was performed
+    pub action: Option<AuditAction>,
+    /// Only list entries created at or after this time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "created_at.gte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_at_gte: Option<PrimitiveDateTime>,
+    /// Only list entries created at or before this time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(
+        default,
+        rename = "created_at.lte",
+        with = "custom_serde::iso8601::option"
+    )]
+    pub created_at_lte: Option<PrimitiveDateTime>,
+    /// The maximum number of entries to return, between 1 and 100. Defaults to 10.
+    pub limit: Option<i64>,
+    /// The number of entries to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier of the entry
+    pub audit_id: String,
+    /// The kind of credential that made the change
+    pub actor_type: AuditActorType,
+    /// The identifier of the API key or user that made the change
+    pub actor_id: Option<String>,
+    /// The action that was performed
+    pub action: AuditAction,
+    /// The type of the resource that was changed
+    pub resource_type: AuditResourceType,
+    /// The identifier of the resource that was changed
+    pub resource_id: String,
+    /// The fields that changed, with their values before and after the change. Secrets are masked.
+    #[schema(value_type = Object)]
+    pub diff: serde_json::Value,
+    /// The identifier of the request that made the change
+    pub request_id: Option<String>,
+    /// The IP address the request was made from
+    pub ip_address: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -17,5 +17,30 @@ pub struct CurrencyConversionResponse {
     pub currency: String,
 }
 
+/// QueryParams to be send to convert the amount at the rates of a past date
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CurrencyConversionAtDateParams {
+    pub amount: i64,
+    pub to_currency: String,
+    pub from_currency: String,
+    /// Date of the rates to convert at, as `YYYY-MM-DD`
+    pub date: String,
+}
+
+/// Response to be send for convert currency at date route
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HistoricalCurrencyConversionResponse {
+    pub converted_amount: String,
+    pub currency: String,
+    /// Date of the rates used, the latest date with recorded rates on or before the one requested
+    pub rate_date: String,
+    /// Provider the rates were fetched from
+    pub provider: String,
+}
+
 impl ApiEventMetric for CurrencyConversionResponse {}
 impl ApiEventMetric for CurrencyConversionParams {}
+impl ApiEventMetric for CurrencyConversionAtDateParams {}
+impl ApiEventMetric for HistoricalCurrencyConversionResponse {}
@@ -133,6 +133,9 @@ pub struct DisputeListConstraints {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(rename = "received_time.gte")]
     pub received_time_gte: Option<PrimitiveDateTime>,
+    /// Only the open disputes whose evidence is due within this number of hours
+    #[schema(example = 48)]
+    pub expiring_within_hours: Option<u32>,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -192,3 +195,11 @@ pub struct SubmitEvidenceRequest {
     /// Any additional evidence statements
     pub uncategorized_text: Option<String>,
 }
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct AssembleEvidenceResponse {
+    /// Evidence pre-populated from the data of the disputed payment, to be reviewed and submitted
+    pub evidence: SubmitEvidenceRequest,
+    /// File Id of the summary document generated from the disputed payment
+    pub summary_file_id: String,
+}
@@ -146,6 +146,9 @@ impl Connector {
     pub fn requires_defend_dispute(&self) -> bool {
         matches!(self, Self::Checkout)
     }
+    pub fn supports_capture_refunds(&self) -> bool {
+        matches!(self, Self::Checkout | Self::Globalpay)
+    }
 }
 
 #[cfg(feature = "payouts")]
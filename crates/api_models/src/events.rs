@@ -4,6 +4,7 @@ mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod process_tracker;
 pub mod refund;
 pub mod routing;
 pub mod user;
@@ -18,6 +19,7 @@ use crate::{
     admin::*,
     analytics::{api_event::*, sdk_events::*, *},
     api_keys::*,
+    audit_log::*,
     cards_info::*,
     disputes::*,
     files::*,
@@ -35,11 +37,14 @@ impl_misc_api_event_type!(
     PaymentMethodListResponse,
     PaymentMethodCreate,
     PaymentLinkInitiateRequest,
+    PaymentLinkPaymentRequest,
+    PaymentLinkPaymentResponse,
     RetrievePaymentLinkResponse,
     MandateListConstraints,
     CreateFileResponse,
     DisputeResponse,
     SubmitEvidenceRequest,
+    AssembleEvidenceResponse,
     MerchantConnectorResponse,
     MerchantConnectorId,
     MandateResponse,
@@ -73,6 +78,7 @@ impl_misc_api_event_type!(
     ApplepayMerchantResponse,
     ApplepayVerifiedDomainsResponse,
     UpdateApiKeyRequest,
+    RotateApiKeyRequest,
     GetApiEventFiltersRequest,
     ApiEventFiltersResponse,
     GetInfoResponse,
@@ -88,7 +94,9 @@ impl_misc_api_event_type!(
     ApiLogsRequest,
     GetApiEventMetricRequest,
     SdkEventsRequest,
-    ReportRequest
+    ReportRequest,
+    AuditLogListConstraints,
+    AuditLogResponse
 );
 
 #[cfg(feature = "stripe")]
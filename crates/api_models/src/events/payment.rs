@@ -6,10 +6,10 @@ use crate::{
         PaymentMethodResponse, PaymentMethodUpdate,
     },
     payments::{
-        PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters,
-        PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest, PaymentsCancelRequest,
-        PaymentsCaptureRequest, PaymentsRejectRequest, PaymentsRequest, PaymentsResponse,
-        PaymentsRetrieveRequest, PaymentsStartRequest, RedirectionResponse,
+        CaptureListResponse, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
+        PaymentListFilters, PaymentListResponse, PaymentListResponseV2, PaymentsApproveRequest,
+        PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRejectRequest, PaymentsRequest,
+        PaymentsResponse, PaymentsRetrieveRequest, PaymentsStartRequest, RedirectionResponse,
     },
 };
 impl ApiEventMetric for PaymentsRetrieveRequest {
@@ -39,6 +39,14 @@ impl ApiEventMetric for PaymentsCaptureRequest {
     }
 }
 
+impl ApiEventMetric for CaptureListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentsCancelRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
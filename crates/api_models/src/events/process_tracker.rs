@@ -0,0 +1,33 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::process_tracker;
+
+impl ApiEventMetric for process_tracker::ProcessTrackerListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for process_tracker::ProcessTrackerResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for process_tracker::ProcessTrackerListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for process_tracker::ProcessTrackerRescheduleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+
+impl ApiEventMetric for process_tracker::SchedulerStreamStatsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
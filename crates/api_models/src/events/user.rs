@@ -6,8 +6,11 @@ use crate::user::{
     dashboard_metadata::{
         GetMetaDataRequest, GetMetaDataResponse, GetMultipleMetaDataPayload, SetMetaDataRequest,
     },
-    ChangePasswordRequest, ConnectAccountRequest, ConnectAccountResponse,
-    CreateInternalUserRequest, GetUsersResponse, SwitchMerchantIdRequest, UserMerchantCreate,
+    AcceptInviteRequest, BeginTotpResponse, ChangePasswordRequest, ConnectAccountRequest,
+    ConnectAccountResponse, CreateInternalUserRequest, ForgotPasswordRequest, GetUsersResponse,
+    InviteUserRequest, InviteUserResponse, ResetPasswordRequest, ResetTotpRequest,
+    SsoAuthorizeRequest, SsoAuthorizeResponse, SsoCallbackRequest, SwitchMerchantIdRequest,
+    UpdateSsoConfigRequest, UpdateTotpEnforcementRequest, UserMerchantCreate, VerifyTotpRequest,
 };
 
 impl ApiEventMetric for ConnectAccountResponse {
@@ -30,7 +33,20 @@ common_utils::impl_misc_api_event_type!(
     SwitchMerchantIdRequest,
     CreateInternalUserRequest,
     UserMerchantCreate,
-    GetUsersResponse
+    GetUsersResponse,
+    BeginTotpResponse,
+    VerifyTotpRequest,
+    ResetTotpRequest,
+    UpdateTotpEnforcementRequest,
+    UpdateSsoConfigRequest,
+    SsoAuthorizeRequest,
+    SsoAuthorizeResponse,
+    SsoCallbackRequest,
+    InviteUserRequest,
+    InviteUserResponse,
+    AcceptInviteRequest,
+    ForgotPasswordRequest,
+    ResetPasswordRequest
 );
 
 #[cfg(feature = "dummy_connector")]
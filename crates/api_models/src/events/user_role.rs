@@ -1,8 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::user_role::{
-    AuthorizationInfoResponse, GetRoleRequest, ListRolesResponse, RoleInfoResponse,
-    UpdateUserRoleRequest,
+    AuthorizationInfoResponse, CreateRoleRequest, DeleteUserRoleRequest, GetRoleRequest,
+    ListRolesResponse, RoleInfoResponse, UpdateRoleRequest, UpdateUserRoleRequest,
 };
 
 common_utils::impl_misc_api_event_type!(
@@ -10,5 +10,8 @@ common_utils::impl_misc_api_event_type!(
     RoleInfoResponse,
     GetRoleRequest,
     AuthorizationInfoResponse,
-    UpdateUserRoleRequest
+    UpdateUserRoleRequest,
+    CreateRoleRequest,
+    UpdateRoleRequest,
+    DeleteUserRoleRequest
 );
@@ -2,6 +2,7 @@
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
+pub mod audit_log;
 pub mod bank_accounts;
 pub mod cards_info;
 pub mod conditional_configs;
@@ -22,6 +23,7 @@ pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod process_tracker;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_configs;
@@ -8,6 +8,7 @@ use common_utils::{
 };
 use masking::Secret;
 use router_derive::Setter;
+use rust_decimal::Decimal;
 use time::PrimitiveDateTime;
 use url::Url;
 use utoipa::ToSchema;
@@ -313,6 +314,10 @@ pub struct PaymentsRequest {
 
     ///Request for an incremental authorization
     pub request_incremental_authorization: Option<bool>,
+
+    /// The currency the customer pays in. When it differs from `currency`, the amount is converted at a rate which is locked for a limited time
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<api_enums::Currency>,
 }
 
 impl PaymentsRequest {
@@ -413,6 +418,34 @@ pub struct PaymentAttemptResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
+    /// The currency conversion quote the customer paid with, if the payment was made in another currency
+    pub fx_quote: Option<FxQuote>,
+}
+
+/// A quote locking the rate at which the amount of a payment is converted to the currency the customer pays in
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct FxQuote {
+    /// The currency the customer pays in
+    #[schema(value_type = Currency, example = "EUR")]
+    pub presentment_currency: api_enums::Currency,
+    /// The amount the customer pays, in the lowest denomination of the presentment currency
+    #[schema(example = 6540)]
+    pub presentment_amount: i64,
+    /// Units of the presentment currency for one unit of the payment currency, before the markup
+    #[schema(value_type = String, example = "0.9231")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub market_rate: Decimal,
+    /// The markup applied on the market rate, in basis points
+    #[schema(example = 200)]
+    pub markup_basis_points: u16,
+    /// Units of the presentment currency for one unit of the payment currency, markup included
+    #[schema(value_type = String, example = "0.9416")]
+    #[serde(with = "rust_decimal::serde::str")]
+    pub exchange_rate: Decimal,
+    /// Time until which the payment can be confirmed at this rate
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
 }
 
 #[derive(
@@ -445,6 +478,18 @@ pub struct CaptureResponse {
     pub error_reason: Option<String>,
     /// reference to the capture at connector side
     pub reference_id: Option<String>,
+    /// Whether this was the last capture made on the payment
+    pub final_capture: bool,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct CaptureListResponse {
+    /// The identifier for the payment
+    pub payment_id: String,
+    /// The number of captures included in the list
+    pub count: usize,
+    /// The captures made on the payment, in the order they were made
+    pub data: Vec<CaptureResponse>,
 }
 
 impl PaymentsRequest {
@@ -1807,6 +1852,9 @@ pub struct PaymentsCaptureRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+    /// Marks this as the last capture on a payment captured in multiple parts. Any amount left uncaptured after it is released
+    #[schema(example = false)]
+    pub final_capture: Option<bool>,
 }
 
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
@@ -2216,6 +2264,17 @@ pub struct PaymentsResponse {
 
     /// If true incremental authorization can be performed on this payment
     pub incremental_authorization_allowed: Option<bool>,
+
+    /// The currency the customer pays in, if it differs from `currency`
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub presentment_currency: Option<api_enums::Currency>,
+
+    /// The amount the customer pays, in the lowest denomination of the presentment currency
+    #[schema(example = 6540)]
+    pub presentment_amount: Option<i64>,
+
+    /// The currency conversion quote locked for this payment
+    pub fx_quote: Option<FxQuote>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -3214,6 +3273,24 @@ pub struct PaymentLinkObject {
     pub payment_link_config: Option<admin::PaymentLinkConfig>,
     /// Custom merchant name for payment link
     pub custom_merchant_name: Option<String>,
+    /// Whether the link completes a single payment or starts a new payment on every visit
+    #[schema(value_type = Option<PaymentLinkType>, example = "single_use")]
+    pub link_type: Option<api_enums::PaymentLinkType>,
+    /// Whether the amount is fixed or entered by the customer, only multi use links accept a customer entered amount
+    #[schema(value_type = Option<PaymentLinkAmountType>, example = "fixed")]
+    pub amount_type: Option<api_enums::PaymentLinkAmountType>,
+    /// The locale in which the payment link page is rendered
+    #[schema(max_length = 32, example = "en")]
+    pub locale: Option<String>,
+    /// The URL to which the customer is redirected after a successful payment
+    #[schema(max_length = 255, example = "https://example.com/success")]
+    pub success_url: Option<String>,
+    /// The URL to which the customer is redirected after a failed payment
+    #[schema(max_length = 255, example = "https://example.com/failure")]
+    pub failure_url: Option<String>,
+    /// The tax included in the amount, shown on the invoice of the payment link
+    #[schema(example = 650)]
+    pub tax_amount: Option<i64>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
@@ -3238,9 +3315,19 @@ pub struct RetrievePaymentLinkResponse {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub link_expiry: Option<PrimitiveDateTime>,
     pub description: Option<String>,
-    pub status: String,
+    #[schema(value_type = PaymentLinkStatus)]
+    pub status: api_enums::PaymentLinkStatus,
     #[schema(value_type = Option<Currency>)]
     pub currency: Option<api_enums::Currency>,
+    #[schema(value_type = PaymentLinkType)]
+    pub link_type: api_enums::PaymentLinkType,
+    #[schema(value_type = PaymentLinkAmountType)]
+    pub amount_type: api_enums::PaymentLinkAmountType,
+    pub custom_domain: Option<String>,
+    pub locale: Option<String>,
+    pub success_url: Option<String>,
+    pub failure_url: Option<String>,
+    pub tax_amount: Option<i64>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -3249,6 +3336,29 @@ pub struct PaymentLinkInitiateRequest {
     pub payment_id: String,
 }
 
+/// Request made from the page of a multi use payment link to start a payment of its own
+#[derive(Default, Debug, serde::Deserialize, Clone, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLinkPaymentRequest {
+    /// Amount entered by the customer, in the lowest denomination of the currency. Required for
+    /// links accepting a customer entered amount, and ignored otherwise
+    #[schema(example = 6540)]
+    pub amount: Option<i64>,
+}
+
+#[derive(Debug, Clone, ToSchema, serde::Serialize)]
+pub struct PaymentLinkPaymentResponse {
+    /// The identifier of the payment created for the customer
+    pub payment_id: String,
+    /// The client secret of the payment, used to confirm it from the payment link page
+    pub client_secret: String,
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct PaymentLinkDetails {
     pub amount: i64,
@@ -3264,6 +3374,14 @@ pub struct PaymentLinkDetails {
     pub order_details: Option<Vec<OrderDetailsWithAmount>>,
     pub max_items_visible_after_collapse: i8,
     pub sdk_theme: Option<String>,
+    pub locale: Option<String>,
+    pub success_url: Option<String>,
+    pub failure_url: Option<String>,
+    /// Set when the customer has to enter the amount before the payment can be started
+    pub amount_required: bool,
+    /// Set for multi use links, whose payments are created on request from the payment link page
+    /// through this URL
+    pub create_payment_url: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::ProcessTrackerStatus;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListConstraints {
+    /// The runner responsible for executing the task, e.g. `PAYMENTS_SYNC_WORKFLOW`
+    pub runner: Option<String>,
+    /// Scheduler status of the task
+    pub status: Option<ProcessTrackerStatus>,
+    /// Business status set by the workflow, e.g. `Pending` or `GLOBAL_ERROR`
+    pub business_status: Option<String>,
+    /// Limit on the number of tasks to return, between 1 and 100. Defaults to 10.
+    pub limit: Option<i64>,
+    /// The number of tasks to skip before starting to collect the result set
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ProcessTrackerResponse {
+    /// Unique identifier of the task
+    pub id: String,
+    /// Name of the task
+    pub name: Option<String>,
+    /// Tags attached to the task
+    pub tag: Vec<String>,
+    /// The runner responsible for executing the task
+    pub runner: Option<String>,
+    /// Scheduler status of the task
+    pub status: ProcessTrackerStatus,
+    /// Business status set by the workflow
+    pub business_status: String,
+    /// The number of times this task has been retried
+    pub retry_count: i32,
+    /// The time at which the task is next scheduled to run
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    /// Workflow specific data the task was created with
+    pub tracking_data: serde_json::Value,
+    /// Events recorded against the task, oldest first
+    pub event: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ProcessTrackerListResponse {
+    /// The number of tasks included in the list
+    pub size: usize,
+    /// The list of tasks matching the constraints
+    pub data: Vec<ProcessTrackerResponse>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// The time at which the task should be picked up by the producer
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+    /// Reset the retry count of the task to zero
+    #[serde(default)]
+    pub reset_retry_count: bool,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SchedulerStreamStatsResponse {
+    /// Statistics of the streams of every priority lane, starting with the default lane
+    pub streams: Vec<StreamStats>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct StreamStats {
+    /// Name of the Redis stream the producer appends batches to
+    pub stream: String,
+    /// Weight of the priority lane backed by the stream
+    pub weight: u32,
+    /// Number of entries (batches) present in the stream
+    pub length: usize,
+    /// Statistics of every consumer group reading from the stream
+    pub consumer_groups: Vec<ConsumerGroupStats>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ConsumerGroupStats {
+    /// Name of the consumer group
+    pub name: String,
+    /// Number of consumers registered in the group
+    pub consumers: u64,
+    /// Number of entries delivered to consumers but not yet acknowledged
+    pub pending: u64,
+    /// ID of the last entry delivered to the group
+    pub last_delivered_id: String,
+    /// Number of entries still waiting to be delivered to the group.
+    /// Only reported by Redis 7.0 and above.
+    pub lag: Option<u64>,
+}
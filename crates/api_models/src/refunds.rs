@@ -48,6 +48,10 @@ pub struct RefundRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// The identifier of the capture to refund, for payments captured in multiple parts. If provided, the amount defaults to the capture amount and cannot exceed the unrefunded amount of the capture
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4_1_1")]
+    pub capture_id: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -128,6 +132,8 @@ pub struct RefundResponse {
     #[schema(example = "stripe")]
     pub connector: String,
     pub profile_id: Option<String>,
+    /// The identifier of the capture the refund is issued against
+    pub capture_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
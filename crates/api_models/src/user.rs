@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::pii;
 use masking::Secret;
 
@@ -20,11 +22,24 @@ pub struct ConnectAccountResponse {
     pub email: pii::Email,
     pub verification_days_left: Option<i64>,
     pub user_role: String,
+    /// Present when the user has to complete the TOTP step before signing in, in which case the
+    /// `token` can only be used with the TOTP endpoints
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub totp_challenge: Option<TotpChallenge>,
     //this field is added for audit/debug reasons
     #[serde(skip_serializing)]
     pub user_id: String,
 }
 
+#[derive(serde::Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum TotpChallenge {
+    /// The user has set up TOTP and has to verify a code
+    Verify,
+    /// The organization of the user enforces TOTP, which the user has to set up first
+    Enroll,
+}
+
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
 pub struct ChangePasswordRequest {
     pub new_password: Secret<String>,
@@ -36,6 +51,38 @@ pub struct SwitchMerchantIdRequest {
     pub merchant_id: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct InviteUserRequest {
+    pub email: pii::Email,
+    pub name: Secret<String>,
+    pub role_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct InviteUserResponse {
+    pub user_id: String,
+    /// False when the invited user already has an account, in which case they are added to the
+    /// merchant without an invitation email
+    pub is_email_sent: bool,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct AcceptInviteRequest {
+    pub token: Secret<String>,
+    pub password: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ForgotPasswordRequest {
+    pub email: pii::Email,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResetPasswordRequest {
+    pub token: Secret<String>,
+    pub password: Secret<String>,
+}
+
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
 pub struct CreateInternalUserRequest {
     pub name: Secret<String>,
@@ -62,3 +109,75 @@ pub struct UserDetails {
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub last_modified_at: time::PrimitiveDateTime,
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct BeginTotpResponse {
+    /// The base32 encoded TOTP secret, for authenticator apps which cannot scan the QR code
+    pub secret: Secret<String>,
+    /// The `otpauth://` URI to be rendered as a QR code
+    pub provisioning_uri: Secret<String>,
+    /// Single use codes which can be used in place of a TOTP, shown only once
+    pub recovery_codes: Vec<Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct VerifyTotpRequest {
+    pub totp: Option<Secret<String>>,
+    pub recovery_code: Option<Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ResetTotpRequest {
+    pub user_id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateTotpEnforcementRequest {
+    pub enforce_totp: bool,
+}
+
+/// The OpenID Connect provider used by the users of an organization to sign in
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct OidcConfig {
+    /// The issuer identifier of the provider, used to discover its endpoints
+    pub issuer: String,
+    pub client_id: String,
+    /// The dashboard URL the provider redirects to after the user signs in
+    pub redirect_uri: String,
+    /// The merchant the users signing in for the first time are added to
+    pub merchant_id: String,
+    /// The role of the users signing in for the first time, when none of the mappings apply
+    pub default_role_id: String,
+    /// The ID token claim holding the groups or roles of the user at the provider
+    pub role_claim: Option<String>,
+    /// Maps the values of the role claim to the role IDs in Hyperswitch
+    #[serde(default)]
+    pub role_mapping: HashMap<String, String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateSsoConfigRequest {
+    #[serde(flatten)]
+    pub oidc_config: OidcConfig,
+    pub client_secret: Secret<String>,
+    /// Disables the password sign in for the users of the organization
+    #[serde(default)]
+    pub enforce_sso: bool,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SsoAuthorizeRequest {
+    pub org_id: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SsoAuthorizeResponse {
+    /// The URL of the provider the dashboard redirects the user to
+    pub authorization_url: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SsoCallbackRequest {
+    pub code: Secret<String>,
+    pub state: String,
+}
@@ -3,9 +3,12 @@ pub struct ListRolesResponse(pub Vec<RoleInfoResponse>);
 
 #[derive(Debug, serde::Serialize)]
 pub struct RoleInfoResponse {
-    pub role_id: &'static str,
+    pub role_id: String,
     pub permissions: Vec<Permission>,
-    pub role_name: &'static str,
+    pub role_name: String,
+    /// The scope of the role, only present for the custom roles created by the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<common_enums::RoleScope>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -13,7 +16,21 @@ pub struct GetRoleRequest {
     pub role_id: String,
 }
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateRoleRequest {
+    pub role_name: String,
+    pub permissions: Vec<Permission>,
+    #[serde(default)]
+    pub scope: common_enums::RoleScope,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct UpdateRoleRequest {
+    pub role_name: Option<String>,
+    pub permissions: Option<Vec<Permission>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -41,6 +58,7 @@ pub enum Permission {
     SurchargeDecisionManagerRead,
     UsersRead,
     UsersWrite,
+    AuditLogRead,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -57,6 +75,7 @@ pub enum PermissionModule {
     Files,
     ThreeDsDecisionManager,
     SurchargeDecisionManager,
+    AuditLogs,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -81,6 +100,11 @@ pub struct UpdateUserRoleRequest {
     pub role_id: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DeleteUserRoleRequest {
+    pub user_id: String,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub enum UserStatus {
     Active,
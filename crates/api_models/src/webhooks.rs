@@ -165,6 +165,8 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = CaptureResponse)]
+    CaptureDetails(Box<payments::CaptureResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -58,6 +58,8 @@ pub enum AttemptStatus {
     PaymentMethodAwaited,
     ConfirmationAwaited,
     DeviceDataCollectionPending,
+    /// The authorization lapsed before the payment was captured
+    Expired,
 }
 
 #[derive(
@@ -897,6 +899,7 @@ pub enum EventType {
     PaymentFailed,
     PaymentProcessing,
     PaymentCancelled,
+    PaymentExpired,
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
@@ -907,8 +910,11 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
+    CaptureSucceeded,
+    CaptureFailed,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -934,6 +940,34 @@ pub enum MerchantStorageScheme {
     RedisKv,
 }
 
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "db_enum")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProcessTrackerStatus {
+    // Picked by the producer
+    Processing,
+    // State when the task is added
+    New,
+    // Send to retry
+    Pending,
+    // Picked by consumer
+    ProcessStarted,
+    // Finished by consumer
+    Finish,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -964,6 +998,8 @@ pub enum IntentStatus {
     RequiresCapture,
     PartiallyCaptured,
     PartiallyCapturedAndCapturable,
+    /// The authorization lapsed before the payment was captured
+    Expired,
 }
 
 #[derive(
@@ -2002,3 +2038,260 @@ pub enum ConnectorStatus {
     Inactive,
     Active,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkType {
+    /// The link can be used to complete a single payment
+    #[default]
+    SingleUse,
+    /// Every visit to the link starts a new payment, until the link expires or is deactivated
+    MultiUse,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkAmountType {
+    /// The amount of the payment link is charged
+    #[default]
+    Fixed,
+    /// The customer enters the amount to be paid on the payment link page
+    CustomerEntered,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentLinkStatus {
+    #[default]
+    Active,
+    Expired,
+    Completed,
+    Deactivated,
+}
+
+/// The entities a custom role can be assigned within
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoleScope {
+    /// The role can only be assigned to the users of the merchant which created it
+    #[default]
+    Merchant,
+    /// The role can be assigned to the users of any merchant in the organization
+    Organization,
+}
+
+/// The status of the TOTP based two factor authentication of a dashboard user
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TotpStatus {
+    /// The user has not set up TOTP
+    #[default]
+    NotSet,
+    /// The user has started the TOTP enrollment but has not verified a code yet
+    InProgress,
+    /// The user has set up TOTP and is required to verify a code when signing in
+    Set,
+}
+
+/// The kind of credential that performed an audited action
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    /// A merchant API key
+    ApiKey,
+    /// The admin API key
+    AdminApiKey,
+    /// A dashboard user
+    User,
+    /// An action not tied to a credential, such as a scheduled job
+    System,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Activate,
+    Deactivate,
+    Revoke,
+    Rotate,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditResourceType {
+    MerchantAccount,
+    MerchantConnectorAccount,
+    RoutingAlgorithm,
+    ApiKey,
+    GsmRule,
+    UserRole,
+    Role,
+}
+
+/// The resources that a restricted API key can be granted access to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyResource {
+    Payments,
+    Refunds,
+    Customers,
+    PaymentMethods,
+    Mandates,
+    Disputes,
+    Files,
+    Payouts,
+    Events,
+    Routing,
+    PaymentLinks,
+    MerchantAccount,
+    MerchantConnectors,
+    EphemeralKeys,
+}
+
+/// The operations that a restricted API key can be granted on a resource
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ApiKeyVerb {
+    Read,
+    Create,
+    Update,
+    Delete,
+}
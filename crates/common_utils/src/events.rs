@@ -42,6 +42,7 @@ pub enum ApiEventsType {
     ResourceListAPI,
     PaymentRedirectionResponse,
     Gsm,
+    ProcessTracker,
     // TODO: This has to be removed once the corresponding apiEventTypes are created
     Miscellaneous,
     RustLocker,
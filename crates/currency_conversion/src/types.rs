@@ -11,6 +11,9 @@ use crate::error::CurrencyConversionError;
 pub struct ExchangeRates {
     pub base_currency: Currency,
     pub conversion: HashMap<Currency, CurrencyFactors>,
+    /// Unix timestamp at which the rates were published, if known
+    #[serde(default)]
+    pub timestamp: Option<i64>,
 }
 
 /// Stores the multiplicative factor for  conversion between currency to base and vice versa
@@ -36,9 +39,16 @@ impl ExchangeRates {
         Self {
             base_currency,
             conversion,
+            timestamp: None,
         }
     }
 
+    /// Records the time at which the rates were published
+    pub fn with_timestamp(mut self, timestamp: i64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
     /// The flow here is from_currency -> base_currency -> to_currency
     /// from to_currency -> base currency
     pub fn forward_conversion(
@@ -52,4 +52,7 @@ pub struct PaymentIntent {
     pub surcharge_applicable: Option<bool>,
     pub request_incremental_authorization: storage_enums::RequestIncrementalAuthorization,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<i64>,
+    pub fx_quote: Option<serde_json::Value>,
 }
@@ -109,6 +109,9 @@ pub struct PaymentIntentNew {
     pub surcharge_applicable: Option<bool>,
     pub request_incremental_authorization: storage_enums::RequestIncrementalAuthorization,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<i64>,
+    pub fx_quote: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -161,6 +164,9 @@ pub enum PaymentIntentUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
         updated_by: String,
+        presentment_currency: Option<storage_enums::Currency>,
+        presentment_amount: Option<i64>,
+        fx_quote: Option<serde_json::Value>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -218,6 +224,9 @@ pub struct PaymentIntentUpdateInternal {
     pub updated_by: String,
     pub surcharge_applicable: Option<bool>,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<Option<storage_enums::Currency>>,
+    pub presentment_amount: Option<Option<i64>>,
+    pub fx_quote: Option<Option<serde_json::Value>>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -241,6 +250,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency,
+                presentment_amount,
+                fx_quote,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -260,6 +272,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency: Some(presentment_currency),
+                presentment_amount: Some(presentment_amount),
+                fx_quote: Some(fx_quote),
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
@@ -16,6 +16,17 @@ pub struct ApiKey {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    /// The resources and verbs the key is restricted to, as `resource:verb` entries. Keys without
+    /// scopes have full access to the merchant.
+    pub scopes: Option<Vec<String>>,
+    pub profile_id: Option<String>,
+    pub ip_allowlist: Option<Vec<String>>,
+    /// The key this key was issued to replace, if it was issued by rotating a key
+    pub rotated_from: Option<String>,
+    /// The key issued to replace this key, if it has been rotated
+    pub rotated_to: Option<String>,
+    /// The expiry this key had before it was rotated, if it has been rotated
+    pub original_expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Insertable)]
@@ -30,6 +41,10 @@ pub struct ApiKeyNew {
     pub created_at: PrimitiveDateTime,
     pub expires_at: Option<PrimitiveDateTime>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub profile_id: Option<String>,
+    pub ip_allowlist: Option<Vec<String>>,
+    pub rotated_from: Option<String>,
 }
 
 #[derive(Debug)]
@@ -39,10 +54,17 @@ pub enum ApiKeyUpdate {
         description: Option<String>,
         expires_at: Option<Option<PrimitiveDateTime>>,
         last_used: Option<PrimitiveDateTime>,
+        scopes: Option<Vec<String>>,
+        ip_allowlist: Option<Vec<String>>,
     },
     LastUsedUpdate {
         last_used: PrimitiveDateTime,
     },
+    RotatedUpdate {
+        expires_at: Option<PrimitiveDateTime>,
+        rotated_to: String,
+        original_expires_at: Option<PrimitiveDateTime>,
+    },
 }
 
 #[derive(Debug, AsChangeset)]
@@ -52,6 +74,10 @@ pub(crate) struct ApiKeyUpdateInternal {
     pub description: Option<String>,
     pub expires_at: Option<Option<PrimitiveDateTime>>,
     pub last_used: Option<PrimitiveDateTime>,
+    pub scopes: Option<Vec<String>>,
+    pub ip_allowlist: Option<Vec<String>>,
+    pub rotated_to: Option<String>,
+    pub original_expires_at: Option<PrimitiveDateTime>,
 }
 
 impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
@@ -62,17 +88,41 @@ impl From<ApiKeyUpdate> for ApiKeyUpdateInternal {
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                ip_allowlist,
             } => Self {
                 name,
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                ip_allowlist,
+                rotated_to: None,
+                original_expires_at: None,
             },
             ApiKeyUpdate::LastUsedUpdate { last_used } => Self {
                 last_used: Some(last_used),
                 name: None,
                 description: None,
                 expires_at: None,
+                scopes: None,
+                ip_allowlist: None,
+                rotated_to: None,
+                original_expires_at: None,
+            },
+            ApiKeyUpdate::RotatedUpdate {
+                expires_at,
+                rotated_to,
+                original_expires_at,
+            } => Self {
+                expires_at: Some(expires_at),
+                rotated_to: Some(rotated_to),
+                original_expires_at,
+                name: None,
+                description: None,
+                last_used: None,
+                scopes: None,
+                ip_allowlist: None,
             },
         }
     }
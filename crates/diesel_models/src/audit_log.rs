@@ -0,0 +1,42 @@
+use common_utils::custom_serde;
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+/// An entry of the audit log. Entries are append-only, so there is no update type.
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub audit_id: String,
+    pub merchant_id: Option<String>,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub action: storage_enums::AuditAction,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub diff: serde_json::Value,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLog {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub audit_id: String,
+    pub merchant_id: Option<String>,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub action: storage_enums::AuditAction,
+    pub resource_type: storage_enums::AuditResourceType,
+    pub resource_id: String,
+    pub diff: serde_json::Value,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -32,6 +32,9 @@ pub struct BusinessProfile {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub delayed_capture_hours: Option<i32>,
+    pub email_settings: Option<serde_json::Value>,
+    pub dispute_settings: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -55,6 +58,9 @@ pub struct BusinessProfileNew {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub delayed_capture_hours: Option<i32>,
+    pub email_settings: Option<serde_json::Value>,
+    pub dispute_settings: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -75,6 +81,9 @@ pub struct BusinessProfileUpdateInternal {
     pub is_recon_enabled: Option<bool>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub delayed_capture_hours: Option<i32>,
+    pub email_settings: Option<serde_json::Value>,
+    pub dispute_settings: Option<serde_json::Value>,
 }
 
 impl From<BusinessProfileNew> for BusinessProfile {
@@ -97,6 +106,9 @@ impl From<BusinessProfileNew> for BusinessProfile {
             payout_routing_algorithm: new.payout_routing_algorithm,
             is_recon_enabled: new.is_recon_enabled,
             applepay_verified_domains: new.applepay_verified_domains,
+            delayed_capture_hours: new.delayed_capture_hours,
+            email_settings: new.email_settings,
+            dispute_settings: new.dispute_settings,
         }
     }
 }
@@ -118,6 +130,9 @@ impl BusinessProfileUpdateInternal {
             payout_routing_algorithm,
             is_recon_enabled,
             applepay_verified_domains,
+            delayed_capture_hours,
+            email_settings,
+            dispute_settings,
         } = self;
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -136,6 +151,9 @@ impl BusinessProfileUpdateInternal {
             payout_routing_algorithm,
             is_recon_enabled: is_recon_enabled.unwrap_or(source.is_recon_enabled),
             applepay_verified_domains,
+            delayed_capture_hours: delayed_capture_hours.or(source.delayed_capture_hours),
+            email_settings: email_settings.or(source.email_settings),
+            dispute_settings: dispute_settings.or(source.dispute_settings),
             ..source
         }
     }
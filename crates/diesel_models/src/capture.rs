@@ -28,6 +28,8 @@ pub struct Capture {
     pub capture_sequence: i16,
     // reference to the capture at connector side
     pub connector_response_reference_id: Option<String>,
+    /// Whether no further captures are to be made on the authorization after this one
+    pub final_capture: bool,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
@@ -52,6 +54,7 @@ pub struct CaptureNew {
     pub connector_capture_id: Option<String>,
     pub capture_sequence: i16,
     pub connector_response_reference_id: Option<String>,
+    pub final_capture: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
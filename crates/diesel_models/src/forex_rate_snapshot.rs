@@ -0,0 +1,51 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::{Date, PrimitiveDateTime};
+
+use crate::{enums as storage_enums, schema::forex_rate_snapshot};
+
+/// Exchange rates observed on a day, kept for converting historical payments
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = forex_rate_snapshot, primary_key(rate_date))]
+pub struct ForexRateSnapshot {
+    pub rate_date: Date,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub rates_timestamp: i64,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = forex_rate_snapshot)]
+pub struct ForexRateSnapshotNew {
+    pub rate_date: Date,
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub rates_timestamp: i64,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = forex_rate_snapshot)]
+pub struct ForexRateSnapshotUpdate {
+    pub provider: String,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub rates_timestamp: i64,
+    pub last_modified: PrimitiveDateTime,
+}
+
+impl From<ForexRateSnapshotNew> for ForexRateSnapshotUpdate {
+    fn from(value: ForexRateSnapshotNew) -> Self {
+        Self {
+            provider: value.provider,
+            base_currency: value.base_currency,
+            rates: value.rates,
+            rates_timestamp: value.rates_timestamp,
+            last_modified: value.last_modified,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
@@ -13,6 +14,7 @@ pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 #[allow(unused)]
 pub mod fraud_check;
 pub mod gsm;
@@ -35,6 +37,7 @@ pub mod process_tracker;
 pub mod query;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod role;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
@@ -1,12 +1,16 @@
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 
-use crate::schema::organization;
+use crate::{encryption::Encryption, schema::organization};
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
 #[diesel(table_name = organization, primary_key(org_id))]
 pub struct Organization {
     pub org_id: String,
     pub org_name: Option<String>,
+    pub enforce_totp: bool,
+    pub sso_config: Option<serde_json::Value>,
+    pub sso_client_secret: Option<Encryption>,
+    pub enforce_sso: bool,
 }
 
 #[derive(Clone, Debug, Insertable)]
@@ -20,16 +24,54 @@ pub struct OrganizationNew {
 #[diesel(table_name = organization)]
 pub struct OrganizationUpdateInternal {
     org_name: Option<String>,
+    enforce_totp: Option<bool>,
+    sso_config: Option<serde_json::Value>,
+    sso_client_secret: Option<Encryption>,
+    enforce_sso: Option<bool>,
 }
 
 pub enum OrganizationUpdate {
-    Update { org_name: Option<String> },
+    Update {
+        org_name: Option<String>,
+    },
+    TotpEnforcementUpdate {
+        enforce_totp: bool,
+    },
+    SsoConfigUpdate {
+        sso_config: serde_json::Value,
+        sso_client_secret: Encryption,
+        enforce_sso: bool,
+    },
 }
 
 impl From<OrganizationUpdate> for OrganizationUpdateInternal {
     fn from(value: OrganizationUpdate) -> Self {
         match value {
-            OrganizationUpdate::Update { org_name } => Self { org_name },
+            OrganizationUpdate::Update { org_name } => Self {
+                org_name,
+                enforce_totp: None,
+                sso_config: None,
+                sso_client_secret: None,
+                enforce_sso: None,
+            },
+            OrganizationUpdate::TotpEnforcementUpdate { enforce_totp } => Self {
+                org_name: None,
+                enforce_totp: Some(enforce_totp),
+                sso_config: None,
+                sso_client_secret: None,
+                enforce_sso: None,
+            },
+            OrganizationUpdate::SsoConfigUpdate {
+                sso_config,
+                sso_client_secret,
+                enforce_sso,
+            } => Self {
+                org_name: None,
+                enforce_totp: None,
+                sso_config: Some(sso_config),
+                sso_client_secret: Some(sso_client_secret),
+                enforce_sso: Some(enforce_sso),
+            },
         }
     }
 }
@@ -63,6 +63,7 @@ pub struct PaymentAttempt {
     pub encoded_data: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub fx_quote: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Queryable, Serialize, Deserialize)]
@@ -128,6 +129,7 @@ pub struct PaymentAttemptNew {
     pub encoded_data: Option<String>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub fx_quote: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -148,6 +150,7 @@ pub enum PaymentAttemptUpdate {
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         updated_by: String,
+        fx_quote: Option<serde_json::Value>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
@@ -184,6 +187,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        fx_quote: Option<serde_json::Value>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -310,6 +314,7 @@ pub struct PaymentAttemptUpdateInternal {
     encoded_data: Option<String>,
     unified_code: Option<Option<String>>,
     unified_message: Option<Option<String>>,
+    fx_quote: Option<Option<serde_json::Value>>,
 }
 
 impl PaymentAttemptUpdate {
@@ -351,6 +356,7 @@ impl PaymentAttemptUpdate {
             encoded_data,
             unified_code,
             unified_message,
+            fx_quote,
         } = self.into();
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -391,6 +397,7 @@ impl PaymentAttemptUpdate {
             encoded_data: encoded_data.or(source.encoded_data),
             unified_code: unified_code.unwrap_or(source.unified_code),
             unified_message: unified_message.unwrap_or(source.unified_message),
+            fx_quote: fx_quote.unwrap_or(source.fx_quote),
             ..source
         }
     }
@@ -416,6 +423,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -434,6 +442,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote: Some(fx_quote),
                 ..Default::default()
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
@@ -466,6 +475,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 merchant_connector_id,
                 surcharge_amount,
                 tax_amount,
+                fx_quote,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -488,6 +498,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 merchant_connector_id,
                 surcharge_amount,
                 tax_amount,
+                fx_quote: Some(fx_quote),
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -54,6 +54,9 @@ pub struct PaymentIntent {
     pub surcharge_applicable: Option<bool>,
     pub request_incremental_authorization: RequestIncrementalAuthorization,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<i64>,
+    pub fx_quote: Option<serde_json::Value>,
 }
 
 #[derive(
@@ -111,6 +114,9 @@ pub struct PaymentIntentNew {
     pub surcharge_applicable: Option<bool>,
     pub request_incremental_authorization: RequestIncrementalAuthorization,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<storage_enums::Currency>,
+    pub presentment_amount: Option<i64>,
+    pub fx_quote: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -163,6 +169,9 @@ pub enum PaymentIntentUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         payment_confirm_source: Option<storage_enums::PaymentSource>,
         updated_by: String,
+        presentment_currency: Option<storage_enums::Currency>,
+        presentment_amount: Option<i64>,
+        fx_quote: Option<serde_json::Value>,
     },
     PaymentAttemptAndAttemptCountUpdate {
         active_attempt_id: String,
@@ -221,6 +230,9 @@ pub struct PaymentIntentUpdateInternal {
     pub updated_by: String,
     pub surcharge_applicable: Option<bool>,
     pub incremental_authorization_allowed: Option<bool>,
+    pub presentment_currency: Option<Option<storage_enums::Currency>>,
+    pub presentment_amount: Option<Option<i64>>,
+    pub fx_quote: Option<Option<serde_json::Value>>,
 }
 
 impl PaymentIntentUpdate {
@@ -252,6 +264,9 @@ impl PaymentIntentUpdate {
             updated_by,
             surcharge_applicable,
             incremental_authorization_allowed,
+            presentment_currency,
+            presentment_amount,
+            fx_quote,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -283,6 +298,9 @@ impl PaymentIntentUpdate {
             surcharge_applicable: surcharge_applicable.or(source.surcharge_applicable),
 
             incremental_authorization_allowed,
+            presentment_currency: presentment_currency.unwrap_or(source.presentment_currency),
+            presentment_amount: presentment_amount.unwrap_or(source.presentment_amount),
+            fx_quote: fx_quote.unwrap_or(source.fx_quote),
             ..source
         }
     }
@@ -309,6 +327,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency,
+                presentment_amount,
+                fx_quote,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -328,6 +349,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency: Some(presentment_currency),
+                presentment_amount: Some(presentment_amount),
+                fx_quote: Some(fx_quote),
                 ..Default::default()
             },
             PaymentIntentUpdate::MetadataUpdate {
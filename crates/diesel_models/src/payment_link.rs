@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
@@ -23,6 +23,14 @@ pub struct PaymentLink {
     pub custom_merchant_name: Option<String>,
     pub payment_link_config: Option<serde_json::Value>,
     pub description: Option<String>,
+    pub link_type: storage_enums::PaymentLinkType,
+    pub amount_type: storage_enums::PaymentLinkAmountType,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub custom_domain: Option<String>,
+    pub locale: Option<String>,
+    pub success_url: Option<String>,
+    pub failure_url: Option<String>,
+    pub tax_amount: Option<i64>,
 }
 
 #[derive(
@@ -53,4 +61,37 @@ pub struct PaymentLinkNew {
     pub custom_merchant_name: Option<String>,
     pub payment_link_config: Option<serde_json::Value>,
     pub description: Option<String>,
+    pub link_type: storage_enums::PaymentLinkType,
+    pub amount_type: storage_enums::PaymentLinkAmountType,
+    pub status: storage_enums::PaymentLinkStatus,
+    pub custom_domain: Option<String>,
+    pub locale: Option<String>,
+    pub success_url: Option<String>,
+    pub failure_url: Option<String>,
+    pub tax_amount: Option<i64>,
+}
+
+#[derive(Debug)]
+pub enum PaymentLinkUpdate {
+    StatusUpdate {
+        status: storage_enums::PaymentLinkStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_link)]
+pub struct PaymentLinkUpdateInternal {
+    pub status: Option<storage_enums::PaymentLinkStatus>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentLinkUpdate> for PaymentLinkUpdateInternal {
+    fn from(payment_link_update: PaymentLinkUpdate) -> Self {
+        match payment_link_update {
+            PaymentLinkUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                last_modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
 }
@@ -75,6 +75,14 @@ pub enum ProcessTrackerUpdate {
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
     },
+    /// Update applied by an operator, recording the action in the `event` history of the task
+    ManualUpdate {
+        status: storage_enums::ProcessTrackerStatus,
+        business_status: Option<String>,
+        retry_count: Option<i32>,
+        schedule_time: Option<PrimitiveDateTime>,
+        event: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
@@ -86,6 +94,7 @@ pub struct ProcessTrackerUpdateInternal {
     tracking_data: Option<serde_json::Value>,
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
+    event: Option<Vec<String>>,
     updated_at: Option<PrimitiveDateTime>,
 }
 
@@ -98,6 +107,7 @@ impl Default for ProcessTrackerUpdateInternal {
             tracking_data: Option::default(),
             business_status: Option::default(),
             status: Option::default(),
+            event: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
         }
     }
@@ -121,6 +131,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 tracking_data,
                 business_status,
                 status,
+                event: None,
                 updated_at,
             },
             ProcessTrackerUpdate::StatusUpdate {
@@ -141,6 +152,20 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 schedule_time: Some(schedule_time),
                 ..Default::default()
             },
+            ProcessTrackerUpdate::ManualUpdate {
+                status,
+                business_status,
+                retry_count,
+                schedule_time,
+                event,
+            } => Self {
+                status: Some(status),
+                business_status,
+                retry_count,
+                schedule_time,
+                event: Some(event),
+                ..Default::default()
+            },
         }
     }
 }
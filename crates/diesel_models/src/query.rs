@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
@@ -10,6 +11,7 @@ pub mod dashboard_metadata;
 pub mod dispute;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod generics;
 pub mod gsm;
@@ -28,6 +30,7 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
@@ -1,5 +1,9 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, PgExpressionMethods,
+};
+use error_stack::ResultExt;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -57,6 +61,35 @@ impl ApiKey {
         }
     }
 
+    /// Updates the API key only if its expiry and the key it was rotated to are still the ones that
+    /// were read, so that concurrent rotations cannot both replace it
+    #[instrument(skip(conn))]
+    pub async fn update_if_unchanged_by_merchant_id_key_id(
+        conn: &PgPooledConn,
+        merchant_id: String,
+        key_id: String,
+        current_expires_at: Option<PrimitiveDateTime>,
+        current_rotated_to: Option<String>,
+        api_key_update: ApiKeyUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id)
+                .and(dsl::key_id.eq(key_id))
+                .and(dsl::expires_at.is_not_distinct_from(current_expires_at))
+                .and(dsl::rotated_to.is_not_distinct_from(current_rotated_to)),
+            ApiKeyUpdateInternal::from(api_key_update),
+        )
+        .await
+        .attach_printable("API key with the given key ID does not exist or has been changed")
+    }
+
     #[instrument(skip(conn))]
     pub async fn revoke_by_merchant_id_key_id(
         conn: &PgPooledConn,
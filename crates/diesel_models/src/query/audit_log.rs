@@ -0,0 +1,14 @@
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    audit_log::{AuditLog, AuditLogNew},
+    PgPooledConn, StorageResult,
+};
+
+impl AuditLogNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
@@ -0,0 +1,55 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use error_stack::report;
+use router_env::{instrument, tracing};
+use time::Date;
+
+use super::generics;
+use crate::{
+    errors,
+    forex_rate_snapshot::{ForexRateSnapshot, ForexRateSnapshotNew, ForexRateSnapshotUpdate},
+    schema::forex_rate_snapshot::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ForexRateSnapshotNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ForexRateSnapshot> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ForexRateSnapshot {
+    #[instrument(skip(conn))]
+    pub async fn update_by_rate_date(
+        conn: &PgPooledConn,
+        rate_date: Date,
+        snapshot: ForexRateSnapshotUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn, rate_date, snapshot,
+        )
+        .await
+    }
+
+    /// Finds the latest snapshot taken on or before `rate_date`
+    #[instrument(skip(conn))]
+    pub async fn find_latest_on_or_before(
+        conn: &PgPooledConn,
+        rate_date: Date,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::rate_date.le(rate_date),
+            Some(1),
+            None,
+            Some(dsl::rate_date.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable(format!("No forex rate snapshot on or before {rate_date}"))
+        })
+    }
+}
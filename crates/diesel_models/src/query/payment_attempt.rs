@@ -56,6 +56,30 @@ impl PaymentAttempt {
         }
     }
 
+    /// Updates the payment attempt only if its status is still the one it was read with, failing
+    /// with [`DatabaseError::NotFound`] otherwise
+    #[instrument(skip(conn))]
+    pub async fn update_with_attempt_id_and_status(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::status.eq(self.status)),
+            PaymentAttemptUpdateInternal::from(payment_attempt),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
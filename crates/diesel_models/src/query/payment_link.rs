@@ -3,7 +3,7 @@ use router_env::{instrument, tracing};
 
 use super::generics;
 use crate::{
-    payment_link::{PaymentLink, PaymentLinkNew},
+    payment_link::{PaymentLink, PaymentLinkNew, PaymentLinkUpdate, PaymentLinkUpdateInternal},
     schema::payment_link::dsl,
     PgPooledConn, StorageResult,
 };
@@ -26,4 +26,23 @@ impl PaymentLink {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_payment_link_id(
+        conn: &PgPooledConn,
+        payment_link_id: &str,
+        payment_link_update: PaymentLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_link_id.eq(payment_link_id.to_owned()),
+            PaymentLinkUpdateInternal::from(payment_link_update),
+        )
+        .await
+    }
 }
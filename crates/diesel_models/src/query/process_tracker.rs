@@ -1,8 +1,13 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
-use super::generics;
+use super::generics::{self, db_metrics};
 use crate::{
     enums, errors,
     process_tracker::{
@@ -136,4 +141,45 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_filters(
+        conn: &PgPooledConn,
+        runner: Option<String>,
+        status: Option<enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(runner) = runner {
+            filter = filter.filter(dsl::runner.eq(runner));
+        }
+        if let Some(status) = status {
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(business_status) = business_status {
+            filter = filter.filter(dsl::business_status.eq(business_status));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering processes by specified constraints")
+    }
 }
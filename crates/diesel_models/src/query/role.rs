@@ -0,0 +1,83 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::tracing::{self, instrument};
+
+use crate::{
+    enums::RoleScope, query::generics, role::*, schema::roles::dsl, PgPooledConn, StorageResult,
+};
+
+impl RoleNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Role> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Role {
+    pub async fn find_by_role_id(conn: &PgPooledConn, role_id: String) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::role_id.eq(role_id),
+        )
+        .await
+    }
+
+    /// Finds a role which can be assigned within the merchant, either because it was created by
+    /// the merchant or because it is scoped to the organization of the merchant
+    pub async fn find_by_role_id_in_merchant_scope(
+        conn: &PgPooledConn,
+        role_id: String,
+        merchant_id: String,
+        org_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::role_id.eq(role_id).and(
+                dsl::merchant_id.eq(merchant_id).or(dsl::org_id
+                    .eq(org_id)
+                    .and(dsl::scope.eq(RoleScope::Organization))),
+            ),
+        )
+        .await
+    }
+
+    pub async fn update_by_role_id(
+        conn: &PgPooledConn,
+        role_id: String,
+        role_update: RoleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::role_id.eq(role_id),
+            RoleUpdateInternal::from(role_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_role_id(conn: &PgPooledConn, role_id: String) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::role_id.eq(role_id))
+            .await
+    }
+
+    /// Lists the roles which can be assigned within the merchant
+    pub async fn list_roles(
+        conn: &PgPooledConn,
+        merchant_id: String,
+        org_id: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id).or(dsl::org_id
+                .eq(org_id)
+                .and(dsl::scope.eq(RoleScope::Organization))),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
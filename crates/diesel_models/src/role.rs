@@ -0,0 +1,74 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums, schema::roles};
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Identifiable, Queryable)]
+#[diesel(table_name = roles)]
+pub struct Role {
+    pub id: i32,
+    pub role_name: String,
+    pub role_id: String,
+    pub merchant_id: String,
+    pub org_id: String,
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub permissions: Vec<String>,
+    pub scope: enums::RoleScope,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    pub created_by: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+}
+
+#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = roles)]
+pub struct RoleNew {
+    pub role_name: String,
+    pub role_id: String,
+    pub merchant_id: String,
+    pub org_id: String,
+    pub permissions: Vec<String>,
+    pub scope: enums::RoleScope,
+    pub created_at: PrimitiveDateTime,
+    pub created_by: String,
+    pub last_modified_at: PrimitiveDateTime,
+    pub last_modified_by: String,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = roles)]
+pub struct RoleUpdateInternal {
+    role_name: Option<String>,
+    permissions: Option<Vec<String>>,
+    last_modified_by: String,
+    last_modified_at: PrimitiveDateTime,
+}
+
+pub enum RoleUpdate {
+    UpdateDetails {
+        role_name: Option<String>,
+        permissions: Option<Vec<String>>,
+        last_modified_by: String,
+    },
+}
+
+impl From<RoleUpdate> for RoleUpdateInternal {
+    fn from(value: RoleUpdate) -> Self {
+        let last_modified_at = common_utils::date_time::now();
+        match value {
+            RoleUpdate::UpdateDetails {
+                role_name,
+                permissions,
+                last_modified_by,
+            } => Self {
+                role_name,
+                permissions,
+                last_modified_by,
+                last_modified_at,
+            },
+        }
+    }
+}
@@ -54,6 +54,44 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        ip_allowlist -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 64]
+        rotated_from -> Nullable<Varchar>,
+        #[max_length = 64]
+        rotated_to -> Nullable<Varchar>,
+        original_expires_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (id) {
+        id -> Int4,
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        actor_type -> Varchar,
+        #[max_length = 255]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        action -> Varchar,
+        #[max_length = 64]
+        resource_type -> Varchar,
+        #[max_length = 255]
+        resource_id -> Varchar,
+        diff -> Jsonb,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamp,
     }
 }
 
@@ -83,6 +121,9 @@ diesel::table! {
         payout_routing_algorithm -> Nullable<Jsonb>,
         is_recon_enabled -> Bool,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        delayed_capture_hours -> Nullable<Int4>,
+        email_settings -> Nullable<Jsonb>,
+        dispute_settings -> Nullable<Jsonb>,
     }
 }
 
@@ -118,6 +159,7 @@ diesel::table! {
         capture_sequence -> Int2,
         #[max_length = 128]
         connector_response_reference_id -> Nullable<Varchar>,
+        final_capture -> Bool,
     }
 }
 
@@ -267,6 +309,9 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        content -> Nullable<Jsonb>,
     }
 }
 
@@ -299,6 +344,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    forex_rate_snapshot (rate_date) {
+        rate_date -> Date,
+        #[max_length = 64]
+        provider -> Varchar,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        rates_timestamp -> Int8,
+        created_at -> Timestamp,
+        last_modified -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -544,6 +605,10 @@ diesel::table! {
         #[max_length = 32]
         org_id -> Varchar,
         org_name -> Nullable<Text>,
+        enforce_totp -> Bool,
+        sso_config -> Nullable<Jsonb>,
+        sso_client_secret -> Nullable<Bytea>,
+        enforce_sso -> Bool,
     }
 }
 
@@ -617,6 +682,7 @@ diesel::table! {
         unified_code -> Nullable<Varchar>,
         #[max_length = 1024]
         unified_message -> Nullable<Varchar>,
+        fx_quote -> Nullable<Jsonb>,
     }
 }
 
@@ -680,6 +746,9 @@ diesel::table! {
         surcharge_applicable -> Nullable<Bool>,
         request_incremental_authorization -> RequestIncrementalAuthorization,
         incremental_authorization_allowed -> Nullable<Bool>,
+        presentment_currency -> Nullable<Currency>,
+        presentment_amount -> Nullable<Int8>,
+        fx_quote -> Nullable<Jsonb>,
     }
 }
 
@@ -706,6 +775,21 @@ diesel::table! {
         payment_link_config -> Nullable<Jsonb>,
         #[max_length = 255]
         description -> Nullable<Varchar>,
+        #[max_length = 32]
+        link_type -> Varchar,
+        #[max_length = 32]
+        amount_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        custom_domain -> Nullable<Varchar>,
+        #[max_length = 32]
+        locale -> Nullable<Varchar>,
+        #[max_length = 255]
+        success_url -> Nullable<Varchar>,
+        #[max_length = 255]
+        failure_url -> Nullable<Varchar>,
+        tax_amount -> Nullable<Int8>,
     }
 }
 
@@ -895,6 +979,8 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        capture_id -> Nullable<Varchar>,
     }
 }
 
@@ -916,6 +1002,32 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    roles (id) {
+        id -> Int4,
+        #[max_length = 64]
+        role_name -> Varchar,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        org_id -> Varchar,
+        permissions -> Array<Nullable<Text>>,
+        #[max_length = 64]
+        scope -> Varchar,
+        created_at -> Timestamp,
+        #[max_length = 64]
+        created_by -> Varchar,
+        last_modified_at -> Timestamp,
+        #[max_length = 64]
+        last_modified_by -> Varchar,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -980,12 +1092,17 @@ diesel::table! {
         is_verified -> Bool,
         created_at -> Timestamp,
         last_modified_at -> Timestamp,
+        #[max_length = 64]
+        totp_status -> Varchar,
+        totp_secret -> Nullable<Bytea>,
+        totp_recovery_codes -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_log,
     business_profile,
     captures,
     cards_info,
@@ -995,6 +1112,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     dispute,
     events,
     file_metadata,
+    forex_rate_snapshot,
     fraud_check,
     gateway_status_map,
     locker_mock_up,
@@ -1012,6 +1130,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     process_tracker,
     refund,
     reverse_lookup,
+    roles,
     routing_algorithm,
     user_roles,
     users,
@@ -3,7 +3,7 @@ use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
-use crate::schema::users;
+use crate::{encryption::Encryption, enums, schema::users};
 
 pub mod dashboard_metadata;
 
@@ -19,6 +19,10 @@ pub struct User {
     pub is_verified: bool,
     pub created_at: PrimitiveDateTime,
     pub last_modified_at: PrimitiveDateTime,
+    pub totp_status: enums::TotpStatus,
+    pub totp_secret: Option<Encryption>,
+    #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
+    pub totp_recovery_codes: Option<Vec<String>>,
 }
 
 #[derive(
@@ -42,6 +46,9 @@ pub struct UserUpdateInternal {
     password: Option<Secret<String>>,
     is_verified: Option<bool>,
     last_modified_at: PrimitiveDateTime,
+    totp_status: Option<enums::TotpStatus>,
+    totp_secret: Option<Option<Encryption>>,
+    totp_recovery_codes: Option<Option<Vec<String>>>,
 }
 
 #[derive(Debug)]
@@ -52,6 +59,12 @@ pub enum UserUpdate {
         password: Option<Secret<String>>,
         is_verified: Option<bool>,
     },
+    TotpUpdate {
+        totp_status: Option<enums::TotpStatus>,
+        totp_secret: Option<Encryption>,
+        totp_recovery_codes: Option<Vec<String>>,
+    },
+    TotpReset,
 }
 
 impl From<UserUpdate> for UserUpdateInternal {
@@ -63,6 +76,9 @@ impl From<UserUpdate> for UserUpdateInternal {
                 password: None,
                 is_verified: Some(true),
                 last_modified_at,
+                totp_status: None,
+                totp_secret: None,
+                totp_recovery_codes: None,
             },
             UserUpdate::AccountUpdate {
                 name,
@@ -73,6 +89,31 @@ impl From<UserUpdate> for UserUpdateInternal {
                 password,
                 is_verified,
                 last_modified_at,
+                totp_status: None,
+                totp_secret: None,
+                totp_recovery_codes: None,
+            },
+            UserUpdate::TotpUpdate {
+                totp_status,
+                totp_secret,
+                totp_recovery_codes,
+            } => Self {
+                name: None,
+                password: None,
+                is_verified: None,
+                last_modified_at,
+                totp_status,
+                totp_secret: totp_secret.map(Some),
+                totp_recovery_codes: totp_recovery_codes.map(Some),
+            },
+            UserUpdate::TotpReset => Self {
+                name: None,
+                password: None,
+                is_verified: None,
+                last_modified_at,
+                totp_status: Some(enums::TotpStatus::NotSet),
+                totp_secret: Some(None),
+                totp_recovery_codes: Some(None),
             },
         }
     }
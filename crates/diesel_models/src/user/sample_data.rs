@@ -114,6 +114,7 @@ impl PaymentAttemptBatchNew {
             encoded_data: self.encoded_data,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            fx_quote: None,
         }
     }
 }
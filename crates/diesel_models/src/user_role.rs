@@ -1,11 +1,13 @@
+use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
 use crate::{enums, schema::user_roles};
 
-#[derive(Clone, Debug, Identifiable, Queryable)]
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize)]
 #[diesel(table_name = user_roles)]
 pub struct UserRole {
+    #[serde(skip_serializing)]
     pub id: i32,
     pub user_id: String,
     pub merchant_id: String,
@@ -14,7 +16,9 @@ pub struct UserRole {
     pub status: enums::UserStatus,
     pub created_by: String,
     pub last_modified_by: String,
+    #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
     pub last_modified_at: PrimitiveDateTime,
 }
 
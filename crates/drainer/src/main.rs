@@ -11,6 +11,11 @@ async fn main() -> DrainerResult<()> {
     #[allow(clippy::expect_used)]
     conf.validate()
         .expect("Failed to validate drainer configuration");
+    #[allow(clippy::expect_used)]
+    let conf = conf
+        .decrypt_secrets()
+        .await
+        .expect("Failed to decrypt the drainer configuration secrets");
 
     let store = services::Store::new(&conf, false).await;
     let store = std::sync::Arc::new(store);
@@ -37,6 +37,6 @@ impl Store {
 
     pub fn drainer_stream(&self, shard_key: &str) -> String {
         // Example: {shard_5}_drainer_stream
-        format!("{{{}}}_{}", shard_key, self.config.drainer_stream_name,)
+        redis_interface::hash_tagged_key(shard_key, &self.config.drainer_stream_name)
     }
 }
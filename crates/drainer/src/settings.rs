@@ -4,6 +4,7 @@ use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
 #[cfg(feature = "kms")]
 use external_services::kms;
+use external_services::secrets_management::SecretsManagementConfig;
 use redis_interface as redis;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use router_env::{env, logger};
@@ -34,6 +35,7 @@ pub struct Settings {
     pub drainer: DrainerSettings,
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
+    pub secrets_management: SecretsManagementConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -153,7 +155,8 @@ impl Settings {
                     .try_parsing(true)
                     .separator("__")
                     .list_separator(",")
-                    .with_list_parse_key("redis.cluster_urls"),
+                    .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls"),
             )
             .build()?;
 
@@ -171,7 +174,40 @@ impl Settings {
             errors::DrainerError::ConfigParsingError("invalid Redis configuration".into())
         })?;
         self.drainer.validate()?;
+        self.secrets_management
+            .validate()
+            .map_err(|error| errors::DrainerError::ConfigParsingError(error.into()))?;
 
         Ok(())
     }
+
+    /// Decrypts the database password with the secret manager selected in the
+    /// `secrets_management` section. Builds with the `kms` feature decrypt the KMS encrypted
+    /// password with the `kms` configuration when connecting to the database instead.
+    pub async fn decrypt_secrets(
+        #[cfg_attr(feature = "kms", allow(unused_mut))] mut self,
+    ) -> errors::DrainerResult<Self> {
+        use error_stack::ResultExt;
+
+        #[cfg_attr(feature = "kms", allow(unused_variables))]
+        let secret_management_client = self
+            .secrets_management
+            .get_secret_management_client()
+            .await
+            .change_context(errors::DrainerError::UnexpectedError(
+                "failed to create the secrets management client".into(),
+            ))?;
+
+        #[cfg(not(feature = "kms"))]
+        {
+            self.master_database.password = secret_management_client
+                .get_secret(self.master_database.password)
+                .await
+                .change_context(errors::DrainerError::UnexpectedError(
+                    "failed to decrypt the database password".into(),
+                ))?;
+        }
+
+        Ok(self)
+    }
 }
@@ -7,6 +7,9 @@ use serde::Deserialize;
 /// Implementation of aws ses client
 pub mod ses;
 
+/// Implementation of smtp client
+pub mod smtp;
+
 /// Custom Result type alias for Email operations.
 pub type EmailResult<T> = CustomResult<T, EmailError>;
 
@@ -111,6 +114,7 @@ pub trait EmailData {
 }
 
 dyn_clone::clone_trait_object!(EmailClient<RichText = Body>);
+dyn_clone::clone_trait_object!(EmailClient<RichText = String>);
 
 /// List of available email clients to choose from
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -118,6 +122,9 @@ pub enum AvailableEmailClients {
     #[default]
     /// AWS ses email client
     SES,
+
+    /// SMTP server, such as a self hosted mail server or a local mail sink
+    SMTP,
 }
 
 /// Struct that contains the settings required to construct an EmailClient.
@@ -138,10 +145,27 @@ pub struct EmailSettings {
     /// Configs related to AWS Simple Email Service
     pub aws_ses: Option<ses::SESConfig>,
 
+    /// Configs related to the SMTP server
+    pub smtp: Option<smtp::SmtpServerConfig>,
+
     /// The active email client to use
     pub active_email_client: AvailableEmailClients,
 }
 
+impl EmailSettings {
+    /// Verifies that the configuration of the active email client is usable
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self.active_email_client {
+            AvailableEmailClients::SES => Ok(()),
+            AvailableEmailClients::SMTP => self
+                .smtp
+                .as_ref()
+                .ok_or("SMTP configuration is required when the active email client is SMTP")?
+                .validate(),
+        }
+    }
+}
+
 /// Errors that could occur from EmailClient.
 #[derive(Debug, thiserror::Error)]
 pub enum EmailError {
@@ -9,8 +9,9 @@ pub mod email;
 #[cfg(feature = "kms")]
 pub mod kms;
 
+pub mod secrets_management;
+
 /// Crate specific constants
-#[cfg(feature = "kms")]
 pub mod consts {
     /// General purpose base64 engine
     pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
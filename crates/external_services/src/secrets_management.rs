@@ -0,0 +1,152 @@
+//! Interactions with the secret managers used to decrypt the secrets held in the application
+//! configuration
+
+#[cfg(feature = "kms")]
+mod aws_kms;
+pub mod hashicorp_vault;
+pub mod local_keyfile;
+pub mod no_encryption;
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+#[cfg(feature = "kms")]
+use crate::kms;
+
+/// Trait defining the interface for decrypting the secrets held in the application configuration
+#[async_trait::async_trait]
+pub trait SecretManagementInterface: Send + Sync + std::fmt::Debug {
+    /// Decrypts the provided encrypted secret
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError>;
+}
+
+/// The secret manager used to decrypt the secrets held in the application configuration. Builds
+/// with the `kms` feature keep decrypting the secrets typed as KMS values, and the other secrets
+/// they have always decrypted with AWS KMS, through the `kms` configuration where they are used,
+/// so the secret manager only decrypts the remaining secrets in those builds.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "secrets_manager", rename_all = "snake_case")]
+pub enum SecretsManagementConfig {
+    /// AWS KMS, where the secrets are base64 encoded KMS ciphertexts. Requires the `kms` feature.
+    #[cfg(feature = "kms")]
+    AwsKms {
+        /// Configuration of the AWS KMS client
+        aws_kms: kms::KmsConfig,
+    },
+
+    /// A HashiCorp Vault compatible transit secrets engine, where the secrets are transit
+    /// ciphertexts of the form `vault:v1:...`. Requires the `hashicorp-vault` feature.
+    HashicorpVault {
+        /// Configuration of the HashiCorp Vault client
+        hc_vault: hashicorp_vault::HashiCorpVaultConfig,
+    },
+
+    /// A key stored in a local file, where the secrets are base64 encoded AES-256-GCM ciphertexts
+    LocalKeyfile {
+        /// Configuration of the local keyfile
+        local_keyfile: local_keyfile::LocalKeyfileConfig,
+    },
+
+    /// The secrets are held in plaintext
+    #[default]
+    NoEncryption,
+}
+
+impl SecretsManagementConfig {
+    /// Verifies that the secret manager configuration is usable with the features the
+    /// application was built with
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms { aws_kms } => aws_kms.validate(),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault { hc_vault } => hc_vault.validate(),
+            #[cfg(not(feature = "hashicorp-vault"))]
+            Self::HashicorpVault { .. } => {
+                Err("The hashicorp_vault secrets manager requires the hashicorp-vault feature")
+            }
+            Self::LocalKeyfile { local_keyfile } => local_keyfile.validate(),
+            Self::NoEncryption => Ok(()),
+        }
+    }
+
+    /// Constructs the client of the configured secret manager, failing if the secret manager
+    /// cannot be reached
+    pub async fn get_secret_management_client(
+        &self,
+    ) -> CustomResult<Box<dyn SecretManagementInterface>, SecretsManagementError> {
+        match self {
+            #[cfg(feature = "kms")]
+            Self::AwsKms { aws_kms } => Ok(Box::new(kms::KmsClient::new(aws_kms).await)),
+            #[cfg(feature = "hashicorp-vault")]
+            Self::HashicorpVault { hc_vault } => Ok(Box::new(
+                hashicorp_vault::HashiCorpVault::connect(hc_vault).await?,
+            )),
+            #[cfg(not(feature = "hashicorp-vault"))]
+            Self::HashicorpVault { .. } => Err(error_stack::report!(
+                SecretsManagementError::ClientCreationFailed
+            )
+            .attach_printable(
+                "The hashicorp_vault secrets manager requires the hashicorp-vault feature",
+            )),
+            Self::LocalKeyfile { local_keyfile } => {
+                Ok(Box::new(local_keyfile::LocalKeyfile::new(local_keyfile)?))
+            }
+            Self::NoEncryption => Ok(Box::new(no_encryption::NoEncryption)),
+        }
+    }
+}
+
+/// Errors that could occur during secret management operations
+#[derive(Debug, thiserror::Error)]
+pub enum SecretsManagementError {
+    /// The secret manager client could not be constructed
+    #[error("Failed to create the secrets management client")]
+    ClientCreationFailed,
+
+    /// The secret could not be fetched or decrypted
+    #[error("Failed to fetch the secret from the secrets manager")]
+    FetchSecretFailed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_encryption_is_always_usable() {
+        assert!(SecretsManagementConfig::NoEncryption.validate().is_ok());
+    }
+
+    #[cfg(not(feature = "hashicorp-vault"))]
+    #[tokio::test]
+    async fn test_hashicorp_vault_requires_feature() {
+        let config = SecretsManagementConfig::HashicorpVault {
+            hc_vault: hashicorp_vault::HashiCorpVaultConfig {
+                url: "http://127.0.0.1:8200".to_string(),
+                token: Secret::new("hvs.test-token".to_string()),
+                key_name: "hyperswitch".to_string(),
+                ..Default::default()
+            },
+        };
+
+        assert!(config.validate().is_err());
+        assert!(config.get_secret_management_client().await.is_err());
+    }
+
+    #[cfg(feature = "kms")]
+    #[test]
+    fn test_aws_kms_requires_key_id() {
+        let config = SecretsManagementConfig::AwsKms {
+            aws_kms: kms::KmsConfig {
+                region: "us-east-1".to_string(),
+                ..Default::default()
+            },
+        };
+
+        assert!(config.validate().is_err());
+    }
+}
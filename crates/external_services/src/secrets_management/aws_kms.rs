@@ -0,0 +1,19 @@
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use super::{SecretManagementInterface, SecretsManagementError};
+use crate::kms::KmsClient;
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for KmsClient {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        self.decrypt(input.peek())
+            .await
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .map(Secret::new)
+    }
+}
@@ -0,0 +1,308 @@
+//! Secret manager backed by a HashiCorp Vault compatible transit secrets engine
+
+#[cfg(feature = "hashicorp-vault")]
+use base64::Engine;
+#[cfg(feature = "hashicorp-vault")]
+use common_utils::errors::CustomResult;
+#[cfg(feature = "hashicorp-vault")]
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "hashicorp-vault")]
+use masking::ExposeInterface;
+use masking::{PeekInterface, Secret};
+#[cfg(feature = "hashicorp-vault")]
+use router_env::logger;
+
+#[cfg(feature = "hashicorp-vault")]
+use super::{SecretManagementInterface, SecretsManagementError};
+#[cfg(feature = "hashicorp-vault")]
+use crate::consts;
+
+/// Header carrying the token used to authenticate with Vault
+#[cfg(feature = "hashicorp-vault")]
+const VAULT_TOKEN_HEADER: &str = "X-Vault-Token";
+
+/// Configuration parameters required for constructing a [`HashiCorpVault`]
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct HashiCorpVaultConfig {
+    /// Base URL of the Vault server, for example `http://127.0.0.1:8200`
+    pub url: String,
+
+    /// Token used to authenticate with the Vault server
+    pub token: Secret<String>,
+
+    /// Path at which the transit secrets engine is mounted
+    pub mount_path: String,
+
+    /// Name of the transit key the secrets were encrypted with
+    pub key_name: String,
+
+    /// Timeout for the requests made to the Vault server, in seconds
+    pub timeout: u64,
+}
+
+impl Default for HashiCorpVaultConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            token: Secret::default(),
+            mount_path: "transit".to_string(),
+            key_name: String::new(),
+            timeout: 10,
+        }
+    }
+}
+
+impl HashiCorpVaultConfig {
+    /// Verifies that the [`HashiCorpVault`] configuration is usable
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.url.is_default_or_empty(), || {
+            Err("HashiCorp Vault URL must not be empty")
+        })?;
+
+        when(self.token.peek().is_default_or_empty(), || {
+            Err("HashiCorp Vault token must not be empty")
+        })?;
+
+        when(self.mount_path.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit mount path must not be empty")
+        })?;
+
+        when(self.key_name.is_default_or_empty(), || {
+            Err("HashiCorp Vault transit key name must not be empty")
+        })
+    }
+}
+
+/// Client for decrypting secrets with the Vault transit secrets engine
+#[cfg(feature = "hashicorp-vault")]
+#[derive(Debug, Clone)]
+pub struct HashiCorpVault {
+    client: reqwest::Client,
+    decrypt_url: String,
+    token: Secret<String>,
+}
+
+#[cfg(feature = "hashicorp-vault")]
+#[derive(serde::Serialize)]
+struct TransitDecryptRequest<'a> {
+    ciphertext: &'a str,
+}
+
+#[cfg(feature = "hashicorp-vault")]
+#[derive(serde::Deserialize)]
+struct TransitDecryptResponse {
+    data: TransitDecryptResponseData,
+}
+
+#[cfg(feature = "hashicorp-vault")]
+#[derive(serde::Deserialize)]
+struct TransitDecryptResponseData {
+    plaintext: Secret<String>,
+}
+
+#[cfg(feature = "hashicorp-vault")]
+impl HashiCorpVault {
+    /// Constructs a new Vault transit client, after checking that the transit key can be read with
+    /// the configured token
+    pub async fn connect(
+        config: &HashiCorpVaultConfig,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(config.timeout))
+            .build()
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Failed to construct the HTTP client for HashiCorp Vault")?;
+
+        let base_url = format!(
+            "{}/v1/{}",
+            config.url.trim_end_matches('/'),
+            config.mount_path.trim_matches('/')
+        );
+
+        client
+            .get(format!("{base_url}/keys/{}", config.key_name))
+            .header(VAULT_TOKEN_HEADER, config.token.peek())
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|error| {
+                logger::error!(hashicorp_vault_error=?error, "Failed to read the transit key");
+                error
+            })
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Failed to read the transit key from HashiCorp Vault")?;
+
+        Ok(Self {
+            client,
+            decrypt_url: format!("{base_url}/decrypt/{}", config.key_name),
+            token: config.token.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "hashicorp-vault")]
+#[async_trait::async_trait]
+impl SecretManagementInterface for HashiCorpVault {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let response = self
+            .client
+            .post(&self.decrypt_url)
+            .header(VAULT_TOKEN_HEADER, self.token.peek())
+            .json(&TransitDecryptRequest {
+                ciphertext: input.peek(),
+            })
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|error| {
+                logger::error!(hashicorp_vault_error=?error, "Failed to decrypt the secret");
+                error
+            })
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)?
+            .json::<TransitDecryptResponse>()
+            .await
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to deserialize the HashiCorp Vault response")?;
+
+        let plaintext = consts::BASE64_ENGINE
+            .decode(response.data.plaintext.expose())
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to base64 decode the decrypted secret")?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to UTF-8 decode the decrypted secret")
+            .map(Secret::new)
+    }
+}
+
+#[cfg(all(test, feature = "hashicorp-vault"))]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use wiremock::{
+        matchers::{body_json, header, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    use super::*;
+
+    const TOKEN: &str = "hvs.test-token";
+
+    fn get_config(url: String) -> HashiCorpVaultConfig {
+        HashiCorpVaultConfig {
+            url,
+            token: Secret::new(TOKEN.to_string()),
+            key_name: "hyperswitch".to_string(),
+            ..Default::default()
+        }
+    }
+
+    async fn mock_transit_key(server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/hyperswitch"))
+            .and(header(VAULT_TOKEN_HEADER, TOKEN))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "name": "hyperswitch", "type": "aes256-gcm96" }
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_vault_decrypts_transit_ciphertext() {
+        let server = MockServer::start().await;
+        mock_transit_key(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/decrypt/hyperswitch"))
+            .and(header(VAULT_TOKEN_HEADER, TOKEN))
+            .and(body_json(
+                serde_json::json!({ "ciphertext": "vault:v1:abcd" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "plaintext": consts::BASE64_ENGINE.encode("database_password") }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = HashiCorpVault::connect(&get_config(server.uri()))
+            .await
+            .expect("Failed to connect to HashiCorp Vault");
+        let secret = client
+            .get_secret(Secret::new("vault:v1:abcd".to_string()))
+            .await
+            .expect("Failed to decrypt the secret");
+
+        assert_eq!(secret.expose(), "database_password");
+    }
+
+    #[tokio::test]
+    async fn test_vault_connection_fails_without_transit_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/v1/transit/keys/hyperswitch"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        assert!(HashiCorpVault::connect(&get_config(server.uri()))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_vault_connection_fails_when_unreachable() {
+        // Nothing listens on the port once the listener is dropped
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        assert!(HashiCorpVault::connect(&get_config(url)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_vault_rejected_decryption_fails() {
+        let server = MockServer::start().await;
+        mock_transit_key(&server).await;
+        Mock::given(method("POST"))
+            .and(path("/v1/transit/decrypt/hyperswitch"))
+            .respond_with(ResponseTemplate::new(403))
+            .mount(&server)
+            .await;
+
+        let client = HashiCorpVault::connect(&get_config(server.uri()))
+            .await
+            .unwrap();
+
+        assert!(client
+            .get_secret(Secret::new("vault:v1:abcd".to_string()))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_vault_config_validation() {
+        assert!(get_config("http://127.0.0.1:8200".to_string())
+            .validate()
+            .is_ok());
+        assert!(get_config(String::new()).validate().is_err());
+        assert!(HashiCorpVaultConfig {
+            key_name: String::new(),
+            ..get_config("http://127.0.0.1:8200".to_string())
+        }
+        .validate()
+        .is_err());
+    }
+}
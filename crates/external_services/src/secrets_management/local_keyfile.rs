@@ -0,0 +1,140 @@
+//! Secret manager backed by an AES-256-GCM key stored in a local file
+
+use std::path::PathBuf;
+
+use base64::Engine;
+use common_utils::{
+    crypto::{DecodeMessage, GcmAes256},
+    errors::CustomResult,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+use super::{SecretManagementInterface, SecretsManagementError};
+use crate::consts;
+
+/// Configuration parameters required for constructing a [`LocalKeyfile`]
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct LocalKeyfileConfig {
+    /// Path of the file holding the hex encoded 32 byte key
+    pub key_file_path: PathBuf,
+}
+
+impl LocalKeyfileConfig {
+    /// Verifies that the [`LocalKeyfile`] configuration is usable
+    pub fn validate(&self) -> Result<(), &'static str> {
+        common_utils::fp_utils::when(self.key_file_path.as_os_str().is_empty(), || {
+            Err("Local keyfile path must not be empty")
+        })
+    }
+}
+
+/// Secret manager decrypting the base64 encoded AES-256-GCM ciphertexts with a local key
+#[derive(Clone)]
+pub struct LocalKeyfile {
+    key: Secret<Vec<u8>>,
+}
+
+impl std::fmt::Debug for LocalKeyfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalKeyfile").finish_non_exhaustive()
+    }
+}
+
+impl LocalKeyfile {
+    /// Reads the key from the configured key file
+    pub fn new(config: &LocalKeyfileConfig) -> CustomResult<Self, SecretsManagementError> {
+        let encoded_key = std::fs::read_to_string(&config.key_file_path)
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Failed to read the local key file")?;
+
+        Self::from_hex_key(encoded_key.trim())
+    }
+
+    /// Constructs the secret manager from a hex encoded 32 byte key
+    pub fn from_hex_key(encoded_key: &str) -> CustomResult<Self, SecretsManagementError> {
+        let key = hex::decode(encoded_key)
+            .into_report()
+            .change_context(SecretsManagementError::ClientCreationFailed)
+            .attach_printable("Failed to hex decode the local key")?;
+
+        if key.len() != 32 {
+            return Err(SecretsManagementError::ClientCreationFailed)
+                .into_report()
+                .attach_printable("The local key must be 32 bytes long");
+        }
+
+        Ok(Self {
+            key: Secret::new(key),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretManagementInterface for LocalKeyfile {
+    async fn get_secret(
+        &self,
+        input: Secret<String>,
+    ) -> CustomResult<Secret<String>, SecretsManagementError> {
+        let ciphertext = consts::BASE64_ENGINE
+            .decode(input.peek())
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to base64 decode the secret")?;
+
+        let plaintext = GcmAes256
+            .decode_message(self.key.peek(), ciphertext.into())
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to decrypt the secret")?;
+
+        String::from_utf8(plaintext)
+            .into_report()
+            .change_context(SecretsManagementError::FetchSecretFailed)
+            .attach_printable("Failed to UTF-8 decode the decrypted secret")
+            .map(Secret::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use common_utils::crypto::EncodeMessage;
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    const KEY: &str = "73ad7bbbbc640c845a150f67d058b279849370cd2c1f3c67c4dd6c869213e13a";
+
+    #[tokio::test]
+    async fn test_local_keyfile_decryption() {
+        let client = LocalKeyfile::from_hex_key(KEY).unwrap();
+        let ciphertext = GcmAes256
+            .encode_message(&hex::decode(KEY).unwrap(), b"database_password")
+            .unwrap();
+
+        let secret = client
+            .get_secret(Secret::new(consts::BASE64_ENGINE.encode(ciphertext)))
+            .await
+            .expect("Failed to decrypt the secret");
+
+        assert_eq!(secret.expose(), "database_password");
+    }
+
+    #[tokio::test]
+    async fn test_local_keyfile_rejects_plaintext() {
+        let client = LocalKeyfile::from_hex_key(KEY).unwrap();
+
+        assert!(client
+            .get_secret(Secret::new("database_password".to_string()))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_local_keyfile_rejects_short_key() {
+        assert!(LocalKeyfile::from_hex_key("73ad7bbb").is_err());
+    }
+}
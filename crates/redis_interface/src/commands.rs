@@ -15,19 +15,23 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, LuaInterface, PubsubInterface, StreamsInterface},
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
-        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
+        RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XReadResponse,
     },
 };
-use futures::StreamExt;
+use futures::{stream::BoxStream, StreamExt};
 use router_env::{instrument, logger, tracing};
+use tokio::sync::broadcast::error::RecvError;
 
 use crate::{
     errors,
-    types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SetnxReply},
+    types::{
+        DelReply, HsetnxReply, MsetnxReply, PubSubMessage, RedisEntryId, SetnxReply, StreamCapKind,
+        StreamCapTrim, StreamGroupInfo,
+    },
 };
 
 impl super::RedisConnectionPool {
@@ -37,6 +41,21 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisValue> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return value
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|value| {
+                    client
+                        .store()
+                        .set(key, value, Some(self.config.default_ttl.into()), false)
+                        .map(|_| ())
+                })
+                .into_report()
+                .change_context(errors::RedisError::SetFailed);
+        }
+
         self.pool
             .set(
                 key,
@@ -58,6 +77,24 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisMap> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        let value: RedisMap = value
+            .try_into()
+            .map_err(Into::into)
+            .into_report()
+            .change_context(errors::RedisError::SetFailed)?;
+        let value = value.inner();
+        self.ensure_same_slot(value.keys())?;
+
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .set_multiple_if_not_exist(value)
+                .and_then(MsetnxReply::from_value)
+                .into_report()
+                .change_context(errors::RedisError::SetFailed);
+        }
+
         self.pool
             .msetnx(value)
             .await
@@ -65,6 +102,24 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    /// Commands operating on multiple keys are rejected by a cluster unless all the keys map to
+    /// the same hash slot, which can be ensured by building the keys with
+    /// [`hash_tagged_key`](crate::hash_tagged_key).
+    fn ensure_same_slot<'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a RedisKey>,
+    ) -> CustomResult<(), errors::RedisError> {
+        if !self.config.cluster_enabled {
+            return Ok(());
+        }
+
+        let mut slots = keys.into_iter().map(RedisKey::cluster_hash);
+        let first_slot = slots.next();
+        fp_utils::when(slots.any(|slot| Some(slot) != first_slot), || {
+            Err(errors::RedisError::CrossSlotKeys).into_report()
+        })
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn serialize_and_set_key_if_not_exist<V>(
         &self,
@@ -109,6 +164,16 @@ impl super::RedisConnectionPool {
         let serialized = Encode::<V>::encode_to_vec(&value)
             .change_context(errors::RedisError::JsonSerializationFailed)?;
 
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .set(key, serialized.into(), Some(seconds), false)
+                .map(|_| ())
+                .into_report()
+                .change_context(errors::RedisError::SetExFailed);
+        }
+
         self.pool
             .set(
                 key,
@@ -127,6 +192,16 @@ impl super::RedisConnectionPool {
     where
         V: FromRedis + Unpin + Send + 'static,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .get(key)
+                .and_then(V::from_value)
+                .into_report()
+                .change_context(errors::RedisError::GetFailed);
+        }
+
         self.pool
             .get(key)
             .await
@@ -139,6 +214,15 @@ impl super::RedisConnectionPool {
     where
         V: Into<MultipleKeys> + Unpin + Send + 'static,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .exists(key)
+                .into_report()
+                .change_context(errors::RedisError::GetFailed);
+        }
+
         self.pool
             .exists(key)
             .await
@@ -166,6 +250,16 @@ impl super::RedisConnectionPool {
 
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn delete_key(&self, key: &str) -> CustomResult<DelReply, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .delete(key)
+                .and_then(DelReply::from_value)
+                .into_report()
+                .change_context(errors::RedisError::DeleteFailed);
+        }
+
         self.pool
             .del(key)
             .await
@@ -184,6 +278,21 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisValue> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return value
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|value| {
+                    client
+                        .store()
+                        .set(key, value, Some(seconds), false)
+                        .map(|_| ())
+                })
+                .into_report()
+                .change_context(errors::RedisError::SetExFailed);
+        }
+
         self.pool
             .set(key, value, Some(Expiration::EX(seconds)), None, false)
             .await
@@ -202,13 +311,23 @@ impl super::RedisConnectionPool {
         V: TryInto<RedisValue> + Debug + Send + Sync,
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        let seconds = seconds.unwrap_or(self.config.default_ttl.into());
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return value
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|value| client.store().set(key, value, Some(seconds), true))
+                .and_then(SetnxReply::from_value)
+                .into_report()
+                .change_context(errors::RedisError::SetFailed);
+        }
+
         self.pool
             .set(
                 key,
                 value,
-                Some(Expiration::EX(
-                    seconds.unwrap_or(self.config.default_ttl.into()),
-                )),
+                Some(Expiration::EX(seconds)),
                 Some(SetOptions::NX),
                 false,
             )
@@ -223,6 +342,15 @@ impl super::RedisConnectionPool {
         key: &str,
         seconds: i64,
     ) -> CustomResult<(), errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .expire(key, seconds)
+                .into_report()
+                .change_context(errors::RedisError::SetExpiryFailed);
+        }
+
         self.pool
             .expire(key, seconds)
             .await
@@ -230,12 +358,50 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// Increments the counter held at the key and returns its new value. The expiry is only set
+    /// when the counter is created, so that it counts within a fixed window.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .incr(key, seconds)
+                .into_report()
+                .change_context(errors::RedisError::IncrementFailed);
+        }
+
+        let value: i64 = self
+            .pool
+            .incr(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)?;
+        if value == 1 {
+            self.set_expiry(key, seconds).await?;
+        }
+        Ok(value)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expire_at(
         &self,
         key: &str,
         timestamp: i64,
     ) -> CustomResult<(), errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .expire_at(key, timestamp)
+                .into_report()
+                .change_context(errors::RedisError::SetExpiryFailed);
+        }
+
         self.pool
             .expire_at(key, timestamp)
             .await
@@ -255,8 +421,7 @@ impl super::RedisConnectionPool {
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
         let output: Result<(), _> = self
-            .pool
-            .hset(key, values)
+            .raw_hset(key, values)
             .await
             .into_report()
             .change_context(errors::RedisError::SetHashFailed);
@@ -281,8 +446,7 @@ impl super::RedisConnectionPool {
         V::Error: Into<fred::error::RedisError> + Send + Sync,
     {
         let output: Result<HsetnxReply, _> = self
-            .pool
-            .hsetnx(key, field, value)
+            .raw_hsetnx(key, field, value)
             .await
             .into_report()
             .change_context(errors::RedisError::SetHashFieldFailed);
@@ -323,6 +487,19 @@ impl super::RedisConnectionPool {
         V: FromRedis + Unpin + Send + 'static,
         K: Into<MultipleKeys> + Send + Debug,
     {
+        let keys = Into::<MultipleKeys>::into(keys).inner();
+        self.ensure_same_slot(keys.iter())?;
+
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .get_multiple(keys)
+                .and_then(FromRedis::from_value)
+                .into_report()
+                .change_context(errors::RedisError::GetFailed);
+        }
+
         self.pool
             .mget(keys)
             .await
@@ -381,6 +558,16 @@ impl super::RedisConnectionPool {
         pattern: &str,
         count: Option<u32>,
     ) -> CustomResult<Vec<String>, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            // The whole hash is returned at once, `count` is only a hint to the server
+            return client
+                .store()
+                .hscan(key, pattern)
+                .into_report()
+                .change_context(errors::RedisError::GetHashFieldFailed);
+        }
+
         Ok(self
             .pool
             .hscan::<&str, &str>(key, pattern, count)
@@ -433,6 +620,16 @@ impl super::RedisConnectionPool {
     where
         V: FromRedis + Unpin + Send + 'static,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .hget(key, field)
+                .and_then(V::from_value)
+                .into_report()
+                .change_context(errors::RedisError::GetHashFieldFailed);
+        }
+
         self.pool
             .hget(key, field)
             .await
@@ -472,6 +669,18 @@ impl super::RedisConnectionPool {
         F: TryInto<MultipleOrderedPairs> + Debug + Send + Sync,
         F::Error: Into<fred::error::RedisError> + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return fields
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|fields: MultipleOrderedPairs| {
+                    client.store().xadd(stream, entry_id, fields.inner())
+                })
+                .into_report()
+                .change_context(errors::RedisError::StreamAppendFailed);
+        }
+
         self.pool
             .xadd(stream, false, None, entry_id, fields)
             .await
@@ -488,6 +697,22 @@ impl super::RedisConnectionPool {
     where
         Ids: Into<MultipleStrings> + Debug + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xdel(
+                    stream,
+                    Into::<MultipleStrings>::into(ids)
+                        .inner()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                )
+                .into_report()
+                .change_context(errors::RedisError::StreamDeleteFailed);
+        }
+
         self.pool
             .xdel(stream, ids)
             .await
@@ -496,15 +721,22 @@ impl super::RedisConnectionPool {
     }
 
     #[instrument(level = "DEBUG", skip(self))]
-    pub async fn stream_trim_entries<C>(
+    pub async fn stream_trim_entries(
         &self,
         stream: &str,
-        xcap: C,
-    ) -> CustomResult<usize, errors::RedisError>
-    where
-        C: TryInto<XCap> + Debug + Send + Sync,
-        C::Error: Into<fred::error::RedisError> + Send + Sync,
-    {
+        xcap: (StreamCapKind, StreamCapTrim, &str),
+    ) -> CustomResult<usize, errors::RedisError> {
+        // Trimming is always exact for the in-process store
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            let (kind, _trim, threshold) = xcap;
+            return client
+                .store()
+                .xtrim(stream, kind, threshold)
+                .into_report()
+                .change_context(errors::RedisError::StreamTrimFailed);
+        }
+
         self.pool
             .xtrim(stream, xcap)
             .await
@@ -522,6 +754,15 @@ impl super::RedisConnectionPool {
     where
         Ids: Into<MultipleIDs> + Debug + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xack(stream, group, Into::<MultipleIDs>::into(ids).inner())
+                .into_report()
+                .change_context(errors::RedisError::StreamAcknowledgeFailed);
+        }
+
         self.pool
             .xack(stream, group, ids)
             .await
@@ -534,6 +775,15 @@ impl super::RedisConnectionPool {
     where
         K: Into<RedisKey> + Debug + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xlen(&Into::<RedisKey>::into(stream).as_str_lossy())
+                .into_report()
+                .change_context(errors::RedisError::GetLengthFailed);
+        }
+
         self.pool
             .xlen(stream)
             .await
@@ -552,13 +802,27 @@ impl super::RedisConnectionPool {
         K: Into<MultipleKeys> + Debug + Send + Sync,
         Ids: Into<MultipleIDs> + Debug + Send + Sync,
     {
+        let read_count = read_count.unwrap_or(self.config.default_stream_read_count);
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xread(
+                    Into::<MultipleKeys>::into(streams).inner(),
+                    Into::<MultipleIDs>::into(ids).inner(),
+                    Some(read_count),
+                )
+                .into_report()
+                .map_err(|err| match err.current_context().kind() {
+                    RedisErrorKind::NotFound => {
+                        err.change_context(errors::RedisError::StreamEmptyOrNotAvailable)
+                    }
+                    _ => err.change_context(errors::RedisError::StreamReadFailed),
+                });
+        }
+
         self.pool
-            .xread_map(
-                Some(read_count.unwrap_or(self.config.default_stream_read_count)),
-                None,
-                streams,
-                ids,
-            )
+            .xread_map(Some(read_count), None, streams, ids)
             .await
             .into_report()
             .map_err(|err| match err.current_context().kind() {
@@ -582,6 +846,40 @@ impl super::RedisConnectionPool {
         K: Into<MultipleKeys> + Debug + Send + Sync,
         Ids: Into<MultipleIDs> + Debug + Send + Sync,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            // Reads from the in-process store never block
+            let streams = Into::<MultipleKeys>::into(streams).inner();
+            let ids = Into::<MultipleIDs>::into(ids).inner();
+            return match group {
+                Some((group_name, consumer_name)) => {
+                    client
+                        .store()
+                        .xreadgroup(group_name, consumer_name, streams, ids, count)
+                }
+                None => client.store().xread(streams, ids, count).map(|response| {
+                    response
+                        .into_iter()
+                        .map(|(stream, entries)| {
+                            let entries = entries
+                                .into_iter()
+                                .map(|(id, fields)| {
+                                    let fields = fields
+                                        .into_iter()
+                                        .map(|(field, value)| (field, Some(value)))
+                                        .collect();
+                                    (id, fields)
+                                })
+                                .collect();
+                            (stream, entries)
+                        })
+                        .collect()
+                }),
+            }
+            .into_report()
+            .change_context(errors::RedisError::StreamReadFailed);
+        }
+
         match group {
             Some((group_name, consumer_name)) => {
                 self.pool
@@ -611,6 +909,15 @@ impl super::RedisConnectionPool {
             Err(errors::RedisError::InvalidRedisEntryId)?;
         }
 
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xgroup_create(stream, group, id)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupCreateFailed);
+        }
+
         self.pool
             .xgroup_create(stream, group, id, true)
             .await
@@ -624,6 +931,15 @@ impl super::RedisConnectionPool {
         stream: &str,
         group: &str,
     ) -> CustomResult<usize, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xgroup_destroy(stream, group)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupDestroyFailed);
+        }
+
         self.pool
             .xgroup_destroy(stream, group)
             .await
@@ -639,6 +955,15 @@ impl super::RedisConnectionPool {
         group: &str,
         consumer: &str,
     ) -> CustomResult<usize, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xgroup_delconsumer(stream, group, consumer)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupRemoveConsumerFailed);
+        }
+
         self.pool
             .xgroup_delconsumer(stream, group, consumer)
             .await
@@ -653,6 +978,15 @@ impl super::RedisConnectionPool {
         group: &str,
         id: &RedisEntryId,
     ) -> CustomResult<String, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xgroup_setid(stream, group, id)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupSetIdFailed);
+        }
+
         self.pool
             .xgroup_setid(stream, group, id)
             .await
@@ -673,6 +1007,22 @@ impl super::RedisConnectionPool {
         Ids: Into<MultipleIDs> + Debug + Send + Sync,
         R: FromRedis + Unpin + Send + 'static,
     {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xclaim(
+                    stream,
+                    group,
+                    consumer,
+                    min_idle_time,
+                    Into::<MultipleIDs>::into(ids).inner(),
+                )
+                .and_then(R::from_value)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupClaimFailed);
+        }
+
         self.pool
             .xclaim(
                 stream,
@@ -690,6 +1040,157 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_info(
+        &self,
+        stream: &str,
+    ) -> CustomResult<Vec<StreamGroupInfo>, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client
+                .store()
+                .xinfo_groups(stream)
+                .into_report()
+                .change_context(errors::RedisError::ConsumerGroupInfoFailed);
+        }
+
+        self.pool
+            .xinfo_groups(stream)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ConsumerGroupInfoFailed)
+    }
+
+    //                                              Scripting API
+
+    /// Evaluates a Lua script atomically. Scripts are not supported by the in-memory store, so
+    /// callers are expected to have a fallback for when the evaluation fails.
+    #[instrument(level = "DEBUG", skip(self, script))]
+    pub async fn evaluate_script<V>(
+        &self,
+        script: &str,
+        keys: Vec<String>,
+        args: Vec<String>,
+    ) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        #[cfg(feature = "in_memory")]
+        if self.in_memory.is_some() {
+            return Err(errors::RedisError::ScriptEvaluationFailed)
+                .into_report()
+                .attach_printable("Scripts are not supported by the in-memory store");
+        }
+
+        self.pool
+            .eval(script, keys, args)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::ScriptEvaluationFailed)
+    }
+
+    //                                              Pub/Sub API
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn subscribe_to_channel(
+        &self,
+        channel: &str,
+    ) -> CustomResult<(), errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            client.subscribe(channel);
+            return Ok(());
+        }
+
+        // Spawns a task that will automatically re-subscribe to any channels or channel patterns used by the client.
+        self.subscriber.manage_subscriptions();
+        self.subscriber
+            .subscribe::<(), _>(channel)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SubscribeError)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn publish_to_channel(
+        &self,
+        channel: &str,
+        value: RedisValue,
+    ) -> CustomResult<usize, errors::RedisError> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return Ok(client.publish(channel, value));
+        }
+
+        self.publisher
+            .publish(channel, value)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::PublishError)
+    }
+
+    async fn raw_hset<V>(&self, key: &str, values: V) -> Result<(), fred::error::RedisError>
+    where
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return values
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|values: RedisMap| client.store().hset(key, values.inner()));
+        }
+
+        self.pool.hset(key, values).await
+    }
+
+    async fn raw_hsetnx<V>(
+        &self,
+        key: &str,
+        field: &str,
+        value: V,
+    ) -> Result<HsetnxReply, fred::error::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return value
+                .try_into()
+                .map_err(Into::into)
+                .and_then(|value| client.store().hsetnx(key, field, value))
+                .and_then(HsetnxReply::from_value);
+        }
+
+        self.pool.hsetnx(key, field, value).await
+    }
+
+    /// Stream of the messages received on the channels the connection is subscribed to.
+    pub fn on_channel_message(&self) -> BoxStream<'static, PubSubMessage> {
+        #[cfg(feature = "in_memory")]
+        if let Some(client) = &self.in_memory {
+            return client.on_message();
+        }
+
+        futures::stream::unfold(self.subscriber.on_message(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(message) => return Some((PubSubMessage::from(message), rx)),
+                    Err(RecvError::Lagged(skipped)) => {
+                        logger::warn!(
+                            skipped,
+                            "Pub/sub receiver lagged behind, messages were dropped"
+                        );
+                    }
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed()
+    }
 }
 
 #[cfg(test)]
@@ -771,4 +1272,172 @@ mod tests {
 
         assert!(is_success);
     }
+
+    #[cfg(feature = "in_memory")]
+    fn in_memory_settings() -> RedisSettings {
+        RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(feature = "in_memory")]
+    #[tokio::test]
+    async fn test_in_memory_keys_and_hashes() {
+        use crate::SetnxReply;
+
+        let pool = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+
+        pool.set_key("key", "value").await.unwrap();
+        assert_eq!(pool.get_key::<String>("key").await.unwrap(), "value");
+        assert!(
+            pool.set_key_if_not_exists_with_expiry("key", "other", None)
+                .await
+                .unwrap()
+                == SetnxReply::KeyNotSet
+        );
+
+        pool.set_expiry("key", 0).await.unwrap();
+        assert!(!pool.exists::<&str>("key").await.unwrap());
+
+        pool.set_hash_fields("hash", vec![("pa_1", "first"), ("ref_1", "second")], None)
+            .await
+            .unwrap();
+        assert_eq!(
+            pool.hscan("hash", "pa_*", None).await.unwrap(),
+            vec!["first".to_string()]
+        );
+        assert_eq!(
+            pool.get_hash_field::<String>("hash", "ref_1")
+                .await
+                .unwrap(),
+            "second"
+        );
+    }
+
+    #[cfg(feature = "in_memory")]
+    #[tokio::test]
+    async fn test_in_memory_stream_consumer_group() {
+        let pool = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+
+        pool.consumer_group_create("stream", "group", &RedisEntryId::AfterLastID)
+            .await
+            .unwrap();
+        pool.stream_append_entry(
+            "stream",
+            &RedisEntryId::AutoGeneratedID,
+            vec![("data", "1")],
+        )
+        .await
+        .unwrap();
+
+        let response = pool
+            .stream_read_with_options(
+                "stream",
+                RedisEntryId::UndeliveredEntryID,
+                Some(1),
+                None,
+                Some(("group", "consumer")),
+            )
+            .await
+            .unwrap();
+        let entries = response.get("stream").unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].1.get("data").cloned().flatten(),
+            Some("1".to_string())
+        );
+
+        let group_info = pool.consumer_group_info("stream").await.unwrap();
+        assert_eq!(group_info[0].pending, 1);
+
+        let entry_ids = entries
+            .iter()
+            .map(|entry| entry.0.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pool.stream_acknowledge_entries("stream", "group", entry_ids.clone())
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            pool.stream_delete_entries("stream", entry_ids)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(pool.stream_get_length("stream").await.unwrap(), 0);
+    }
+
+    #[cfg(feature = "in_memory")]
+    #[tokio::test]
+    async fn test_in_memory_stores_are_not_shared() {
+        let first = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+        let second = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+
+        first.set_key("key", "value").await.unwrap();
+
+        assert!(first.exists::<&str>("key").await.unwrap());
+        assert!(!second.exists::<&str>("key").await.unwrap());
+    }
+
+    #[cfg(feature = "in_memory")]
+    #[tokio::test]
+    async fn test_in_memory_pub_sub() {
+        use futures::StreamExt;
+
+        let pool = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+
+        pool.subscribe_to_channel("channel").await.unwrap();
+        let mut messages = pool.on_channel_message();
+
+        assert_eq!(
+            pool.publish_to_channel("channel", "message".into())
+                .await
+                .unwrap(),
+            1
+        );
+        let message = messages.next().await.unwrap();
+        assert_eq!(message.channel, "channel");
+        assert_eq!(message.value.as_string(), Some("message".to_string()));
+    }
+
+    #[cfg(feature = "in_memory")]
+    #[tokio::test]
+    async fn test_in_memory_pub_sub_continues_after_lagging() {
+        use futures::StreamExt;
+
+        let pool = RedisConnectionPool::new(&in_memory_settings())
+            .await
+            .expect("failed to create in-memory redis connection pool");
+
+        pool.subscribe_to_channel("channel").await.unwrap();
+        let mut messages = pool.on_channel_message();
+
+        // Publishing more messages than the channel holds makes the receiver lag behind, the
+        // oldest messages are dropped but the subscription stays alive
+        for index in 0..2000 {
+            pool.publish_to_channel("channel", index.to_string().into())
+                .await
+                .unwrap();
+        }
+
+        let message = messages.next().await.unwrap();
+        assert_ne!(message.value.as_string(), Some("0".to_string()));
+        assert_eq!(
+            messages.skip(1022).next().await.unwrap().value.as_string(),
+            Some("1999".to_string())
+        );
+    }
 }
@@ -14,6 +14,8 @@ pub enum RedisError {
     SetExFailed,
     #[error("Failed to set expiry for key value in Redis")]
     SetExpiryFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("Failed to get key value in Redis")]
     GetFailed,
     #[error("Failed to delete key value in Redis")]
@@ -42,6 +44,8 @@ pub enum RedisError {
     ConsumerGroupSetIdFailed,
     #[error("Failed to set Redis stream message owner")]
     ConsumerGroupClaimFailed,
+    #[error("Failed to fetch information about the consumer groups of a Redis stream")]
+    ConsumerGroupInfoFailed,
     #[error("Failed to serialize application type to JSON")]
     JsonSerializationFailed,
     #[error("Failed to deserialize application type from JSON")]
@@ -64,6 +68,10 @@ pub enum RedisError {
     PublishError,
     #[error("Failed while receiving message from publisher")]
     OnMessageError,
+    #[error("Keys of a multi-key command map to different cluster hash slots")]
+    CrossSlotKeys,
     #[error("Got an unknown result from redis")]
     UnknownResult,
+    #[error("Failed to evaluate Lua script in Redis")]
+    ScriptEvaluationFailed,
 }
@@ -0,0 +1,1007 @@
+//!
+//! An in-process store emulating the subset of Redis commands used by this crate
+//!
+//! The store is only compiled with the `in_memory` feature, it is selected by setting `in_memory`
+//! in [`RedisSettings`](crate::RedisSettings) and is meant for tests which need to exercise KV
+//! mode, caches, pub/sub or streams without a running Redis server. Every connection pool owns a
+//! separate store, so that tests never observe the state left behind by other tests.
+//!
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use fred::{
+    error::{RedisError, RedisErrorKind},
+    types::{RedisKey, RedisValue, XReadResponse, XID},
+};
+use futures::{stream::BoxStream, StreamExt};
+use router_env::logger;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::types::{PubSubMessage, RedisEntryId, StreamCapKind, StreamGroupInfo};
+
+const MESSAGE_CHANNEL_CAPACITY: usize = 1024;
+
+/// Handle held by a connection pool, tracking the channels subscribed to by the pool.
+pub(crate) struct InMemoryClient {
+    store: Arc<InMemoryStore>,
+    subscriptions: Arc<Mutex<HashSet<String>>>,
+}
+
+impl InMemoryClient {
+    pub(crate) fn new() -> Self {
+        Self {
+            store: Arc::new(InMemoryStore::new()),
+            subscriptions: Default::default(),
+        }
+    }
+
+    pub(crate) fn store(&self) -> &InMemoryStore {
+        &self.store
+    }
+
+    pub(crate) fn subscribe(&self, channel: &str) {
+        if lock(&self.subscriptions).insert(channel.to_owned()) {
+            *lock(&self.store.subscribers)
+                .entry(channel.to_owned())
+                .or_default() += 1;
+        }
+    }
+
+    pub(crate) fn publish(&self, channel: &str, value: RedisValue) -> usize {
+        let subscribers = lock(&self.store.subscribers)
+            .get(channel)
+            .copied()
+            .unwrap_or_default();
+        if subscribers > 0 {
+            // Sending only fails when there are no receivers, which is equivalent to nobody
+            // listening on the channel
+            self.store
+                .messages
+                .send(PubSubMessage {
+                    channel: channel.to_owned(),
+                    value,
+                })
+                .ok();
+        }
+        subscribers
+    }
+
+    pub(crate) fn on_message(&self) -> BoxStream<'static, PubSubMessage> {
+        let subscriptions = self.subscriptions.clone();
+        futures::stream::unfold(self.store.messages.subscribe(), move |mut rx| {
+            let subscriptions = subscriptions.clone();
+            async move {
+                loop {
+                    match rx.recv().await {
+                        Ok(message) if lock(&subscriptions).contains(&message.channel) => {
+                            return Some((message, rx))
+                        }
+                        Ok(_) => {}
+                        Err(RecvError::Lagged(skipped)) => {
+                            logger::warn!(
+                                skipped,
+                                "In-memory pub/sub receiver lagged behind, messages were dropped"
+                            );
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+impl Drop for InMemoryClient {
+    fn drop(&mut self) {
+        let mut subscribers = lock(&self.store.subscribers);
+        for channel in lock(&self.subscriptions).iter() {
+            if let Some(count) = subscribers.get_mut(channel) {
+                *count = count.saturating_sub(1);
+            }
+        }
+    }
+}
+
+pub(crate) struct InMemoryStore {
+    keyspace: Mutex<HashMap<String, Entry>>,
+    subscribers: Mutex<HashMap<String, usize>>,
+    messages: broadcast::Sender<PubSubMessage>,
+}
+
+struct Entry {
+    value: Value,
+    expires_at: Option<SystemTime>,
+}
+
+impl Entry {
+    fn new(value: Value) -> Self {
+        Self {
+            value,
+            expires_at: None,
+        }
+    }
+
+    fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at
+            .map(|expires_at| expires_at <= now)
+            .unwrap_or(false)
+    }
+}
+
+enum Value {
+    String(RedisValue),
+    Hash(BTreeMap<String, RedisValue>),
+    Stream(Stream),
+}
+
+#[derive(Default)]
+struct Stream {
+    entries: BTreeMap<StreamId, Vec<(String, String)>>,
+    last_id: StreamId,
+    groups: BTreeMap<String, ConsumerGroup>,
+}
+
+struct ConsumerGroup {
+    last_delivered_id: StreamId,
+    consumers: HashSet<String>,
+    pending: BTreeMap<StreamId, PendingEntry>,
+}
+
+struct PendingEntry {
+    consumer: String,
+    delivered_at: Instant,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+struct StreamId {
+    milliseconds: u64,
+    sequence_number: u64,
+}
+
+impl fmt::Display for StreamId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.milliseconds, self.sequence_number)
+    }
+}
+
+impl FromStr for StreamId {
+    type Err = RedisError;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        let (milliseconds, sequence_number) = id.split_once('-').unwrap_or((id, "0"));
+        let parse = |part: &str| {
+            part.parse::<u64>().map_err(|_| {
+                RedisError::new(
+                    RedisErrorKind::InvalidArgument,
+                    format!("Invalid stream ID specified: {id}"),
+                )
+            })
+        };
+
+        Ok(Self {
+            milliseconds: parse(milliseconds)?,
+            sequence_number: parse(sequence_number)?,
+        })
+    }
+}
+
+impl StreamId {
+    fn next_after(last_id: Self) -> Self {
+        let milliseconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .and_then(|duration| u64::try_from(duration.as_millis()).ok())
+            .unwrap_or_default();
+
+        if milliseconds > last_id.milliseconds {
+            Self {
+                milliseconds,
+                sequence_number: 0,
+            }
+        } else {
+            Self {
+                milliseconds: last_id.milliseconds,
+                sequence_number: last_id.sequence_number.saturating_add(1),
+            }
+        }
+    }
+}
+
+/// Reference to an entry of a stream, as accepted by the stream commands.
+enum StreamPosition {
+    Id(StreamId),
+    /// `$`, the last entry in the stream
+    Last,
+    /// `>`, entries never delivered to any consumer of the group
+    Undelivered,
+}
+
+impl TryFrom<XID> for StreamPosition {
+    type Error = RedisError;
+
+    fn try_from(id: XID) -> Result<Self, Self::Error> {
+        match id {
+            XID::Manual(id) => match &*id {
+                "$" => Ok(Self::Last),
+                ">" => Ok(Self::Undelivered),
+                id => id.parse().map(Self::Id),
+            },
+            XID::Max => Ok(Self::Last),
+            XID::NewInGroup => Ok(Self::Undelivered),
+            XID::Auto => Err(invalid_argument("`*` is not a valid position in a stream")),
+        }
+    }
+}
+
+impl InMemoryStore {
+    fn new() -> Self {
+        let (messages, _) = broadcast::channel(MESSAGE_CHANNEL_CAPACITY);
+        Self {
+            keyspace: Default::default(),
+            subscribers: Default::default(),
+            messages,
+        }
+    }
+
+    /// Returns the keyspace after evicting the expired keys.
+    fn keyspace(&self) -> MutexGuard<'_, HashMap<String, Entry>> {
+        let mut keyspace = lock(&self.keyspace);
+        let now = SystemTime::now();
+        keyspace.retain(|_, entry| !entry.is_expired(now));
+        keyspace
+    }
+
+    //                                              Keys API
+
+    pub(crate) fn set(
+        &self,
+        key: &str,
+        value: RedisValue,
+        ttl: Option<i64>,
+        only_if_not_exists: bool,
+    ) -> Result<RedisValue, RedisError> {
+        let mut keyspace = self.keyspace();
+        if only_if_not_exists && keyspace.contains_key(key) {
+            return Ok(RedisValue::Null);
+        }
+
+        let mut entry = Entry::new(Value::String(value));
+        entry.expires_at = ttl.map(expiry_after);
+        keyspace.insert(key.to_owned(), entry);
+
+        Ok(RedisValue::String("OK".into()))
+    }
+
+    pub(crate) fn set_multiple_if_not_exist(
+        &self,
+        values: HashMap<RedisKey, RedisValue>,
+    ) -> Result<RedisValue, RedisError> {
+        let mut keyspace = self.keyspace();
+        if values
+            .keys()
+            .any(|key| keyspace.contains_key(key.as_str_lossy().as_ref()))
+        {
+            return Ok(RedisValue::Integer(0));
+        }
+
+        for (key, value) in values {
+            keyspace.insert(
+                key.as_str_lossy().into_owned(),
+                Entry::new(Value::String(value)),
+            );
+        }
+
+        Ok(RedisValue::Integer(1))
+    }
+
+    pub(crate) fn get(&self, key: &str) -> Result<RedisValue, RedisError> {
+        match self.keyspace().get(key).map(|entry| &entry.value) {
+            Some(Value::String(value)) => Ok(value.clone()),
+            Some(_) => Err(wrong_type()),
+            None => Ok(RedisValue::Null),
+        }
+    }
+
+    pub(crate) fn get_multiple(&self, keys: Vec<RedisKey>) -> Result<RedisValue, RedisError> {
+        let keyspace = self.keyspace();
+        let values = keys
+            .iter()
+            .map(|key| {
+                match keyspace
+                    .get(key.as_str_lossy().as_ref())
+                    .map(|entry| &entry.value)
+                {
+                    Some(Value::String(value)) => value.clone(),
+                    Some(_) | None => RedisValue::Null,
+                }
+            })
+            .collect();
+
+        Ok(RedisValue::Array(values))
+    }
+
+    pub(crate) fn exists(&self, key: &str) -> Result<bool, RedisError> {
+        Ok(self.keyspace().contains_key(key))
+    }
+
+    pub(crate) fn delete(&self, key: &str) -> Result<RedisValue, RedisError> {
+        let deleted = self.keyspace().remove(key).is_some();
+        Ok(RedisValue::Integer(deleted.into()))
+    }
+
+    pub(crate) fn incr(&self, key: &str, ttl: i64) -> Result<i64, RedisError> {
+        let mut keyspace = self.keyspace();
+        let entry = keyspace.entry(key.to_owned()).or_insert_with(|| Entry {
+            value: Value::String(RedisValue::Integer(0)),
+            expires_at: Some(expiry_after(ttl)),
+        });
+        let Value::String(value) = &mut entry.value else {
+            return Err(wrong_type());
+        };
+
+        let incremented = value
+            .as_i64()
+            .ok_or_else(|| invalid_argument("value is not an integer"))?
+            .checked_add(1)
+            .ok_or_else(|| invalid_argument("increment or decrement would overflow"))?;
+        *value = RedisValue::Integer(incremented);
+        Ok(incremented)
+    }
+
+    pub(crate) fn expire(&self, key: &str, seconds: i64) -> Result<(), RedisError> {
+        if let Some(entry) = self.keyspace().get_mut(key) {
+            entry.expires_at = Some(expiry_after(seconds));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn expire_at(&self, key: &str, timestamp: i64) -> Result<(), RedisError> {
+        if let Some(entry) = self.keyspace().get_mut(key) {
+            entry.expires_at = Some(
+                u64::try_from(timestamp)
+                    .map(|timestamp| UNIX_EPOCH + Duration::from_secs(timestamp))
+                    .unwrap_or(UNIX_EPOCH),
+            );
+        }
+        Ok(())
+    }
+
+    //                                              Hashes API
+
+    fn with_hash<T>(
+        &self,
+        key: &str,
+        create: bool,
+        f: impl FnOnce(Option<&mut BTreeMap<String, RedisValue>>) -> T,
+    ) -> Result<T, RedisError> {
+        let mut keyspace = self.keyspace();
+        if create && !keyspace.contains_key(key) {
+            keyspace.insert(key.to_owned(), Entry::new(Value::Hash(BTreeMap::new())));
+        }
+
+        match keyspace.get_mut(key).map(|entry| &mut entry.value) {
+            Some(Value::Hash(hash)) => Ok(f(Some(hash))),
+            Some(_) => Err(wrong_type()),
+            None => Ok(f(None)),
+        }
+    }
+
+    pub(crate) fn hset(
+        &self,
+        key: &str,
+        values: HashMap<RedisKey, RedisValue>,
+    ) -> Result<(), RedisError> {
+        self.with_hash(key, true, |hash| {
+            if let Some(hash) = hash {
+                hash.extend(
+                    values
+                        .into_iter()
+                        .map(|(field, value)| (field.as_str_lossy().into_owned(), value)),
+                );
+            }
+        })
+    }
+
+    pub(crate) fn hsetnx(
+        &self,
+        key: &str,
+        field: &str,
+        value: RedisValue,
+    ) -> Result<RedisValue, RedisError> {
+        self.with_hash(key, true, |hash| match hash {
+            Some(hash) if !hash.contains_key(field) => {
+                hash.insert(field.to_owned(), value);
+                RedisValue::Integer(1)
+            }
+            Some(_) | None => RedisValue::Integer(0),
+        })
+    }
+
+    pub(crate) fn hget(&self, key: &str, field: &str) -> Result<RedisValue, RedisError> {
+        self.with_hash(key, false, |hash| {
+            hash.and_then(|hash| hash.get(field).cloned())
+                .unwrap_or(RedisValue::Null)
+        })
+    }
+
+    pub(crate) fn hscan(&self, key: &str, pattern: &str) -> Result<Vec<String>, RedisError> {
+        self.with_hash(key, false, |hash| {
+            hash.map(|hash| {
+                hash.iter()
+                    .filter(|(field, _)| glob_matches(pattern, field))
+                    .filter_map(|(_, value)| value.as_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+        })
+    }
+
+    //                                              Streams API
+
+    fn with_stream<T>(
+        &self,
+        key: &str,
+        create: bool,
+        f: impl FnOnce(Option<&mut Stream>) -> Result<T, RedisError>,
+    ) -> Result<T, RedisError> {
+        let mut keyspace = self.keyspace();
+        if create && !keyspace.contains_key(key) {
+            keyspace.insert(key.to_owned(), Entry::new(Value::Stream(Stream::default())));
+        }
+
+        match keyspace.get_mut(key).map(|entry| &mut entry.value) {
+            Some(Value::Stream(stream)) => f(Some(stream)),
+            Some(_) => Err(wrong_type()),
+            None => f(None),
+        }
+    }
+
+    pub(crate) fn xadd(
+        &self,
+        key: &str,
+        id: &RedisEntryId,
+        fields: Vec<(RedisKey, RedisValue)>,
+    ) -> Result<(), RedisError> {
+        self.with_stream(key, true, |stream| {
+            let stream = stream.ok_or_else(|| no_such_key(key))?;
+            let id = match id {
+                RedisEntryId::AutoGeneratedID => StreamId::next_after(stream.last_id),
+                RedisEntryId::UserSpecifiedID {
+                    milliseconds,
+                    sequence_number,
+                } => format!("{milliseconds}-{sequence_number}").parse()?,
+                RedisEntryId::AfterLastID | RedisEntryId::UndeliveredEntryID => Err(
+                    invalid_argument("Stream entries require an explicit or auto generated ID"),
+                )?,
+            };
+
+            if id <= stream.last_id {
+                Err(invalid_argument(
+                    "The ID specified in XADD is equal or smaller than the target stream top item",
+                ))?;
+            }
+
+            stream.entries.insert(
+                id,
+                fields
+                    .into_iter()
+                    .map(|(field, value)| {
+                        (
+                            field.as_str_lossy().into_owned(),
+                            value.as_string().unwrap_or_default(),
+                        )
+                    })
+                    .collect(),
+            );
+            stream.last_id = id;
+            Ok(())
+        })
+    }
+
+    pub(crate) fn xdel(&self, key: &str, ids: Vec<String>) -> Result<usize, RedisError> {
+        let ids = ids
+            .iter()
+            .map(|id| id.parse::<StreamId>())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.with_stream(key, false, |stream| {
+            Ok(stream
+                .map(|stream| {
+                    ids.iter()
+                        .filter(|id| stream.entries.remove(id).is_some())
+                        .count()
+                })
+                .unwrap_or_default())
+        })
+    }
+
+    pub(crate) fn xtrim(
+        &self,
+        key: &str,
+        kind: StreamCapKind,
+        threshold: &str,
+    ) -> Result<usize, RedisError> {
+        self.with_stream(key, false, |stream| {
+            let Some(stream) = stream else {
+                return Ok(0);
+            };
+            let length_before = stream.entries.len();
+
+            match kind {
+                StreamCapKind::MinID => {
+                    let min_id = threshold.parse::<StreamId>()?;
+                    stream.entries = stream.entries.split_off(&min_id);
+                }
+                StreamCapKind::MaxLen => {
+                    let max_length = threshold.parse::<usize>().map_err(|_| {
+                        invalid_argument("The MAXLEN argument must be a non-negative integer")
+                    })?;
+                    let trimmed = stream.entries.len().saturating_sub(max_length);
+                    let retained_ids = stream
+                        .entries
+                        .keys()
+                        .skip(trimmed)
+                        .copied()
+                        .collect::<Vec<_>>();
+                    stream
+                        .entries
+                        .retain(|id, _| retained_ids.binary_search(id).is_ok());
+                }
+            }
+
+            Ok(length_before - stream.entries.len())
+        })
+    }
+
+    pub(crate) fn xlen(&self, key: &str) -> Result<usize, RedisError> {
+        self.with_stream(key, false, |stream| {
+            Ok(stream
+                .map(|stream| stream.entries.len())
+                .unwrap_or_default())
+        })
+    }
+
+    pub(crate) fn xack(&self, key: &str, group: &str, ids: Vec<XID>) -> Result<usize, RedisError> {
+        let ids = ids
+            .into_iter()
+            .map(stream_id)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.with_stream(key, false, |stream| {
+            Ok(stream
+                .and_then(|stream| stream.groups.get_mut(group))
+                .map(|group| {
+                    ids.iter()
+                        .filter(|id| group.pending.remove(id).is_some())
+                        .count()
+                })
+                .unwrap_or_default())
+        })
+    }
+
+    pub(crate) fn xread(
+        &self,
+        keys: Vec<RedisKey>,
+        ids: Vec<XID>,
+        count: Option<u64>,
+    ) -> Result<XReadResponse<String, String, String, String>, RedisError> {
+        let count = read_count(count);
+        let mut response = HashMap::new();
+
+        for (key, id) in keys.iter().zip(ids) {
+            let key = key.as_str_lossy();
+            let entries = self.with_stream(&key, false, |stream| {
+                let Some(stream) = stream else {
+                    return Ok(Vec::new());
+                };
+                let after = match StreamPosition::try_from(id)? {
+                    StreamPosition::Id(id) => id,
+                    StreamPosition::Last => stream.last_id,
+                    StreamPosition::Undelivered => Err(invalid_argument(
+                        "The `>` ID can be specified only when calling XREADGROUP",
+                    ))?,
+                };
+
+                Ok(entries_after(&stream.entries, after, count)
+                    .map(|(id, fields)| (id.to_string(), fields.iter().cloned().collect()))
+                    .collect::<Vec<_>>())
+            })?;
+
+            if !entries.is_empty() {
+                response.insert(key.into_owned(), entries);
+            }
+        }
+
+        if response.is_empty() {
+            Err(RedisError::new(
+                RedisErrorKind::NotFound,
+                "No entries available in the requested streams",
+            ))
+        } else {
+            Ok(response)
+        }
+    }
+
+    pub(crate) fn xreadgroup(
+        &self,
+        group: &str,
+        consumer: &str,
+        keys: Vec<RedisKey>,
+        ids: Vec<XID>,
+        count: Option<u64>,
+    ) -> Result<XReadResponse<String, String, String, Option<String>>, RedisError> {
+        let count = read_count(count);
+        let mut response = HashMap::new();
+
+        for (key, id) in keys.iter().zip(ids) {
+            let key = key.as_str_lossy();
+            let entries = self.with_stream(&key, false, |stream| {
+                let stream = stream.ok_or_else(|| no_group(&key, group))?;
+                let position = StreamPosition::try_from(id)?;
+                let Stream {
+                    entries, groups, ..
+                } = stream;
+                let consumer_group = groups.get_mut(group).ok_or_else(|| no_group(&key, group))?;
+                consumer_group.consumers.insert(consumer.to_owned());
+
+                let to_response = |id: &StreamId, fields: Option<&Vec<(String, String)>>| {
+                    let fields = fields
+                        .map(|fields| {
+                            fields
+                                .iter()
+                                .map(|(field, value)| (field.clone(), Some(value.clone())))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (id.to_string(), fields)
+                };
+
+                match position {
+                    StreamPosition::Undelivered => {
+                        let delivered =
+                            entries_after(entries, consumer_group.last_delivered_id, count)
+                                .map(|(id, fields)| (*id, fields))
+                                .collect::<Vec<_>>();
+
+                        for (id, _) in delivered.iter() {
+                            consumer_group.last_delivered_id = *id;
+                            consumer_group.pending.insert(
+                                *id,
+                                PendingEntry {
+                                    consumer: consumer.to_owned(),
+                                    delivered_at: Instant::now(),
+                                },
+                            );
+                        }
+                        Ok(delivered
+                            .into_iter()
+                            .map(|(id, fields)| to_response(&id, Some(fields)))
+                            .collect())
+                    }
+                    // Any other ID returns the entries pending for the consumer
+                    StreamPosition::Id(after) => Ok(consumer_group
+                        .pending
+                        .iter()
+                        .filter(|(id, pending)| **id > after && pending.consumer == consumer)
+                        .take(count)
+                        .map(|(id, _)| to_response(id, entries.get(id)))
+                        .collect()),
+                    StreamPosition::Last => Err(invalid_argument(
+                        "The `$` ID is meaningless in the context of XREADGROUP",
+                    )),
+                }
+            })?;
+
+            if !entries.is_empty() {
+                response.insert(key.into_owned(), entries);
+            }
+        }
+
+        Ok(response)
+    }
+
+    //                                              Consumer Group API
+
+    pub(crate) fn xgroup_create(
+        &self,
+        key: &str,
+        group: &str,
+        id: &RedisEntryId,
+    ) -> Result<(), RedisError> {
+        self.with_stream(key, true, |stream| {
+            let stream = stream.ok_or_else(|| no_such_key(key))?;
+            if stream.groups.contains_key(group) {
+                Err(RedisError::new(
+                    RedisErrorKind::InvalidArgument,
+                    "BUSYGROUP Consumer Group name already exists",
+                ))?;
+            }
+
+            let last_delivered_id = match id {
+                RedisEntryId::UserSpecifiedID {
+                    milliseconds,
+                    sequence_number,
+                } => format!("{milliseconds}-{sequence_number}").parse()?,
+                RedisEntryId::AfterLastID => stream.last_id,
+                RedisEntryId::AutoGeneratedID | RedisEntryId::UndeliveredEntryID => Err(
+                    invalid_argument("Invalid ID specified for the consumer group"),
+                )?,
+            };
+
+            stream.groups.insert(
+                group.to_owned(),
+                ConsumerGroup {
+                    last_delivered_id,
+                    consumers: HashSet::new(),
+                    pending: BTreeMap::new(),
+                },
+            );
+            Ok(())
+        })
+    }
+
+    pub(crate) fn xgroup_destroy(&self, key: &str, group: &str) -> Result<usize, RedisError> {
+        self.with_stream(key, false, |stream| {
+            let stream = stream.ok_or_else(|| no_such_key(key))?;
+            Ok(usize::from(stream.groups.remove(group).is_some()))
+        })
+    }
+
+    pub(crate) fn xgroup_delconsumer(
+        &self,
+        key: &str,
+        group: &str,
+        consumer: &str,
+    ) -> Result<usize, RedisError> {
+        self.with_stream(key, false, |stream| {
+            let consumer_group = stream
+                .and_then(|stream| stream.groups.get_mut(group))
+                .ok_or_else(|| no_group(key, group))?;
+
+            let pending_before = consumer_group.pending.len();
+            consumer_group
+                .pending
+                .retain(|_, pending| pending.consumer != consumer);
+            consumer_group.consumers.remove(consumer);
+
+            Ok(pending_before - consumer_group.pending.len())
+        })
+    }
+
+    pub(crate) fn xgroup_setid(
+        &self,
+        key: &str,
+        group: &str,
+        id: &RedisEntryId,
+    ) -> Result<String, RedisError> {
+        self.with_stream(key, false, |stream| {
+            let stream = stream.ok_or_else(|| no_group(key, group))?;
+            let last_id = stream.last_id;
+            let consumer_group = stream
+                .groups
+                .get_mut(group)
+                .ok_or_else(|| no_group(key, group))?;
+
+            consumer_group.last_delivered_id = match id {
+                RedisEntryId::UserSpecifiedID {
+                    milliseconds,
+                    sequence_number,
+                } => format!("{milliseconds}-{sequence_number}").parse()?,
+                RedisEntryId::AfterLastID => last_id,
+                RedisEntryId::AutoGeneratedID | RedisEntryId::UndeliveredEntryID => Err(
+                    invalid_argument("Invalid ID specified for the consumer group"),
+                )?,
+            };
+            Ok("OK".to_owned())
+        })
+    }
+
+    pub(crate) fn xclaim(
+        &self,
+        key: &str,
+        group: &str,
+        consumer: &str,
+        min_idle_time: u64,
+        ids: Vec<XID>,
+    ) -> Result<RedisValue, RedisError> {
+        let ids = ids
+            .into_iter()
+            .map(stream_id)
+            .collect::<Result<Vec<_>, _>>()?;
+        let min_idle_time = Duration::from_millis(min_idle_time);
+
+        self.with_stream(key, false, |stream| {
+            let Stream {
+                entries, groups, ..
+            } = stream.ok_or_else(|| no_group(key, group))?;
+            let consumer_group = groups.get_mut(group).ok_or_else(|| no_group(key, group))?;
+            consumer_group.consumers.insert(consumer.to_owned());
+
+            let mut claimed = Vec::new();
+            for id in ids {
+                let Some(pending) = consumer_group.pending.get_mut(&id) else {
+                    continue;
+                };
+                if pending.delivered_at.elapsed() < min_idle_time {
+                    continue;
+                }
+
+                pending.consumer = consumer.to_owned();
+                pending.delivered_at = Instant::now();
+                if let Some(fields) = entries.get(&id) {
+                    let fields = fields
+                        .iter()
+                        .flat_map(|(field, value)| {
+                            [
+                                RedisValue::String(field.as_str().into()),
+                                RedisValue::String(value.as_str().into()),
+                            ]
+                        })
+                        .collect();
+                    claimed.push(RedisValue::Array(vec![
+                        RedisValue::String(id.to_string().into()),
+                        RedisValue::Array(fields),
+                    ]));
+                }
+            }
+
+            Ok(RedisValue::Array(claimed))
+        })
+    }
+
+    pub(crate) fn xinfo_groups(&self, key: &str) -> Result<Vec<StreamGroupInfo>, RedisError> {
+        self.with_stream(key, false, |stream| {
+            let stream: &Stream = stream.ok_or_else(|| no_such_key(key))?;
+            Ok(stream
+                .groups
+                .iter()
+                .map(|(name, group)| StreamGroupInfo {
+                    name: name.clone(),
+                    consumers: u64::try_from(group.consumers.len()).unwrap_or(u64::MAX),
+                    pending: u64::try_from(group.pending.len()).unwrap_or(u64::MAX),
+                    last_delivered_id: group.last_delivered_id.to_string(),
+                    lag: u64::try_from(
+                        entries_after(&stream.entries, group.last_delivered_id, usize::MAX).count(),
+                    )
+                    .ok(),
+                })
+                .collect())
+        })
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // The store holds no invariants which could be broken by a panicking writer
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn expiry_after(seconds: i64) -> SystemTime {
+    SystemTime::now() + Duration::from_secs(u64::try_from(seconds).unwrap_or_default())
+}
+
+fn read_count(count: Option<u64>) -> usize {
+    count
+        .and_then(|count| usize::try_from(count).ok())
+        .unwrap_or(usize::MAX)
+}
+
+fn entries_after(
+    entries: &BTreeMap<StreamId, Vec<(String, String)>>,
+    after: StreamId,
+    count: usize,
+) -> impl Iterator<Item = (&StreamId, &Vec<(String, String)>)> {
+    entries
+        .range(after..)
+        .filter(move |(id, _)| **id > after)
+        .take(count)
+}
+
+fn stream_id(id: XID) -> Result<StreamId, RedisError> {
+    match StreamPosition::try_from(id)? {
+        StreamPosition::Id(id) => Ok(id),
+        StreamPosition::Last | StreamPosition::Undelivered => {
+            Err(invalid_argument("Expected an explicit stream entry ID"))
+        }
+    }
+}
+
+/// Matches `value` against a glob-style pattern supporting `*` and `?`, as used by `HSCAN`.
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+fn wrong_type() -> RedisError {
+    RedisError::new(
+        RedisErrorKind::InvalidArgument,
+        "WRONGTYPE Operation against a key holding the wrong kind of value",
+    )
+}
+
+fn invalid_argument(message: &'static str) -> RedisError {
+    RedisError::new(RedisErrorKind::InvalidArgument, message)
+}
+
+fn no_such_key(key: &str) -> RedisError {
+    RedisError::new(RedisErrorKind::NotFound, format!("No such key: {key}"))
+}
+
+fn no_group(key: &str, group: &str) -> RedisError {
+    RedisError::new(
+        RedisErrorKind::NotFound,
+        format!("NOGROUP No such key '{key}' or consumer group '{group}'"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("pa_*", "pa_1234"));
+        assert!(glob_matches("*", ""));
+        assert!(glob_matches("pa_?_*", "pa_1_attempt"));
+        assert!(!glob_matches("pa_*", "ref_1234"));
+        assert!(!glob_matches("pa_?", "pa_12"));
+    }
+
+    #[test]
+    fn test_incr_counts_from_zero_and_keeps_expiry() {
+        let store = InMemoryStore::new();
+
+        assert_eq!(store.incr("attempts", 60).unwrap(), 1);
+        assert_eq!(store.incr("attempts", 1).unwrap(), 2);
+        assert!(store.keyspace()["attempts"].expires_at.unwrap() > expiry_after(30));
+
+        store
+            .set("name", RedisValue::String("value".into()), None, false)
+            .unwrap();
+        assert!(store.incr("name", 60).is_err());
+    }
+
+    #[test]
+    fn test_stream_id_ordering() {
+        let first = "1-1".parse::<StreamId>().unwrap();
+        let second = "1-2".parse::<StreamId>().unwrap();
+        let third = "2".parse::<StreamId>().unwrap();
+
+        assert!(first < second && second < third);
+        assert!(StreamId::next_after(third) > third);
+        assert!("invalid".parse::<StreamId>().is_err());
+    }
+}
@@ -42,6 +42,12 @@ pub struct RedisSettings {
     pub port: u16,
     pub cluster_enabled: bool,
     pub cluster_urls: Vec<String>,
+    /// Discover the master through Sentinel, `host` and `port` then refer to a Sentinel node
+    pub sentinel_enabled: bool,
+    /// Addresses of the other Sentinel nodes, in the `host:port` format
+    pub sentinel_urls: Vec<String>,
+    /// Name of the master monitored by the Sentinel nodes
+    pub sentinel_service_name: String,
     pub use_legacy_version: bool,
     pub pool_size: usize,
     pub reconnect_max_attempts: u32,
@@ -52,6 +58,9 @@ pub struct RedisSettings {
     /// TTL for hash-tables in seconds
     pub default_hash_ttl: u32,
     pub stream_read_count: u64,
+    /// Use an in-process store instead of connecting to Redis, intended for tests only
+    #[cfg(feature = "in_memory")]
+    pub in_memory: bool,
 }
 
 impl RedisSettings {
@@ -71,7 +80,61 @@ impl RedisSettings {
                 "Redis `cluster_urls` must be specified if `cluster_enabled` is `true`".into(),
             ))
             .into_report()
-        })
+        })?;
+
+        when(self.cluster_enabled && self.sentinel_enabled, || {
+            Err(errors::RedisError::InvalidConfiguration(
+                "Redis `cluster_enabled` and `sentinel_enabled` cannot both be `true`".into(),
+            ))
+            .into_report()
+        })?;
+
+        when(
+            self.sentinel_enabled && self.sentinel_service_name.is_default_or_empty(),
+            || {
+                Err(errors::RedisError::InvalidConfiguration(
+                    "Redis `sentinel_service_name` must be specified if `sentinel_enabled` is `true`"
+                        .into(),
+                ))
+                .into_report()
+            },
+        )
+    }
+
+    /// Connection URL in the format expected by `fred` for the configured deployment mode.
+    ///
+    /// The host and port always refer to one of the nodes, the other nodes are passed as `node`
+    /// query parameters.
+    pub(crate) fn connection_url(&self) -> String {
+        let nodes = |urls: &[String]| {
+            urls.iter()
+                .map(|url| format!("node={url}"))
+                .collect::<Vec<_>>()
+                .join("&")
+        };
+
+        if self.cluster_enabled {
+            // redis-cluster://host:port?node=bar.com:30002&node=baz.com:30003
+            format!(
+                "redis-cluster://{}:{}?{}",
+                self.host,
+                self.port,
+                nodes(&self.cluster_urls)
+            )
+        } else if self.sentinel_enabled {
+            // redis-sentinel://host:port?sentinelServiceName=master&node=bar.com:26379
+            let mut url = format!(
+                "redis-sentinel://{}:{}?sentinelServiceName={}",
+                self.host, self.port, self.sentinel_service_name
+            );
+            if !self.sentinel_urls.is_empty() {
+                url.push('&');
+                url.push_str(&nodes(&self.sentinel_urls));
+            }
+            url
+        } else {
+            format!("redis://{}:{}", self.host, self.port)
+        }
     }
 }
 
@@ -82,6 +145,9 @@ impl Default for RedisSettings {
             port: 6379,
             cluster_enabled: false,
             cluster_urls: vec![],
+            sentinel_enabled: false,
+            sentinel_urls: vec![],
+            sentinel_service_name: String::new(),
             use_legacy_version: false,
             pool_size: 5,
             reconnect_max_attempts: 5,
@@ -89,6 +155,8 @@ impl Default for RedisSettings {
             default_ttl: 300,
             stream_read_count: 1,
             default_hash_ttl: 900,
+            #[cfg(feature = "in_memory")]
+            in_memory: false,
         }
     }
 }
@@ -245,3 +313,60 @@ impl fred::types::FromRedis for DelReply {
         }
     }
 }
+
+#[derive(Clone, Debug)]
+pub struct StreamGroupInfo {
+    pub name: String,
+    pub consumers: u64,
+    pub pending: u64,
+    pub last_delivered_id: String,
+    /// Number of entries yet to be delivered to the group, reported by Redis 7.0 and above
+    pub lag: Option<u64>,
+}
+
+impl fred::types::FromRedis for StreamGroupInfo {
+    fn from_value(value: fred::types::RedisValue) -> Result<Self, fred::error::RedisError> {
+        let mut info: std::collections::HashMap<String, FredRedisValue> =
+            fred::types::FromRedis::from_value(value)?;
+        let mut take_field = |field: &'static str| {
+            info.remove(field).ok_or_else(|| {
+                fred::error::RedisError::new(
+                    fred::error::RedisErrorKind::Parse,
+                    format!("Missing `{field}` in XINFO GROUPS reply"),
+                )
+            })
+        };
+
+        let name = take_field("name")?.as_string().unwrap_or_default();
+        let consumers = take_field("consumers")?.as_u64().unwrap_or_default();
+        let pending = take_field("pending")?.as_u64().unwrap_or_default();
+        let last_delivered_id = take_field("last-delivered-id")?
+            .as_string()
+            .unwrap_or_default();
+        // `lag` is absent on older servers and `nil` when Redis cannot determine it
+        let lag = take_field("lag").ok().and_then(|lag| lag.as_u64());
+
+        Ok(Self {
+            name,
+            consumers,
+            pending,
+            last_delivered_id,
+            lag,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PubSubMessage {
+    pub channel: String,
+    pub value: FredRedisValue,
+}
+
+impl From<fred::types::Message> for PubSubMessage {
+    fn from(message: fred::types::Message) -> Self {
+        Self {
+            channel: message.channel.to_string(),
+            value: message.value,
+        }
+    }
+}
@@ -66,6 +66,13 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    if let scheduler::SchedulerFlow::Producer = scheduler_flow {
+        workflows::forex_rate_snapshot::add_forex_rate_snapshot_task(&state)
+            .await
+            .map_err(|error| logger::error!(forex_rate_snapshot_task_error=?error))
+            .ok();
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     eprintln!("Scheduler shut down");
@@ -79,6 +86,9 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    AuthorizationExpiryWorkflow,
+    DisputeDeadlineWorkflow,
+    ForexRateSnapshotWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -103,6 +113,15 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            Some(PTRunner::AuthorizationExpiryWorkflow) => {
+                Box::new(workflows::authorization_expiry::AuthorizationExpiryWorkflow)
+            }
+            Some(PTRunner::DisputeDeadlineWorkflow) => {
+                Box::new(workflows::dispute_deadline::DisputeDeadlineWorkflow)
+            }
+            Some(PTRunner::ForexRateSnapshotWorkflow) => {
+                Box::new(workflows::forex_rate_snapshot::ForexRateSnapshotWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
@@ -1,6 +1,10 @@
 pub mod app;
+pub mod charges;
 pub mod customers;
+pub mod events;
+pub mod pagination;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -21,6 +25,8 @@ impl StripeApis {
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state.clone()))
+            .service(app::Charges::server(state.clone()))
+            .service(app::Events::server(state))
     }
 }
@@ -1,7 +1,10 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*, webhooks::*};
-use crate::routes::{self, mandates, webhooks};
+use super::{
+    charges::*, customers::*, events::*, payment_intents::*, payment_methods::*, refunds::*,
+    setup_intents::*, webhooks::*,
+};
+use crate::routes::{self, webhooks};
 
 pub struct PaymentIntents;
 
@@ -112,12 +115,37 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+            .service(web::resource("").route(web::get().to(payment_method_list)))
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_method_attach)))
+            .service(web::resource("/{id}/detach").route(web::post().to(payment_method_detach)))
+    }
+}
+
+pub struct Charges;
+
+impl Charges {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/charges")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(charge_list)))
+            .service(web::resource("/{charge_id}").route(web::get().to(charge_retrieve)))
+    }
+}
+
+pub struct Events;
+
+impl Events {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(event_list)))
+            .service(web::resource("/{event_id}").route(web::get().to(event_retrieve)))
     }
 }
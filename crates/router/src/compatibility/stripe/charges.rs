@@ -0,0 +1,135 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::pagination::{self, StripeList};
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse, StorageErrorExt},
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::domain,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ChargesRetrieve))]
+pub async fn charge_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let charge_id = path.into_inner();
+    let flow = Flow::ChargesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeChargeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        charge_id,
+        |state, auth, charge_id| retrieve_charge(state, auth.merchant_account, charge_id),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ChargesList))]
+pub async fn charge_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripeChargeListRequest>,
+) -> HttpResponse {
+    let payload = query.into_inner();
+    let flow = Flow::ChargesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripeChargeResponse>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| list_charges(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Charges are the attempts made on a payment, a charge is identified by the attempt id
+async fn retrieve_charge(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    charge_id: String,
+) -> RouterResponse<types::StripeChargeResponse> {
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &charge_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: format!("No such charge: '{charge_id}'"),
+        })?;
+
+    Ok(api::ApplicationResponse::Json(payment_attempt.into()))
+}
+
+async fn list_charges(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    req: types::StripeChargeListRequest,
+) -> RouterResponse<StripeList<types::StripeChargeResponse>> {
+    let payment_id = req
+        .payment_intent
+        .clone()
+        .ok_or(ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_intent",
+        })?;
+
+    let mut payment_attempts = state
+        .store
+        .find_attempts_by_merchant_id_payment_id(
+            &merchant_account.merchant_id,
+            &payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+    payment_attempts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let (payment_attempts, has_more) = pagination::paginate(
+        payment_attempts,
+        &req.get_list_params(),
+        "charge",
+        |payment_attempt| payment_attempt.attempt_id.as_str(),
+    )?;
+
+    Ok(api::ApplicationResponse::Json(StripeList::new(
+        payment_attempts.into_iter().map(From::from).collect(),
+        has_more,
+        "/v1/charges",
+    )))
+}
@@ -0,0 +1,130 @@
+use common_utils::events::ApiEventMetric;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::pagination::StripeListParams,
+    types::storage::{self, enums},
+};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StripeChargeListRequest {
+    pub payment_intent: Option<String>,
+    pub limit: Option<i64>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+impl StripeChargeListRequest {
+    pub fn get_list_params(&self) -> StripeListParams {
+        StripeListParams {
+            limit: self.limit,
+            starting_after: self.starting_after.clone(),
+            ending_before: self.ending_before.clone(),
+        }
+    }
+}
+
+impl ApiEventMetric for StripeChargeListRequest {}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeChargeResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub captured: bool,
+    pub currency: Option<String>,
+    pub payment_intent: String,
+    pub payment_method: Option<String>,
+    pub payment_method_details: Option<StripeChargePaymentMethodDetails>,
+    pub status: StripeChargeStatus,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub created: i64,
+}
+
+impl ApiEventMetric for StripeChargeResponse {}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeChargePaymentMethodDetails {
+    #[serde(rename = "type")]
+    pub payment_method: enums::PaymentMethod,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargeStatus {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl From<enums::AttemptStatus> for StripeChargeStatus {
+    fn from(status: enums::AttemptStatus) -> Self {
+        match status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+            | enums::AttemptStatus::CaptureInitiated
+            | enums::AttemptStatus::CaptureFailed
+            | enums::AttemptStatus::AutoRefunded => Self::Succeeded,
+            enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::RouterDeclined
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::Failure
+            | enums::AttemptStatus::Voided
+            | enums::AttemptStatus::VoidInitiated
+            | enums::AttemptStatus::VoidFailed
+            | enums::AttemptStatus::Expired => Self::Failed,
+            enums::AttemptStatus::Started
+            | enums::AttemptStatus::AuthenticationPending
+            | enums::AttemptStatus::AuthenticationSuccessful
+            | enums::AttemptStatus::Authorizing
+            | enums::AttemptStatus::CodInitiated
+            | enums::AttemptStatus::Unresolved
+            | enums::AttemptStatus::Pending
+            | enums::AttemptStatus::PaymentMethodAwaited
+            | enums::AttemptStatus::ConfirmationAwaited
+            | enums::AttemptStatus::DeviceDataCollectionPending => Self::Pending,
+        }
+    }
+}
+
+impl From<storage::PaymentAttempt> for StripeChargeResponse {
+    fn from(attempt: storage::PaymentAttempt) -> Self {
+        let amount_captured = match attempt.status {
+            enums::AttemptStatus::Charged | enums::AttemptStatus::PartialCharged => {
+                attempt.amount_to_capture.unwrap_or(attempt.amount)
+            }
+            // Whatever is still capturable on the attempt has not been captured yet
+            enums::AttemptStatus::PartialChargedAndChargeable => attempt
+                .amount
+                .saturating_sub(attempt.amount_capturable)
+                .max(0),
+            _ => 0,
+        };
+
+        Self {
+            id: attempt.attempt_id,
+            object: "charge",
+            amount: attempt.amount,
+            amount_captured,
+            captured: amount_captured > 0,
+            currency: attempt.currency.map(|currency| currency.to_string()),
+            payment_intent: attempt.payment_id,
+            payment_method: attempt.payment_method_id,
+            payment_method_details: attempt.payment_method.map(|payment_method| {
+                StripeChargePaymentMethodDetails {
+                    payment_method,
+                    payment_method_type: attempt.payment_method_type,
+                }
+            }),
+            status: StripeChargeStatus::from(attempt.status),
+            failure_code: attempt.error_code,
+            failure_message: attempt.error_message,
+            created: attempt.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing, Flow};
+
+use super::pagination::StripeList;
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    routes,
+    services::{api, authentication as auth},
+    types::{domain, storage},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::EventsRetrieve))]
+pub async fn event_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let event_id = path.into_inner();
+    let flow = Flow::EventsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeEventResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        event_id,
+        |state, auth, event_id| retrieve_event(state, auth.merchant_account, event_id),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EventsList))]
+pub async fn event_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripeEventListRequest>,
+) -> HttpResponse {
+    let payload = query.into_inner();
+    let flow = Flow::EventsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripeEventResponse>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| list_events(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn find_event(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    event_id: &str,
+) -> RouterResult<storage::Event> {
+    db.find_event_by_merchant_id_event_id(merchant_id, event_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::GenericNotFoundError {
+            message: format!("No such event: '{event_id}'"),
+        })
+}
+
+async fn retrieve_event(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    event_id: String,
+) -> RouterResponse<types::StripeEventResponse> {
+    let event = find_event(&*state.store, &merchant_account.merchant_id, &event_id).await?;
+
+    Ok(api::ApplicationResponse::Json(event.into()))
+}
+
+async fn list_events(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    req: types::StripeEventListRequest,
+) -> RouterResponse<StripeList<types::StripeEventResponse>> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.merchant_id.as_str();
+    let limit = req.get_list_params().get_limit();
+
+    let starting_after_id = match req.starting_after.as_deref() {
+        Some(event_id) => Some(find_event(db, merchant_id, event_id).await?.id),
+        None => None,
+    };
+    let ending_before_id = match (starting_after_id, req.ending_before.as_deref()) {
+        (None, Some(event_id)) => Some(find_event(db, merchant_id, event_id).await?.id),
+        _ => None,
+    };
+
+    // One extra event is fetched to know if there are more events beyond this page
+    let mut events = db
+        .list_events_by_merchant_id(
+            merchant_id,
+            storage::EventListConstraints {
+                starting_after_id,
+                ending_before_id,
+                limit: limit.saturating_add(1),
+            },
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events")?;
+
+    let has_more = events.len() > usize::try_from(limit).unwrap_or_default();
+    if has_more {
+        // When paging backwards the extra event is the one furthest from the cursor, the newest
+        if ending_before_id.is_some() {
+            events.remove(0);
+        } else {
+            events.pop();
+        }
+    }
+
+    Ok(api::ApplicationResponse::Json(StripeList::new(
+        events.into_iter().map(From::from).collect(),
+        has_more,
+        "/v1/events",
+    )))
+}
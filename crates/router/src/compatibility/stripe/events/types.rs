@@ -0,0 +1,61 @@
+use common_utils::{events::ApiEventMetric, pii};
+use masking::{ExposeInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::{pagination::StripeListParams, webhooks::get_stripe_event_type},
+    types::storage,
+};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StripeEventListRequest {
+    pub limit: Option<i64>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+impl StripeEventListRequest {
+    pub fn get_list_params(&self) -> StripeListParams {
+        StripeListParams {
+            limit: self.limit,
+            starting_after: self.starting_after.clone(),
+            ending_before: self.ending_before.clone(),
+        }
+    }
+}
+
+impl ApiEventMetric for StripeEventListRequest {}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StripeEventResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: &'static str,
+    pub created: i64,
+    pub data: Option<pii::SecretSerdeValue>,
+}
+
+impl ApiEventMetric for StripeEventResponse {}
+
+impl From<storage::Event> for StripeEventResponse {
+    fn from(event: storage::Event) -> Self {
+        // The stored content is the webhook delivered to the merchant, which carries the object
+        // under `data` for stripe compatible merchants and under `content` otherwise
+        let data = event.content.and_then(|content| {
+            let mut content = content.expose();
+            content
+                .as_object_mut()
+                .and_then(|content| content.remove("data").or_else(|| content.remove("content")))
+                .map(Secret::new)
+        });
+
+        Self {
+            id: event.event_id,
+            object: "event",
+            stype: get_stripe_event_type(event.event_type),
+            created: event.created_at.assume_utc().unix_timestamp(),
+            data,
+        }
+    }
+}
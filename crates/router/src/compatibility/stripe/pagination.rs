@@ -0,0 +1,133 @@
+use common_utils::events::ApiEventMetric;
+use error_stack::report;
+use serde::{Deserialize, Serialize};
+
+use crate::core::errors;
+
+const DEFAULT_LIST_LIMIT: i64 = 10;
+const MAX_LIST_LIMIT: i64 = 100;
+
+/// Cursor based pagination parameters accepted by the stripe list endpoints
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StripeListParams {
+    pub limit: Option<i64>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+impl StripeListParams {
+    /// The requested page size, clamped to the bounds stripe allows
+    pub fn get_limit(&self) -> i64 {
+        self.limit
+            .unwrap_or(DEFAULT_LIST_LIMIT)
+            .clamp(1, MAX_LIST_LIMIT)
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StripeList<T> {
+    pub object: &'static str,
+    pub data: Vec<T>,
+    pub has_more: bool,
+    pub url: String,
+}
+
+impl<T> StripeList<T> {
+    pub fn new(data: Vec<T>, has_more: bool, url: impl Into<String>) -> Self {
+        Self {
+            object: "list",
+            data,
+            has_more,
+            url: url.into(),
+        }
+    }
+}
+
+impl<T> ApiEventMetric for StripeList<T> {}
+
+/// Pages through `items`, which are expected to be ordered newest first, using the cursors in
+/// `params`. The cursor is looked up with `get_id` and is excluded from the page.
+pub fn paginate<T>(
+    items: Vec<T>,
+    params: &StripeListParams,
+    object: &str,
+    get_id: impl Fn(&T) -> &str,
+) -> errors::RouterResult<(Vec<T>, bool)> {
+    let limit = usize::try_from(params.get_limit()).unwrap_or_default();
+    let find_cursor = |cursor: &str| {
+        items
+            .iter()
+            .position(|item| get_id(item) == cursor)
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("No such {object}: '{cursor}'"),
+                })
+            })
+    };
+
+    match (&params.starting_after, &params.ending_before) {
+        (Some(starting_after), _) => {
+            let start = find_cursor(starting_after)? + 1;
+            let has_more = items.len() > start + limit;
+            Ok((
+                items.into_iter().skip(start).take(limit).collect(),
+                has_more,
+            ))
+        }
+        (None, Some(ending_before)) => {
+            let end = find_cursor(ending_before)?;
+            let start = end.saturating_sub(limit);
+            Ok((
+                items.into_iter().skip(start).take(end - start).collect(),
+                start > 0,
+            ))
+        }
+        (None, None) => {
+            let has_more = items.len() > limit;
+            Ok((items.into_iter().take(limit).collect(), has_more))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn ids(count: usize) -> Vec<String> {
+        (0..count).map(|index| format!("id_{index}")).collect()
+    }
+
+    #[test]
+    fn test_paginate_forwards() {
+        let params = StripeListParams {
+            limit: Some(2),
+            starting_after: Some("id_1".to_string()),
+            ending_before: None,
+        };
+        let (page, has_more) = paginate(ids(5), &params, "charge", String::as_str).unwrap();
+        assert_eq!(page, vec!["id_2", "id_3"]);
+        assert!(has_more);
+    }
+
+    #[test]
+    fn test_paginate_backwards() {
+        let params = StripeListParams {
+            limit: Some(2),
+            starting_after: None,
+            ending_before: Some("id_3".to_string()),
+        };
+        let (page, has_more) = paginate(ids(5), &params, "charge", String::as_str).unwrap();
+        assert_eq!(page, vec!["id_1", "id_2"]);
+        assert!(has_more);
+    }
+
+    #[test]
+    fn test_paginate_unknown_cursor() {
+        let params = StripeListParams {
+            starting_after: Some("id_9".to_string()),
+            ..Default::default()
+        };
+        assert!(paginate(ids(5), &params, "charge", String::as_str).is_err());
+    }
+}
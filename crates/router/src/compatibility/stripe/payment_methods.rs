@@ -0,0 +1,300 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use super::pagination::{self, StripeList};
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    db::StorageInterface,
+    routes::{self, mandates},
+    services::{api, authentication as auth},
+    types::{api::payment_methods, domain, storage},
+};
+
+/// Prefix of the mandate ids, which can be detached through the payment methods api as well
+const MANDATE_ID_PREFIX: &str = "man_";
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsList))]
+pub async fn payment_method_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripePaymentMethodListRequest>,
+) -> HttpResponse {
+    let payload = query.into_inner();
+    let flow = Flow::PaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        StripeList<types::StripePaymentMethodResponse>,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| list_payment_methods(state, auth.merchant_account, auth.key_store, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| retrieve_payment_method(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsAttach))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let mut payload: types::StripeAttachPaymentMethodRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+    payload.payment_method_id = path.into_inner();
+
+    let flow = Flow::PaymentMethodsAttach;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| attach_payment_method(state, auth.merchant_account, auth.key_store, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn payment_method_detach(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    // Mandates were detached through this route before payment methods were supported here
+    if path.starts_with(MANDATE_ID_PREFIX) {
+        return mandates::revoke_mandate(state, req, path).await;
+    }
+
+    let payload = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let flow = Flow::PaymentMethodsDelete;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| detach_payment_method(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn find_merchant_payment_method(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_method_id: &str,
+) -> RouterResult<storage::PaymentMethod> {
+    db.find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)
+        .and_then(|payment_method| {
+            if payment_method.merchant_id == merchant_account.merchant_id {
+                Ok(payment_method)
+            } else {
+                Err(report!(ApiErrorResponse::PaymentMethodNotFound))
+            }
+        })
+}
+
+async fn list_payment_methods(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: types::StripePaymentMethodListRequest,
+) -> RouterResponse<StripeList<types::StripePaymentMethodResponse>> {
+    let db = &*state.store;
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer,
+        &merchant_account.merchant_id,
+        &key_store,
+    )
+    .await
+    .to_not_found_response(ApiErrorResponse::CustomerNotFound)?;
+
+    let mut customer_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &req.customer,
+            &merchant_account.merchant_id,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentMethodNotFound)?;
+    customer_payment_methods.retain(|payment_method| {
+        req.payment_method
+            .map_or(true, |pm_type| payment_method.payment_method == pm_type)
+    });
+    customer_payment_methods.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    // The page is picked before fetching the card details, to only hit the locker for the
+    // payment methods being returned
+    let (customer_payment_methods, has_more) = pagination::paginate(
+        customer_payment_methods,
+        &req.get_list_params(),
+        "payment_method",
+        |payment_method| payment_method.payment_method_id.as_str(),
+    )?;
+
+    let mut data = Vec::with_capacity(customer_payment_methods.len());
+    for payment_method in customer_payment_methods {
+        data.push(
+            cards::get_payment_method_response(&state, payment_method)
+                .await?
+                .into(),
+        );
+    }
+
+    Ok(api::ApplicationResponse::Json(StripeList::new(
+        data,
+        has_more,
+        "/v1/payment_methods",
+    )))
+}
+
+async fn retrieve_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payment_methods::PaymentMethodId,
+) -> RouterResponse<types::StripePaymentMethodResponse> {
+    let payment_method =
+        find_merchant_payment_method(&*state.store, &merchant_account, &req.payment_method_id)
+            .await?;
+    let response = cards::get_payment_method_response(&state, payment_method).await?;
+
+    Ok(api::ApplicationResponse::Json(response.into()))
+}
+
+/// Payment methods are saved against a customer when they are created, attaching only succeeds
+/// when the payment method already belongs to the customer as they can't be moved across
+async fn attach_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: types::StripeAttachPaymentMethodRequest,
+) -> RouterResponse<types::StripePaymentMethodResponse> {
+    let db = &*state.store;
+    db.find_customer_by_customer_id_merchant_id(
+        &req.customer,
+        &merchant_account.merchant_id,
+        &key_store,
+    )
+    .await
+    .to_not_found_response(ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_method =
+        find_merchant_payment_method(db, &merchant_account, &req.payment_method_id).await?;
+    if payment_method.customer_id != req.customer {
+        return Err(report!(ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The payment method '{}' is attached to another customer and cannot be attached to '{}'",
+                req.payment_method_id, req.customer
+            ),
+        }));
+    }
+    let response = cards::get_payment_method_response(&state, payment_method).await?;
+
+    Ok(api::ApplicationResponse::Json(response.into()))
+}
+
+async fn detach_payment_method(
+    state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payment_methods::PaymentMethodId,
+) -> RouterResponse<types::StripePaymentMethodResponse> {
+    let payment_method =
+        find_merchant_payment_method(&*state.store, &merchant_account, &req.payment_method_id)
+            .await?;
+    let mut response: types::StripePaymentMethodResponse =
+        cards::get_payment_method_response(&state, payment_method)
+            .await?
+            .into();
+
+    cards::delete_payment_method(state, merchant_account, req).await?;
+
+    // A detached payment method no longer belongs to any customer
+    response.customer = None;
+    Ok(api::ApplicationResponse::Json(response))
+}
@@ -0,0 +1,93 @@
+use common_utils::{events::ApiEventMetric, pii};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::pagination::StripeListParams,
+    types::api::{enums as api_enums, payment_methods},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripePaymentMethodListRequest {
+    pub customer: String,
+    #[serde(rename = "type")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    pub limit: Option<i64>,
+    pub starting_after: Option<String>,
+    pub ending_before: Option<String>,
+}
+
+impl StripePaymentMethodListRequest {
+    pub fn get_list_params(&self) -> StripeListParams {
+        StripeListParams {
+            limit: self.limit,
+            starting_after: self.starting_after.clone(),
+            ending_before: self.ending_before.clone(),
+        }
+    }
+}
+
+impl ApiEventMetric for StripePaymentMethodListRequest {}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StripeAttachPaymentMethodRequest {
+    #[serde(skip)]
+    pub payment_method_id: String,
+    pub customer: String,
+}
+
+impl ApiEventMetric for StripeAttachPaymentMethodRequest {}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method: api_enums::PaymentMethod,
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    pub customer: Option<String>,
+    pub card: Option<StripePaymentMethodCard>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created: Option<i64>,
+}
+
+impl ApiEventMetric for StripePaymentMethodResponse {}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct StripePaymentMethodCard {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+impl From<payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            payment_method: res.payment_method,
+            payment_method_type: res.payment_method_type,
+            customer: res.customer_id,
+            card: res.card.map(From::from),
+            metadata: res.metadata,
+            created: res
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
+impl From<payment_methods::CardDetailFromLocker> for StripePaymentMethodCard {
+    fn from(card: payment_methods::CardDetailFromLocker) -> Self {
+        Self {
+            brand: card.scheme,
+            country: card.issuer_country,
+            last4: card.last4_digits,
+            exp_month: card.expiry_month,
+            exp_year: card.expiry_year,
+            fingerprint: card.card_fingerprint,
+        }
+    }
+}
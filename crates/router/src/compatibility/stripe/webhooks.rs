@@ -16,15 +16,19 @@ use crate::{
     services::request::Maskable,
 };
 
+/// The stripe api version the objects sent in the outgoing webhooks are modelled after
+const STRIPE_COMPATIBLE_API_VERSION: &str = "2019-11-05";
+
 #[derive(Serialize, Debug)]
 pub struct StripeOutgoingWebhook {
     id: String,
     #[serde(rename = "type")]
     stype: &'static str,
     object: &'static str,
+    api_version: &'static str,
+    livemode: bool,
     data: StripeWebhookObject,
     created: u64,
-    // api_version: "2019-11-05", // not used
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
@@ -74,21 +78,42 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    Charge(StripeCaptureResponse),
 }
 
 #[derive(Serialize, Debug)]
 pub struct StripeDisputeResponse {
     pub id: String,
-    pub amount: String,
+    pub object: &'static str,
+    pub amount: i64,
     pub currency: String,
+    pub charge: String,
     pub payment_intent: String,
     pub reason: Option<String>,
     pub status: StripeDisputeStatus,
+    pub evidence_details: StripeDisputeEvidenceDetails,
+    pub created: i64,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeCaptureResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: Option<String>,
+    pub status: String,
+    pub final_capture: bool,
 }
 
 #[derive(Serialize, Debug)]
 pub struct StripeMandateResponse {
-    pub mandate_id: String,
+    pub id: String,
+    pub object: &'static str,
     pub status: StripeMandateStatus,
     pub payment_method_id: String,
     pub payment_method: String,
@@ -117,13 +142,40 @@ pub enum StripeDisputeStatus {
 
 impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
     fn from(res: api_models::disputes::DisputeResponse) -> Self {
+        // Dispute amounts are stored as strings of the amount in the lowest denomination
+        let amount = res.amount.parse().unwrap_or_else(|error| {
+            logger::error!(%error, "incorrect value for `dispute.amount` provided {}", res.amount);
+            0
+        });
+
         Self {
             id: res.dispute_id,
-            amount: res.amount,
-            currency: res.currency,
+            object: "dispute",
+            amount,
+            currency: res.currency.to_ascii_lowercase(),
+            charge: res.attempt_id,
             payment_intent: res.payment_id,
             reason: res.connector_reason,
             status: StripeDisputeStatus::from(res.dispute_status),
+            evidence_details: StripeDisputeEvidenceDetails {
+                due_by: res
+                    .challenge_required_by
+                    .map(|due_by| due_by.assume_utc().unix_timestamp()),
+            },
+            created: res.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
+impl From<api_models::payments::CaptureResponse> for StripeCaptureResponse {
+    fn from(res: api_models::payments::CaptureResponse) -> Self {
+        Self {
+            id: res.capture_id,
+            object: "charge",
+            amount: res.amount,
+            currency: res.currency.map(|currency| currency.to_string()),
+            status: res.status.to_string(),
+            final_capture: res.final_capture,
         }
     }
 }
@@ -131,7 +183,8 @@ impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
 impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     fn from(res: api_models::mandates::MandateResponse) -> Self {
         Self {
-            mandate_id: res.mandate_id,
+            id: res.mandate_id,
+            object: "mandate",
             payment_method: res.payment_method,
             payment_method_id: res.payment_method_id,
             status: StripeMandateStatus::from(res.status),
@@ -163,26 +216,29 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
+pub(super) fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
         api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
         api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
-
-        // the below are not really stripe compatible because stripe doesn't provide this
-        api_models::enums::EventType::ActionRequired => "action.required",
-        api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
+        api_models::enums::EventType::ActionRequired => "payment_intent.requires_action",
+        // Stripe cancels the payment intents which are not captured in time
+        api_models::enums::EventType::PaymentExpired => "payment_intent.canceled",
+        api_models::enums::EventType::RefundSucceeded => "refund.updated",
         api_models::enums::EventType::RefundFailed => "refund.failed",
-        api_models::enums::EventType::DisputeOpened => "dispute.failed",
-        api_models::enums::EventType::DisputeExpired => "dispute.expired",
-        api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
-        api_models::enums::EventType::DisputeCancelled => "dispute.cancelled",
-        api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
-        api_models::enums::EventType::DisputeWon => "dispute.won",
-        api_models::enums::EventType::DisputeLost => "dispute.lost",
-        api_models::enums::EventType::MandateActive => "mandate.active",
-        api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::DisputeOpened => "charge.dispute.created",
+        api_models::enums::EventType::DisputeChallenged
+        | api_models::enums::EventType::DisputeDeadlineApproaching => "charge.dispute.updated",
+        api_models::enums::EventType::DisputeExpired
+        | api_models::enums::EventType::DisputeAccepted
+        | api_models::enums::EventType::DisputeCancelled
+        | api_models::enums::EventType::DisputeWon
+        | api_models::enums::EventType::DisputeLost => "charge.dispute.closed",
+        api_models::enums::EventType::MandateActive
+        | api_models::enums::EventType::MandateRevoked => "mandate.updated",
+        api_models::enums::EventType::CaptureSucceeded => "charge.captured",
+        api_models::enums::EventType::CaptureFailed => "charge.failed",
     }
 }
 
@@ -193,6 +249,8 @@ impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
             stype: get_stripe_event_type(value.event_type),
             data: StripeWebhookObject::from(value.content),
             object: "event",
+            api_version: STRIPE_COMPATIBLE_API_VERSION,
+            livemode: router_env::env::which() == router_env::env::Env::Production,
             // put this conversion it into a function
             created: u64::try_from(value.timestamp.assume_utc().unix_timestamp()).unwrap_or_else(
                 |error| {
@@ -223,6 +281,144 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::CaptureDetails(capture) => Self::Charge((*capture).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use api_models::enums::EventType;
+    use common_utils::{
+        crypto::{HmacSha256, SignMessage},
+        date_time,
+        ext_traits::Encode,
+    };
+
+    use super::*;
+
+    /// The default tolerance used by the stripe libraries when verifying a signature
+    const SIGNATURE_TOLERANCE_SECONDS: i64 = 300;
+
+    fn get_mandate_webhook(event_type: EventType) -> StripeOutgoingWebhook {
+        StripeOutgoingWebhook::from(api::OutgoingWebhook {
+            merchant_id: "merchant_1".to_string(),
+            event_id: "evt_1".to_string(),
+            event_type,
+            content: api::OutgoingWebhookContent::MandateDetails(Box::new(
+                api_models::mandates::MandateResponse {
+                    mandate_id: "man_1".to_string(),
+                    status: MandateStatus::Active,
+                    payment_method_id: "pm_1".to_string(),
+                    payment_method: "card".to_string(),
+                    ..Default::default()
+                },
+            )),
+            timestamp: date_time::now(),
+        })
+    }
+
+    /// Verifies the signature header the way the stripe libraries do
+    fn verify_stripe_signature(payload: &str, header: &str, secret: &str) -> bool {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+        for (key, value) in header.split(',').filter_map(|item| item.split_once('=')) {
+            match key {
+                "t" => timestamp = value.parse::<i64>().ok(),
+                "v1" => signatures.push(value),
+                _ => {}
+            }
+        }
+        let Some(timestamp) = timestamp else {
+            return false;
+        };
+
+        let expected_signature = hex::encode(
+            HmacSha256
+                .sign_message(
+                    secret.as_bytes(),
+                    format!("{timestamp}.{payload}").as_bytes(),
+                )
+                .unwrap(),
+        );
+        let now = date_time::now().assume_utc().unix_timestamp();
+
+        signatures.contains(&expected_signature.as_str())
+            && (now - timestamp).abs() <= SIGNATURE_TOLERANCE_SECONDS
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_is_verifiable() {
+        let webhook = get_mandate_webhook(EventType::MandateActive);
+        let payload = Encode::<serde_json::Value>::encode_to_string_of_json(&webhook).unwrap();
+
+        let header = webhook
+            .get_outgoing_webhooks_signature(Some("whsec_test".to_string()))
+            .unwrap()
+            .expect("signature must be generated");
+
+        assert!(header.starts_with("t="));
+        assert!(verify_stripe_signature(&payload, &header, "whsec_test"));
+        assert!(!verify_stripe_signature(&payload, &header, "whsec_other"));
+        assert!(!verify_stripe_signature(
+            &payload.replace("man_1", "man_2"),
+            &header,
+            "whsec_test"
+        ));
+    }
+
+    #[test]
+    fn test_outgoing_webhook_signature_requires_key() {
+        let webhook = get_mandate_webhook(EventType::MandateActive);
+        assert!(webhook.get_outgoing_webhooks_signature(None).is_err());
+    }
+
+    #[test]
+    fn test_outgoing_webhook_envelope() {
+        let webhook = get_mandate_webhook(EventType::MandateRevoked);
+        let event = Encode::<serde_json::Value>::encode_to_value(&webhook).unwrap();
+
+        assert_eq!(event["id"], "evt_1");
+        assert_eq!(event["object"], "event");
+        assert_eq!(event["type"], "mandate.updated");
+        assert_eq!(event["api_version"], STRIPE_COMPATIBLE_API_VERSION);
+        assert_eq!(event["livemode"], false);
+        assert!(event["created"].is_u64());
+        assert_eq!(event["data"]["object"]["object"], "mandate");
+        assert_eq!(event["data"]["object"]["id"], "man_1");
+    }
+
+    #[test]
+    fn test_stripe_event_types() {
+        let event_types = [
+            (EventType::PaymentSucceeded, "payment_intent.succeeded"),
+            (EventType::PaymentFailed, "payment_intent.payment_failed"),
+            (EventType::PaymentProcessing, "payment_intent.processing"),
+            (EventType::PaymentCancelled, "payment_intent.canceled"),
+            (EventType::PaymentExpired, "payment_intent.canceled"),
+            (EventType::ActionRequired, "payment_intent.requires_action"),
+            (EventType::RefundSucceeded, "refund.updated"),
+            (EventType::RefundFailed, "refund.failed"),
+            (EventType::DisputeOpened, "charge.dispute.created"),
+            (EventType::DisputeChallenged, "charge.dispute.updated"),
+            (EventType::DisputeExpired, "charge.dispute.closed"),
+            (EventType::DisputeAccepted, "charge.dispute.closed"),
+            (EventType::DisputeCancelled, "charge.dispute.closed"),
+            (EventType::DisputeWon, "charge.dispute.closed"),
+            (EventType::DisputeLost, "charge.dispute.closed"),
+            (
+                EventType::DisputeDeadlineApproaching,
+                "charge.dispute.updated",
+            ),
+            (EventType::MandateActive, "mandate.updated"),
+            (EventType::MandateRevoked, "mandate.updated"),
+            (EventType::CaptureSucceeded, "charge.captured"),
+            (EventType::CaptureFailed, "charge.failed"),
+        ];
+
+        for (event_type, stripe_event_type) in event_types {
+            assert_eq!(get_stripe_event_type(event_type), stripe_event_type);
         }
     }
 }
@@ -15,6 +15,7 @@ impl Default for super::settings::Server {
             request_body_limit: 16 * 1024, // POST request body is limited to 16KiB
             base_url: "http://localhost:8080".into(),
             shutdown_timeout: 30,
+            trusted_proxies: Vec::new(),
         }
     }
 }
@@ -46,6 +47,45 @@ impl Default for super::settings::Proxy {
     }
 }
 
+impl Default for super::settings::ForexApi {
+    fn default() -> Self {
+        Self {
+            providers: vec![
+                super::settings::ForexProviderKind::OpenExchangeRates,
+                super::settings::ForexProviderKind::ApiLayer,
+            ],
+            static_rates_file: None,
+            local_fetch_retry_count: 0,
+            api_key: Default::default(),
+            fallback_api_key: Default::default(),
+            call_delay: 0,
+            local_fetch_retry_delay: 0,
+            api_timeout: 0,
+            redis_lock_timeout: 0,
+        }
+    }
+}
+
+impl Default for super::settings::MultiCurrency {
+    fn default() -> Self {
+        Self {
+            // 15 minutes
+            quote_validity: 15 * 60,
+            markup_basis_points: 0,
+        }
+    }
+}
+
+impl Default for super::settings::AuthorizationExpiryPolicy {
+    fn default() -> Self {
+        Self {
+            // 7 days
+            validity_hours: 7 * 24,
+            action: super::settings::AuthorizationExpiryAction::Expire,
+        }
+    }
+}
+
 impl Default for super::settings::Locker {
     fn default() -> Self {
         Self {
@@ -4723,6 +4763,17 @@ impl Default for super::settings::ApiKeys {
             // Specifies the number of days before API key expiry when email reminders should be sent
             #[cfg(feature = "email")]
             expiry_reminder_days: vec![7, 3, 1],
+
+            // Specifies the number of seconds for which a rotated API key remains valid
+            rotation_overlap_in_secs: 60 * 60 * 24, // 1 day
+
+            // Specifies the maximum number of seconds for which a rotated API key can be requested
+            // to remain valid
+            max_rotation_overlap_in_secs: 60 * 60 * 24 * 7, // 7 days
+
+            // Specifies the minimum number of seconds between writes of the last used time of an
+            // API key to the database
+            last_used_flush_interval_in_secs: 60 * 5, // 5 minutes
         }
     }
 }
@@ -0,0 +1,266 @@
+use common_utils::errors::CustomResult;
+use external_services::secrets_management::{SecretManagementInterface, SecretsManagementError};
+use masking::{PeekInterface, Secret};
+
+#[cfg(feature = "olap")]
+use crate::analytics::AnalyticsConfig;
+use crate::configs::settings;
+
+/// Decrypts the secrets held in a configuration structure using the configured secret manager
+#[async_trait::async_trait]
+pub trait SecretsHandler: Sized {
+    async fn decrypt_secrets(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError>;
+}
+
+/// Decrypts the secret, leaving the values which were not configured untouched
+async fn decrypt_secret(
+    secret_management_client: &dyn SecretManagementInterface,
+    value: Secret<String>,
+) -> CustomResult<Secret<String>, SecretsManagementError> {
+    if value.peek().is_empty() {
+        return Ok(value);
+    }
+
+    secret_management_client.get_secret(value).await
+}
+
+async fn decrypt_string(
+    secret_management_client: &dyn SecretManagementInterface,
+    value: String,
+) -> CustomResult<String, SecretsManagementError> {
+    decrypt_secret(secret_management_client, Secret::new(value))
+        .await
+        .map(masking::ExposeInterface::expose)
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::Secrets {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        (
+            self.jwt_secret,
+            self.admin_api_key,
+            self.recon_admin_api_key,
+            self.master_enc_key,
+        ) = tokio::try_join!(
+            decrypt_string(secret_management_client, self.jwt_secret),
+            decrypt_string(secret_management_client, self.admin_api_key),
+            decrypt_string(secret_management_client, self.recon_admin_api_key),
+            decrypt_secret(secret_management_client, self.master_enc_key),
+        )?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::Database {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        self.password = decrypt_secret(secret_management_client, self.password).await?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::Jwekey {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        (
+            self.locker_encryption_key1,
+            self.locker_encryption_key2,
+            self.locker_decryption_key1,
+            self.locker_decryption_key2,
+            self.vault_encryption_key,
+            self.rust_locker_encryption_key,
+            self.vault_private_key,
+            self.tunnel_private_key,
+        ) = tokio::try_join!(
+            decrypt_string(secret_management_client, self.locker_encryption_key1),
+            decrypt_string(secret_management_client, self.locker_encryption_key2),
+            decrypt_string(secret_management_client, self.locker_decryption_key1),
+            decrypt_string(secret_management_client, self.locker_decryption_key2),
+            decrypt_string(secret_management_client, self.vault_encryption_key),
+            decrypt_string(secret_management_client, self.rust_locker_encryption_key),
+            decrypt_string(secret_management_client, self.vault_private_key),
+            decrypt_string(secret_management_client, self.tunnel_private_key),
+        )?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ApiKeys {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        self.hash_key = decrypt_string(secret_management_client, self.hash_key).await?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ForexApi {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        (self.api_key, self.fallback_api_key) = tokio::try_join!(
+            decrypt_secret(secret_management_client, self.api_key),
+            decrypt_secret(secret_management_client, self.fallback_api_key),
+        )?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ApplePayDecryptConifg {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        (
+            self.apple_pay_ppc,
+            self.apple_pay_ppc_key,
+            self.apple_pay_merchant_cert,
+            self.apple_pay_merchant_cert_key,
+        ) = tokio::try_join!(
+            decrypt_string(secret_management_client, self.apple_pay_ppc),
+            decrypt_string(secret_management_client, self.apple_pay_ppc_key),
+            decrypt_string(secret_management_client, self.apple_pay_merchant_cert),
+            decrypt_string(secret_management_client, self.apple_pay_merchant_cert_key),
+        )?;
+        Ok(self)
+    }
+}
+
+#[cfg(not(feature = "kms"))]
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ApplepayMerchantConfigs {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        (
+            self.merchant_cert,
+            self.merchant_cert_key,
+            self.common_merchant_identifier,
+        ) = tokio::try_join!(
+            decrypt_string(secret_management_client, self.merchant_cert),
+            decrypt_string(secret_management_client, self.merchant_cert_key),
+            decrypt_string(secret_management_client, self.common_merchant_identifier),
+        )?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl SecretsHandler for AnalyticsConfig {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        // Builds with the kms feature decrypt the sqlx password with the `kms` configuration when
+        // the analytics provider is created
+        #[cfg_attr(feature = "kms", allow(unused_variables))]
+        let (sqlx, clickhouse) = match &mut self {
+            Self::Sqlx { sqlx } => (Some(sqlx), None),
+            Self::Clickhouse { clickhouse } => (None, Some(clickhouse)),
+            Self::CombinedCkh { sqlx, clickhouse } | Self::CombinedSqlx { sqlx, clickhouse } => {
+                (Some(sqlx), Some(clickhouse))
+            }
+        };
+
+        #[cfg(not(feature = "kms"))]
+        if let Some(sqlx) = sqlx {
+            sqlx.password = decrypt_secret(secret_management_client, sqlx.password.clone()).await?;
+        }
+        if let Some(clickhouse) = clickhouse {
+            if let Some(password) = clickhouse.password.take() {
+                clickhouse.password =
+                    Some(decrypt_string(secret_management_client, password).await?);
+            }
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "email")]
+#[async_trait::async_trait]
+impl SecretsHandler for external_services::email::EmailSettings {
+    async fn decrypt_secrets(
+        mut self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        if let Some(smtp) = self.smtp.as_mut() {
+            if let Some(password) = smtp.password.take() {
+                smtp.password = Some(decrypt_secret(secret_management_client, password).await?);
+            }
+        }
+        Ok(self)
+    }
+}
+
+impl settings::Settings {
+    /// Decrypts the secrets held in the configuration with the secret manager selected in the
+    /// `secrets_management` section
+    pub async fn decrypt_secrets(mut self) -> CustomResult<Self, SecretsManagementError> {
+        // Not every binary validates its configuration, and a secrets manager unusable in this
+        // build must not be skipped
+        self.secrets_management.validate().map_err(|error| {
+            error_stack::report!(SecretsManagementError::ClientCreationFailed)
+                .attach_printable(error)
+        })?;
+        let secret_management_client = self
+            .secrets_management
+            .get_secret_management_client()
+            .await?;
+        let client = secret_management_client.as_ref();
+
+        // Builds with the kms feature decrypt these secrets with the `kms` configuration where
+        // they are used
+        #[cfg(not(feature = "kms"))]
+        {
+            self.secrets = self.secrets.decrypt_secrets(client).await?;
+            self.master_database = self.master_database.decrypt_secrets(client).await?;
+            #[cfg(feature = "olap")]
+            {
+                self.replica_database = self.replica_database.decrypt_secrets(client).await?;
+            }
+            self.jwekey = self.jwekey.decrypt_secrets(client).await?;
+            self.api_keys = self.api_keys.decrypt_secrets(client).await?;
+            self.forex_api = self.forex_api.decrypt_secrets(client).await?;
+            self.applepay_decrypt_keys = self.applepay_decrypt_keys.decrypt_secrets(client).await?;
+            self.applepay_merchant_configs = self
+                .applepay_merchant_configs
+                .decrypt_secrets(client)
+                .await?;
+        }
+        #[cfg(feature = "olap")]
+        {
+            self.analytics = self.analytics.decrypt_secrets(client).await?;
+        }
+        #[cfg(feature = "email")]
+        {
+            self.email = self.email.decrypt_secrets(client).await?;
+        }
+
+        Ok(self)
+    }
+}
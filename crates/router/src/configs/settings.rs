@@ -13,12 +13,13 @@ use config::{Environment, File};
 use external_services::email::EmailSettings;
 #[cfg(feature = "kms")]
 use external_services::kms;
+use external_services::secrets_management::SecretsManagementConfig;
 use redis_interface::RedisSettings;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use rust_decimal::Decimal;
 use scheduler::SchedulerSettings;
 use serde::{de::Error, Deserialize, Deserializer};
-use storage_impl::config::QueueStrategy;
+use storage_impl::{config::QueueStrategy, redis::cache::CacheSettings};
 
 #[cfg(feature = "olap")]
 use crate::analytics::AnalyticsConfig;
@@ -71,11 +72,14 @@ pub struct Settings {
     #[cfg(feature = "olap")]
     pub replica_database: Database,
     pub redis: RedisSettings,
+    pub cache: CacheSettings,
     pub log: Log,
     pub secrets: Secrets,
     pub locker: Locker,
     pub connectors: Connectors,
     pub forex_api: ForexApi,
+    pub multi_currency: MultiCurrency,
+    pub authorization_expiry: AuthorizationExpiry,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
@@ -88,6 +92,7 @@ pub struct Settings {
     pub api_keys: ApiKeys,
     #[cfg(feature = "kms")]
     pub kms: kms::KmsConfig,
+    pub secrets_management: SecretsManagementConfig,
     #[cfg(feature = "s3")]
     pub file_upload_config: FileUploadConfig,
     pub tokenization: TokenizationConfig,
@@ -107,6 +112,7 @@ pub struct Settings {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: ApplepayMerchantConfigs,
     pub lock_settings: LockSettings,
+    pub rate_limit: RateLimitSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub payment_link: PaymentLink,
     #[cfg(feature = "olap")]
@@ -126,11 +132,17 @@ pub struct KvConfig {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct PaymentLink {
     pub sdk_url: String,
+    /// Limit on the payments created from each multi use payment link
+    pub payments_rate_limit: Option<TokenBucketSettings>,
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct ForexApi {
+    /// Providers to fetch rates from, tried in the order listed
+    pub providers: Vec<ForexProviderKind>,
+    /// Rates file read by the `static_file` provider
+    pub static_rates_file: Option<PathBuf>,
     pub local_fetch_retry_count: u64,
     pub api_key: masking::Secret<String>,
     pub fallback_api_key: masking::Secret<String>,
@@ -144,6 +156,65 @@ pub struct ForexApi {
     pub redis_lock_timeout: u64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MultiCurrency {
+    /// Time in seconds for which a currency conversion quote can be used to confirm a payment
+    pub quote_validity: i64,
+    /// Markup applied on the market rate of currency conversions, in basis points
+    pub markup_basis_points: u16,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AuthorizationExpiry {
+    /// Policy applied when no connector or card network specific policy is configured
+    pub default_policy: AuthorizationExpiryPolicy,
+    /// Policies per connector, keyed by connector name
+    pub connectors: HashMap<String, AuthorizationExpiryPolicy>,
+    /// Policies per card network, keyed by the lowercase network name. These take precedence
+    /// over the connector policies
+    pub card_networks: HashMap<String, AuthorizationExpiryPolicy>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct AuthorizationExpiryPolicy {
+    /// Hours after which an authorization lapses at the connector
+    pub validity_hours: u32,
+    /// What is done with a payment still awaiting capture when its authorization lapses
+    pub action: AuthorizationExpiryAction,
+}
+
+#[derive(Debug, Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    Capture,
+    Void,
+    Expire,
+}
+
+impl AuthorizationExpiry {
+    pub fn get_policy(
+        &self,
+        connector: Option<&str>,
+        card_network: Option<&enums::CardNetwork>,
+    ) -> AuthorizationExpiryPolicy {
+        card_network
+            .and_then(|network| self.card_networks.get(&network.to_string().to_lowercase()))
+            .or_else(|| connector.and_then(|connector| self.connectors.get(connector)))
+            .copied()
+            .unwrap_or(self.default_policy)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ForexProviderKind {
+    OpenExchangeRates,
+    ApiLayer,
+    StaticFile,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DefaultExchangeRates {
     pub base_currency: String,
@@ -511,6 +582,9 @@ pub struct Server {
     pub request_body_limit: usize,
     pub base_url: String,
     pub shutdown_timeout: u64,
+    /// The IP addresses or CIDR ranges of the proxies in front of the server, whose
+    /// `X-Forwarded-For` header is trusted to carry the address of the client
+    pub trusted_proxies: Vec<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -692,6 +766,17 @@ pub struct ApiKeys {
     // Specifies the number of days before API key expiry when email reminders should be sent
     #[cfg(feature = "email")]
     pub expiry_reminder_days: Vec<u8>,
+
+    // Specifies the number of seconds for which a rotated API key remains valid
+    pub rotation_overlap_in_secs: u32,
+
+    // Specifies the maximum number of seconds for which a rotated API key can be requested to remain
+    // valid
+    pub max_rotation_overlap_in_secs: u32,
+
+    // Specifies the minimum number of seconds between writes of the last used time of an API key
+    // to the database, the latest time is kept in Redis in between
+    pub last_used_flush_interval_in_secs: u32,
 }
 
 #[cfg(feature = "s3")]
@@ -775,6 +860,7 @@ impl Settings {
                     .list_separator(",")
                     .with_list_parse_key("log.telemetry.route_to_trace")
                     .with_list_parse_key("redis.cluster_urls")
+                    .with_list_parse_key("redis.sentinel_urls")
                     .with_list_parse_key("connectors.supported.wallets")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
@@ -812,6 +898,8 @@ impl Settings {
         }
         self.secrets.validate()?;
         self.locker.validate()?;
+        self.forex_api.validate()?;
+        self.authorization_expiry.validate()?;
         self.connectors.validate("connectors")?;
 
         self.scheduler
@@ -825,9 +913,18 @@ impl Settings {
         self.kms
             .validate()
             .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        self.secrets_management
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
+        #[cfg(feature = "email")]
+        self.email
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.into()))?;
         #[cfg(feature = "s3")]
         self.file_upload_config.validate()?;
         self.lock_settings.validate()?;
+        self.rate_limit.validate()?;
+        self.payment_link.validate()?;
         self.events.validate()?;
         Ok(())
     }
@@ -857,6 +954,29 @@ pub struct Payouts {
     pub payout_eligibility: bool,
 }
 
+/// Token bucket limits on the rate of requests, checked once the request has been authenticated.
+/// The buckets are kept in Redis, and in the memory of the server if Redis is not available.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    pub enabled: bool,
+    /// Limit on the requests of each merchant
+    pub merchant: Option<TokenBucketSettings>,
+    /// Limit on the requests made with each API key
+    pub api_key: Option<TokenBucketSettings>,
+    /// Limits on the requests of each merchant to a group of routes, keyed by the name of the
+    /// route group, such as `payments` or `refunds`
+    pub route_groups: HashMap<String, TokenBucketSettings>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+pub struct TokenBucketSettings {
+    /// The number of requests that can be made in a burst
+    pub capacity: u32,
+    /// The number of requests added back to the bucket every second
+    pub refill_rate: u32,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LockSettings {
     pub redis_lock_expiry_seconds: u32,
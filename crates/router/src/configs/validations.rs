@@ -45,6 +45,49 @@ impl super::settings::Secrets {
     }
 }
 
+impl super::settings::ForexApi {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.providers.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "at least one forex provider must be configured".into(),
+            ))
+        })?;
+
+        when(
+            self.providers
+                .contains(&super::settings::ForexProviderKind::StaticFile)
+                && self.static_rates_file.is_none(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "static rates file must be set when using the static file forex provider"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::AuthorizationExpiry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        let policies = std::iter::once(&self.default_policy)
+            .chain(self.connectors.values())
+            .chain(self.card_networks.values());
+
+        for policy in policies {
+            when(policy.validity_hours == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "authorization validity must be at least an hour".into(),
+                ))
+            })?;
+        }
+        Ok(())
+    }
+}
+
 impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -68,11 +111,24 @@ impl super::settings::Locker {
 
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
-        common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "server host must not be empty".into(),
             ))
-        })
+        })?;
+
+        when(
+            !self.trusted_proxies.iter().all(|entry| {
+                crate::services::authentication::api_key_scope::is_valid_ip_range(entry)
+            }),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "server trusted proxies must be IP addresses or CIDR ranges".into(),
+                ))
+            },
+        )
     }
 }
 
@@ -151,17 +207,74 @@ impl super::settings::ApiKeys {
         use common_utils::fp_utils::when;
 
         #[cfg(feature = "kms")]
-        return when(self.kms_encrypted_hash_key.is_default_or_empty(), || {
+        when(self.kms_encrypted_hash_key.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty when KMS feature is enabled".into(),
             ))
-        });
+        })?;
 
         #[cfg(not(feature = "kms"))]
         when(self.hash_key.is_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "API key hashing key must not be empty".into(),
             ))
+        })?;
+
+        when(
+            self.rotation_overlap_in_secs > self.max_rotation_overlap_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "API key rotation overlap must not exceed the maximum rotation overlap".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::RateLimitSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        use common_utils::fp_utils::when;
+
+        use crate::routes::lock_utils::ApiIdentifier;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        for route_group in self.route_groups.keys() {
+            when(ApiIdentifier::from_str(route_group).is_err(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "rate limit route group `{route_group}` is not a known route group"
+                )))
+            })?;
+        }
+
+        self.merchant
+            .iter()
+            .chain(self.api_key.iter())
+            .chain(self.route_groups.values())
+            .try_for_each(|bucket| {
+                when(bucket.capacity == 0 || bucket.refill_rate == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "rate limit capacity and refill_rate must not be 0".into(),
+                    ))
+                })
+            })
+    }
+}
+
+impl super::settings::PaymentLink {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.payments_rate_limit.iter().try_for_each(|bucket| {
+            when(bucket.capacity == 0 || bucket.refill_rate == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "payment link rate limit capacity and refill_rate must not be 0".into(),
+                ))
+            })
         })
     }
 }
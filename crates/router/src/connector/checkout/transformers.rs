@@ -755,10 +755,11 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsCaptureRouterData>> for Payment
         let connector_auth = &item.router_data.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
-        let capture_type = if item.router_data.request.is_multiple_capture() {
-            CaptureType::NonFinal
-        } else {
-            CaptureType::Final
+        let capture_type = match &item.router_data.request.multiple_capture_data {
+            Some(multiple_capture_data) if !multiple_capture_data.final_capture => {
+                CaptureType::NonFinal
+            }
+            _ => CaptureType::Final,
         };
         let reference = item
             .router_data
@@ -826,6 +827,8 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
 pub struct RefundRequest {
     amount: Option<i64>,
     reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capture_action_id: Option<String>,
 }
 
 impl<F> TryFrom<&CheckoutRouterData<&types::RefundsRouterData<F>>> for RefundRequest {
@@ -837,6 +840,8 @@ impl<F> TryFrom<&CheckoutRouterData<&types::RefundsRouterData<F>>> for RefundReq
         Ok(Self {
             amount: Some(item.amount.to_owned()),
             reference,
+            // The action id of the capture is its connector capture id
+            capture_action_id: item.router_data.request.connector_capture_id.clone(),
         })
     }
 }
@@ -253,6 +253,29 @@ pub struct FortePaymentsResponse {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ForteMeta {
     pub auth_id: String,
+    /// Address and card verification results of the authorization, kept for dispute evidence
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avs_result: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cvv_result: Option<String>,
+}
+
+impl ForteMeta {
+    /// Carries the verification results of the authorization over from the connector metadata of
+    /// the payment, since the capture and void responses do not report them
+    fn keeping_verification_results(
+        auth_id: String,
+        connector_meta: Option<serde_json::Value>,
+    ) -> Self {
+        let previous_meta = utils::to_connector_meta::<Self>(connector_meta).ok();
+        Self {
+            auth_id,
+            avs_result: previous_meta
+                .as_ref()
+                .and_then(|meta| meta.avs_result.clone()),
+            cvv_result: previous_meta.and_then(|meta| meta.cvv_result),
+        }
+    }
 }
 
 impl<F, T>
@@ -274,6 +297,8 @@ impl<F, T>
                 mandate_reference: None,
                 connector_metadata: Some(serde_json::json!(ForteMeta {
                     auth_id: item.response.authorization_code,
+                    avs_result: item.response.response.avs_result,
+                    cvv_result: item.response.response.cvv_result,
                 })),
                 network_txn_id: None,
                 connector_response_reference_id: Some(transaction_id.to_string()),
@@ -322,6 +347,8 @@ impl<F, T>
                 mandate_reference: None,
                 connector_metadata: Some(serde_json::json!(ForteMeta {
                     auth_id: item.response.authorization_code,
+                    avs_result: item.response.response.avs_result,
+                    cvv_result: item.response.response.cvv_result,
                 })),
                 network_txn_id: None,
                 connector_response_reference_id: Some(transaction_id.to_string()),
@@ -388,9 +415,12 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<ForteCaptureResponse>>
                 resource_id: types::ResponseId::ConnectorTransactionId(transaction_id.clone()),
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: Some(serde_json::json!(ForteMeta {
-                    auth_id: item.response.authorization_code,
-                })),
+                connector_metadata: Some(serde_json::json!(
+                    ForteMeta::keeping_verification_results(
+                        item.response.authorization_code,
+                        item.data.request.connector_meta.clone(),
+                    )
+                )),
                 network_txn_id: None,
                 connector_response_reference_id: Some(item.response.transaction_id.to_string()),
                 incremental_authorization_allowed: None,
@@ -441,13 +471,12 @@ pub struct ForteCancelResponse {
     pub response: CancelResponseStatus,
 }
 
-impl<F, T>
-    TryFrom<types::ResponseRouterData<F, ForteCancelResponse, T, types::PaymentsResponseData>>
-    for types::RouterData<F, T, types::PaymentsResponseData>
+impl TryFrom<types::PaymentsCancelResponseRouterData<ForteCancelResponse>>
+    for types::PaymentsCancelRouterData
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<F, ForteCancelResponse, T, types::PaymentsResponseData>,
+        item: types::PaymentsCancelResponseRouterData<ForteCancelResponse>,
     ) -> Result<Self, Self::Error> {
         let transaction_id = &item.response.transaction_id;
         Ok(Self {
@@ -456,9 +485,12 @@ impl<F, T>
                 resource_id: types::ResponseId::ConnectorTransactionId(transaction_id.to_string()),
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: Some(serde_json::json!(ForteMeta {
-                    auth_id: item.response.authorization_code,
-                })),
+                connector_metadata: Some(serde_json::json!(
+                    ForteMeta::keeping_verification_results(
+                        item.response.authorization_code,
+                        item.data.request.connector_meta.clone(),
+                    )
+                )),
                 network_txn_id: None,
                 connector_response_reference_id: Some(transaction_id.to_string()),
                 incremental_authorization_allowed: None,
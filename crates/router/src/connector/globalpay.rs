@@ -725,10 +725,15 @@ impl ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsRespon
         req: &types::RefundsRouterData<api::Execute>,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
+        // Each capture of a payment captured in multiple parts is a transaction of its own, which
+        // is refunded separately
         Ok(format!(
             "{}transactions/{}/refund",
             self.base_url(connectors),
-            req.request.connector_transaction_id
+            req.request
+                .connector_capture_id
+                .as_ref()
+                .unwrap_or(&req.request.connector_transaction_id)
         ))
     }
 
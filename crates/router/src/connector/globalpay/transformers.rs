@@ -10,7 +10,9 @@ use super::{
         self, ApmProvider, GlobalpayPaymentsRequest, GlobalpayRefreshTokenRequest, Initiator,
         PaymentMethodData, Sequence, StoredCredential,
     },
-    response::{GlobalpayPaymentStatus, GlobalpayPaymentsResponse, GlobalpayRefreshTokenResponse},
+    response::{
+        Card, GlobalpayPaymentStatus, GlobalpayPaymentsResponse, GlobalpayRefreshTokenResponse,
+    },
 };
 use crate::{
     connector::utils::{self, CardData, PaymentsAuthorizeRequestData, RouterData, WalletData},
@@ -92,7 +94,9 @@ impl TryFrom<&types::PaymentsCaptureRouterData> for requests::GlobalpayCaptureRe
         Ok(Self {
             amount: Some(value.request.amount_to_capture.to_string()),
             capture_sequence: value.request.multiple_capture_data.clone().map(|mcd| {
-                if mcd.capture_sequence == 1 {
+                if mcd.final_capture {
+                    Sequence::Last
+                } else if mcd.capture_sequence == 1 {
                     Sequence::First
                 } else {
                     Sequence::Subsequent
@@ -219,6 +223,11 @@ fn get_payment_response(
                 payment_method_id: None,
             })
     });
+    let connector_metadata = response
+        .payment_method
+        .as_ref()
+        .and_then(|pm| pm.card.as_ref())
+        .and_then(get_verification_results);
     match status {
         enums::AttemptStatus::Failure => Err(ErrorResponse {
             message: response
@@ -231,7 +240,7 @@ fn get_payment_response(
             resource_id: types::ResponseId::ConnectorTransactionId(response.id),
             redirection_data,
             mandate_reference,
-            connector_metadata: None,
+            connector_metadata,
             network_txn_id: None,
             connector_response_reference_id: response.reference,
             incremental_authorization_allowed: None,
@@ -239,6 +248,25 @@ fn get_payment_response(
     }
 }
 
+/// Address and card verification results of the card, kept in the connector metadata of the
+/// payment for dispute evidence. Responses that do not report them leave the metadata untouched.
+fn get_verification_results(card: &Card) -> Option<serde_json::Value> {
+    let provider = card.provider.as_ref();
+    let avs_result = provider
+        .and_then(|provider| provider.card_provider_avs_result.clone())
+        .or_else(|| card.avs_address_result.clone());
+    let cvv_result = card
+        .cvv_result
+        .clone()
+        .or_else(|| provider.and_then(|provider| provider.card_provider_cvv_result.clone()));
+    (avs_result.is_some() || cvv_result.is_some()).then(|| {
+        serde_json::json!({
+            "avs_result": avs_result,
+            "cvv_result": cvv_result,
+        })
+    })
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, GlobalpayPaymentsResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
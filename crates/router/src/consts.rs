@@ -38,6 +38,8 @@ pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::STANDARD;
 pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
+pub(crate) const BASE64_ENGINE_URL_SAFE_NO_PAD: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::URL_SAFE_NO_PAD;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
@@ -60,12 +62,44 @@ pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
 
+pub const API_KEY_LAST_USED_PREFIX: &str = "API_KEY_LAST_USED_";
+pub const API_KEY_LAST_USED_FLUSH_PREFIX: &str = "API_KEY_LAST_USED_FLUSH_";
+pub const API_KEY_LAST_USED_EXPIRY_SECONDS: i64 = 60 * 60 * 24 * 7; // 7 days
+
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub const JWT_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24 * 2; // 2 days
 
+#[cfg(any(feature = "olap", feature = "oltp"))]
+pub const USER_BLACKLIST_PREFIX: &str = "BU_";
+
+#[cfg(feature = "olap")]
+pub const SINGLE_PURPOSE_TOKEN_TIME_IN_SECS: u64 = 60 * 5; // 5 minutes
+
+#[cfg(feature = "olap")]
+pub const SINGLE_PURPOSE_TOKEN_BLACKLIST_PREFIX: &str = "BSPT_";
+
 #[cfg(feature = "email")]
 pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 
+#[cfg(feature = "email")]
+pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
+
+#[cfg(feature = "email")]
+pub const MERCHANT_ALERT_REDIS_PREFIX: &str = "MERCHANT_ALERT";
+#[cfg(feature = "email")]
+pub const REFUND_VOLUME_ALERT_WINDOW_IN_SECS: i64 = 60 * 60 * 24; // 1 day
+#[cfg(feature = "email")]
+pub const WEBHOOK_FAILURE_ALERT_INTERVAL_IN_SECS: i64 = 60 * 60; // 1 hour
+
+/// Number of hours before the evidence of a dispute is due at which the dispute is accepted, when
+/// it is eligible for automatic acceptance, leaving the merchant time to act if the connector fails
+/// to accept it
+pub const DISPUTE_AUTO_ACCEPT_OFFSET_IN_HOURS: i64 = 2;
+
+/// Number of minutes after which the daily forex rate snapshot is retried when no forex provider
+/// could be reached
+pub const FOREX_RATE_SNAPSHOT_RETRY_DELAY_IN_MINUTES: i64 = 60;
+
 #[cfg(feature = "olap")]
 pub const VERIFY_CONNECTOR_ID_PREFIX: &str = "conn_verify";
 #[cfg(feature = "olap")]
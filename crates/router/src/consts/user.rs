@@ -1,2 +1,25 @@
 pub const MAX_NAME_LENGTH: usize = 70;
 pub const MAX_COMPANY_NAME_LENGTH: usize = 70;
+
+pub const TOTP_ISSUER_NAME: &str = "Hyperswitch";
+/// Length of the TOTP secret in bytes, as recommended by RFC 4226
+pub const TOTP_SECRET_LENGTH: usize = 20;
+pub const TOTP_DIGITS: usize = 6;
+/// Number of time steps before and after the current one in which a TOTP is still accepted
+pub const TOTP_TOLERANCE: u8 = 1;
+pub const TOTP_VALIDITY_DURATION_IN_SECONDS: u64 = 30;
+pub const TOTP_RECOVERY_CODES_COUNT: usize = 8;
+pub const TOTP_RECOVERY_CODE_LENGTH: usize = 10;
+pub const TOTP_FAILED_ATTEMPTS_REDIS_PREFIX: &str = "TOTP_FAILED_ATTEMPTS_";
+/// Number of failed TOTP or recovery code attempts after which the user is locked out
+pub const TOTP_MAX_FAILED_ATTEMPTS: i64 = 5;
+pub const TOTP_LOCKOUT_DURATION_IN_SECS: i64 = 60 * 15; // 15 minutes
+
+pub const SSO_STATE_REDIS_PREFIX: &str = "SSO_STATE";
+pub const SSO_STATE_EXPIRY_IN_SECS: i64 = 60 * 10; // 10 minutes
+pub const SSO_STATE_LENGTH: usize = 32;
+/// Length of the PKCE code verifier, which RFC 7636 requires to be between 43 and 128
+pub const PKCE_CODE_VERIFIER_LENGTH: usize = 64;
+pub const OIDC_DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+pub const OIDC_SCOPES: &str = "openid email profile";
+pub const TEMP_PASSWORD_LENGTH: usize = 64;
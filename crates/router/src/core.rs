@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
+pub mod audit_log;
 pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
@@ -15,11 +16,15 @@ pub mod gsm;
 pub mod locker_migration;
 pub mod mandate;
 pub mod metrics;
+#[cfg(feature = "email")]
+pub mod notifications;
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_config;
@@ -2,7 +2,8 @@ use std::str::FromStr;
 
 use api_models::{
     admin::{self as admin_types},
-    enums as api_enums, routing as routing_types,
+    enums::{self as api_enums, AuditAction, AuditResourceType},
+    routing as routing_types,
 };
 use common_utils::{
     crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
@@ -18,6 +19,7 @@ use uuid::Uuid;
 use crate::{
     consts,
     core::{
+        audit_log,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::helpers,
         routing::helpers as routing_helpers,
@@ -208,6 +210,7 @@ pub async fn create_merchant_account(
             default_profile: None,
             recon_status: diesel_models::enums::ReconStatus::NotRequested,
             payment_link_config,
+            bound_profile_id: None,
         })
     }
     .await
@@ -270,12 +273,23 @@ pub async fn create_merchant_account(
     })
     .ok();
 
-    Ok(service_api::ApplicationResponse::Json(
-        merchant_account
-            .try_into()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let merchant_account: api::MerchantAccountResponse = merchant_account
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_log::record(
+        &state,
+        Some(&merchant_account.merchant_id),
+        AuditAction::Create,
+        AuditResourceType::MerchantAccount,
+        &merchant_account.merchant_id,
+        None,
+        Some(&merchant_account),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(merchant_account))
 }
 
 #[cfg(feature = "olap")]
@@ -429,6 +443,9 @@ pub async fn update_business_profile_cascade(
             frm_routing_algorithm: None,
             payout_routing_algorithm: None,
             applepay_verified_domains: None,
+            delayed_capture_hours: None,
+            email_settings: None,
+            dispute_settings: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -463,6 +480,14 @@ pub async fn merchant_account_update(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    let merchant_account_before: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
     if &req.merchant_id != merchant_id {
         Err(report!(errors::ValidationError::IncorrectValueProvided {
             field_name: "parent_merchant_id"
@@ -594,12 +619,23 @@ pub async fn merchant_account_update(
 
     // If there are any new business labels generated, create business profile
 
-    Ok(service_api::ApplicationResponse::Json(
-        response
-            .try_into()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    let response: api::MerchantAccountResponse = response
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    audit_log::record(
+        &state,
+        Some(merchant_id.as_str()),
+        AuditAction::Update,
+        AuditResourceType::MerchantAccount,
+        merchant_id,
+        Some(&merchant_account_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
 pub async fn merchant_account_delete(
@@ -608,6 +644,17 @@ pub async fn merchant_account_delete(
 ) -> RouterResponse<api::MerchantAccountDeleteResponse> {
     let mut is_deleted = false;
     let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account_before: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
     let is_merchant_account_deleted = db
         .delete_merchant_account_by_merchant_id(&merchant_id)
         .await
@@ -638,6 +685,17 @@ pub async fn merchant_account_delete(
     }
     .ok();
 
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Delete,
+        AuditResourceType::MerchantAccount,
+        &merchant_id,
+        Some(&merchant_account_before),
+        None,
+    )
+    .await;
+
     let response = api::MerchantAccountDeleteResponse {
         merchant_id,
         deleted: is_deleted,
@@ -980,7 +1038,19 @@ pub async fn create_payment_connector(
         ],
     );
 
-    let mca_response = mca.try_into()?;
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    audit_log::record(
+        &state,
+        Some(merchant_id.as_str()),
+        AuditAction::Create,
+        AuditResourceType::MerchantConnectorAccount,
+        &mca_response.merchant_connector_id,
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -1106,6 +1176,8 @@ pub async fn update_payment_connector(
     let (connector_status, disabled) =
         validate_status_and_disabled(req.status, req.disabled, auth, mca.status)?;
 
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: None,
         connector_type: Some(req.connector_type),
@@ -1149,7 +1221,18 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_log::record(
+        &state,
+        Some(merchant_id),
+        AuditAction::Update,
+        AuditResourceType::MerchantConnectorAccount,
+        merchant_connector_id,
+        Some(&mca_before),
+        Some(&response),
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -1170,7 +1253,7 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca: api_models::admin::MerchantConnectorResponse = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             &merchant_id,
             &merchant_connector_id,
@@ -1179,7 +1262,8 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
             id: merchant_connector_id.clone(),
-        })?;
+        })?
+        .try_into()?;
 
     let is_deleted = db
         .delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -1191,6 +1275,17 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Delete,
+        AuditResourceType::MerchantConnectorAccount,
+        &merchant_connector_id,
+        Some(&mca),
+        None,
+    )
+    .await;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1470,6 +1565,30 @@ pub async fn update_business_profile(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    let email_settings = request
+        .email_settings
+        .as_ref()
+        .map(|email_settings| {
+            utils::Encode::<api_models::admin::EmailNotificationSettings>::encode_to_value(
+                email_settings,
+            )
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "email_settings",
+            })
+        })
+        .transpose()?;
+
+    let dispute_settings = request
+        .dispute_settings
+        .as_ref()
+        .map(|dispute_settings| {
+            utils::Encode::<api_models::admin::DisputeSettings>::encode_to_value(dispute_settings)
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute_settings",
+                })
+        })
+        .transpose()?;
+
     let business_profile_update = storage::business_profile::BusinessProfileUpdateInternal {
         profile_name: request.profile_name,
         modified_at: Some(date_time::now()),
@@ -1485,6 +1604,9 @@ pub async fn update_business_profile(
         payout_routing_algorithm: request.payout_routing_algorithm,
         is_recon_enabled: None,
         applepay_verified_domains: request.applepay_verified_domains,
+        delayed_capture_hours: request.delayed_capture_hours.map(i32::from),
+        email_settings,
+        dispute_settings,
     };
 
     let updated_business_profile = db
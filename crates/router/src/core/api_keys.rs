@@ -1,3 +1,4 @@
+use common_enums::{AuditAction, AuditResourceType};
 use common_utils::date_time;
 #[cfg(feature = "email")]
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
@@ -5,16 +6,21 @@ use error_stack::{report, IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, tracing};
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
 
 #[cfg(feature = "email")]
 use crate::types::storage::enums;
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_log,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
     routes::{metrics, AppState},
-    services::ApplicationResponse,
+    services::{authentication::api_key_scope, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
     utils,
 };
@@ -149,6 +155,10 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    validate_api_key_restrictions(api_key.scopes.as_deref(), api_key.ip_allowlist.as_deref())?;
+    core_utils::validate_and_get_business_profile(store, api_key.profile_id.as_ref(), &merchant_id)
+        .await?;
+
     let hash_key = get_hash_key(
         api_key_config,
         #[cfg(feature = "kms")]
@@ -166,6 +176,13 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        scopes: api_key
+            .scopes
+            .as_deref()
+            .map(api_key_scope::to_stored_scopes),
+        profile_id: api_key.profile_id,
+        ip_allowlist: api_key.ip_allowlist,
+        rotated_from: None,
     };
 
     let api_key = store
@@ -195,9 +212,20 @@ pub async fn create_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(
-        (api_key, plaintext_api_key).foreign_into(),
-    ))
+    let response: api::CreateApiKeyResponse = (api_key, plaintext_api_key).foreign_into();
+
+    audit_log::record(
+        &state,
+        Some(&response.merchant_id),
+        AuditAction::Create,
+        AuditResourceType::ApiKey,
+        &response.key_id,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
 
 // Add api_key_expiry task to the process_tracker table.
@@ -281,16 +309,374 @@ pub async fn retrieve_api_key(
     key_id: &str,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let store = state.store.as_ref();
-    let api_key = store
+    let mut api_key = store
         .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
         .attach_printable("Failed to retrieve new API key")?
         .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?; // If retrieve returned `None`
 
+    // The last used time in the database may lag behind the one in Redis by up to the flush
+    // interval
+    api_key.last_used = api_key
+        .last_used
+        .max(get_api_key_last_used(store, key_id).await);
+
     Ok(ApplicationResponse::Json(api_key.foreign_into()))
 }
 
+/// Records the time at which the API key was used. The latest time is kept in Redis, while only the
+/// first use in every flush interval is written to the database, so that authenticating a request
+/// does not write to the database every time. The time in the database may therefore lag behind
+/// the one in Redis, which is why retrieving the API key reads both. Failing to record the time
+/// does not fail the request.
+#[instrument(skip_all)]
+pub async fn record_api_key_usage(
+    store: &dyn crate::db::StorageInterface,
+    api_key_config: &settings::ApiKeys,
+    api_key: &storage::ApiKey,
+    last_used: time::PrimitiveDateTime,
+) {
+    let redis_conn = match store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let last_used_key = format!("{}{}", consts::API_KEY_LAST_USED_PREFIX, api_key.key_id);
+    if let Err(error) = redis_conn
+        .set_key_with_expiry(
+            last_used_key.as_str(),
+            last_used.assume_utc().unix_timestamp(),
+            consts::API_KEY_LAST_USED_EXPIRY_SECONDS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to record the last used time of the API key");
+    }
+
+    // Only the request that sets the flush key writes to the database, until the key expires
+    let flush_key = format!(
+        "{}{}",
+        consts::API_KEY_LAST_USED_FLUSH_PREFIX,
+        api_key.key_id
+    );
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            flush_key.as_str(),
+            true,
+            Some(i64::from(api_key_config.last_used_flush_interval_in_secs)),
+        )
+        .await
+    {
+        Ok(SetnxReply::KeySet) => {
+            if let Err(error) = store
+                .update_api_key(
+                    api_key.merchant_id.clone(),
+                    api_key.key_id.clone(),
+                    storage::ApiKeyUpdate::LastUsedUpdate { last_used },
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to flush the last used time of the API key");
+            }
+        }
+        Ok(SetnxReply::KeyNotSet) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to set the last used flush key of the API key"
+            );
+        }
+    }
+}
+
+/// Returns the last used time of the API key recorded in Redis, if any
+async fn get_api_key_last_used(
+    store: &dyn crate::db::StorageInterface,
+    key_id: &str,
+) -> Option<time::PrimitiveDateTime> {
+    let redis_conn = store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+    let last_used_key = format!("{}{}", consts::API_KEY_LAST_USED_PREFIX, key_id);
+
+    redis_conn
+        .get_key::<Option<i64>>(last_used_key.as_str())
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to get the last used time of the API key"))
+        .ok()
+        .flatten()
+        .and_then(|last_used| time::OffsetDateTime::from_unix_timestamp(last_used).ok())
+        .map(date_time::convert_to_pdt)
+}
+
+/// Returns whether the API key has expired at the specified time
+pub fn is_api_key_expired(api_key: &storage::ApiKey, now: time::PrimitiveDateTime) -> bool {
+    api_key
+        .expires_at
+        .map(|expires_at| expires_at < now)
+        .unwrap_or(false)
+}
+
+/// Returns the number of seconds for which the API key being rotated remains valid, which must not
+/// exceed the configured maximum
+fn get_rotation_overlap_in_secs(
+    overlap_in_secs: Option<u32>,
+    api_key_config: &settings::ApiKeys,
+) -> RouterResult<u32> {
+    let overlap_in_secs = overlap_in_secs.unwrap_or(api_key_config.rotation_overlap_in_secs);
+    if overlap_in_secs > api_key_config.max_rotation_overlap_in_secs {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "overlap_in_secs must not exceed {}",
+                api_key_config.max_rotation_overlap_in_secs
+            ),
+        }));
+    }
+
+    Ok(overlap_in_secs)
+}
+
+/// Returns the expiry the API key had before it was rotated. A rotation that failed to issue the
+/// new API key has already shortened the expiry of the API key, so the original expiry is recorded
+/// alongside it.
+fn get_original_expiry(api_key: &storage::ApiKey) -> Option<time::PrimitiveDateTime> {
+    if api_key.rotated_to.is_some() {
+        api_key.original_expires_at
+    } else {
+        api_key.expires_at
+    }
+}
+
+/// Returns the expiry of the API key being rotated, which remains valid for the overlap window or
+/// until it expires if that is sooner, and the default expiry of the new API key, which is valid for
+/// as long as the API key being rotated was originally issued for
+fn get_rotation_expiries(
+    api_key: &storage::ApiKey,
+    overlap_in_secs: u32,
+    now: time::PrimitiveDateTime,
+) -> (time::PrimitiveDateTime, Option<time::PrimitiveDateTime>) {
+    let overlap_ends_at = now.saturating_add(time::Duration::seconds(i64::from(overlap_in_secs)));
+    let rotated_expires_at = api_key.expires_at.map_or(overlap_ends_at, |expires_at| {
+        expires_at.min(overlap_ends_at)
+    });
+    let new_expires_at = get_original_expiry(api_key)
+        .map(|expires_at| now.saturating_add(expires_at - api_key.created_at));
+
+    (rotated_expires_at, new_expires_at)
+}
+
+/// Shortens the expiry of the API key being rotated before issuing the new API key, so that a
+/// failure in between cannot leave two long lived API keys. The rotation can be retried in that
+/// case, as the new API key was never issued. The API key is only shortened if it has not changed
+/// since it was read, so that concurrent rotations cannot both issue a new API key.
+async fn replace_api_key(
+    store: &dyn crate::db::StorageInterface,
+    api_key: &storage::ApiKey,
+    new_api_key: storage::ApiKeyNew,
+    rotated_expires_at: time::PrimitiveDateTime,
+) -> RouterResult<(storage::ApiKey, storage::ApiKey)> {
+    let rotated_api_key = store
+        .update_api_key_if_unchanged(
+            api_key.merchant_id.clone(),
+            api_key.key_id.clone(),
+            api_key.expires_at,
+            api_key.rotated_to.clone(),
+            storage::ApiKeyUpdate::RotatedUpdate {
+                expires_at: Some(rotated_expires_at),
+                rotated_to: new_api_key.key_id.clone(),
+                original_expires_at: get_original_expiry(api_key),
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "API key was changed while it was being rotated".to_string(),
+        })?;
+
+    let new_api_key = store
+        .insert_api_key(new_api_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert new API key")?;
+
+    Ok((rotated_api_key, new_api_key))
+}
+
+/// Issues a new API key to replace the specified API key, with the same name, description and
+/// restrictions. The replaced API key remains valid for the overlap window, or until it expires if
+/// that is sooner, so that the new API key can be deployed before the old one stops working.
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: AppState,
+    #[cfg(feature = "kms")] kms_client: &kms::KmsClient,
+    request: api::RotateApiKeyRequest,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    let merchant_id = request.merchant_id;
+    let key_id = request.key_id;
+    let store = state.store.as_ref();
+
+    let api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    let now = date_time::now();
+    if let Some(rotated_to) = api_key.rotated_to.as_deref() {
+        // A rotation that failed to issue the new API key can be retried
+        let issued_api_key = store
+            .find_api_key_by_merchant_id_key_id_optional(&merchant_id, rotated_to)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve new API key")?;
+        if issued_api_key.is_some() {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "API key has already been rotated".to_string(),
+            }));
+        }
+    }
+    if is_api_key_expired(&api_key, now) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "API key has expired".to_string(),
+        }));
+    }
+
+    let overlap_in_secs =
+        get_rotation_overlap_in_secs(request.overlap_in_secs, &state.conf.api_keys)?;
+    let (rotated_expires_at, new_expires_at) =
+        get_rotation_expiries(&api_key, overlap_in_secs, now);
+
+    let hash_key = get_hash_key(
+        &state.conf.api_keys,
+        #[cfg(feature = "kms")]
+        kms_client,
+    )
+    .await?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let new_api_key = storage::ApiKeyNew {
+        key_id: PlaintextApiKey::new_key_id(),
+        merchant_id: merchant_id.to_owned(),
+        name: api_key.name.clone(),
+        description: api_key.description.clone(),
+        hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+        prefix: plaintext_api_key.prefix(),
+        created_at: now,
+        expires_at: request.expiration.map_or(new_expires_at, Into::into),
+        last_used: None,
+        scopes: api_key.scopes.clone(),
+        profile_id: api_key.profile_id.clone(),
+        ip_allowlist: api_key.ip_allowlist.clone(),
+        rotated_from: Some(key_id.to_owned()),
+    };
+
+    let (rotated_api_key, new_api_key) =
+        replace_api_key(store, &api_key, new_api_key, rotated_expires_at).await?;
+
+    metrics::API_KEY_CREATED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "merchant",
+            merchant_id.clone(),
+        )],
+    );
+
+    #[cfg(feature = "email")]
+    {
+        // The rotated API key expires within the overlap window, so its reminders are no longer
+        // needed, while the new API key is reminded of like a newly created one
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &key_id)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+
+        if new_api_key.expires_at.is_some() {
+            let expiry_reminder_days = state.conf.api_keys.expiry_reminder_days.clone();
+
+            add_api_key_expiry_task(store, &new_api_key, expiry_reminder_days)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    let api_key_before: api::RetrieveApiKeyResponse = api_key.foreign_into();
+    let api_key_after: api::RetrieveApiKeyResponse = rotated_api_key.foreign_into();
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Rotate,
+        AuditResourceType::ApiKey,
+        &key_id,
+        Some(&api_key_before),
+        Some(&api_key_after),
+    )
+    .await;
+
+    let response: api::CreateApiKeyResponse = (new_api_key, plaintext_api_key).foreign_into();
+
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Create,
+        AuditResourceType::ApiKey,
+        &response.key_id,
+        None,
+        Some(&response),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Validates that a restricted API key is granted at least one operation, and that its allowlist
+/// only contains IP addresses and CIDR ranges
+fn validate_api_key_restrictions(
+    scopes: Option<&[api::ApiKeyScope]>,
+    ip_allowlist: Option<&[String]>,
+) -> RouterResult<()> {
+    if scopes.map_or(false, |scopes| {
+        api_key_scope::to_stored_scopes(scopes).is_empty()
+    }) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`scopes` must grant at least one operation".to_string(),
+        }));
+    }
+
+    if let Some(invalid_entry) = ip_allowlist
+        .into_iter()
+        .flatten()
+        .find(|entry| !api_key_scope::is_valid_ip_range(entry))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`{invalid_entry}` in `ip_allowlist` is not an IP address or a CIDR range"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub async fn update_api_key(
     state: AppState,
@@ -300,6 +686,16 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    validate_api_key_restrictions(api_key.scopes.as_deref(), api_key.ip_allowlist.as_deref())?;
+
+    let api_key_before: api::RetrieveApiKeyResponse = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?
+        .foreign_into();
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -363,7 +759,20 @@ pub async fn update_api_key(
         }
     }
 
-    Ok(ApplicationResponse::Json(api_key.foreign_into()))
+    let response: api::RetrieveApiKeyResponse = api_key.foreign_into();
+
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Update,
+        AuditResourceType::ApiKey,
+        &key_id,
+        Some(&api_key_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(response))
 }
 
 // Update api_key_expiry task in the process_tracker table.
@@ -434,6 +843,12 @@ pub async fn revoke_api_key(
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
+    let api_key_before: Option<api::RetrieveApiKeyResponse> = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .map(ForeignInto::foreign_into);
     let revoked = store
         .revoke_api_key(merchant_id, key_id)
         .await
@@ -466,6 +881,19 @@ pub async fn revoke_api_key(
         }
     }
 
+    if revoked {
+        audit_log::record(
+            &state,
+            Some(merchant_id),
+            AuditAction::Revoke,
+            AuditResourceType::ApiKey,
+            key_id,
+            api_key_before.as_ref(),
+            None,
+        )
+        .await;
+    }
+
     Ok(ApplicationResponse::Json(api::RevokeApiKeyResponse {
         merchant_id: merchant_id.to_owned(),
         key_id: key_id.to_owned(),
@@ -550,6 +978,7 @@ impl From<storage::HashedApiKey> for HashedApiKey {
 mod tests {
     #![allow(clippy::expect_used, clippy::unwrap_used)]
     use super::*;
+    use crate::db::api_keys::ApiKeyInterface;
 
     #[tokio::test]
     async fn test_hashing_and_verification() {
@@ -573,4 +1002,243 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    fn get_api_key_new(
+        key_id: &str,
+        created_at: time::PrimitiveDateTime,
+        expires_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::ApiKeyNew {
+        storage::ApiKeyNew {
+            key_id: key_id.to_string(),
+            merchant_id: "merchant".to_string(),
+            name: "api key".to_string(),
+            description: None,
+            hashed_api_key: key_id.to_string().into(),
+            prefix: "snd_abcde".to_string(),
+            created_at,
+            expires_at,
+            last_used: None,
+            scopes: None,
+            profile_id: None,
+            ip_allowlist: None,
+            rotated_from: None,
+        }
+    }
+
+    async fn get_mock_db() -> storage_impl::MockDb {
+        storage_impl::MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store")
+    }
+
+    #[test]
+    fn test_rotation_overlap_is_capped() {
+        let api_key_config = settings::ApiKeys {
+            rotation_overlap_in_secs: 60,
+            max_rotation_overlap_in_secs: 3600,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_rotation_overlap_in_secs(None, &api_key_config).unwrap(),
+            60
+        );
+        assert_eq!(
+            get_rotation_overlap_in_secs(Some(3600), &api_key_config).unwrap(),
+            3600
+        );
+        assert!(get_rotation_overlap_in_secs(Some(3601), &api_key_config).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_rotated_api_key_expires_after_overlap() {
+        let store = get_mock_db().await;
+        let now = date_time::now();
+        let api_key = store
+            .insert_api_key(get_api_key_new(
+                "old_key",
+                now - time::Duration::days(30),
+                Some(now + time::Duration::days(60)),
+            ))
+            .await
+            .unwrap();
+
+        let (rotated_expires_at, new_expires_at) = get_rotation_expiries(&api_key, 3600, now);
+        assert_eq!(rotated_expires_at, now + time::Duration::seconds(3600));
+        // The new API key is valid for as long as the rotated one was issued for
+        assert_eq!(new_expires_at, Some(now + time::Duration::days(90)));
+
+        let mut new_api_key = get_api_key_new("new_key", now, new_expires_at);
+        new_api_key.rotated_from = Some(api_key.key_id.clone());
+        let (rotated_api_key, new_api_key) =
+            replace_api_key(&store, &api_key, new_api_key, rotated_expires_at)
+                .await
+                .unwrap();
+
+        assert_eq!(rotated_api_key.rotated_to, Some(new_api_key.key_id.clone()));
+        let overlap_end = now + time::Duration::seconds(3600);
+        assert!(!is_api_key_expired(&rotated_api_key, overlap_end));
+        assert!(is_api_key_expired(
+            &rotated_api_key,
+            overlap_end + time::Duration::seconds(1)
+        ));
+        assert!(!is_api_key_expired(
+            &new_api_key,
+            overlap_end + time::Duration::seconds(1)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failed_rotation_does_not_leave_rotated_api_key_valid() {
+        let store = get_mock_db().await;
+        let now = date_time::now();
+        let api_key = store
+            .insert_api_key(get_api_key_new("old_key", now, None))
+            .await
+            .unwrap();
+        store
+            .insert_api_key(get_api_key_new("taken_key", now, None))
+            .await
+            .unwrap();
+
+        let (rotated_expires_at, _) = get_rotation_expiries(&api_key, 3600, now);
+        // Issuing the new API key fails as its key ID is already taken
+        assert!(replace_api_key(
+            &store,
+            &api_key,
+            get_api_key_new("taken_key", now, None),
+            rotated_expires_at,
+        )
+        .await
+        .is_err());
+
+        let api_key = store
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "old_key")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(api_key.expires_at, Some(rotated_expires_at));
+    }
+
+    #[tokio::test]
+    async fn test_retried_rotation_keeps_original_validity() {
+        let store = get_mock_db().await;
+        let now = date_time::now();
+        let api_key = store
+            .insert_api_key(get_api_key_new(
+                "old_key",
+                now - time::Duration::days(30),
+                Some(now + time::Duration::days(60)),
+            ))
+            .await
+            .unwrap();
+        store
+            .insert_api_key(get_api_key_new("taken_key", now, None))
+            .await
+            .unwrap();
+
+        let (rotated_expires_at, _) = get_rotation_expiries(&api_key, 3600, now);
+        assert!(replace_api_key(
+            &store,
+            &api_key,
+            get_api_key_new("taken_key", now, None),
+            rotated_expires_at,
+        )
+        .await
+        .is_err());
+
+        let api_key = store
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "old_key")
+            .await
+            .unwrap()
+            .unwrap();
+        let retried_at = now + time::Duration::seconds(60);
+        let (rotated_expires_at, new_expires_at) =
+            get_rotation_expiries(&api_key, 3600, retried_at);
+        // The retry neither extends the overlap window nor shortens the new API key
+        assert_eq!(rotated_expires_at, now + time::Duration::seconds(3600));
+        assert_eq!(new_expires_at, Some(retried_at + time::Duration::days(90)));
+
+        let (rotated_api_key, _) = replace_api_key(
+            &store,
+            &api_key,
+            get_api_key_new("new_key", retried_at, new_expires_at),
+            rotated_expires_at,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            rotated_api_key.original_expires_at,
+            Some(now + time::Duration::days(60))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_rotation_is_rejected() {
+        let store = get_mock_db().await;
+        let now = date_time::now();
+        let api_key = store
+            .insert_api_key(get_api_key_new("old_key", now, None))
+            .await
+            .unwrap();
+
+        let (rotated_expires_at, _) = get_rotation_expiries(&api_key, 3600, now);
+        replace_api_key(
+            &store,
+            &api_key,
+            get_api_key_new("first_key", now, None),
+            rotated_expires_at,
+        )
+        .await
+        .unwrap();
+
+        // A rotation that read the API key before it was rotated cannot rotate it again
+        let error = replace_api_key(
+            &store,
+            &api_key,
+            get_api_key_new("second_key", now, None),
+            rotated_expires_at,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+        assert!(store
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "second_key")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_last_used_is_written_to_database_once_per_flush_interval() {
+        let store = get_mock_db().await;
+        let api_key_config = settings::ApiKeys {
+            last_used_flush_interval_in_secs: 300,
+            ..Default::default()
+        };
+        // Redis keeps the time with a precision of seconds
+        let first_used = date_time::now().replace_nanosecond(0).unwrap();
+        let last_used = first_used + time::Duration::seconds(10);
+        let api_key = store
+            .insert_api_key(get_api_key_new("key", first_used, None))
+            .await
+            .unwrap();
+
+        record_api_key_usage(&store, &api_key_config, &api_key, first_used).await;
+        record_api_key_usage(&store, &api_key_config, &api_key, last_used).await;
+
+        let stored_api_key = store
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "key")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored_api_key.last_used, Some(first_used));
+        assert_eq!(get_api_key_last_used(&store, "key").await, Some(last_used));
+    }
 }
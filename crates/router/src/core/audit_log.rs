@@ -0,0 +1,266 @@
+use api_models::audit_log as audit_log_api;
+use common_enums::{AuditAction, AuditActorType, AuditResourceType};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::{app::AppStateInfo, AppState},
+    services::{self, authentication as auth},
+    types::storage,
+    utils,
+};
+
+const LIST_LOWER_LIMIT: i64 = 1;
+const LIST_UPPER_LIMIT: i64 = 100;
+const LIST_DEFAULT_LIMIT: i64 = 10;
+
+/// The credential and the address a request was made with, captured when the request is
+/// authenticated so that the changes it makes can be attributed to it
+#[derive(Clone, Debug)]
+pub struct AuditContext {
+    pub actor_type: AuditActorType,
+    pub actor_id: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+impl Default for AuditContext {
+    fn default() -> Self {
+        Self {
+            actor_type: AuditActorType::System,
+            actor_id: None,
+            ip_address: None,
+        }
+    }
+}
+
+impl AuditContext {
+    pub fn new(auth_type: &auth::AuthenticationType, ip_address: Option<String>) -> Self {
+        let (actor_type, actor_id) = match auth_type {
+            auth::AuthenticationType::ApiKey { key_id, .. } => {
+                (AuditActorType::ApiKey, Some(key_id.clone()))
+            }
+            auth::AuthenticationType::AdminApiKey => (AuditActorType::AdminApiKey, None),
+            auth::AuthenticationType::MerchantJwt { user_id, .. } => {
+                (AuditActorType::User, user_id.clone())
+            }
+            auth::AuthenticationType::SinglePurposeJwt { user_id, .. } => {
+                (AuditActorType::User, Some(user_id.clone()))
+            }
+            auth::AuthenticationType::MerchantId { .. }
+            | auth::AuthenticationType::PublishableKey { .. }
+            | auth::AuthenticationType::WebhookAuth { .. }
+            | auth::AuthenticationType::NoAuth => (AuditActorType::System, None),
+        };
+
+        Self {
+            actor_type,
+            actor_id,
+            ip_address,
+        }
+    }
+}
+
+/// Records a change made to a resource in the audit log. The values before and after the change
+/// are serialized with their secrets masked, and only the fields that differ are stored.
+///
+/// Failing to record the change does not fail the request, as the change has already been made
+/// by the time it is recorded.
+#[instrument(skip_all)]
+pub async fn record<T: Serialize>(
+    state: &AppState,
+    merchant_id: Option<&str>,
+    action: AuditAction,
+    resource_type: AuditResourceType,
+    resource_id: &str,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let diff = match (mask(before), mask(after)) {
+        (Ok(before), Ok(after)) => compute_diff(before, after),
+        (Err(error), _) | (_, Err(error)) => {
+            logger::error!(?error, "Failed to serialize the audited resource");
+            return;
+        }
+    };
+
+    let audit_context = &state.audit_context;
+    let audit_log = storage::AuditLogNew {
+        audit_id: utils::generate_id(consts::ID_LENGTH, "audit"),
+        merchant_id: merchant_id.map(ToOwned::to_owned),
+        actor_type: audit_context.actor_type,
+        actor_id: audit_context.actor_id.clone(),
+        action,
+        resource_type,
+        resource_id: resource_id.to_owned(),
+        diff,
+        request_id: state.get_request_id(),
+        ip_address: audit_context.ip_address.clone(),
+        created_at: common_utils::date_time::now(),
+    };
+
+    if let Err(error) = state.store.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to record the audit log");
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_audit_logs(
+    state: AppState,
+    merchant_id: String,
+    constraints: audit_log_api::AuditLogListConstraints,
+) -> RouterResponse<Vec<audit_log_api::AuditLogResponse>> {
+    let limit = validate_list_limit(constraints.limit)?;
+    if constraints.offset.map_or(false, |offset| offset < 0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset should not be negative".to_string(),
+        }));
+    }
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id(
+            &merchant_id,
+            audit_log_api::AuditLogListConstraints {
+                limit: Some(limit),
+                ..constraints
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the audit log")?;
+
+    Ok(services::ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(|audit_log| audit_log_api::AuditLogResponse {
+                audit_id: audit_log.audit_id,
+                actor_type: audit_log.actor_type,
+                actor_id: audit_log.actor_id,
+                action: audit_log.action,
+                resource_type: audit_log.resource_type,
+                resource_id: audit_log.resource_id,
+                diff: audit_log.diff,
+                request_id: audit_log.request_id,
+                ip_address: audit_log.ip_address,
+                created_at: audit_log.created_at,
+            })
+            .collect(),
+    ))
+}
+
+fn validate_list_limit(limit: Option<i64>) -> errors::RouterResult<i64> {
+    match limit {
+        Some(limit) if !(LIST_LOWER_LIMIT..=LIST_UPPER_LIMIT).contains(&limit) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between {LIST_LOWER_LIMIT} and {LIST_UPPER_LIMIT}"
+                ),
+            }))
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(LIST_DEFAULT_LIMIT),
+    }
+}
+
+fn mask<T: Serialize>(value: Option<&T>) -> Result<serde_json::Value, serde_json::Error> {
+    value.map_or(Ok(serde_json::Value::Null), masking::masked_serialize)
+}
+
+/// Returns the top level fields that differ between the two values, as
+/// `{"field": {"before": .., "after": ..}}`. A missing value, as on creation or deletion, is
+/// treated as an object without any fields.
+fn compute_diff(before: serde_json::Value, after: serde_json::Value) -> serde_json::Value {
+    let into_fields = |value| match value {
+        serde_json::Value::Object(fields) => fields,
+        serde_json::Value::Null => serde_json::Map::new(),
+        value => serde_json::Map::from_iter([("value".to_string(), value)]),
+    };
+    let before = into_fields(before);
+    let mut after = into_fields(after);
+
+    let mut diff = serde_json::Map::new();
+    for (field, before_value) in before {
+        let after_value = after.remove(&field).unwrap_or(serde_json::Value::Null);
+        if before_value != after_value {
+            diff.insert(
+                field,
+                serde_json::json!({ "before": before_value, "after": after_value }),
+            );
+        }
+    }
+    for (field, after_value) in after {
+        diff.insert(
+            field,
+            serde_json::json!({ "before": serde_json::Value::Null, "after": after_value }),
+        );
+    }
+
+    serde_json::Value::Object(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use masking::Secret;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Resource {
+        name: String,
+        secret: Secret<String>,
+        enabled: bool,
+    }
+
+    #[test]
+    fn test_diff_contains_only_changed_fields_with_secrets_masked() {
+        let before = Resource {
+            name: "connector".to_string(),
+            secret: Secret::new("old_secret".to_string()),
+            enabled: false,
+        };
+        let after = Resource {
+            name: "connector".to_string(),
+            secret: Secret::new("new_secret".to_string()),
+            enabled: true,
+        };
+
+        #[allow(clippy::unwrap_used)]
+        let diff = compute_diff(mask(Some(&before)).unwrap(), mask(Some(&after)).unwrap());
+
+        assert!(diff.get("name").is_none());
+        assert_eq!(
+            diff["enabled"],
+            serde_json::json!({ "before": false, "after": true })
+        );
+        assert!(!diff.to_string().contains("old_secret"));
+        assert!(!diff.to_string().contains("new_secret"));
+    }
+
+    #[test]
+    fn test_diff_of_created_resource() {
+        let after = Resource {
+            name: "connector".to_string(),
+            secret: Secret::new("secret".to_string()),
+            enabled: true,
+        };
+
+        #[allow(clippy::unwrap_used)]
+        let diff = compute_diff(mask::<Resource>(None).unwrap(), mask(Some(&after)).unwrap());
+
+        assert_eq!(
+            diff["name"],
+            serde_json::json!({ "before": null, "after": "connector" })
+        );
+    }
+
+    #[test]
+    fn test_validate_list_limit() {
+        assert_eq!(validate_list_limit(None).ok(), Some(LIST_DEFAULT_LIMIT));
+        assert_eq!(validate_list_limit(Some(100)).ok(), Some(100));
+        assert!(validate_list_limit(Some(0)).is_err());
+        assert!(validate_list_limit(Some(i64::MAX)).is_err());
+    }
+}
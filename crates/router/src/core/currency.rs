@@ -1,10 +1,10 @@
 use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 
 use crate::{
     core::errors::ApiErrorResponse,
     services::ApplicationResponse,
-    utils::currency::{self, convert_currency, get_forex_rates},
+    utils::currency::{self, convert_currency, get_forex_rates, ForexCacheError},
     AppState,
 };
 
@@ -49,3 +49,45 @@ pub async fn convert_forex(
         .change_context(ApiErrorResponse::InternalServerError)?,
     ))
 }
+
+pub async fn convert_forex_at_date(
+    state: AppState,
+    amount: i64,
+    to_currency: String,
+    from_currency: String,
+    date: String,
+) -> CustomResult<
+    ApplicationResponse<api_models::currency::HistoricalCurrencyConversionResponse>,
+    ApiErrorResponse,
+> {
+    let date_format = time::format_description::parse("[year]-[month]-[day]")
+        .into_report()
+        .change_context(ApiErrorResponse::InternalServerError)?;
+    let rate_date = time::Date::parse(&date, &date_format)
+        .into_report()
+        .change_context(ApiErrorResponse::InvalidDataFormat {
+            field_name: "date".to_string(),
+            expected_format: "YYYY-MM-DD".to_string(),
+        })?;
+
+    let response =
+        currency::convert_currency_at_date(&state, amount, to_currency, from_currency, rate_date)
+            .await
+            .map_err(|error| {
+                let api_error = match error.current_context() {
+                    ForexCacheError::SnapshotNotFound => ApiErrorResponse::GenericNotFoundError {
+                        message: format!("No forex rates recorded on or before {rate_date}"),
+                    },
+                    ForexCacheError::CurrencyNotAcceptable | ForexCacheError::ConversionError => {
+                        ApiErrorResponse::InvalidRequestData {
+                            message: "Conversion between the currencies is not supported"
+                                .to_string(),
+                        }
+                    }
+                    _ => ApiErrorResponse::InternalServerError,
+                };
+                error.change_context(api_error)
+            })?;
+
+    Ok(ApplicationResponse::Json(response))
+}
@@ -2,10 +2,12 @@ use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::ValueExt;
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use scheduler::{db::process_tracker::ProcessTrackerExt, errors as sch_errors, utils as pt_utils};
+pub mod evidence_assembly;
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+    errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
     metrics,
 };
 use crate::{
@@ -15,12 +17,13 @@ use crate::{
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
     utils,
+    workflows::dispute_deadline,
 };
 
 #[instrument(skip(state))]
@@ -423,3 +426,201 @@ pub async fn retrieve_dispute_evidence(
         transformers::get_dispute_evidence_vec(&state, merchant_account, dispute_evidence).await?;
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
+
+#[instrument(skip(state))]
+pub async fn assemble_evidence(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::AssembleEvidenceResponse> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&merchant_account.merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    common_utils::fp_utils::when(
+        !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
+        || {
+            metrics::ASSEMBLE_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC.add(
+                &metrics::CONTEXT,
+                1,
+                &[],
+            );
+            Err(errors::ApiErrorResponse::DisputeStatusValidationFailed {
+                reason: format!(
+                "Evidence cannot be assembled because the dispute is in {} stage and has {} status",
+                dispute.dispute_stage, dispute.dispute_status
+            ),
+            })
+        },
+    )?;
+    let payment_evidence =
+        evidence_assembly::get_payment_evidence(&state, &merchant_account, &key_store, &dispute)
+            .await?;
+    let summary_document = evidence_assembly::render_summary_document(&dispute, &payment_evidence);
+    let create_file_request = api::CreateFileRequest {
+        file_size: i32::try_from(summary_document.len())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Dispute evidence summary document is too large")?,
+        file: summary_document,
+        file_name: Some(format!(
+            "dispute_evidence_summary_{}.pdf",
+            dispute.dispute_id
+        )),
+        file_type: mime::APPLICATION_PDF,
+        purpose: api::FilePurpose::DisputeEvidence,
+        dispute_id: Some(dispute.dispute_id.clone()),
+    };
+    let create_file_response = files::files_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store,
+        create_file_request,
+    )
+    .await?;
+    let summary_file_id = match create_file_response {
+        services::ApplicationResponse::Json(res) => res.file_id,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received from files create core")?,
+    };
+    let mut dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    // The new summary document is stored on the dispute before the previous one is deleted, so
+    // that a failure part way through never leaves the dispute without a summary document
+    let previous_summary_file_id = dispute_evidence
+        .summary_file
+        .replace(summary_file_id.clone());
+    let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceUpdate {
+        evidence: utils::Encode::<api::DisputeEvidence>::encode_to_value(&dispute_evidence)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while encoding dispute evidence")?
+            .into(),
+    };
+    let dispute_id = dispute.dispute_id.clone();
+    let dispute = state
+        .store
+        .update_dispute(dispute, update_dispute)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.to_owned(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+    if let Some(previous_summary_file_id) = previous_summary_file_id {
+        delete_summary_file(&state, &merchant_account, &previous_summary_file_id).await?;
+    }
+    let evidence = evidence_assembly::build_evidence_request(
+        &dispute,
+        dispute_evidence,
+        &payment_evidence,
+        summary_file_id.clone(),
+    );
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::AssembleEvidenceResponse {
+            evidence,
+            summary_file_id,
+        },
+    ))
+}
+
+/// Deletes the summary document of a previous assembly of the evidence, so that it is replaced
+/// instead of being left behind. Files uploaded to a connector cannot be deleted from it, so only
+/// their metadata is deleted.
+async fn delete_summary_file(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    summary_file_id: &str,
+) -> RouterResult<()> {
+    let file_metadata = match state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, summary_file_id)
+        .await
+    {
+        Ok(file_metadata) => file_metadata,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while fetching the previous summary document")
+        }
+    };
+
+    if file_metadata.file_upload_provider == Some(storage_enums::FileUploadProvider::Router) {
+        files::helpers::delete_file_using_file_id(
+            state,
+            summary_file_id.to_owned(),
+            merchant_account,
+        )
+        .await?;
+    }
+    state
+        .store
+        .delete_file_metadata_by_merchant_id_file_id(&merchant_account.merchant_id, summary_file_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the previous summary document")?;
+
+    Ok(())
+}
+
+/// Parses the dispute settings configured for the business profile
+pub fn get_dispute_settings(
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<Option<api_models::admin::DisputeSettings>> {
+    business_profile
+        .dispute_settings
+        .clone()
+        .map(|dispute_settings| dispute_settings.parse_value("DisputeSettings"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the dispute settings of the business profile")
+}
+
+/// Schedules the reminders of the evidence deadline of an open dispute and its automatic
+/// acceptance, as configured in the dispute settings of the business profile. The task runs right
+/// away so that the merchant is reminded of a deadline that is already close.
+pub async fn add_dispute_deadline_task(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    dispute: &storage::Dispute,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    if dispute.challenge_required_by.is_none() || get_dispute_settings(business_profile)?.is_none()
+    {
+        return Ok(());
+    }
+
+    let tracking_data = dispute_deadline::DisputeDeadlineTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+    };
+    let runner = "DISPUTE_DEADLINE_WORKFLOW";
+    let task = "DISPUTE_DEADLINE";
+    let process_tracker_id =
+        pt_utils::get_process_tracker_id(runner, task, &dispute.dispute_id, &dispute.merchant_id);
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        common_utils::date_time::now(),
+    )?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The task is created when the dispute is first seen open, and picks up changes to the
+        // deadline on each of its runs
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
@@ -0,0 +1,590 @@
+//! Assembly of dispute evidence from the data of the disputed payment.
+//!
+//! The addresses, customer details, order details, authentication and verification results and
+//! refund history of the payment are gathered into a pre-populated [`SubmitEvidenceRequest`], and
+//! rendered into a PDF summary document that can be submitted along with the evidence, since PDF
+//! is the document format accepted by all the connectors that support dispute evidence files.
+
+use api_models::{disputes::SubmitEvidenceRequest, payments::AdditionalPaymentData};
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface};
+use router_env::logger;
+use time::{format_description::well_known::Rfc3339, PrimitiveDateTime};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers,
+    },
+    routes::AppState,
+    types::{
+        self,
+        api::DisputeEvidence,
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::pdf::{Font, PdfWriter},
+};
+
+/// Number of characters of the regular font that fit the width of a page
+const MAX_LINE_LENGTH: usize = 95;
+
+/// Data of the disputed payment that is relevant to the evidence of a dispute
+#[derive(Debug)]
+pub struct PaymentEvidence {
+    pub payment_intent: storage::PaymentIntent,
+    pub payment_attempt: storage::PaymentAttempt,
+    pub customer_name: Option<String>,
+    pub customer_email: Option<String>,
+    pub customer_ip: Option<String>,
+    pub billing_address: Option<String>,
+    pub shipping_address: Option<String>,
+    pub order_items: Vec<String>,
+    pub payment_method: Option<String>,
+    pub three_ds_result: Option<String>,
+    pub avs_result: Option<String>,
+    pub cvv_result: Option<String>,
+    pub refunds: Vec<storage::Refund>,
+}
+
+pub async fn get_payment_evidence(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &diesel_models::dispute::Dispute,
+) -> RouterResult<PaymentEvidence> {
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let billing_address = helpers::get_address_by_id(
+        db,
+        payment_intent.billing_address_id.clone(),
+        key_store,
+        payment_intent.payment_id.clone(),
+        merchant_account.merchant_id.clone(),
+        merchant_account.storage_scheme,
+    )
+    .await?;
+    let shipping_address = helpers::get_address_by_id(
+        db,
+        payment_intent.shipping_address_id.clone(),
+        key_store,
+        payment_intent.payment_id.clone(),
+        merchant_account.merchant_id.clone(),
+        merchant_account.storage_scheme,
+    )
+    .await?;
+
+    let customer = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                &merchant_account.merchant_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the customer of the disputed payment")?,
+        None => None,
+    };
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the refunds of the disputed payment")?;
+
+    let customer_name = customer
+        .as_ref()
+        .and_then(|customer| customer.name.clone())
+        .map(|name| name.into_inner().expose())
+        .or_else(|| billing_address.as_ref().and_then(get_full_name));
+    let customer_email = customer
+        .and_then(|customer| customer.email)
+        .map(|email| email.into_inner().expose());
+    let customer_ip = payment_attempt
+        .browser_info
+        .clone()
+        .map(|browser_info| {
+            browser_info.parse_value::<types::BrowserInformation>("BrowserInformation")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the browser information of the disputed payment")?
+        .and_then(|browser_info| browser_info.ip_address)
+        .map(|ip_address| ip_address.to_string());
+
+    let order_items = get_order_items(&payment_intent);
+    let payment_method = get_payment_method_description(&payment_attempt);
+    let three_ds_result = get_three_ds_result(&payment_attempt);
+    let (avs_result, cvv_result) = get_verification_results(&payment_attempt);
+
+    Ok(PaymentEvidence {
+        customer_name,
+        customer_email,
+        customer_ip,
+        billing_address: billing_address.as_ref().and_then(format_address),
+        shipping_address: shipping_address.as_ref().and_then(format_address),
+        order_items,
+        payment_method,
+        three_ds_result,
+        avs_result,
+        cvv_result,
+        refunds,
+        payment_intent,
+        payment_attempt,
+    })
+}
+
+/// Pre-populates the evidence of the dispute from the data of the payment, keeping the files that
+/// were already attached to the dispute. The summary document is used as the uncategorized file
+/// unless one was attached already.
+pub fn build_evidence_request(
+    dispute: &diesel_models::dispute::Dispute,
+    dispute_evidence: DisputeEvidence,
+    payment_evidence: &PaymentEvidence,
+    summary_file_id: String,
+) -> SubmitEvidenceRequest {
+    let product_description = if payment_evidence.order_items.is_empty() {
+        payment_evidence.payment_intent.description.clone()
+    } else {
+        Some(payment_evidence.order_items.join(", "))
+    };
+    let transaction_details = get_transaction_details(payment_evidence);
+
+    SubmitEvidenceRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        billing_address: payment_evidence.billing_address.clone(),
+        shipping_address: payment_evidence.shipping_address.clone(),
+        customer_name: payment_evidence.customer_name.clone(),
+        customer_email_address: payment_evidence.customer_email.clone(),
+        customer_purchase_ip: payment_evidence.customer_ip.clone(),
+        product_description,
+        uncategorized_text: (!transaction_details.is_empty())
+            .then(|| transaction_details.join("\n")),
+        cancellation_policy: dispute_evidence.cancellation_policy,
+        customer_communication: dispute_evidence.customer_communication,
+        customer_signature: dispute_evidence.customer_signature,
+        receipt: dispute_evidence.receipt,
+        refund_policy: dispute_evidence.refund_policy,
+        service_documentation: dispute_evidence.service_documentation,
+        shipping_documentation: dispute_evidence.shipping_documentation,
+        invoice_showing_distinct_transactions: dispute_evidence
+            .invoice_showing_distinct_transactions,
+        recurring_transaction_agreement: dispute_evidence.recurring_transaction_agreement,
+        uncategorized_file: dispute_evidence
+            .uncategorized_file
+            .or(Some(summary_file_id)),
+        ..Default::default()
+    }
+}
+
+/// Renders the summary document of the dispute and the payment as a PDF
+pub fn render_summary_document(
+    dispute: &diesel_models::dispute::Dispute,
+    payment_evidence: &PaymentEvidence,
+) -> Vec<u8> {
+    let payment_intent = &payment_evidence.payment_intent;
+    let payment_attempt = &payment_evidence.payment_attempt;
+
+    let mut dispute_fields = Vec::new();
+    push_field(&mut dispute_fields, "Dispute ID", Some(&dispute.dispute_id));
+    push_field(&mut dispute_fields, "Connector", Some(&dispute.connector));
+    push_field(
+        &mut dispute_fields,
+        "Connector dispute ID",
+        Some(&dispute.connector_dispute_id),
+    );
+    push_field(
+        &mut dispute_fields,
+        "Disputed amount",
+        Some(&format!("{} {}", dispute.amount, dispute.currency)),
+    );
+    push_field(
+        &mut dispute_fields,
+        "Reason",
+        dispute.connector_reason.as_ref(),
+    );
+    push_field(
+        &mut dispute_fields,
+        "Reason code",
+        dispute.connector_reason_code.as_ref(),
+    );
+    push_field(
+        &mut dispute_fields,
+        "Received at",
+        Some(&format_time(dispute.created_at)),
+    );
+
+    let mut payment_fields = Vec::new();
+    push_field(
+        &mut payment_fields,
+        "Payment ID",
+        Some(&payment_intent.payment_id),
+    );
+    push_field(
+        &mut payment_fields,
+        "Amount",
+        Some(&format_amount(
+            payment_attempt.amount,
+            payment_intent.currency,
+        )),
+    );
+    push_field(
+        &mut payment_fields,
+        "Status",
+        Some(&payment_attempt.status.to_string()),
+    );
+    push_field(
+        &mut payment_fields,
+        "Created at",
+        Some(&format_time(payment_intent.created_at)),
+    );
+    push_field(
+        &mut payment_fields,
+        "Description",
+        payment_intent.description.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "Statement descriptor",
+        payment_intent.statement_descriptor_name.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "Connector transaction ID",
+        payment_attempt.connector_transaction_id.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "Payment method",
+        payment_evidence.payment_method.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "3D Secure",
+        payment_evidence.three_ds_result.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "AVS result",
+        payment_evidence.avs_result.as_ref(),
+    );
+    push_field(
+        &mut payment_fields,
+        "CVV result",
+        payment_evidence.cvv_result.as_ref(),
+    );
+
+    let mut customer_fields = Vec::new();
+    push_field(
+        &mut customer_fields,
+        "Name",
+        payment_evidence.customer_name.as_ref(),
+    );
+    push_field(
+        &mut customer_fields,
+        "Email",
+        payment_evidence.customer_email.as_ref(),
+    );
+    push_field(
+        &mut customer_fields,
+        "IP address",
+        payment_evidence.customer_ip.as_ref(),
+    );
+    push_field(
+        &mut customer_fields,
+        "Billing address",
+        payment_evidence.billing_address.as_ref(),
+    );
+    push_field(
+        &mut customer_fields,
+        "Shipping address",
+        payment_evidence.shipping_address.as_ref(),
+    );
+
+    let refunds = if payment_evidence.refunds.is_empty() {
+        vec!["No refunds were made for this payment".to_string()]
+    } else {
+        payment_evidence.refunds.iter().map(format_refund).collect()
+    };
+
+    let mut pdf = PdfWriter::new();
+    pdf.line(
+        Font::Bold,
+        16.0,
+        &format!(
+            "Dispute evidence summary for payment {}",
+            payment_intent.payment_id
+        ),
+    );
+    for (heading, lines) in [
+        ("Dispute", dispute_fields),
+        ("Payment", payment_fields),
+        ("Customer", customer_fields),
+        ("Order details", payment_evidence.order_items.clone()),
+        ("Refunds", refunds),
+    ] {
+        if lines.is_empty() {
+            continue;
+        }
+        pdf.advance(10.0);
+        pdf.line(Font::Bold, 12.0, heading);
+        for line in lines.iter().flat_map(|line| wrap_line(line)) {
+            pdf.line(Font::Regular, 10.0, &line);
+        }
+    }
+
+    pdf.finish()
+}
+
+/// Statements about how the payment was authenticated, verified and refunded
+fn get_transaction_details(payment_evidence: &PaymentEvidence) -> Vec<String> {
+    let mut details = Vec::new();
+    if let Some(three_ds_result) = payment_evidence.three_ds_result.as_ref() {
+        details.push(format!("3D Secure: {three_ds_result}"));
+    }
+    if let Some(avs_result) = payment_evidence.avs_result.as_ref() {
+        details.push(format!("AVS result: {avs_result}"));
+    }
+    if let Some(cvv_result) = payment_evidence.cvv_result.as_ref() {
+        details.push(format!("CVV result: {cvv_result}"));
+    }
+    details.extend(
+        payment_evidence
+            .refunds
+            .iter()
+            .map(|refund| format!("Refund {}", format_refund(refund))),
+    );
+    details
+}
+
+fn get_order_items(payment_intent: &storage::PaymentIntent) -> Vec<String> {
+    payment_intent
+        .order_details
+        .iter()
+        .flatten()
+        .filter_map(|order_detail| {
+            order_detail
+                .peek()
+                .clone()
+                .parse_value::<api_models::payments::OrderDetailsWithAmount>(
+                    "OrderDetailsWithAmount",
+                )
+                .map_err(|error| logger::warn!(?error, "Ignoring invalid order details"))
+                .ok()
+        })
+        .map(|order_detail| {
+            format!(
+                "{} x {} ({})",
+                order_detail.quantity,
+                order_detail.product_name,
+                format_amount(order_detail.amount, payment_intent.currency)
+            )
+        })
+        .collect()
+}
+
+fn get_payment_method_description(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    let card = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| {
+            data.parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|data| match data {
+            AdditionalPaymentData::Card(card) => Some(card),
+            _ => None,
+        });
+
+    match card {
+        Some(card) => {
+            let network = card
+                .card_network
+                .map_or_else(|| "Card".to_string(), |network| network.to_string());
+            Some(match card.last4 {
+                Some(last4) => format!("{network} ending in {last4}"),
+                None => network,
+            })
+        }
+        None => payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .or_else(|| {
+                payment_attempt
+                    .payment_method
+                    .map(|payment_method| payment_method.to_string())
+            }),
+    }
+}
+
+fn get_three_ds_result(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    let result = match payment_attempt.authentication_type? {
+        storage_enums::AuthenticationType::ThreeDs
+            if matches!(
+                payment_attempt.status,
+                storage_enums::AttemptStatus::Charged
+                    | storage_enums::AttemptStatus::Authorized
+                    | storage_enums::AttemptStatus::PartialCharged
+            ) =>
+        {
+            "the customer was authenticated with 3D Secure"
+        }
+        storage_enums::AuthenticationType::ThreeDs => "3D Secure authentication was requested",
+        storage_enums::AuthenticationType::NoThreeDs => "3D Secure authentication was not used",
+    };
+    Some(result.to_string())
+}
+
+/// Returns the address and card verification results that the connector reported for the attempt,
+/// which connectors that provide them store in the connector metadata of the attempt
+fn get_verification_results(
+    payment_attempt: &storage::PaymentAttempt,
+) -> (Option<String>, Option<String>) {
+    let get_result = |key: &str| {
+        payment_attempt
+            .connector_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(key))
+            .and_then(|result| match result {
+                serde_json::Value::String(result) => Some(result.clone()),
+                serde_json::Value::Null => None,
+                result => Some(result.to_string()),
+            })
+    };
+    (get_result("avs_result"), get_result("cvv_result"))
+}
+
+fn get_full_name(address: &domain::Address) -> Option<String> {
+    let name = [address.first_name.as_ref(), address.last_name.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|name| name.peek().trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!name.is_empty()).then_some(name)
+}
+
+fn format_address(address: &domain::Address) -> Option<String> {
+    let secret_parts = [
+        address.line1.as_ref(),
+        address.line2.as_ref(),
+        address.line3.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|line| line.peek().clone());
+    let city = address.city.clone();
+    let state = address.state.as_ref().map(|state| state.peek().clone());
+    let zip = address.zip.as_ref().map(|zip| zip.peek().clone());
+    let country = address.country.map(|country| country.to_string());
+
+    let parts = secret_parts
+        .chain([city, state, zip, country].into_iter().flatten())
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+fn format_refund(refund: &storage::Refund) -> String {
+    let mut refund_description = format!(
+        "{} of {} {} on {}",
+        refund.refund_id,
+        format_amount(refund.refund_amount, Some(refund.currency)),
+        refund.refund_status,
+        format_time(refund.created_at)
+    );
+    if let Some(reason) = refund.refund_reason.as_ref() {
+        refund_description.push_str(&format!(" ({reason})"));
+    }
+    refund_description
+}
+
+fn format_amount(amount: i64, currency: Option<storage_enums::Currency>) -> String {
+    currency
+        .and_then(|currency| {
+            currency
+                .to_currency_base_unit(amount)
+                .ok()
+                .map(|amount| format!("{amount} {currency}"))
+        })
+        .unwrap_or_else(|| amount.to_string())
+}
+
+fn format_time(time: PrimitiveDateTime) -> String {
+    time.assume_utc()
+        .format(&Rfc3339)
+        .unwrap_or_else(|_| time.to_string())
+}
+
+fn push_field(fields: &mut Vec<String>, name: &str, value: Option<&String>) {
+    if let Some(value) = value.filter(|value| !value.trim().is_empty()) {
+        fields.push(format!("{name}: {value}"));
+    }
+}
+
+/// Wraps the line at whitespace so that it fits the width of the page, indenting the lines it
+/// continues on
+fn wrap_line(line: &str) -> Vec<String> {
+    if line.chars().count() <= MAX_LINE_LENGTH {
+        return vec![line.to_string()];
+    }
+
+    let mut wrapped_lines = Vec::new();
+    let mut current_line = String::new();
+    for word in line.split_whitespace() {
+        if !current_line.trim().is_empty()
+            && current_line.chars().count() + 1 + word.chars().count() > MAX_LINE_LENGTH
+        {
+            wrapped_lines.push(std::mem::replace(&mut current_line, "  ".to_string()));
+        }
+        if !current_line.trim().is_empty() {
+            current_line.push(' ');
+        }
+        current_line.push_str(word);
+    }
+    wrapped_lines.push(current_line);
+    wrapped_lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_line() {
+        let line = format!("Billing address: {}", "word ".repeat(40));
+        let wrapped_lines = wrap_line(&line);
+
+        assert!(wrapped_lines.len() > 1);
+        assert!(wrapped_lines
+            .iter()
+            .all(|wrapped_line| wrapped_line.chars().count() <= MAX_LINE_LENGTH));
+        assert!(wrapped_lines[0].starts_with("Billing address: word"));
+        assert!(wrapped_lines[1].starts_with("  word"));
+        assert_eq!(wrap_line("Name: Jane"), vec!["Name: Jane".to_string()]);
+    }
+}
@@ -238,6 +238,8 @@ pub enum ApiErrorResponse {
     CurrencyNotSupported { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_24", message = "Merchant connector account is configured with invalid {config}")]
     InvalidConnectorConfiguration { config: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_25", message = "Too many requests, retry after {retry_after} seconds")]
+    RateLimitExceeded { retry_after: u64 },
 }
 
 impl PTError for ApiErrorResponse {
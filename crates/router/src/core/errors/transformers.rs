@@ -270,6 +270,10 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidConnectorConfiguration {config} => {
                 AER::BadRequest(ApiError::new("IR", 24, format!("Merchant connector account is configured with invalid {config}"), None))
             }
+            Self::RateLimitExceeded { retry_after } => AER::TooManyRequests(
+                ApiError::new("IR", 25, format!("Too many requests, retry after {retry_after} seconds"), None),
+                *retry_after,
+            ),
         }
     }
 }
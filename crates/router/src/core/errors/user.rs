@@ -44,6 +44,24 @@ pub enum UserErrors {
     InvalidMetadataRequest,
     #[error("MerchantIdParsingError")]
     MerchantIdParsingError,
+    #[error("RoleNameAlreadyExists")]
+    RoleNameAlreadyExists,
+    #[error("InvalidTotp")]
+    InvalidTotp,
+    #[error("TotpNotSetup")]
+    TotpNotSetup,
+    #[error("TotpAlreadySetup")]
+    TotpAlreadySetup,
+    #[error("TotpAttemptsExceeded")]
+    TotpAttemptsExceeded,
+    #[error("SsoEnforced")]
+    SsoEnforced,
+    #[error("SsoNotConfigured")]
+    SsoNotConfigured,
+    #[error("SsoAuthenticationFailed")]
+    SsoAuthenticationFailed,
+    #[error("LinkInvalid")]
+    LinkInvalid,
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -123,6 +141,48 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::MerchantIdParsingError => {
                 AER::BadRequest(ApiError::new(sub_code, 28, "Invalid Merchant Id", None))
             }
+            Self::RoleNameAlreadyExists => AER::BadRequest(ApiError::new(
+                sub_code,
+                29,
+                "A role with this name already exists",
+                None,
+            )),
+            Self::InvalidTotp => {
+                AER::Unauthorized(ApiError::new(sub_code, 30, "Invalid TOTP", None))
+            }
+            Self::TotpNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 31, "TOTP is not set up", None))
+            }
+            Self::TotpAlreadySetup => {
+                AER::BadRequest(ApiError::new(sub_code, 32, "TOTP is already set up", None))
+            }
+            Self::TotpAttemptsExceeded => AER::TooManyRequests(
+                ApiError::new(
+                    sub_code,
+                    37,
+                    "Too many failed TOTP attempts, try again later",
+                    None,
+                ),
+                crate::consts::user::TOTP_LOCKOUT_DURATION_IN_SECS.unsigned_abs(),
+            ),
+            Self::SsoEnforced => AER::BadRequest(ApiError::new(
+                sub_code,
+                33,
+                "Password sign in is disabled for your organization, sign in with SSO",
+                None,
+            )),
+            Self::SsoNotConfigured => AER::BadRequest(ApiError::new(
+                sub_code,
+                34,
+                "SSO is not configured for the organization",
+                None,
+            )),
+            Self::SsoAuthenticationFailed => {
+                AER::Unauthorized(ApiError::new(sub_code, 35, "SSO sign in failed", None))
+            }
+            Self::LinkInvalid => {
+                AER::Unauthorized(ApiError::new(sub_code, 36, "Invalid or expired link", None))
+            }
         }
     }
 }
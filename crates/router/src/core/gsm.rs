@@ -1,11 +1,14 @@
-use api_models::gsm as gsm_api_types;
+use api_models::{
+    enums::{AuditAction, AuditResourceType},
+    gsm as gsm_api_types,
+};
 use diesel_models::gsm as storage;
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 
 use crate::{
     core::{
-        errors,
+        audit_log, errors,
         errors::{RouterResponse, StorageErrorExt},
     },
     db::gsm::GsmInterface,
@@ -20,12 +23,32 @@ pub async fn create_gsm_rule(
     gsm_rule: gsm_api_types::GsmCreateRequest,
 ) -> RouterResponse<gsm_api_types::GsmResponse> {
     let db = state.store.as_ref();
-    GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
-        .await
-        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
-            message: "GSM with given key already exists in our records".to_string(),
-        })
-        .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    let gsm_rule: gsm_api_types::GsmResponse =
+        GsmInterface::add_gsm_rule(db, gsm_rule.foreign_into())
+            .await
+            .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+                message: "GSM with given key already exists in our records".to_string(),
+            })?
+            .foreign_into();
+
+    audit_log::record(
+        &state,
+        None,
+        AuditAction::Create,
+        AuditResourceType::GsmRule,
+        &get_gsm_rule_id(
+            &gsm_rule.connector,
+            &gsm_rule.flow,
+            &gsm_rule.sub_flow,
+            &gsm_rule.code,
+            &gsm_rule.message,
+        ),
+        None,
+        Some(&gsm_rule),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm_rule))
 }
 
 #[instrument(skip_all)]
@@ -68,7 +91,22 @@ pub async fn update_gsm_rule(
         unified_code,
         unified_message,
     } = gsm_request;
-    GsmInterface::update_gsm_rule(
+    let gsm_rule_id = get_gsm_rule_id(&connector.to_string(), &flow, &sub_flow, &code, &message);
+    let gsm_rule_before: gsm_api_types::GsmResponse = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?
+    .foreign_into();
+
+    let gsm_rule: gsm_api_types::GsmResponse = GsmInterface::update_gsm_rule(
         db,
         connector.to_string(),
         flow,
@@ -88,8 +126,21 @@ pub async fn update_gsm_rule(
     .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
         message: "GSM with given key does not exist in our records".to_string(),
     })
-    .attach_printable("Failed while updating Gsm rule")
-    .map(|gsm| services::ApplicationResponse::Json(gsm.foreign_into()))
+    .attach_printable("Failed while updating Gsm rule")?
+    .foreign_into();
+
+    audit_log::record(
+        &state,
+        None,
+        AuditAction::Update,
+        AuditResourceType::GsmRule,
+        &gsm_rule_id,
+        Some(&gsm_rule_before),
+        Some(&gsm_rule),
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(gsm_rule))
 }
 
 #[instrument(skip_all)]
@@ -105,6 +156,20 @@ pub async fn delete_gsm_rule(
         code,
         message,
     } = gsm_request;
+    let gsm_rule_before: gsm_api_types::GsmResponse = GsmInterface::find_gsm_rule(
+        db,
+        connector.to_string(),
+        flow.clone(),
+        sub_flow.clone(),
+        code.clone(),
+        message.clone(),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+        message: "GSM with given key does not exist in our records".to_string(),
+    })?
+    .foreign_into();
+
     match GsmInterface::delete_gsm_rule(
         db,
         connector.to_string(),
@@ -121,6 +186,16 @@ pub async fn delete_gsm_rule(
     {
         Ok(is_deleted) => {
             if is_deleted {
+                audit_log::record(
+                    &state,
+                    None,
+                    AuditAction::Delete,
+                    AuditResourceType::GsmRule,
+                    &get_gsm_rule_id(&connector.to_string(), &flow, &sub_flow, &code, &message),
+                    Some(&gsm_rule_before),
+                    None,
+                )
+                .await;
                 Ok(services::ApplicationResponse::Json(
                     gsm_api_types::GsmDeleteResponse {
                         gsm_rule_delete: true,
@@ -139,3 +214,14 @@ pub async fn delete_gsm_rule(
         Err(err) => Err(err),
     }
 }
+
+/// GSM rules are identified by their key, as they do not have an identifier of their own
+fn get_gsm_rule_id(
+    connector: &str,
+    flow: &str,
+    sub_flow: &str,
+    code: &str,
+    message: &str,
+) -> String {
+    format!("{connector}:{flow}:{sub_flow}:{code}:{message}")
+}
@@ -32,6 +32,12 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(
+    ASSEMBLE_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
+    GLOBAL_METER
+); //No. of status validation failures while assembling evidence for a dispute
+counter_metric!(DISPUTE_DEADLINE_REMINDER_SENT_METRIC, GLOBAL_METER); // No. of reminders sent ahead of the evidence deadline of a dispute
+counter_metric!(DISPUTE_AUTO_ACCEPTED_METRIC, GLOBAL_METER); // No. of disputes accepted automatically ahead of their evidence deadline
 
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
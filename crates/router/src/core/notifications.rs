@@ -0,0 +1,363 @@
+use std::str::FromStr;
+
+use api_models::admin::{EmailNotificationSettings, MerchantAlertType};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::business_profile::BusinessProfile;
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use super::errors::{self, RouterResult};
+use crate::{
+    consts,
+    db::StorageInterface,
+    routes::AppState,
+    services::email::types as email_types,
+    types::{api, domain, storage::enums},
+};
+
+/// Parses the email settings configured for the business profile
+pub fn get_email_settings(
+    business_profile: &BusinessProfile,
+) -> RouterResult<Option<EmailNotificationSettings>> {
+    business_profile
+        .email_settings
+        .clone()
+        .map(|email_settings| email_settings.parse_value("EmailNotificationSettings"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the email settings of the business profile")
+}
+
+/// Sends the customer receipts and merchant alerts that the business profile has opted into
+/// for the given event
+#[instrument(skip_all)]
+pub async fn send_event_notifications(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &BusinessProfile,
+    event_type: enums::EventType,
+    content: &api::OutgoingWebhookContent,
+) -> RouterResult<()> {
+    let Some(email_settings) = get_email_settings(business_profile)? else {
+        return Ok(());
+    };
+
+    match (event_type, content) {
+        (
+            enums::EventType::PaymentSucceeded,
+            api::OutgoingWebhookContent::PaymentDetails(payment),
+        ) if email_settings.send_customer_receipts => {
+            let Some(recipient_email) = payment.email.clone().map(|email| email.into_inner())
+            else {
+                logger::debug!("Skipping the payment receipt as the customer email is missing");
+                return Ok(());
+            };
+
+            let currency = enums::Currency::from_str(&payment.currency).ok();
+            let amount = currency
+                .and_then(|currency| currency.to_currency_base_unit(payment.amount).ok())
+                .unwrap_or_else(|| payment.amount.to_string());
+
+            let receipt = email_types::PaymentReceipt {
+                recipient_email,
+                merchant_name: get_merchant_name(merchant_account),
+                profile_name: business_profile.profile_name.clone(),
+                payment_id: payment.payment_id.clone().unwrap_or_default(),
+                amount,
+                currency: payment.currency.clone(),
+                templates: email_settings.templates,
+            };
+
+            let send_email_result = state
+                .email_client
+                .compose_and_send_email(Box::new(receipt), state.conf.proxy.https_url.as_ref())
+                .await;
+            logger::info!(?send_email_result);
+        }
+        (enums::EventType::DisputeOpened, api::OutgoingWebhookContent::DisputeDetails(dispute)) => {
+            let message = format!(
+                "A dispute of {} {} was opened against the payment {}. Submit your evidence before {}.",
+                dispute.amount,
+                dispute.currency,
+                dispute.payment_id,
+                dispute
+                    .challenge_required_by
+                    .map(|due_by| due_by.to_string())
+                    .unwrap_or_else(|| "the deadline set by the connector".to_string()),
+            );
+            send_merchant_alert(
+                state,
+                business_profile,
+                &email_settings,
+                MerchantAlertType::DisputeOpened,
+                message,
+            )
+            .await;
+        }
+        (
+            enums::EventType::DisputeDeadlineApproaching,
+            api::OutgoingWebhookContent::DisputeDetails(dispute),
+        ) => {
+            let Some(challenge_required_by) = dispute.challenge_required_by else {
+                return Ok(());
+            };
+            let message = format!(
+                "The evidence of the dispute {} of {} {} against the payment {} is due by {}. \
+                 Submit your evidence or accept the dispute before then.",
+                dispute.dispute_id,
+                dispute.amount,
+                dispute.currency,
+                dispute.payment_id,
+                challenge_required_by,
+            );
+            send_merchant_alert(
+                state,
+                business_profile,
+                &email_settings,
+                MerchantAlertType::DisputeDeadlineApproaching,
+                message,
+            )
+            .await;
+        }
+        (enums::EventType::RefundSucceeded, api::OutgoingWebhookContent::RefundDetails(_)) => {
+            check_refund_volume(state, merchant_account, business_profile, &email_settings).await?;
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Alerts the merchant that their webhook endpoint did not accept an outgoing webhook. The alert
+/// is sent at most once per throttling interval for a business profile.
+#[instrument(skip_all)]
+pub async fn send_webhook_failure_alert(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+) -> RouterResult<()> {
+    let Some(email_settings) = get_email_settings(business_profile)? else {
+        return Ok(());
+    };
+
+    if !is_alert_enabled(&email_settings, MerchantAlertType::WebhookEndpointFailing)
+        || !acquire_alert_slot(
+            &*state.store,
+            &business_profile.profile_id,
+            MerchantAlertType::WebhookEndpointFailing,
+            consts::WEBHOOK_FAILURE_ALERT_INTERVAL_IN_SECS,
+        )
+        .await?
+    {
+        return Ok(());
+    }
+
+    send_merchant_alert(
+        state,
+        business_profile,
+        &email_settings,
+        MerchantAlertType::WebhookEndpointFailing,
+        "Your webhook endpoint did not accept the webhooks we sent. Check that the endpoint \
+         is reachable and responds with a successful status code."
+            .to_string(),
+    )
+    .await;
+
+    Ok(())
+}
+
+async fn check_refund_volume(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &BusinessProfile,
+    email_settings: &EmailNotificationSettings,
+) -> RouterResult<()> {
+    let Some(threshold) = email_settings.refund_volume_threshold else {
+        return Ok(());
+    };
+
+    if !is_alert_enabled(email_settings, MerchantAlertType::HighRefundVolume) {
+        return Ok(());
+    }
+
+    let start_time = common_utils::date_time::now()
+        - time::Duration::seconds(consts::REFUND_VOLUME_ALERT_WINDOW_IN_SECS);
+    let refund_list_request = api_models::refunds::RefundListRequest {
+        payment_id: None,
+        refund_id: None,
+        profile_id: Some(business_profile.profile_id.clone()),
+        limit: None,
+        offset: None,
+        time_range: Some(api_models::payments::TimeRange {
+            start_time,
+            end_time: None,
+        }),
+        connector: None,
+        currency: None,
+        refund_status: Some(vec![enums::RefundStatus::Success]),
+    };
+
+    let refund_count = state
+        .store
+        .get_total_count_of_refunds(
+            &merchant_account.merchant_id,
+            &refund_list_request,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the refunds of the business profile")?;
+
+    if refund_count <= i64::from(threshold)
+        || !acquire_alert_slot(
+            &*state.store,
+            &business_profile.profile_id,
+            MerchantAlertType::HighRefundVolume,
+            consts::REFUND_VOLUME_ALERT_WINDOW_IN_SECS,
+        )
+        .await?
+    {
+        return Ok(());
+    }
+
+    send_merchant_alert(
+        state,
+        business_profile,
+        email_settings,
+        MerchantAlertType::HighRefundVolume,
+        format!(
+            "{refund_count} refunds succeeded in the last 24 hours, which is above the \
+             configured threshold of {threshold}."
+        ),
+    )
+    .await;
+
+    Ok(())
+}
+
+async fn send_merchant_alert(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    email_settings: &EmailNotificationSettings,
+    alert_type: MerchantAlertType,
+    message: String,
+) {
+    if !is_alert_enabled(email_settings, alert_type) {
+        return;
+    }
+
+    for recipient_email in &email_settings.alert_recipients {
+        let alert = email_types::MerchantAlert {
+            recipient_email: recipient_email.clone(),
+            alert_type,
+            message: message.clone(),
+            profile_name: business_profile.profile_name.clone(),
+            footer: email_settings
+                .templates
+                .as_ref()
+                .and_then(|templates| templates.footer.clone()),
+        };
+
+        let send_email_result = state
+            .email_client
+            .compose_and_send_email(Box::new(alert), state.conf.proxy.https_url.as_ref())
+            .await;
+        logger::info!(?alert_type, ?send_email_result);
+    }
+}
+
+fn is_alert_enabled(
+    email_settings: &EmailNotificationSettings,
+    alert_type: MerchantAlertType,
+) -> bool {
+    !email_settings.alert_recipients.is_empty()
+        && email_settings.enabled_alerts.contains(&alert_type)
+}
+
+/// Returns `true` if no alert of this type was sent for the business profile within `interval`
+/// seconds, in which case the caller is expected to send it
+async fn acquire_alert_slot(
+    db: &dyn StorageInterface,
+    profile_id: &str,
+    alert_type: MerchantAlertType,
+    interval: i64,
+) -> RouterResult<bool> {
+    let redis_conn = db
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = format!(
+        "{}_{:?}_{}",
+        consts::MERCHANT_ALERT_REDIS_PREFIX,
+        alert_type,
+        profile_id
+    );
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(&key, "true", Some(interval))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set the merchant alert key in redis")?;
+
+    Ok(matches!(reply, redis::SetnxReply::KeySet))
+}
+
+fn get_merchant_name(merchant_account: &domain::MerchantAccount) -> String {
+    merchant_account
+        .merchant_name
+        .clone()
+        .map(|merchant_name| merchant_name.into_inner().expose())
+        .unwrap_or_else(|| merchant_account.merchant_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+    use storage_impl::MockDb;
+
+    #[tokio::test]
+    async fn test_alert_slot_is_acquired_once_per_interval() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+
+        assert!(acquire_alert_slot(
+            &mockdb,
+            "pro_1",
+            MerchantAlertType::WebhookEndpointFailing,
+            60
+        )
+        .await
+        .unwrap());
+        assert!(!acquire_alert_slot(
+            &mockdb,
+            "pro_1",
+            MerchantAlertType::WebhookEndpointFailing,
+            60
+        )
+        .await
+        .unwrap());
+
+        // Slots are kept apart for each alert type and business profile
+        assert!(
+            acquire_alert_slot(&mockdb, "pro_1", MerchantAlertType::HighRefundVolume, 60)
+                .await
+                .unwrap()
+        );
+        assert!(acquire_alert_slot(
+            &mockdb,
+            "pro_2",
+            MerchantAlertType::WebhookEndpointFailing,
+            60
+        )
+        .await
+        .unwrap());
+    }
+}
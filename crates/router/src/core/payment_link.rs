@@ -1,3 +1,5 @@
+mod invoice;
+
 use api_models::admin as admin_types;
 use common_utils::{
     consts::{
@@ -7,19 +9,26 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use futures::future;
-use masking::{PeekInterface, Secret};
-use time::PrimitiveDateTime;
+use masking::{ExposeInterface, PeekInterface, Secret};
 
 use super::errors::{self, RouterResult, StorageErrorExt};
 use crate::{
-    core::payments::helpers,
+    core::{
+        payment_methods::Oss,
+        payments::{self, helpers},
+        utils as core_utils,
+    },
+    db::StorageInterface,
     errors::RouterResponse,
     routes::AppState,
-    services,
+    services::{self, rate_limit},
     types::{
-        api::payment_link::PaymentLinkResponseExt, domain, storage::enums as storage_enums,
+        api::{self, payment_link::PaymentLinkResponseExt},
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
     },
+    utils,
 };
 
 pub async fn retrieve_payment_link(
@@ -32,7 +41,7 @@ pub async fn retrieve_payment_link(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let status = check_payment_link_status(payment_link_object.fulfilment_time);
+    let status = check_payment_link_status(&payment_link_object);
 
     let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
         payment_link_object,
@@ -57,27 +66,33 @@ pub async fn intiate_payment_link_flow(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-    let payment_link_id = payment_intent
-        .payment_link_id
-        .get_required_value("payment_link_id")
-        .change_context(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    let payment_link = find_active_payment_link(db, &payment_intent).await?;
 
-    helpers::validate_payment_status_against_not_allowed_statuses(
-        &payment_intent.status,
-        &[
-            storage_enums::IntentStatus::Cancelled,
-            storage_enums::IntentStatus::Succeeded,
-            storage_enums::IntentStatus::Processing,
-            storage_enums::IntentStatus::RequiresCapture,
-            storage_enums::IntentStatus::RequiresMerchantAction,
-        ],
-        "use payment link for",
-    )?;
+    // Payments of multi use links are created from the payment the link was created with, once
+    // the customer starts paying on the page of the link, through `create_payment_url`
+    let create_payment_url = match payment_link.link_type {
+        storage_enums::PaymentLinkType::MultiUse => {
+            Some(format!("{}/payments", payment_link.link_to_pay))
+        }
+        storage_enums::PaymentLinkType::SingleUse => None,
+    };
+    let amount_required =
+        payment_link.amount_type == storage_enums::PaymentLinkAmountType::CustomerEntered;
 
-    let payment_link = db
-        .find_payment_link_by_payment_link_id(&payment_link_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    if payment_link.link_type == storage_enums::PaymentLinkType::SingleUse {
+        helpers::validate_payment_status_against_not_allowed_statuses(
+            &payment_intent.status,
+            &[
+                storage_enums::IntentStatus::Cancelled,
+                storage_enums::IntentStatus::Expired,
+                storage_enums::IntentStatus::Succeeded,
+                storage_enums::IntentStatus::Processing,
+                storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::RequiresMerchantAction,
+            ],
+            "use payment link for",
+        )?;
+    }
 
     let payment_link_config = if let Some(pl_config) = payment_link.payment_link_config.clone() {
         extract_payment_link_config(Some(pl_config))?
@@ -85,7 +100,13 @@ pub async fn intiate_payment_link_flow(
         extract_payment_link_config(merchant_account.payment_link_config.clone())?
     };
 
-    let order_details = validate_order_details(payment_intent.order_details)?;
+    let order_details = match payment_link.amount_type {
+        storage_enums::PaymentLinkAmountType::Fixed => {
+            validate_order_details(payment_intent.order_details)?
+        }
+        // Order details do not add up to an amount entered by the customer
+        storage_enums::PaymentLinkAmountType::CustomerEntered => None,
+    };
 
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url {
         payment_create_return_url
@@ -120,7 +141,12 @@ pub async fn intiate_payment_link_flow(
         return_url,
         expiry: payment_link.fulfilment_time,
         pub_key,
-        client_secret,
+        // The payment a multi use link was created with is never paid itself
+        client_secret: if create_payment_url.is_some() {
+            String::new()
+        } else {
+            client_secret
+        },
         merchant_logo: payment_link_config
             .clone()
             .map(|pl_config| {
@@ -135,6 +161,11 @@ pub async fn intiate_payment_link_flow(
                 .color_scheme
                 .map(|color| color.sdk_theme.unwrap_or(default_sdk_theme.to_string()))
         }),
+        locale: payment_link.locale,
+        success_url: payment_link.success_url,
+        failure_url: payment_link.failure_url,
+        amount_required,
+        create_payment_url,
     };
 
     let js_script = get_js_script(payment_details)?;
@@ -152,6 +183,138 @@ pub async fn intiate_payment_link_flow(
     )))
 }
 
+/// Creates a payment of its own for a customer paying on the page of a multi use payment link
+pub async fn create_payment_link_payment(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: String,
+    request: api_models::payments::PaymentLinkPaymentRequest,
+) -> RouterResponse<api_models::payments::PaymentLinkPaymentResponse> {
+    let db = &*state.store;
+    let template_payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_link = find_active_payment_link(db, &template_payment_intent).await?;
+    if payment_link.link_type != storage_enums::PaymentLinkType::MultiUse {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "payments can only be created from multi use payment links".to_string(),
+        })?
+    }
+
+    if let Some(bucket) = state.conf.payment_link.payments_rate_limit {
+        rate_limit::check_rate_limit(
+            &state,
+            "payment_link",
+            &format!("payment_link_{}", payment_link.payment_link_id),
+            bucket,
+        )
+        .await?;
+    }
+
+    let amount = match payment_link.amount_type {
+        storage_enums::PaymentLinkAmountType::Fixed => template_payment_intent.amount,
+        storage_enums::PaymentLinkAmountType::CustomerEntered => {
+            let payment_link_config =
+                if let Some(pl_config) = payment_link.payment_link_config.clone() {
+                    extract_payment_link_config(Some(pl_config))?
+                } else {
+                    extract_payment_link_config(merchant_account.payment_link_config.clone())?
+                };
+            validate_customer_entered_amount(request.amount, payment_link_config.as_ref())?
+        }
+    };
+
+    let payment_intent = create_payment_for_multi_use_link(
+        &state,
+        &merchant_account,
+        key_store,
+        &payment_link,
+        &template_payment_intent,
+        amount,
+    )
+    .await?;
+
+    let client_secret = payment_intent
+        .client_secret
+        .get_required_value("client_secret")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Client secret not set for the payment created from the payment link")?;
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::PaymentLinkPaymentResponse {
+            payment_id: payment_intent.payment_id,
+            client_secret,
+            amount: payment_intent.amount,
+            currency,
+        },
+    ))
+}
+
+/// Finds the payment link of the payment, failing unless customers can pay through it
+async fn find_active_payment_link(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<storage::PaymentLink> {
+    let payment_link_id = payment_intent
+        .payment_link_id
+        .clone()
+        .get_required_value("payment_link_id")
+        .change_context(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(&payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let payment_link_status = check_payment_link_status(&payment_link);
+    if payment_link_status != storage_enums::PaymentLinkStatus::Active {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("payment link is {payment_link_status}"),
+        })?
+    }
+    Ok(payment_link)
+}
+
+/// Checks an amount entered by the customer against the bounds configured for the payment link
+fn validate_customer_entered_amount(
+    amount: Option<i64>,
+    payment_link_config: Option<&admin_types::PaymentLinkConfig>,
+) -> RouterResult<i64> {
+    let amount = amount.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "amount",
+    })?;
+    let min_amount = payment_link_config
+        .and_then(|pl_config| pl_config.min_amount)
+        .unwrap_or(1)
+        .max(1);
+    let max_amount = payment_link_config.and_then(|pl_config| pl_config.max_amount);
+
+    if amount < min_amount {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("amount must be at least {min_amount}"),
+        })?
+    }
+    if let Some(max_amount) = max_amount.filter(|max_amount| amount > *max_amount) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("amount must be at most {max_amount}"),
+        })?
+    }
+    Ok(amount)
+}
+
 /*
 The get_js_script function is used to inject dynamic value to payment_link sdk, which is unique to every payment.
 */
@@ -224,14 +387,361 @@ pub async fn list_payment_link(
     Ok(services::ApplicationResponse::Json(payment_link_list))
 }
 
-pub fn check_payment_link_status(fulfillment_time: Option<PrimitiveDateTime>) -> String {
-    let curr_time = Some(common_utils::date_time::now());
+pub fn check_payment_link_status(
+    payment_link: &storage::PaymentLink,
+) -> storage_enums::PaymentLinkStatus {
+    match payment_link.status {
+        storage_enums::PaymentLinkStatus::Active | storage_enums::PaymentLinkStatus::Expired => {
+            let is_expired = payment_link
+                .fulfilment_time
+                .map_or(false, |fulfilment_time| {
+                    common_utils::date_time::now() > fulfilment_time
+                });
+            if is_expired {
+                storage_enums::PaymentLinkStatus::Expired
+            } else {
+                storage_enums::PaymentLinkStatus::Active
+            }
+        }
+        status @ (storage_enums::PaymentLinkStatus::Completed
+        | storage_enums::PaymentLinkStatus::Deactivated) => status,
+    }
+}
 
-    if curr_time > fulfillment_time {
-        "expired".to_string()
-    } else {
-        "active".to_string()
+pub async fn deactivate_payment_link(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<api_models::payments::RetrievePaymentLinkResponse> {
+    let db = &*state.store;
+    let payment_link =
+        find_merchant_payment_link(db, &merchant_account.merchant_id, &payment_link_id).await?;
+
+    let payment_link_status = check_payment_link_status(&payment_link);
+    if payment_link_status != storage_enums::PaymentLinkStatus::Active {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("payment link is already {payment_link_status}"),
+        })?
     }
+
+    let payment_link = db
+        .update_payment_link_by_payment_link_id(
+            &payment_link_id,
+            storage::PaymentLinkUpdate::StatusUpdate {
+                status: storage_enums::PaymentLinkStatus::Deactivated,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    let status = check_payment_link_status(&payment_link);
+    let response =
+        api_models::payments::RetrievePaymentLinkResponse::foreign_from((payment_link, status));
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+async fn find_merchant_payment_link(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    payment_link_id: &str,
+) -> RouterResult<storage::PaymentLink> {
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    if payment_link.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::PaymentLinkNotFound)?
+    }
+    Ok(payment_link)
+}
+
+pub async fn retrieve_payment_link_qr_code(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let payment_link = find_merchant_payment_link(
+        &*state.store,
+        &merchant_account.merchant_id,
+        &payment_link_id,
+    )
+    .await?;
+
+    let qr_code = utils::QrImage::png_from_data(&payment_link.link_to_pay)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create QR code for payment link")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        qr_code,
+        mime::IMAGE_PNG,
+    )))
+}
+
+pub async fn retrieve_payment_link_invoice(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_link_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = &*state.store;
+    let payment_link =
+        find_merchant_payment_link(db, &merchant_account.merchant_id, &payment_link_id).await?;
+
+    // A paid link is documented with a receipt, links that can no longer be paid get none
+    let title = match check_payment_link_status(&payment_link) {
+        storage_enums::PaymentLinkStatus::Active => "Invoice",
+        storage_enums::PaymentLinkStatus::Completed => "Receipt",
+        status @ (storage_enums::PaymentLinkStatus::Expired
+        | storage_enums::PaymentLinkStatus::Deactivated) => {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("payment link is {status}"),
+            })?
+        }
+    };
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_link.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let currency = payment_link
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        })?;
+    let format_amount = |amount: i64| {
+        currency
+            .to_currency_base_unit_with_zero_decimal_check(amount)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert amount to its base unit")
+    };
+
+    let line_items = match validate_order_details(payment_intent.order_details)? {
+        Some(order_details)
+            if payment_link.amount_type == storage_enums::PaymentLinkAmountType::Fixed =>
+        {
+            order_details
+                .into_iter()
+                .map(|order| {
+                    let total_amount = order
+                        .amount
+                        .checked_mul(i64::from(order.quantity))
+                        .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                            field_name: "order_details",
+                        })
+                        .into_report()
+                        .attach_printable("Order line item total overflows")?;
+                    Ok(invoice::InvoiceLineItem {
+                        unit_amount: format_amount(order.amount)?,
+                        total_amount: format_amount(total_amount)?,
+                        name: order.product_name,
+                        quantity: order.quantity,
+                    })
+                })
+                .collect::<RouterResult<Vec<_>>>()?
+        }
+        _ => vec![invoice::InvoiceLineItem {
+            name: payment_link
+                .description
+                .clone()
+                .unwrap_or_else(|| "Payment".to_string()),
+            quantity: 1,
+            unit_amount: format_amount(payment_link.amount)?,
+            total_amount: format_amount(payment_link.amount)?,
+        }],
+    };
+
+    let merchant_name = payment_link.custom_merchant_name.clone().unwrap_or(
+        merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+            .unwrap_or_default(),
+    );
+
+    let invoice_data = invoice::InvoiceData {
+        title,
+        invoice_number: payment_link.payment_link_id,
+        merchant_name,
+        merchant_details: get_merchant_details_lines(&merchant_account)?,
+        issued_on: payment_link.created_at.date().to_string(),
+        due_on: payment_link
+            .fulfilment_time
+            .map(|fulfilment_time| fulfilment_time.date().to_string()),
+        description: payment_link.description,
+        currency: currency.to_string(),
+        line_items,
+        tax_amount: payment_link.tax_amount.map(format_amount).transpose()?,
+        total_amount: format_amount(payment_link.amount)?,
+        link_to_pay: payment_link.link_to_pay,
+    };
+
+    let document = invoice::render_invoice(invoice_data)?;
+    Ok(services::ApplicationResponse::FileData((
+        document,
+        mime::APPLICATION_PDF,
+    )))
+}
+
+/// Contact and address lines of the merchant, as printed on payment link invoices
+fn get_merchant_details_lines(
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<Vec<String>> {
+    let merchant_details = merchant_account
+        .merchant_details
+        .clone()
+        .map(|merchant_details| {
+            merchant_details
+                .into_inner()
+                .expose()
+                .parse_value::<admin_types::MerchantDetails>("MerchantDetails")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant details")?;
+
+    let Some(merchant_details) = merchant_details else {
+        return Ok(Vec::new());
+    };
+
+    let address_lines = merchant_details
+        .address
+        .map(|address| {
+            let locality = [
+                address.city,
+                address.state.map(|state| state.expose()),
+                address.zip.map(|zip| zip.expose()),
+            ];
+            vec![
+                address.line1.map(|line| line.expose()),
+                address.line2.map(|line| line.expose()),
+                address.line3.map(|line| line.expose()),
+                Some(
+                    locality
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                )
+                .filter(|locality| !locality.is_empty()),
+                address.country.map(|country| country.to_string()),
+            ]
+        })
+        .unwrap_or_default();
+
+    Ok(address_lines
+        .into_iter()
+        .chain([
+            merchant_details
+                .primary_email
+                .map(|email| email.peek().to_owned()),
+            merchant_details.primary_phone.map(|phone| phone.expose()),
+            merchant_details.website,
+        ])
+        .flatten()
+        .collect())
+}
+
+/// Marks a single use payment link as completed once its payment has succeeded
+pub async fn complete_payment_link(state: &AppState, payment_link_id: &str) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_link = db
+        .find_payment_link_by_payment_link_id(payment_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+
+    if payment_link.link_type == storage_enums::PaymentLinkType::SingleUse
+        && payment_link.status == storage_enums::PaymentLinkStatus::Active
+    {
+        db.update_payment_link_by_payment_link_id(
+            payment_link_id,
+            storage::PaymentLinkUpdate::StatusUpdate {
+                status: storage_enums::PaymentLinkStatus::Completed,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+    }
+    Ok(())
+}
+
+async fn create_payment_for_multi_use_link(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_link: &storage::PaymentLink,
+    template_payment_intent: &storage::PaymentIntent,
+    amount: i64,
+) -> RouterResult<storage::PaymentIntent> {
+    let payment_id = core_utils::get_or_generate_id("payment_id", &None, "pay").into_report()?;
+    let return_url = template_payment_intent
+        .return_url
+        .as_ref()
+        .map(|return_url| url::Url::parse(return_url))
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid return_url in payment intent")?;
+
+    let order_details = match payment_link.amount_type {
+        storage_enums::PaymentLinkAmountType::Fixed => {
+            validate_order_details(template_payment_intent.order_details.clone())?
+        }
+        storage_enums::PaymentLinkAmountType::CustomerEntered => None,
+    };
+
+    let payment_request = api_models::payments::PaymentsRequest {
+        payment_id: Some(api_models::payments::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        amount: Some(amount.into()),
+        currency: template_payment_intent.currency,
+        confirm: Some(false),
+        description: template_payment_intent.description.clone(),
+        return_url,
+        order_details,
+        metadata: template_payment_intent.metadata.clone(),
+        business_country: template_payment_intent.business_country,
+        business_label: template_payment_intent.business_label.clone(),
+        profile_id: template_payment_intent.profile_id.clone(),
+        ..Default::default()
+    };
+
+    Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        merchant_account.clone(),
+        key_store,
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
 
 fn validate_order_details(
@@ -280,3 +790,50 @@ fn extract_payment_link_config(
         })
         .transpose()
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn payment_link_config(
+        min_amount: Option<i64>,
+        max_amount: Option<i64>,
+    ) -> admin_types::PaymentLinkConfig {
+        admin_types::PaymentLinkConfig {
+            merchant_logo: None,
+            color_scheme: None,
+            min_amount,
+            max_amount,
+            custom_domain: None,
+        }
+    }
+
+    #[test]
+    fn test_customer_entered_amount_within_bounds() {
+        let config = payment_link_config(Some(100), Some(10_000));
+
+        assert_eq!(
+            validate_customer_entered_amount(Some(100), Some(&config)).unwrap(),
+            100
+        );
+        assert_eq!(
+            validate_customer_entered_amount(Some(10_000), Some(&config)).unwrap(),
+            10_000
+        );
+        assert!(validate_customer_entered_amount(Some(99), Some(&config)).is_err());
+        assert!(validate_customer_entered_amount(Some(10_001), Some(&config)).is_err());
+    }
+
+    #[test]
+    fn test_customer_entered_amount_without_bounds() {
+        assert_eq!(validate_customer_entered_amount(Some(1), None).unwrap(), 1);
+        assert!(validate_customer_entered_amount(Some(0), None).is_err());
+        assert!(validate_customer_entered_amount(Some(-100), None).is_err());
+        assert!(validate_customer_entered_amount(None, None).is_err());
+
+        // A minimum below one does not allow payments of no amount
+        let config = payment_link_config(Some(-5), None);
+        assert!(validate_customer_entered_amount(Some(0), Some(&config)).is_err());
+    }
+}
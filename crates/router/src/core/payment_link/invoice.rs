@@ -0,0 +1,168 @@
+//! Payment link invoices, rendered with the [`PdfWriter`].
+
+use crate::{
+    core::errors::RouterResult,
+    utils::pdf::{Font, PdfWriter, MARGIN},
+};
+
+pub struct InvoiceLineItem {
+    pub name: String,
+    pub quantity: u16,
+    pub unit_amount: String,
+    pub total_amount: String,
+}
+
+pub struct InvoiceData {
+    pub title: &'static str,
+    pub invoice_number: String,
+    pub merchant_name: String,
+    pub merchant_details: Vec<String>,
+    pub issued_on: String,
+    pub due_on: Option<String>,
+    pub description: Option<String>,
+    pub currency: String,
+    pub line_items: Vec<InvoiceLineItem>,
+    pub tax_amount: Option<String>,
+    pub total_amount: String,
+    pub link_to_pay: String,
+}
+
+pub fn render_invoice(invoice: InvoiceData) -> RouterResult<Vec<u8>> {
+    let mut pdf = PdfWriter::new();
+    let quantity_column = MARGIN + 280.0;
+    let unit_amount_column = MARGIN + 340.0;
+    let total_amount_column = MARGIN + 420.0;
+
+    pdf.line(Font::Bold, 20.0, invoice.title);
+    pdf.line(
+        Font::Regular,
+        10.0,
+        &format!("{} number: {}", invoice.title, invoice.invoice_number),
+    );
+    pdf.line(
+        Font::Regular,
+        10.0,
+        &format!("Issued on: {}", invoice.issued_on),
+    );
+    if let Some(due_on) = &invoice.due_on {
+        pdf.line(Font::Regular, 10.0, &format!("Due on: {due_on}"));
+    }
+
+    pdf.advance(10.0);
+    pdf.line(Font::Bold, 12.0, &invoice.merchant_name);
+    for detail in invoice.merchant_details.iter() {
+        pdf.line(Font::Regular, 10.0, detail);
+    }
+
+    if let Some(description) = &invoice.description {
+        pdf.advance(10.0);
+        pdf.line(Font::Regular, 10.0, description);
+    }
+
+    pdf.advance(10.0);
+    pdf.columns(
+        Font::Bold,
+        10.0,
+        &[
+            (MARGIN, "Item"),
+            (quantity_column, "Qty"),
+            (unit_amount_column, "Unit price"),
+            (total_amount_column, "Amount"),
+        ],
+    );
+    pdf.rule();
+    for item in invoice.line_items.iter() {
+        let quantity = item.quantity.to_string();
+        pdf.columns(
+            Font::Regular,
+            10.0,
+            &[
+                (MARGIN, &item.name),
+                (quantity_column, &quantity),
+                (unit_amount_column, &item.unit_amount),
+                (total_amount_column, &item.total_amount),
+            ],
+        );
+    }
+    pdf.rule();
+
+    if let Some(tax_amount) = &invoice.tax_amount {
+        pdf.columns(
+            Font::Regular,
+            10.0,
+            &[
+                (unit_amount_column, "Tax included"),
+                (total_amount_column, tax_amount),
+            ],
+        );
+    }
+    let total = format!("{} {}", invoice.currency, invoice.total_amount);
+    pdf.columns(
+        Font::Bold,
+        12.0,
+        &[(unit_amount_column, "Total"), (total_amount_column, &total)],
+    );
+
+    pdf.advance(20.0);
+    pdf.line(Font::Bold, 12.0, "Pay online");
+    pdf.qr_code(&invoice.link_to_pay)?;
+    pdf.link(
+        Font::Regular,
+        9.0,
+        &invoice.link_to_pay,
+        &invoice.link_to_pay,
+    );
+
+    Ok(pdf.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+
+    fn invoice_data(line_items: usize) -> InvoiceData {
+        InvoiceData {
+            title: "Invoice",
+            invoice_number: "plink_123".to_string(),
+            merchant_name: "Café (Retail)".to_string(),
+            merchant_details: vec!["123, King Street".to_string()],
+            issued_on: "2023-12-07".to_string(),
+            due_on: Some("2023-12-14".to_string()),
+            description: None,
+            currency: "USD".to_string(),
+            line_items: (0..line_items)
+                .map(|index| InvoiceLineItem {
+                    name: format!("Item {index}"),
+                    quantity: 1,
+                    unit_amount: "10.00".to_string(),
+                    total_amount: "10.00".to_string(),
+                })
+                .collect(),
+            tax_amount: Some("1.00".to_string()),
+            total_amount: "10.00".to_string(),
+            link_to_pay: "https://example.com/payment_link/merchant/pay_123".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_invoice() {
+        let document = render_invoice(invoice_data(2)).unwrap();
+        let document_text = String::from_utf8_lossy(&document);
+
+        assert!(document.starts_with(b"%PDF-1.4"));
+        assert!(document_text.ends_with("%%EOF\n"));
+        assert!(document_text.contains("/Count 1"));
+        assert!(document_text.contains("(Caf\u{fffd} \\(Retail\\)) Tj"));
+        assert!(document_text.contains("/URI (https://example.com/payment_link/merchant/pay_123)"));
+    }
+
+    #[test]
+    fn test_render_invoice_spanning_pages() {
+        let document = render_invoice(invoice_data(80)).unwrap();
+        let document_text = String::from_utf8_lossy(&document);
+
+        assert!(document_text.contains("/Count 2"));
+    }
+}
@@ -2595,9 +2595,19 @@ pub async fn retrieve_payment_method(
         .find_payment_method(&pm.payment_method_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    get_payment_method_response(&state, pm)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Builds the response of a stored payment method, fetching the card details from the locker
+pub async fn get_payment_method_response(
+    state: &routes::AppState,
+    pm: storage::PaymentMethod,
+) -> errors::RouterResult<api::PaymentMethodResponse> {
     let card = if pm.payment_method == enums::PaymentMethod::Card {
         let card = get_card_from_locker(
-            &state,
+            state,
             &pm.customer_id,
             &pm.merchant_id,
             &pm.payment_method_id,
@@ -2612,21 +2622,19 @@ pub async fn retrieve_payment_method(
     } else {
         None
     };
-    Ok(services::ApplicationResponse::Json(
-        api::PaymentMethodResponse {
-            merchant_id: pm.merchant_id,
-            customer_id: Some(pm.customer_id),
-            payment_method_id: pm.payment_method_id,
-            payment_method: pm.payment_method,
-            payment_method_type: pm.payment_method_type,
-            card,
-            metadata: pm.metadata,
-            created: Some(pm.created_at),
-            recurring_enabled: false,           //[#219]
-            installment_payment_enabled: false, //[#219]
-            payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
-        },
-    ))
+    Ok(api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id,
+        customer_id: Some(pm.customer_id),
+        payment_method_id: pm.payment_method_id,
+        payment_method: pm.payment_method,
+        payment_method_type: pm.payment_method_type,
+        card,
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+        recurring_enabled: false,           //[#219]
+        installment_payment_enabled: false, //[#219]
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219],
+    })
 }
 
 #[instrument(skip_all)]
@@ -47,7 +47,9 @@ use super::{
     errors::StorageErrorExt, payment_methods::surcharge_decision_configs, utils as core_utils,
 };
 use crate::{
-    configs::settings::PaymentMethodTypeTokenFilter,
+    configs::settings::{
+        AuthorizationExpiryAction, AuthorizationExpiryPolicy, PaymentMethodTypeTokenFilter,
+    },
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::PaymentMethodRetrieve,
@@ -68,7 +70,7 @@ use crate::{
         add_apple_pay_flow_metrics, add_connector_http_status_code_metrics, Encode, OptionExt,
         ValueExt,
     },
-    workflows::payment_sync,
+    workflows::{authorization_expiry, payment_sync},
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -179,6 +181,7 @@ where
             .to_domain()?
             .populate_payment_data(state, &mut payment_data, &req, &merchant_account)
             .await?;
+        let previous_intent_status = payment_data.payment_intent.status;
         payment_data = match connector_details {
             api::ConnectorCallType::PreDetermined(connector) => {
                 let schedule_time = if should_add_task_to_process_tracker {
@@ -327,6 +330,12 @@ where
                 .await?
             }
         };
+        if is_authorization_completed(previous_intent_status, payment_data.payment_intent.status) {
+            add_authorization_expiry_task(state, &business_profile, &payment_data.payment_attempt)
+                .await
+                .map_err(|error| logger::error!(authorization_expiry_task_error=?error))
+                .ok();
+        }
         payment_data
             .payment_attempt
             .payment_token
@@ -1993,6 +2002,53 @@ pub fn is_operation_confirm<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "PaymentConfirm")
 }
 
+pub async fn list_captures(
+    state: AppState,
+    merchant: domain::MerchantAccount,
+    payment_id: String,
+) -> RouterResponse<api_models::payments::CaptureListResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant.merchant_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &merchant.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let captures = db
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            &merchant.merchant_id,
+            &payment_intent.payment_id,
+            &payment_attempt.attempt_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while retrieving capture list")?;
+
+    let data: Vec<api_models::payments::CaptureResponse> = captures
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::CaptureListResponse {
+            payment_id: payment_intent.payment_id,
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
 #[cfg(feature = "olap")]
 pub async fn list_payments(
     state: AppState,
@@ -2003,10 +2059,18 @@ pub async fn list_payments(
     helpers::validate_payment_list_request(&constraints)?;
     let merchant_id = &merchant.merchant_id;
     let db = state.store.as_ref();
-    let payment_intents =
-        helpers::filter_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    // Requests made with an API key bound to a business profile only list the payments of that
+    // profile
+    let profile_id = core_utils::get_profile_id_for_list(&merchant, None)?;
+    let payment_intents = helpers::filter_by_constraints(
+        db,
+        &constraints,
+        merchant_id,
+        profile_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
     let collected_futures = payment_intents.into_iter().map(|pi| {
         async {
@@ -2063,11 +2127,31 @@ pub async fn list_payments(
 pub async fn apply_filters_on_payments(
     state: AppState,
     merchant: domain::MerchantAccount,
-    constraints: api::PaymentListFilterConstraints,
+    mut constraints: api::PaymentListFilterConstraints,
 ) -> RouterResponse<api::PaymentListResponseV2> {
     let limit = &constraints.limit;
     helpers::validate_payment_list_request_for_joins(*limit)?;
     let db = state.store.as_ref();
+    constraints.profile_id =
+        core_utils::get_profile_id_for_list(&merchant, constraints.profile_id)?;
+    // Filtering on a single payment ignores the profile filter, the payment is checked instead
+    if let Some(payment_id) = constraints.payment_id.as_ref() {
+        if merchant.bound_profile_id.is_some() {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    payment_id,
+                    &merchant.merchant_id,
+                    merchant.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            core_utils::validate_profile_access(
+                &merchant,
+                payment_intent.profile_id.as_ref(),
+                errors::ApiErrorResponse::PaymentNotFound,
+            )?;
+        }
+    }
     let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
         .get_filtered_payment_intents_attempt(
             &merchant.merchant_id,
@@ -2183,6 +2267,95 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Returns whether the payment has just been authorized and now awaits capture, which is when the
+/// validity of the authorization starts
+fn is_authorization_completed(
+    previous_intent_status: storage_enums::IntentStatus,
+    intent_status: storage_enums::IntentStatus,
+) -> bool {
+    intent_status == storage_enums::IntentStatus::RequiresCapture
+        && previous_intent_status != storage_enums::IntentStatus::RequiresCapture
+}
+
+/// Returns when and how a payment authorized at `authorized_at` is to be acted upon, the delayed
+/// capture of the profile being made only if it is due before the authorization lapses
+fn get_authorization_expiry_schedule(
+    policy: AuthorizationExpiryPolicy,
+    delayed_capture_hours: Option<i32>,
+    authorized_at: time::PrimitiveDateTime,
+) -> (time::PrimitiveDateTime, AuthorizationExpiryAction) {
+    let expires_at =
+        authorized_at.saturating_add(time::Duration::hours(policy.validity_hours.into()));
+    let capture_at = delayed_capture_hours
+        .map(|hours| authorized_at.saturating_add(time::Duration::hours(hours.into())));
+    match capture_at {
+        Some(capture_at) if capture_at < expires_at => {
+            (capture_at, AuthorizationExpiryAction::Capture)
+        }
+        _ => (expires_at, policy.action),
+    }
+}
+
+/// Schedules acting on a payment awaiting capture. The payment is captured once the delay
+/// configured on the business profile elapses, or the authorization expiry policy is applied
+/// when the authorization lapses at the connector, whichever comes first.
+pub async fn add_authorization_expiry_task(
+    state: &AppState,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|data| {
+            data.parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()?
+        .and_then(|data| match data {
+            api_models::payments::AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        });
+    let policy = state
+        .conf
+        .authorization_expiry
+        .get_policy(payment_attempt.connector.as_deref(), card_network.as_ref());
+
+    let (schedule_time, action) = get_authorization_expiry_schedule(
+        policy,
+        business_profile.delayed_capture_hours,
+        common_utils::date_time::now(),
+    );
+
+    let tracking_data = authorization_expiry::AuthorizationExpiryTrackingData {
+        merchant_id: payment_attempt.merchant_id.clone(),
+        payment_id: payment_attempt.payment_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        action,
+    };
+    let runner = "AUTHORIZATION_EXPIRY_WORKFLOW";
+    let task = "AUTHORIZATION_EXPIRY";
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        task,
+        runner,
+        tracking_data,
+        schedule_time,
+    )?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The task is created when the payment is first seen awaiting capture
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
+
 pub async fn reset_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -2796,3 +2969,69 @@ where
 
     Ok(ConnectorCallType::Retryable(connector_data))
 }
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_authorization_expiry_task_is_added_once_authorized() {
+        use storage_enums::IntentStatus;
+
+        assert!(is_authorization_completed(
+            IntentStatus::RequiresConfirmation,
+            IntentStatus::RequiresCapture
+        ));
+        assert!(is_authorization_completed(
+            IntentStatus::Processing,
+            IntentStatus::RequiresCapture
+        ));
+        // Retrieving a payment that awaits capture does not schedule the task again
+        assert!(!is_authorization_completed(
+            IntentStatus::RequiresCapture,
+            IntentStatus::RequiresCapture
+        ));
+        assert!(!is_authorization_completed(
+            IntentStatus::RequiresCapture,
+            IntentStatus::PartiallyCapturedAndCapturable
+        ));
+        assert!(!is_authorization_completed(
+            IntentStatus::RequiresConfirmation,
+            IntentStatus::Succeeded
+        ));
+    }
+
+    #[test]
+    fn test_authorization_expiry_schedule() {
+        let policy = AuthorizationExpiryPolicy {
+            validity_hours: 168,
+            action: AuthorizationExpiryAction::Void,
+        };
+        let authorized_at = datetime!(2023-12-01 10:00:00);
+
+        assert_eq!(
+            get_authorization_expiry_schedule(policy, None, authorized_at),
+            (
+                datetime!(2023-12-08 10:00:00),
+                AuthorizationExpiryAction::Void
+            )
+        );
+        // The delayed capture is made when it is due before the authorization lapses
+        assert_eq!(
+            get_authorization_expiry_schedule(policy, Some(24), authorized_at),
+            (
+                datetime!(2023-12-02 10:00:00),
+                AuthorizationExpiryAction::Capture
+            )
+        );
+        assert_eq!(
+            get_authorization_expiry_schedule(policy, Some(168), authorized_at),
+            (
+                datetime!(2023-12-08 10:00:00),
+                AuthorizationExpiryAction::Void
+            )
+        );
+    }
+}
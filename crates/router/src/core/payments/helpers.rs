@@ -1993,14 +1993,20 @@ pub(super) async fn filter_by_constraints(
     db: &dyn StorageInterface,
     constraints: &api::PaymentListConstraints,
     merchant_id: &str,
+    profile_id: Option<String>,
     storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Vec<PaymentIntent>, errors::DataStorageError> {
+    let mut fetch_constraints =
+        data_models::payments::payment_intent::PaymentIntentFetchConstraints::from(
+            constraints.clone(),
+        );
+    if let data_models::payments::payment_intent::PaymentIntentFetchConstraints::List(params) =
+        &mut fetch_constraints
+    {
+        params.profile_id = profile_id;
+    }
     let result = db
-        .filter_payment_intent_by_constraints(
-            merchant_id,
-            &constraints.clone().into(),
-            storage_scheme,
-        )
+        .filter_payment_intent_by_constraints(merchant_id, &fetch_constraints, storage_scheme)
         .await?;
     Ok(result)
 }
@@ -2528,8 +2534,10 @@ pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> RouterResult<String
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
 
     use super::*;
+    use crate::core::refunds::validator::validate_refund_amount;
 
     #[test]
     fn test_authenticate_client_secret_fulfillment_time_not_expired() {
@@ -2573,6 +2581,9 @@ mod tests {
             request_incremental_authorization:
                 common_enums::RequestIncrementalAuthorization::default(),
             incremental_authorization_allowed: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            fx_quote: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(900);
@@ -2626,6 +2637,9 @@ mod tests {
             request_incremental_authorization:
                 common_enums::RequestIncrementalAuthorization::default(),
             incremental_authorization_allowed: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            fx_quote: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(10);
@@ -2679,6 +2693,9 @@ mod tests {
             request_incremental_authorization:
                 common_enums::RequestIncrementalAuthorization::default(),
             incremental_authorization_allowed: None,
+            presentment_currency: None,
+            presentment_amount: None,
+            fx_quote: None,
         };
         let req_cs = Some("1".to_string());
         let merchant_fulfillment_time = Some(10);
@@ -2689,6 +2706,167 @@ mod tests {
         )
         .is_err())
     }
+
+    fn usd_to_jpy_fx_quote(expires_at: time::PrimitiveDateTime) -> api_models::payments::FxQuote {
+        api_models::payments::FxQuote {
+            presentment_currency: storage_enums::Currency::JPY,
+            presentment_amount: 1503,
+            market_rate: rust_decimal::Decimal::new(14730, 2),
+            markup_basis_points: 200,
+            exchange_rate: rust_decimal::Decimal::new(15025, 2),
+            expires_at,
+        }
+    }
+
+    fn payment_intent_with_fx_quote(fx_quote: &api_models::payments::FxQuote) -> PaymentIntent {
+        PaymentIntent {
+            id: 21,
+            payment_id: "23".to_string(),
+            merchant_id: "22".to_string(),
+            status: storage_enums::IntentStatus::RequiresCapture,
+            amount: 1000,
+            currency: Some(storage_enums::Currency::USD),
+            amount_captured: None,
+            customer_id: None,
+            description: None,
+            return_url: None,
+            metadata: None,
+            connector_id: None,
+            shipping_address_id: None,
+            billing_address_id: None,
+            statement_descriptor_name: None,
+            statement_descriptor_suffix: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            last_synced: None,
+            setup_future_usage: None,
+            off_session: None,
+            client_secret: Some("1".to_string()),
+            active_attempt: data_models::RemoteStorageObject::ForeignID("nopes".to_string()),
+            business_country: None,
+            business_label: None,
+            order_details: None,
+            allowed_payment_method_types: None,
+            connector_metadata: None,
+            feature_metadata: None,
+            attempt_count: 1,
+            payment_link_id: None,
+            profile_id: None,
+            merchant_decision: None,
+            payment_confirm_source: None,
+            surcharge_applicable: None,
+            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            request_incremental_authorization:
+                common_enums::RequestIncrementalAuthorization::default(),
+            incremental_authorization_allowed: None,
+            presentment_currency: Some(fx_quote.presentment_currency),
+            presentment_amount: Some(fx_quote.presentment_amount),
+            fx_quote: Some(serde_json::to_value(fx_quote).unwrap()),
+        }
+    }
+
+    #[test]
+    fn test_full_capture_and_refund_with_fx_quote() {
+        let fx_quote = usd_to_jpy_fx_quote(
+            common_utils::date_time::now().saturating_add(Duration::minutes(15)),
+        );
+        let payment_intent = payment_intent_with_fx_quote(&fx_quote);
+        let currency = storage_enums::Currency::USD;
+
+        // The connector is asked to capture the amount in JPY
+        let connector_capture_amount =
+            convert_to_presentment_amount(&fx_quote, payment_intent.amount, currency).unwrap();
+        assert_eq!(connector_capture_amount, 1503);
+
+        // The captured amount reported back is stored in USD, not in JPY
+        let amount_captured = convert_from_presentment_amount(
+            &fx_quote,
+            connector_capture_amount,
+            currency,
+            payment_intent.amount,
+        )
+        .unwrap();
+        assert_eq!(amount_captured, payment_intent.amount);
+
+        // The whole captured amount can be refunded, and the connector refunds what it captured
+        assert!(validate_refund_amount(amount_captured, &[], amount_captured).is_ok());
+        assert!(validate_refund_amount(amount_captured, &[], amount_captured + 1).is_err());
+        assert_eq!(
+            convert_to_presentment_amount(&fx_quote, amount_captured, currency).unwrap(),
+            connector_capture_amount
+        );
+    }
+
+    #[test]
+    fn test_partial_capture_and_refund_with_fx_quote() {
+        let fx_quote = usd_to_jpy_fx_quote(
+            common_utils::date_time::now().saturating_add(Duration::minutes(15)),
+        );
+        let payment_intent = payment_intent_with_fx_quote(&fx_quote);
+        let currency = storage_enums::Currency::USD;
+
+        // The connector reports a capture of 751 JPY, about half of the payment
+        let amount_captured =
+            convert_from_presentment_amount(&fx_quote, 751, currency, payment_intent.amount)
+                .unwrap();
+        assert_eq!(amount_captured, 500);
+
+        assert!(validate_refund_amount(amount_captured, &[], 500).is_ok());
+        assert!(validate_refund_amount(amount_captured, &[], 501).is_err());
+        assert_eq!(
+            convert_to_presentment_amount(&fx_quote, amount_captured, currency).unwrap(),
+            751
+        );
+    }
+
+    #[test]
+    fn test_get_reusable_fx_quote() {
+        let fx_quote = usd_to_jpy_fx_quote(
+            common_utils::date_time::now().saturating_add(Duration::minutes(15)),
+        );
+        let payment_intent = payment_intent_with_fx_quote(&fx_quote);
+
+        assert_eq!(
+            get_reusable_fx_quote(
+                &payment_intent,
+                storage_enums::Currency::USD,
+                storage_enums::Currency::JPY,
+            )
+            .unwrap()
+            .map(|reused| (reused.presentment_amount, reused.exchange_rate)),
+            Some((fx_quote.presentment_amount, fx_quote.exchange_rate))
+        );
+        assert!(get_reusable_fx_quote(
+            &payment_intent,
+            storage_enums::Currency::USD,
+            storage_enums::Currency::EUR,
+        )
+        .unwrap()
+        .is_none());
+        assert!(get_reusable_fx_quote(
+            &payment_intent,
+            storage_enums::Currency::GBP,
+            storage_enums::Currency::JPY,
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn test_expired_fx_quote_is_not_reused() {
+        let fx_quote = usd_to_jpy_fx_quote(
+            common_utils::date_time::now().saturating_sub(Duration::minutes(1)),
+        );
+        let payment_intent = payment_intent_with_fx_quote(&fx_quote);
+
+        assert!(get_reusable_fx_quote(
+            &payment_intent,
+            storage_enums::Currency::USD,
+            storage_enums::Currency::JPY,
+        )
+        .unwrap()
+        .is_none());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -2944,7 +3122,8 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::Voided
                     | enums::AttemptStatus::AutoRefunded
                     | enums::AttemptStatus::PaymentMethodAwaited
-                    | enums::AttemptStatus::DeviceDataCollectionPending => {
+                    | enums::AttemptStatus::DeviceDataCollectionPending
+                    | enums::AttemptStatus::Expired => {
                         metrics::MANUAL_RETRY_VALIDATION_FAILED.add(
                             &metrics::CONTEXT,
                             1,
@@ -2999,6 +3178,7 @@ pub fn get_attempt_type(
             }
         }
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -3101,6 +3281,7 @@ impl AttemptType {
             merchant_connector_id: None,
             unified_code: None,
             unified_message: None,
+            fx_quote: old_payment_attempt.fx_quote,
         }
     }
 
@@ -3187,7 +3368,8 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::Voided
             | enums::AttemptStatus::AutoRefunded
             | enums::AttemptStatus::PaymentMethodAwaited
-            | enums::AttemptStatus::DeviceDataCollectionPending => {
+            | enums::AttemptStatus::DeviceDataCollectionPending
+            | enums::AttemptStatus::Expired => {
                 logger::error!("Payment Attempt should not be in this state because Attempt to Intent status mapping doesn't allow it");
                 None
             }
@@ -3201,6 +3383,7 @@ pub fn is_manual_retry_allowed(
             | storage_enums::AttemptStatus::Failure => Some(true),
         },
         enums::IntentStatus::Cancelled
+        | enums::IntentStatus::Expired
         | enums::IntentStatus::RequiresCapture
         | enums::IntentStatus::PartiallyCaptured
         | enums::IntentStatus::PartiallyCapturedAndCapturable
@@ -3680,6 +3863,65 @@ pub fn validate_payment_link_request(
     confirm: Option<bool>,
     order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
 ) -> Result<(), errors::ApiErrorResponse> {
+    if payment_link_object.amount_type == Some(enums::PaymentLinkAmountType::CustomerEntered)
+        && payment_link_object.link_type != Some(enums::PaymentLinkType::MultiUse)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "customer entered amount is only supported for multi use payment links"
+                .to_string(),
+        });
+    }
+
+    if payment_link_object
+        .payment_link_config
+        .as_ref()
+        .and_then(|pl_config| pl_config.min_amount.zip(pl_config.max_amount))
+        .map_or(false, |(min_amount, max_amount)| min_amount > max_amount)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment link min_amount must not be greater than max_amount".to_string(),
+        });
+    }
+
+    for (field_name, domain_name) in [
+        (
+            "merchant_custom_domain_name",
+            payment_link_object.merchant_custom_domain_name.as_ref(),
+        ),
+        (
+            "payment_link_config.custom_domain",
+            payment_link_object
+                .payment_link_config
+                .as_ref()
+                .and_then(|pl_config| pl_config.custom_domain.as_ref()),
+        ),
+    ] {
+        if let Some(domain_name) = domain_name {
+            if domain_name.is_empty() || domain_name.contains(['/', ':', ' ']) {
+                return Err(errors::ApiErrorResponse::InvalidDataValue { field_name });
+            }
+        }
+    }
+
+    if payment_link_object
+        .tax_amount
+        .map_or(false, |tax_amount| tax_amount < 0)
+    {
+        return Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "tax_amount",
+        });
+    }
+
+    for (field_name, redirect_url) in [
+        ("success_url", &payment_link_object.success_url),
+        ("failure_url", &payment_link_object.failure_url),
+    ] {
+        if let Some(redirect_url) = redirect_url {
+            url::Url::parse(redirect_url)
+                .map_err(|_| errors::ApiErrorResponse::InvalidDataValue { field_name })?;
+        }
+    }
+
     if let Some(cnf) = confirm {
         if !cnf {
             let current_time = Some(common_utils::date_time::now());
@@ -3762,3 +4004,141 @@ pub fn validate_order_details_amount(
         Ok(())
     }
 }
+
+/// Locks a quote for paying `amount` of `currency` in `presentment_currency`, if the customer
+/// pays in a currency other than the one of the payment
+pub async fn get_fx_quote(
+    state: &AppState,
+    amount: i64,
+    currency: storage_enums::Currency,
+    presentment_currency: Option<storage_enums::Currency>,
+) -> RouterResult<Option<api_models::payments::FxQuote>> {
+    match presentment_currency {
+        Some(presentment_currency) if presentment_currency != currency => {
+            utils::currency::get_fx_quote(state, amount, currency, presentment_currency)
+                .await
+                .map_err(|err| {
+                    let api_error = match err.current_context() {
+                        utils::currency::ForexCacheError::CurrencyNotAcceptable
+                        | utils::currency::ForexCacheError::ConversionError => {
+                            errors::ApiErrorResponse::InvalidRequestData {
+                                message: format!(
+                                    "Conversion from {currency} to {presentment_currency} is not supported"
+                                ),
+                            }
+                        }
+                        _ => errors::ApiErrorResponse::InternalServerError,
+                    };
+                    err.change_context(api_error)
+                })
+                .attach_printable("Failed to lock a currency conversion quote")
+                .map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Determines the quote of a payment being updated to `amount` of `currency`. The rate of the
+/// current quote is kept while it is valid, a new quote is only locked once it has expired or when
+/// the currencies change. No quote is needed when the customer pays in the currency of the payment.
+pub async fn get_or_lock_fx_quote(
+    state: &AppState,
+    payment_intent: &PaymentIntent,
+    amount: i64,
+    currency: storage_enums::Currency,
+    presentment_currency: Option<storage_enums::Currency>,
+) -> RouterResult<Option<api_models::payments::FxQuote>> {
+    let presentment_currency = match presentment_currency {
+        Some(presentment_currency) if presentment_currency != currency => presentment_currency,
+        _ => return Ok(None),
+    };
+
+    match get_reusable_fx_quote(payment_intent, currency, presentment_currency)? {
+        Some(fx_quote) => Ok(Some(api_models::payments::FxQuote {
+            presentment_amount: convert_to_presentment_amount(&fx_quote, amount, currency)?,
+            ..fx_quote
+        })),
+        None => get_fx_quote(state, amount, currency, Some(presentment_currency)).await,
+    }
+}
+
+/// The quote of the payment, if it is still valid for converting `currency` to
+/// `presentment_currency`
+fn get_reusable_fx_quote(
+    payment_intent: &PaymentIntent,
+    currency: storage_enums::Currency,
+    presentment_currency: storage_enums::Currency,
+) -> RouterResult<Option<api_models::payments::FxQuote>> {
+    Ok(get_fx_quote_of_payment(payment_intent)?.filter(|fx_quote| {
+        payment_intent.currency == Some(currency)
+            && fx_quote.presentment_currency == presentment_currency
+            && fx_quote.expires_at > common_utils::date_time::now()
+    }))
+}
+
+pub fn get_fx_quote_of_payment(
+    payment_intent: &PaymentIntent,
+) -> RouterResult<Option<api_models::payments::FxQuote>> {
+    payment_intent
+        .fx_quote
+        .clone()
+        .map(|fx_quote| fx_quote.parse_value("FxQuote"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the currency conversion quote of the payment")
+}
+
+pub fn validate_fx_quote_expiry(fx_quote: &api_models::payments::FxQuote) -> RouterResult<()> {
+    utils::when(fx_quote.expires_at < common_utils::date_time::now(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The currency conversion quote of the payment has expired, update the payment to lock a new quote".to_string(),
+        }))
+    })
+}
+
+/// Converts `amount` of the currency of the payment to the currency the customer pays in, at the
+/// rate locked by `fx_quote`
+pub fn convert_to_presentment_amount(
+    fx_quote: &api_models::payments::FxQuote,
+    amount: i64,
+    currency: storage_enums::Currency,
+) -> RouterResult<i64> {
+    utils::currency::convert_at_rate(
+        amount,
+        currency,
+        fx_quote.presentment_currency,
+        fx_quote.exchange_rate,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the amount to the presentment currency")
+}
+
+/// Converts `presentment_amount` reported by the connector in the currency the customer pays in
+/// back to the currency of the payment. `expected_amount` is the amount of the payment the
+/// connector was asked to process, it is returned as is when the connector processed exactly the
+/// requested amount, so that rounding never makes captured and refundable amounts drift apart.
+pub fn convert_from_presentment_amount(
+    fx_quote: &api_models::payments::FxQuote,
+    presentment_amount: i64,
+    currency: storage_enums::Currency,
+    expected_amount: i64,
+) -> RouterResult<i64> {
+    if convert_to_presentment_amount(fx_quote, expected_amount, currency)? == presentment_amount {
+        return Ok(expected_amount);
+    }
+
+    rust_decimal::Decimal::ONE
+        .checked_div(fx_quote.exchange_rate)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .and_then(|rate| {
+            utils::currency::convert_at_rate(
+                presentment_amount,
+                fx_quote.presentment_currency,
+                currency,
+                rate,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .attach_printable("Failed to convert the amount from the presentment currency")
+}
@@ -12,6 +12,7 @@ use crate::{
         errors::{self, RouterResult, StorageErrorExt},
         payment_methods::PaymentMethodRetrieve,
         payments::{self, helpers, operations, types::MultipleCaptureData},
+        utils as core_utils,
     },
     routes::AppState,
     services,
@@ -56,6 +57,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        core_utils::validate_profile_access(
+            merchant_account,
+            payment_intent.profile_id.as_ref(),
+            errors::ApiErrorResponse::PaymentNotFound,
+        )?;
+
         payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 payment_intent.payment_id.as_str(),
@@ -103,16 +110,27 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+            if previous_captures.iter().any(|capture| {
+                capture.final_capture && capture.status != enums::CaptureStatus::Failed
+            }) {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The final capture has already been made for this payment".into(),
+                })?
+            }
+
             let capture = db
                 .insert_capture(
-                    payment_attempt
-                        .make_new_capture(amount_to_capture, enums::CaptureStatus::Started)?,
+                    payment_attempt.make_new_capture(
+                        amount_to_capture,
+                        enums::CaptureStatus::Started,
+                        request.final_capture.unwrap_or(false),
+                    )?,
                     storage_scheme,
                 )
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::DuplicatePayment {
-                    payment_id: payment_id.clone(),
-                })?;
+                // The capture id is derived from the capture sequence, a concurrent capture of the
+                // payment conflicts with this one instead of being recorded twice
+                .to_duplicate_response(errors::ApiErrorResponse::ResourceBusy)?;
 
             Some(MultipleCaptureData::new_for_create(
                 previous_captures,
@@ -115,6 +115,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             &payment_intent.status,
             &[
                 storage_enums::IntentStatus::Cancelled,
+                storage_enums::IntentStatus::Expired,
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::Processing,
                 storage_enums::IntentStatus::RequiresCapture,
@@ -136,6 +137,21 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             intent_fulfillment_time,
         )?;
 
+        utils::when(
+            request.presentment_currency.is_some()
+                && request.presentment_currency != payment_intent.presentment_currency,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "presentment_currency can only be changed by updating the payment"
+                        .to_string(),
+                })
+            },
+        )?;
+
+        if let Some(fx_quote) = helpers::get_fx_quote_of_payment(&payment_intent)? {
+            helpers::validate_fx_quote_expiry(&fx_quote)?;
+        }
+
         let customer_details = helpers::get_customer_details_from_request(request);
 
         // Stage 2
@@ -707,6 +723,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let m_straight_through_algorithm = straight_through_algorithm.clone();
         let m_error_code = error_code.clone();
         let m_error_message = error_message.clone();
+        let m_fx_quote = payment_data.payment_intent.fx_quote.clone();
         let m_db = state.clone().store;
 
         let surcharge_amount = payment_data
@@ -743,6 +760,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         merchant_connector_id,
                         surcharge_amount,
                         tax_amount,
+                        fx_quote: m_fx_quote,
                     },
                     storage_scheme,
                 )
@@ -763,6 +781,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
         let m_statement_descriptor_suffix = statement_descriptor_suffix.clone();
         let m_order_details = order_details.clone();
         let m_metadata = metadata.clone();
+        let m_presentment_currency = payment_data.payment_intent.presentment_currency;
+        let m_presentment_amount = payment_data.payment_intent.presentment_amount;
+        let m_intent_fx_quote = payment_data.payment_intent.fx_quote.clone();
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
 
@@ -788,6 +809,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         metadata: m_metadata,
                         payment_confirm_source: header_payload.payment_confirm_source,
                         updated_by: m_storage_scheme,
+                        presentment_currency: m_presentment_currency,
+                        presentment_amount: m_presentment_amount,
+                        fx_quote: m_intent_fx_quote,
                     },
                     storage_scheme,
                 )
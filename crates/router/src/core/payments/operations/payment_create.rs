@@ -154,6 +154,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             utils::get_payment_attempt_id(payment_id.clone(), 1)
         };
 
+        let fx_quote =
+            helpers::get_fx_quote(state, amount.into(), currency, request.presentment_currency)
+                .await?;
+
         let payment_intent_new = Self::make_payment_intent(
             &payment_id,
             merchant_account,
@@ -163,6 +167,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_link_data.clone(),
             billing_address.clone().map(|x| x.address_id),
             attempt_id,
+            fx_quote.as_ref(),
             state,
         )
         .await?;
@@ -175,6 +180,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_type,
             request,
             browser_info,
+            fx_quote.as_ref(),
             state,
         )
         .await?;
@@ -601,6 +607,7 @@ impl PaymentCreate {
         payment_method_type: Option<enums::PaymentMethodType>,
         request: &api::PaymentsRequest,
         browser_info: Option<serde_json::Value>,
+        fx_quote: Option<&api_models::payments::FxQuote>,
         state: &AppState,
     ) -> RouterResult<storage::PaymentAttemptNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
@@ -620,6 +627,11 @@ impl PaymentCreate {
             .transpose()
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to encode additional pm data")?;
+        let fx_quote = fx_quote
+            .map(Encode::<api_models::payments::FxQuote>::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the currency conversion quote")?;
         let attempt_id = if core_utils::is_merchant_enabled_for_payment_id_as_connector_request_id(
             &state.conf,
             merchant_id,
@@ -656,6 +668,7 @@ impl PaymentCreate {
                 .mandate_data
                 .as_ref()
                 .and_then(|inner| inner.mandate_type.clone().map(Into::into)),
+            fx_quote,
             ..storage::PaymentAttemptNew::default()
         })
     }
@@ -671,6 +684,7 @@ impl PaymentCreate {
         payment_link_data: Option<api_models::payments::PaymentLinkResponse>,
         billing_address_id: Option<String>,
         active_attempt_id: String,
+        fx_quote: Option<&api_models::payments::FxQuote>,
         state: &AppState,
     ) -> RouterResult<storage::PaymentIntentNew> {
         let created_at @ modified_at @ last_synced = Some(common_utils::date_time::now());
@@ -713,6 +727,14 @@ impl PaymentCreate {
 
         let payment_link_id = payment_link_data.map(|pl_data| pl_data.payment_link_id);
 
+        let presentment_currency = fx_quote.map(|fx_quote| fx_quote.presentment_currency);
+        let presentment_amount = fx_quote.map(|fx_quote| fx_quote.presentment_amount);
+        let fx_quote = fx_quote
+            .map(Encode::<api_models::payments::FxQuote>::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the currency conversion quote")?;
+
         let request_incremental_authorization =
             core_utils::get_request_incremental_authorization_value(
                 request.request_incremental_authorization,
@@ -757,6 +779,9 @@ impl PaymentCreate {
             updated_by: merchant_account.storage_scheme.to_string(),
             request_incremental_authorization,
             incremental_authorization_allowed: None,
+            presentment_currency,
+            presentment_amount,
+            fx_quote,
         })
     }
 
@@ -806,8 +831,23 @@ async fn create_payment_link(
     amount: api::Amount,
     description: Option<String>,
 ) -> RouterResult<Option<api_models::payments::PaymentLinkResponse>> {
+    if payment_link_object
+        .tax_amount
+        .map_or(false, |tax_amount| tax_amount > i64::from(amount))
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "tax_amount cannot be greater than the amount of the payment link".to_string(),
+        })?
+    }
+
     let created_at @ last_modified_at = Some(common_utils::date_time::now());
-    let domain = if let Some(domain_name) = payment_link_object.merchant_custom_domain_name {
+    // The custom domain of the link takes precedence over the one of the merchant
+    let custom_domain = payment_link_object
+        .payment_link_config
+        .as_ref()
+        .and_then(|pl_config| pl_config.custom_domain.clone())
+        .or(payment_link_object.merchant_custom_domain_name);
+    let domain = if let Some(domain_name) = &custom_domain {
         format!("https://{domain_name}")
     } else {
         state.conf.server.base_url.clone()
@@ -838,6 +878,14 @@ async fn create_payment_link(
         description,
         payment_link_config,
         custom_merchant_name: payment_link_object.custom_merchant_name,
+        link_type: payment_link_object.link_type.unwrap_or_default(),
+        amount_type: payment_link_object.amount_type.unwrap_or_default(),
+        status: enums::PaymentLinkStatus::Active,
+        custom_domain,
+        locale: payment_link_object.locale,
+        success_url: payment_link_object.success_url,
+        failure_url: payment_link_object.failure_url,
+        tax_amount: payment_link_object.tax_amount,
     };
     let payment_link_db = db
         .insert_payment_link(payment_link_req)
@@ -5,7 +5,7 @@ use data_models::payments::payment_attempt::PaymentAttempt;
 use error_stack::ResultExt;
 use futures::FutureExt;
 use router_derive;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
@@ -14,7 +14,7 @@ use crate::{
     connector::utils::PaymentResponseRouterData,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        mandate,
+        mandate, payment_link,
         payment_methods::PaymentMethodRetrieve,
         payments::{helpers as payments_helpers, types::MultipleCaptureData, PaymentData},
         utils as core_utils,
@@ -573,8 +573,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
             payment_attempt_update = Some(storage::PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status: multiple_capture_data.get_attempt_status(authorized_amount),
-                amount_capturable: authorized_amount
-                    - multiple_capture_data.get_total_blocked_amount(),
+                amount_capturable: if multiple_capture_data.has_final_capture() {
+                    0
+                } else {
+                    authorized_amount - multiple_capture_data.get_total_blocked_amount()
+                },
                 updated_by: storage_scheme.to_string(),
             });
             Some(multiple_capture_data)
@@ -629,7 +632,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         router_data.amount_captured,
         router_data.status,
         &payment_data,
-    );
+    )?;
 
     let payment_intent_update = match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
@@ -693,6 +696,16 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     )?;
 
     payment_data.payment_intent = payment_intent;
+
+    if payment_data.payment_intent.status == enums::IntentStatus::Succeeded {
+        if let Some(payment_link_id) = &payment_data.payment_intent.payment_link_id {
+            payment_link::complete_payment_link(state, payment_link_id)
+                .await
+                .map_err(|error| logger::error!(payment_link_completion_error=?error))
+                .ok();
+        }
+    }
+
     Ok(payment_data)
 }
 
@@ -754,22 +767,45 @@ fn get_total_amount_captured<F: Clone, T: types::Capturable>(
     amount_captured: Option<i64>,
     router_data_status: enums::AttemptStatus,
     payment_data: &PaymentData<F>,
-) -> Option<i64> {
+) -> RouterResult<Option<i64>> {
     match &payment_data.multiple_capture_data {
         Some(multiple_capture_data) => {
             //multiple capture
-            Some(multiple_capture_data.get_total_blocked_amount())
-        }
-        None => {
-            //Non multiple capture
-            let amount = request.get_capture_amount(payment_data);
-            amount_captured.or_else(|| {
-                if router_data_status == enums::AttemptStatus::Charged {
-                    amount
-                } else {
-                    None
-                }
-            })
+            Ok(Some(multiple_capture_data.get_total_blocked_amount()))
         }
+        //Non multiple capture
+        None => match payments_helpers::get_fx_quote_of_payment(&payment_data.payment_intent)? {
+            // The connector request and response are in the currency of the quote, while the
+            // payment keeps its amounts in its own currency
+            Some(fx_quote) => {
+                let capture_amount = payment_data
+                    .payment_attempt
+                    .amount_to_capture
+                    .unwrap_or_else(|| payment_data.payment_attempt.get_total_amount());
+                let amount_captured = amount_captured
+                    .map(|amount| {
+                        payments_helpers::convert_from_presentment_amount(
+                            &fx_quote,
+                            amount,
+                            payment_data.currency,
+                            capture_amount,
+                        )
+                    })
+                    .transpose()?;
+                Ok(amount_captured.or_else(|| {
+                    (router_data_status == enums::AttemptStatus::Charged).then_some(capture_amount)
+                }))
+            }
+            None => {
+                let amount = request.get_capture_amount(payment_data);
+                Ok(amount_captured.or_else(|| {
+                    if router_data_status == enums::AttemptStatus::Charged {
+                        amount
+                    } else {
+                        None
+                    }
+                }))
+            }
+        },
     }
 }
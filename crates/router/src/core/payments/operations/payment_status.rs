@@ -16,6 +16,7 @@ use crate::{
             helpers, operations, types as payment_types, CustomerDetails, PaymentAddress,
             PaymentData,
         },
+        utils as core_utils,
     },
     db::StorageInterface,
     routes::AppState,
@@ -228,6 +229,11 @@ async fn get_tracker_for_sync<
         storage_scheme,
     )
     .await?;
+    core_utils::validate_profile_access(
+        merchant_account,
+        payment_intent.profile_id.as_ref(),
+        errors::ApiErrorResponse::PaymentNotFound,
+    )?;
 
     let intent_fulfillment_time = helpers::get_merchant_fullfillment_time(
         payment_intent.payment_link_id.clone(),
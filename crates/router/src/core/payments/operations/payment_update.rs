@@ -81,6 +81,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 storage_enums::IntentStatus::Failed,
                 storage_enums::IntentStatus::Succeeded,
                 storage_enums::IntentStatus::RequiresCapture,
+                storage_enums::IntentStatus::Expired,
             ],
             "update",
         )?;
@@ -148,6 +149,33 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        // The rate of a valid quote is kept, the quote is cleared when the customer pays in the
+        // currency of the payment
+        let presentment_currency = request
+            .presentment_currency
+            .or(payment_intent.presentment_currency);
+        let fx_quote = helpers::get_or_lock_fx_quote(
+            state,
+            &payment_intent,
+            amount.into(),
+            currency,
+            presentment_currency,
+        )
+        .await?;
+        payment_intent.presentment_currency = fx_quote
+            .as_ref()
+            .map(|fx_quote| fx_quote.presentment_currency);
+        payment_intent.presentment_amount = fx_quote
+            .as_ref()
+            .map(|fx_quote| fx_quote.presentment_amount);
+        payment_intent.fx_quote = fx_quote
+            .as_ref()
+            .map(Encode::<api_models::payments::FxQuote>::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the currency conversion quote")?;
+        payment_attempt.fx_quote = payment_intent.fx_quote.clone();
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -502,6 +530,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .surcharge_details
             .as_ref()
             .map(|surcharge_details| surcharge_details.tax_on_surcharge_amount);
+        let fx_quote = payment_data.payment_intent.fx_quote.clone();
         payment_data.payment_attempt = state
             .store
             .update_payment_attempt_with_attempt_id(
@@ -522,6 +551,7 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     surcharge_amount,
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
+                    fx_quote: fx_quote.clone(),
                 },
                 storage_scheme,
             )
@@ -563,6 +593,8 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .clone();
         let order_details = payment_data.payment_intent.order_details.clone();
         let metadata = payment_data.payment_intent.metadata.clone();
+        let presentment_currency = payment_data.payment_intent.presentment_currency;
+        let presentment_amount = payment_data.payment_intent.presentment_amount;
 
         payment_data.payment_intent = state
             .store
@@ -586,6 +618,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     metadata,
                     payment_confirm_source: None,
                     updated_by: storage_scheme.to_string(),
+                    presentment_currency,
+                    presentment_amount,
+                    fx_quote,
                 },
                 storage_scheme,
             )
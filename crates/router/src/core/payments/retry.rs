@@ -486,6 +486,7 @@ pub fn make_new_payment_attempt(
         mandate_id: old_payment_attempt.mandate_id,
         browser_info: old_payment_attempt.browser_info,
         payment_token: old_payment_attempt.payment_token,
+        fx_quote: old_payment_attempt.fx_quote,
 
         created_at,
         modified_at,
@@ -545,7 +546,8 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
                 | storage_enums::AttemptStatus::PaymentMethodAwaited
                 | storage_enums::AttemptStatus::ConfirmationAwaited
                 | storage_enums::AttemptStatus::Unresolved
-                | storage_enums::AttemptStatus::DeviceDataCollectionPending => false,
+                | storage_enums::AttemptStatus::DeviceDataCollectionPending
+                | storage_enums::AttemptStatus::Expired => false,
 
                 storage_enums::AttemptStatus::AuthenticationFailed
                 | storage_enums::AttemptStatus::AuthorizationFailed
@@ -84,10 +84,12 @@ where
         incremental_authorization_allowed: None,
     });
 
+    let connector_payment_data = get_connector_payment_data(&payment_data)?;
+    let amount_captured = connector_payment_data.payment_intent.amount_captured;
     let additional_data = PaymentAdditionalData {
         router_base_url: state.conf.server.base_url.clone(),
         connector_name: connector_id.to_string(),
-        payment_data: payment_data.clone(),
+        payment_data: connector_payment_data,
         state,
     };
 
@@ -142,7 +144,7 @@ where
         connector_meta_data: merchant_connector_account.get_metadata(),
         request: T::try_from(additional_data)?,
         response,
-        amount_captured: payment_data.payment_intent.amount_captured,
+        amount_captured,
         access_token: None,
         session_token: None,
         reference_id: None,
@@ -170,6 +172,67 @@ where
     Ok(router_data)
 }
 
+/// The connector charges the customer in the currency of the locked quote of the payment, if any.
+/// Only the copy of the payment data used to build the connector request is converted, the
+/// amounts of the payment are always stored in the currency of the payment.
+fn get_connector_payment_data<F: Clone>(
+    payment_data: &PaymentData<F>,
+) -> RouterResult<PaymentData<F>> {
+    let mut connector_payment_data = payment_data.clone();
+    if let Some(fx_quote) = helpers::get_fx_quote_of_payment(&payment_data.payment_intent)? {
+        let currency = payment_data.currency;
+        let convert = |amount| helpers::convert_to_presentment_amount(&fx_quote, amount, currency);
+
+        let amount = convert(payment_data.amount.into())?;
+        connector_payment_data.amount = amount.into();
+        connector_payment_data.payment_attempt.amount_to_capture = payment_data
+            .payment_attempt
+            .amount_to_capture
+            .map(convert)
+            .transpose()?;
+        connector_payment_data.payment_intent.amount_captured = payment_data
+            .payment_intent
+            .amount_captured
+            .map(convert)
+            .transpose()?;
+        connector_payment_data.surcharge_details = payment_data
+            .surcharge_details
+            .clone()
+            .map(|surcharge_details| {
+                let surcharge_amount = convert(surcharge_details.surcharge_amount)?;
+                let tax_on_surcharge_amount = convert(surcharge_details.tax_on_surcharge_amount)?;
+                let surcharge = match surcharge_details.surcharge {
+                    api_models::payment_methods::Surcharge::Fixed(value) => {
+                        api_models::payment_methods::Surcharge::Fixed(convert(value)?)
+                    }
+                    rate @ api_models::payment_methods::Surcharge::Rate(_) => rate,
+                };
+                // The parts are converted separately, so that they add up to the final amount
+                // charged by the connector
+                let final_amount = amount
+                    .checked_add(surcharge_amount)
+                    .and_then(|amount| amount.checked_add(tax_on_surcharge_amount))
+                    .ok_or(errors::ApiErrorResponse::InvalidDataValue {
+                        field_name: "amount",
+                    })
+                    .into_report()
+                    .attach_printable("Amount with surcharge overflows")?;
+                Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(
+                    api_models::payment_methods::SurchargeDetailsResponse {
+                        surcharge,
+                        surcharge_amount,
+                        tax_on_surcharge_amount,
+                        final_amount,
+                        ..surcharge_details
+                    },
+                )
+            })
+            .transpose()?;
+        connector_payment_data.currency = fx_quote.presentment_currency;
+    }
+    Ok(connector_payment_data)
+}
+
 pub trait ToResponse<Req, D, Op>
 where
     Self: Sized,
@@ -406,6 +469,8 @@ where
             .collect()
     });
 
+    let fx_quote = helpers::get_fx_quote_of_payment(&payment_intent)?;
+
     let merchant_id = payment_attempt.merchant_id.to_owned();
     let payment_method_type = payment_attempt
         .payment_method_type
@@ -692,6 +757,9 @@ where
                         .set_incremental_authorization_allowed(
                             payment_intent.incremental_authorization_allowed,
                         )
+                        .set_presentment_currency(payment_intent.presentment_currency)
+                        .set_presentment_amount(payment_intent.presentment_amount)
+                        .set_fx_quote(fx_quote)
                         .to_owned(),
                     headers,
                 ))
@@ -755,6 +823,9 @@ where
                 unified_code: payment_attempt.unified_code,
                 unified_message: payment_attempt.unified_message,
                 incremental_authorization_allowed: payment_intent.incremental_authorization_allowed,
+                presentment_currency: payment_intent.presentment_currency,
+                presentment_amount: payment_intent.presentment_amount,
+                fx_quote,
                 ..Default::default()
             },
             headers,
@@ -1138,13 +1209,14 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             payment_amount: payment_data.amount.into(),
             connector_meta: payment_data.payment_attempt.connector_metadata,
             multiple_capture_data: match payment_data.multiple_capture_data {
-                Some(multiple_capture_data) => Some(MultipleCaptureRequestData {
-                    capture_sequence: multiple_capture_data.get_captures_count()?,
-                    capture_reference: multiple_capture_data
-                        .get_latest_capture()
-                        .capture_id
-                        .clone(),
-                }),
+                Some(multiple_capture_data) => {
+                    let latest_capture = multiple_capture_data.get_latest_capture();
+                    Some(MultipleCaptureRequestData {
+                        capture_sequence: multiple_capture_data.get_captures_count()?,
+                        capture_reference: latest_capture.capture_id.clone(),
+                        final_capture: latest_capture.final_capture,
+                    })
+                }
                 None => None,
             },
             browser_info,
@@ -114,6 +114,12 @@ impl MultipleCaptureData {
         if authorized_amount == total_captured_amount {
             return storage_enums::AttemptStatus::Charged;
         }
+        if self.all_captures.values().any(|capture| {
+            capture.final_capture && capture.status == storage_enums::CaptureStatus::Charged
+        }) {
+            // the final capture went through, the uncaptured amount is released
+            return storage_enums::AttemptStatus::PartialCharged;
+        }
         let status_count_map = self.get_status_count();
         if status_count_map.get(&storage_enums::CaptureStatus::Charged) > Some(&0) {
             storage_enums::AttemptStatus::PartialChargedAndChargeable
@@ -121,6 +127,12 @@ impl MultipleCaptureData {
             storage_enums::AttemptStatus::CaptureInitiated
         }
     }
+    /// Whether a final capture has been made which has not failed, after which no more captures are allowed
+    pub fn has_final_capture(&self) -> bool {
+        self.all_captures.values().any(|capture| {
+            capture.final_capture && capture.status != storage_enums::CaptureStatus::Failed
+        })
+    }
     pub fn get_pending_captures(&self) -> Vec<&storage::Capture> {
         self.all_captures
             .iter()
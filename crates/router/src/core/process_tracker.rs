@@ -0,0 +1,387 @@
+use api_models::process_tracker as pt_types;
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::{
+    db::{process_tracker::ProcessTrackerInterface, queue::QueueInterface},
+    SchedulerSettings,
+};
+
+use crate::{
+    core::errors::{self, RouterResponse, StorageErrorExt},
+    routes::AppState,
+    services,
+    types::{storage, transformers::ForeignFrom},
+};
+
+const BUSINESS_STATUS_PENDING: &str = "Pending";
+const BUSINESS_STATUS_CANCELLED: &str = "CANCELLED_BY_ADMIN";
+
+const EVENT_RESCHEDULED: &str = "MANUALLY_RESCHEDULED";
+const EVENT_CANCELLED: &str = "MANUALLY_CANCELLED";
+const EVENT_FORCE_RUN: &str = "MANUALLY_FORCE_RUN";
+
+const LIST_LOWER_LIMIT: i64 = 1;
+const LIST_UPPER_LIMIT: i64 = 100;
+const LIST_DEFAULT_LIMIT: i64 = 10;
+
+/// Number of seconds after which a task that was picked up by the scheduler but has not been
+/// updated since is considered stuck, and can be force run
+const STUCK_PROCESS_THRESHOLD_IN_SECS: i64 = 60 * 30; // 30 minutes
+
+/// The manual operations on a task, each allowed from a subset of the task statuses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ManualOperation {
+    Reschedule,
+    ForceRun,
+    Cancel,
+}
+
+#[instrument(skip(state))]
+pub async fn list_processes(
+    state: AppState,
+    constraints: pt_types::ProcessTrackerListConstraints,
+) -> RouterResponse<pt_types::ProcessTrackerListResponse> {
+    let limit = validate_list_limit(constraints.limit)?;
+    if constraints.offset.map_or(false, |offset| offset < 0) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "offset should not be negative".to_string(),
+        }));
+    }
+
+    let db = state.store.as_ref();
+    let processes = db
+        .find_processes_by_filters(
+            constraints.runner,
+            constraints.status,
+            constraints.business_status,
+            Some(limit),
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list processes from process tracker")?;
+
+    let data = processes
+        .into_iter()
+        .map(pt_types::ProcessTrackerResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        pt_types::ProcessTrackerListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_process(
+    state: AppState,
+    process_id: &str,
+) -> RouterResponse<pt_types::ProcessTrackerResponse> {
+    let process = find_process(&state, process_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        pt_types::ProcessTrackerResponse::foreign_from(process),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn reschedule_process(
+    state: AppState,
+    process_id: &str,
+    request: pt_types::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<pt_types::ProcessTrackerResponse> {
+    let process = find_process(&state, process_id).await?;
+    validate_operation(
+        &process,
+        ManualOperation::Reschedule,
+        common_utils::date_time::now(),
+    )?;
+
+    let retry_count = if request.reset_retry_count {
+        0
+    } else {
+        process.retry_count
+    };
+    let update = storage::ProcessTrackerUpdate::ManualUpdate {
+        status: get_requeue_status(retry_count),
+        business_status: Some(BUSINESS_STATUS_PENDING.to_string()),
+        retry_count: Some(retry_count),
+        schedule_time: Some(request.schedule_time),
+        event: append_event(&process, EVENT_RESCHEDULED),
+    };
+
+    update_process(&state, process, update).await
+}
+
+#[instrument(skip(state))]
+pub async fn force_run_process(
+    state: AppState,
+    process_id: &str,
+) -> RouterResponse<pt_types::ProcessTrackerResponse> {
+    let process = find_process(&state, process_id).await?;
+    validate_operation(
+        &process,
+        ManualOperation::ForceRun,
+        common_utils::date_time::now(),
+    )?;
+
+    // The producer picks the task up on its next run, once it is due
+    let update = storage::ProcessTrackerUpdate::ManualUpdate {
+        status: get_requeue_status(process.retry_count),
+        business_status: Some(BUSINESS_STATUS_PENDING.to_string()),
+        retry_count: None,
+        schedule_time: Some(common_utils::date_time::now()),
+        event: append_event(&process, EVENT_FORCE_RUN),
+    };
+
+    update_process(&state, process, update).await
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_process(
+    state: AppState,
+    process_id: &str,
+) -> RouterResponse<pt_types::ProcessTrackerResponse> {
+    let process = find_process(&state, process_id).await?;
+    validate_operation(
+        &process,
+        ManualOperation::Cancel,
+        common_utils::date_time::now(),
+    )?;
+
+    let update = storage::ProcessTrackerUpdate::ManualUpdate {
+        status: storage_enums::ProcessTrackerStatus::Finish,
+        business_status: Some(BUSINESS_STATUS_CANCELLED.to_string()),
+        retry_count: None,
+        schedule_time: None,
+        event: append_event(&process, EVENT_CANCELLED),
+    };
+
+    update_process(&state, process, update).await
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_stream_stats(
+    state: AppState,
+) -> RouterResponse<pt_types::SchedulerStreamStatsResponse> {
+    let db = state.store.as_ref();
+    let scheduler_settings = state
+        .conf
+        .scheduler
+        .clone()
+        .unwrap_or_else(SchedulerSettings::default);
+
+    let mut streams = Vec::new();
+    for (stream, weight) in scheduler_settings.get_weighted_streams() {
+        let length = db
+            .stream_get_length(&stream)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to fetch the length of stream {stream}"))?;
+
+        let consumer_groups = db
+            .consumer_group_info(&stream)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed to fetch consumer groups of stream {stream}")
+            })?
+            .into_iter()
+            .map(|group| pt_types::ConsumerGroupStats {
+                name: group.name,
+                consumers: group.consumers,
+                pending: group.pending,
+                last_delivered_id: group.last_delivered_id,
+                lag: group.lag,
+            })
+            .collect();
+
+        streams.push(pt_types::StreamStats {
+            stream,
+            weight,
+            length,
+            consumer_groups,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        pt_types::SchedulerStreamStatsResponse { streams },
+    ))
+}
+
+async fn find_process(
+    state: &AppState,
+    process_id: &str,
+) -> errors::RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(process_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process from process tracker")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Process `{process_id}` does not exist in our records"),
+            })
+        })
+}
+
+async fn update_process(
+    state: &AppState,
+    process: storage::ProcessTracker,
+    update: storage::ProcessTrackerUpdate,
+) -> RouterResponse<pt_types::ProcessTrackerResponse> {
+    let process_id = process.id.clone();
+    state
+        .store
+        .update_process(process, update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Process `{process_id}` does not exist in our records"),
+        })
+        .map(|process| {
+            services::ApplicationResponse::Json(pt_types::ProcessTrackerResponse::foreign_from(
+                process,
+            ))
+        })
+}
+
+fn validate_list_limit(limit: Option<i64>) -> errors::RouterResult<i64> {
+    match limit {
+        Some(limit) if !(LIST_LOWER_LIMIT..=LIST_UPPER_LIMIT).contains(&limit) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between {LIST_LOWER_LIMIT} and {LIST_UPPER_LIMIT}"
+                ),
+            }))
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(LIST_DEFAULT_LIMIT),
+    }
+}
+
+/// Finished tasks cannot be brought back. Tasks that have been handed over to the consumer cannot
+/// be modified either, since the consumer works on the copy of the task that was appended to the
+/// stream, except for force running the tasks that are stuck there.
+fn validate_operation(
+    process: &storage::ProcessTracker,
+    operation: ManualOperation,
+    now: time::PrimitiveDateTime,
+) -> errors::RouterResult<()> {
+    let is_stuck = process.updated_at
+        <= now.saturating_sub(time::Duration::seconds(STUCK_PROCESS_THRESHOLD_IN_SECS));
+
+    let message = match (process.status, operation) {
+        (
+            storage_enums::ProcessTrackerStatus::New | storage_enums::ProcessTrackerStatus::Pending,
+            _,
+        ) => return Ok(()),
+        (
+            storage_enums::ProcessTrackerStatus::Processing
+            | storage_enums::ProcessTrackerStatus::ProcessStarted,
+            ManualOperation::ForceRun,
+        ) if is_stuck => return Ok(()),
+        (
+            storage_enums::ProcessTrackerStatus::Processing
+            | storage_enums::ProcessTrackerStatus::ProcessStarted,
+            _,
+        ) => format!(
+            "Process `{}` has already been picked up by the scheduler",
+            process.id
+        ),
+        (storage_enums::ProcessTrackerStatus::Finish, _) => {
+            format!("Process `{}` has already finished", process.id)
+        }
+    };
+
+    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+        message
+    }))
+}
+
+fn get_requeue_status(retry_count: i32) -> storage_enums::ProcessTrackerStatus {
+    if retry_count == 0 {
+        storage_enums::ProcessTrackerStatus::New
+    } else {
+        storage_enums::ProcessTrackerStatus::Pending
+    }
+}
+
+fn append_event(process: &storage::ProcessTracker, event: &str) -> Vec<String> {
+    let mut events = process.event.clone();
+    events.push(format!("{event}:{}", common_utils::date_time::now()));
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process_with_status(
+        status: storage_enums::ProcessTrackerStatus,
+        updated_at: time::PrimitiveDateTime,
+    ) -> storage::ProcessTracker {
+        storage::ProcessTracker {
+            id: "process_1".to_string(),
+            name: Some("PAYMENTS_SYNC".to_string()),
+            tag: vec![],
+            runner: Some("PAYMENTS_SYNC_WORKFLOW".to_string()),
+            retry_count: 0,
+            schedule_time: Some(updated_at),
+            rule: String::new(),
+            tracking_data: serde_json::json!({}),
+            business_status: BUSINESS_STATUS_PENDING.to_string(),
+            status,
+            event: vec![],
+            created_at: updated_at,
+            updated_at,
+        }
+    }
+
+    #[test]
+    fn test_validate_list_limit() {
+        assert_eq!(validate_list_limit(None).ok(), Some(LIST_DEFAULT_LIMIT));
+        assert_eq!(validate_list_limit(Some(100)).ok(), Some(100));
+        assert!(validate_list_limit(Some(0)).is_err());
+        assert!(validate_list_limit(Some(i64::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_validate_operation() {
+        let now = time::macros::datetime!(2023-12-01 12:00);
+        let recently = time::macros::datetime!(2023-12-01 11:55);
+        let long_ago = time::macros::datetime!(2023-12-01 10:00);
+        let operations = [
+            ManualOperation::Reschedule,
+            ManualOperation::ForceRun,
+            ManualOperation::Cancel,
+        ];
+
+        for operation in operations {
+            let pending =
+                process_with_status(storage_enums::ProcessTrackerStatus::Pending, recently);
+            assert!(validate_operation(&pending, operation, now).is_ok());
+
+            let finished =
+                process_with_status(storage_enums::ProcessTrackerStatus::Finish, long_ago);
+            assert!(validate_operation(&finished, operation, now).is_err());
+
+            let started = process_with_status(
+                storage_enums::ProcessTrackerStatus::ProcessStarted,
+                recently,
+            );
+            assert!(validate_operation(&started, operation, now).is_err());
+        }
+
+        let stuck = process_with_status(
+            storage_enums::ProcessTrackerStatus::ProcessStarted,
+            long_ago,
+        );
+        assert!(validate_operation(&stuck, ManualOperation::ForceRun, now).is_ok());
+        assert!(validate_operation(&stuck, ManualOperation::Reschedule, now).is_err());
+        assert!(validate_operation(&stuck, ManualOperation::Cancel, now).is_err());
+    }
+}
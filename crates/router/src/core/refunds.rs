@@ -1,5 +1,7 @@
 pub mod validator;
 
+use std::str::FromStr;
+
 use common_utils::ext_traits::AsyncExt;
 use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
@@ -48,6 +50,11 @@ pub async fn refund_create_core(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_access(
+        &merchant_account,
+        payment_intent.profile_id.as_ref(),
+        errors::ApiErrorResponse::PaymentNotFound,
+    )?;
 
     utils::when(
         !(payment_intent.status == enums::IntentStatus::Succeeded
@@ -63,9 +70,32 @@ pub async fn refund_create_core(
         },
     )?;
 
-    // Amount is not passed in request refer from payment intent.
+    payment_attempt = db
+        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+    let capture = match req.capture_id.as_deref() {
+        Some(capture_id) => Some(
+            find_capture_for_refund(
+                db,
+                &payment_attempt,
+                capture_id,
+                merchant_account.storage_scheme,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    // Amount is not passed in request refer from the capture being refunded, or the payment intent.
     amount = req
         .amount
+        .or(capture.as_ref().map(|capture| capture.amount))
         .or(payment_intent.amount_captured)
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .into_report()
@@ -80,15 +110,6 @@ pub async fn refund_create_core(
         .attach_printable("amount less than or equal to zero"))
     })?;
 
-    payment_attempt = db
-        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
-            &req.payment_id,
-            merchant_id,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
-
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -112,6 +133,7 @@ pub async fn refund_create_core(
         &key_store,
         &payment_attempt,
         &payment_intent,
+        capture.as_ref(),
         amount,
         req,
         creds_identifier,
@@ -120,6 +142,69 @@ pub async fn refund_create_core(
     .map(services::ApplicationResponse::Json)
 }
 
+/// Finds the capture of the payment attempt a refund is requested against, which must have been charged
+async fn find_capture_for_refund(
+    db: &dyn db::StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    capture_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::Capture> {
+    let capture = db
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            &payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while retrieving capture list")?
+        .into_iter()
+        .find(|capture| capture.capture_id == capture_id)
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("capture_id {capture_id} does not belong to this payment"),
+        })
+        .into_report()?;
+
+    utils::when(capture.status != enums::CaptureStatus::Charged, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "capture {capture_id} cannot be refunded as it is in {} status",
+                capture.status
+            ),
+        }))
+    })?;
+    validate_capture_refund(&capture)?;
+
+    Ok(capture)
+}
+
+/// Checks that the connector can refund the capture on its own, since refunding the payment as a
+/// whole instead could refund against another capture
+fn validate_capture_refund(capture: &storage::Capture) -> RouterResult<()> {
+    let connector = api_models::enums::Connector::from_str(&capture.connector)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("unable to parse connector name {:?}", capture.connector)
+        })?;
+    utils::when(!connector.supports_capture_refunds(), || {
+        Err(report!(errors::ApiErrorResponse::FlowNotSupported {
+            flow: "Refund against a capture".to_string(),
+            connector: capture.connector.clone(),
+        }))
+    })?;
+
+    utils::when(capture.connector_capture_id.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "capture {} cannot be refunded as the connector did not return an identifier for it",
+                capture.capture_id
+            ),
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn trigger_refund_to_gateway(
     state: &AppState,
@@ -333,6 +418,11 @@ pub async fn refund_retrieve_core(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    core_utils::validate_profile_access(
+        &merchant_account,
+        refund.profile_id.as_ref(),
+        errors::ApiErrorResponse::RefundNotFound,
+    )?;
 
     let payment_id = refund.payment_id.as_str();
     payment_intent = db
@@ -558,6 +648,7 @@ pub async fn validate_and_create_refund(
     key_store: &domain::MerchantKeyStore,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
+    capture: Option<&storage::Capture>,
     refund_amount: i64,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
@@ -613,6 +704,17 @@ pub async fn validate_and_create_refund(
     validator::validate_refund_amount(payment_attempt.amount, &all_refunds, refund_amount)
         .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
+    if let Some(capture) = capture {
+        let capture_refunds: Vec<storage::Refund> = all_refunds
+            .iter()
+            .filter(|refund| refund.capture_id.as_ref() == Some(&capture.capture_id))
+            .cloned()
+            .collect();
+        validator::validate_refund_amount(capture.amount, &capture_refunds, refund_amount)
+            .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)
+            .attach_printable("refund amount exceeds the amount left to refund on the capture")?;
+    }
+
     validator::validate_maximum_refund_against_payment_attempt(
         &all_refunds,
         state.conf.refund.max_attempts,
@@ -646,6 +748,7 @@ pub async fn validate_and_create_refund(
         .set_attempt_id(payment_attempt.attempt_id.clone())
         .set_refund_reason(req.reason)
         .set_profile_id(payment_intent.profile_id.clone())
+        .set_capture_id(capture.map(|capture| capture.capture_id.clone()))
         .to_owned();
 
     let refund = match db
@@ -695,11 +798,14 @@ pub async fn validate_and_create_refund(
 pub async fn refund_list(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    req: api_models::refunds::RefundListRequest,
+    mut req: api_models::refunds::RefundListRequest,
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let db = state.store;
     let limit = validator::validate_refund_list(req.limit)?;
     let offset = req.offset.unwrap_or_default();
+    // Requests made with an API key bound to a business profile only list the refunds of that
+    // profile
+    req.profile_id = core_utils::get_profile_id_for_list(&merchant_account, req.profile_id)?;
 
     let refund_list = db
         .filter_refund_by_constraints(
@@ -772,6 +878,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             created_at: Some(refund.created_at),
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
+            capture_id: refund.capture_id,
         }
     }
 }
@@ -1169,3 +1276,53 @@ pub async fn retry_refund_sync_task(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_capture(connector: &str, connector_capture_id: Option<&str>) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            capture_id: "capture".to_string(),
+            payment_id: "payment".to_string(),
+            merchant_id: "merchant".to_string(),
+            status: enums::CaptureStatus::Charged,
+            amount: 100,
+            currency: Some(enums::Currency::USD),
+            connector: connector.to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "attempt".to_string(),
+            connector_capture_id: connector_capture_id.map(str::to_string),
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+            final_capture: false,
+        }
+    }
+
+    #[test]
+    fn test_capture_refund_requires_connector_capture_id() {
+        assert!(validate_capture_refund(&get_capture("checkout", Some("act_123"))).is_ok());
+
+        let error = validate_capture_refund(&get_capture("checkout", None)).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[test]
+    fn test_capture_refund_requires_connector_support() {
+        let error = validate_capture_refund(&get_capture("stripe", Some("ch_123"))).unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::FlowNotSupported { .. }
+        ));
+    }
+}
@@ -1,9 +1,12 @@
 pub mod helpers;
 pub mod transformers;
 
-use api_models::routing::{self as routing_types, RoutingAlgorithmId};
 #[cfg(feature = "business_profile_routing")]
 use api_models::routing::{RoutingRetrieveLinkQuery, RoutingRetrieveQuery};
+use api_models::{
+    enums::{AuditAction, AuditResourceType},
+    routing::{self as routing_types, RoutingAlgorithmId},
+};
 #[cfg(not(feature = "business_profile_routing"))]
 use common_utils::ext_traits::{Encode, StringExt};
 #[cfg(not(feature = "business_profile_routing"))]
@@ -18,6 +21,7 @@ use crate::types::transformers::{ForeignInto, ForeignTryInto};
 use crate::{
     consts,
     core::{
+        audit_log,
         errors::{RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
@@ -276,9 +280,25 @@ pub async fn link_routing_config(
             },
         )?;
 
+        let routing_ref_before = routing_ref.clone();
         routing_ref.update_algorithm_id(algorithm_id);
-        helpers::update_business_profile_active_algorithm_ref(db, business_profile, routing_ref)
-            .await?;
+        helpers::update_business_profile_active_algorithm_ref(
+            db,
+            business_profile,
+            routing_ref.clone(),
+        )
+        .await?;
+
+        audit_log::record(
+            &state,
+            Some(&merchant_account.merchant_id),
+            AuditAction::Activate,
+            AuditResourceType::RoutingAlgorithm,
+            &routing_algorithm.algorithm_id,
+            Some(&routing_ref_before),
+            Some(&routing_ref),
+        )
+        .await;
 
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(
@@ -321,6 +341,7 @@ pub async fn link_routing_config(
         record.modified_at = modified_at;
         merchant_dictionary.active_id = Some(record.id.clone());
         let response = record.clone();
+        let routing_ref_before = routing_ref.clone();
         routing_ref.update_algorithm_id(algorithm_id);
         helpers::update_merchant_routing_dictionary(
             db,
@@ -328,7 +349,18 @@ pub async fn link_routing_config(
             merchant_dictionary,
         )
         .await?;
-        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
+        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref.clone()).await?;
+
+        audit_log::record(
+            &state,
+            Some(&merchant_account.merchant_id),
+            AuditAction::Activate,
+            AuditResourceType::RoutingAlgorithm,
+            &response.id,
+            Some(&routing_ref_before),
+            Some(&routing_ref),
+        )
+        .await;
 
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
@@ -446,14 +478,16 @@ pub async fn unlink_routing_config(
 
                 let timestamp = common_utils::date_time::now_unix_timestamp();
 
-                match routing_algo_ref.algorithm_id {
+                match routing_algo_ref.algorithm_id.clone() {
                     Some(algorithm_id) => {
                         let routing_algorithm: routing_types::RoutingAlgorithmRef =
                             routing_types::RoutingAlgorithmRef {
                                 algorithm_id: None,
                                 timestamp,
                                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                                surcharge_config_algo_id: routing_algo_ref
+                                    .surcharge_config_algo_id
+                                    .clone(),
                             };
 
                         let record = db
@@ -467,10 +501,21 @@ pub async fn unlink_routing_config(
                         helpers::update_business_profile_active_algorithm_ref(
                             db,
                             business_profile,
-                            routing_algorithm,
+                            routing_algorithm.clone(),
                         )
                         .await?;
 
+                        audit_log::record(
+                            &state,
+                            Some(&merchant_account.merchant_id),
+                            AuditAction::Deactivate,
+                            AuditResourceType::RoutingAlgorithm,
+                            &algorithm_id,
+                            Some(&routing_algo_ref),
+                            Some(&routing_algorithm),
+                        )
+                        .await;
+
                         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
                             &metrics::CONTEXT,
                             1,
@@ -517,7 +562,7 @@ pub async fn unlink_routing_config(
                 algorithm_id: None,
                 timestamp,
                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id.clone(),
             };
 
         let active_algorithm_id = merchant_dictionary
@@ -584,6 +629,17 @@ pub async fn unlink_routing_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
 
+        audit_log::record(
+            &state,
+            Some(&merchant_account.merchant_id),
+            AuditAction::Deactivate,
+            AuditResourceType::RoutingAlgorithm,
+            &response.id,
+            Some(&routing_algo_ref),
+            Some(&routing_algorithm),
+        )
+        .await;
+
         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
     }
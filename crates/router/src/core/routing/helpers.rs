@@ -255,6 +255,9 @@ pub async fn update_business_profile_active_algorithm_ref(
         applepay_verified_domains: None,
         modified_at: None,
         is_recon_enabled: None,
+        delayed_capture_hours: None,
+        email_settings: None,
+        dispute_settings: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -1,10 +1,18 @@
+use std::collections::HashMap;
+
 use api_models::user as user_api;
+#[cfg(feature = "email")]
+use diesel_models::user_role::{UserRoleNew, UserRoleUpdate};
 use diesel_models::{enums::UserStatus, user as storage_user};
 use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, Secret};
 use router_env::env;
+#[cfg(feature = "email")]
+use router_env::logger;
 
-use super::errors::{UserErrors, UserResponse};
+use super::errors::{UserErrors, UserResponse, UserResult};
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
 use crate::{
     consts,
     db::user::UserInterface,
@@ -17,6 +25,8 @@ use crate::{
 pub mod sample_data;
 
 pub mod dashboard_metadata;
+pub mod sso;
+pub mod two_factor_auth;
 
 pub async fn connect_account(
     state: AppState,
@@ -33,21 +43,34 @@ pub async fn connect_account(
         user_from_db.compare_password(request.password)?;
 
         let user_role = user_from_db.get_role_from_db(state.clone()).await?;
-        let jwt_token = user_from_db
-            .get_jwt_auth_token(state.clone(), user_role.org_id)
+        sso::validate_password_sign_in(&state, &user_role.org_id).await?;
+        if let Some(totp_challenge) =
+            two_factor_auth::get_totp_challenge(&state, &user_from_db, &user_role.org_id).await?
+        {
+            let token = auth::SinglePurposeToken::new_token(
+                user_from_db.get_user_id().to_string(),
+                auth::TokenPurpose::Totp,
+                &state.conf,
+            )
             .await?;
 
-        return Ok(ApplicationResponse::Json(
-            user_api::ConnectAccountResponse {
-                token: Secret::new(jwt_token),
-                merchant_id: user_role.merchant_id,
-                name: user_from_db.get_name(),
-                email: user_from_db.get_email(),
-                verification_days_left: None,
-                user_role: user_role.role_id,
-                user_id: user_from_db.get_user_id().to_string(),
-            },
-        ));
+            return Ok(ApplicationResponse::Json(
+                user_api::ConnectAccountResponse {
+                    token: Secret::new(token),
+                    merchant_id: user_role.merchant_id,
+                    name: user_from_db.get_name(),
+                    email: user_from_db.get_email(),
+                    verification_days_left: None,
+                    user_role: user_role.role_id,
+                    totp_challenge: Some(totp_challenge),
+                    user_id: user_from_db.get_user_id().to_string(),
+                },
+            ));
+        }
+
+        return get_signin_response(&state, &user_from_db)
+            .await
+            .map(ApplicationResponse::Json);
     } else if find_user
         .map_err(|e| e.current_context().is_db_not_found())
         .err()
@@ -79,10 +102,6 @@ pub async fn connect_account(
 
         #[cfg(feature = "email")]
         {
-            use router_env::logger;
-
-            use crate::services::email::types as email_types;
-
             let email_contents = email_types::VerifyEmail {
                 recipient_email: domain::UserEmail::from_pii_email(user_from_db.get_email())?,
                 settings: state.conf.clone(),
@@ -108,6 +127,7 @@ pub async fn connect_account(
                 email: user_from_db.get_email(),
                 verification_days_left: None,
                 user_role: user_role.role_id,
+                totp_challenge: None,
                 user_id: user_from_db.get_user_id().to_string(),
             },
         ));
@@ -116,6 +136,30 @@ pub async fn connect_account(
     }
 }
 
+/// Issues the dashboard token of a user who has completed all the sign in steps
+async fn get_signin_response(
+    state: &AppState,
+    user_from_db: &domain::UserFromStorage,
+) -> UserResult<user_api::ConnectAccountResponse> {
+    let user_role = user_from_db.get_role_from_db(state.clone()).await?;
+    let jwt_token = user_from_db
+        .get_jwt_auth_token(state.clone(), user_role.org_id)
+        .await?;
+
+    Ok(user_api::ConnectAccountResponse {
+        token: Secret::new(jwt_token),
+        merchant_id: user_role.merchant_id,
+        name: user_from_db.get_name(),
+        email: user_from_db.get_email(),
+        verification_days_left: None,
+        user_role: user_role.role_id,
+        totp_challenge: None,
+        user_id: user_from_db.get_user_id().to_string(),
+    })
+}
+
+/// Changes the password of the user and revokes the tokens issued to them, including the token
+/// used for the request
 pub async fn change_password(
     state: AppState,
     request: user_api::ChangePasswordRequest,
@@ -145,9 +189,289 @@ pub async fn change_password(
     .await
     .change_context(UserErrors::InternalServerError)?;
 
+    auth::blacklist::insert_user_in_blacklist(&state, user.get_user_id()).await?;
+
     Ok(ApplicationResponse::StatusOk)
 }
 
+/// Invites a user to the merchant. Users without an account are created with a temporary
+/// password and sent an invitation email, while existing users are added to the merchant directly.
+#[cfg(feature = "email")]
+pub async fn invite_user(
+    state: AppState,
+    request: user_api::InviteUserRequest,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_api::InviteUserResponse> {
+    let inviter_user = state
+        .store
+        .find_user_by_id(&user_from_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if inviter_user.email == request.email {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User Inviting themself");
+    }
+
+    utils::user_role::validate_role_id(
+        &state,
+        &request.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await?;
+    utils::user_role::validate_role_grantable(&state, &user_from_token, &request.role_id).await?;
+
+    let invitee_email = domain::UserEmail::from_pii_email(request.email)?;
+    let invitee_user = state
+        .store
+        .find_user_by_email(invitee_email.clone().get_secret().expose().as_str())
+        .await;
+
+    let (invitee_user_id, user_status, invitee_name) = match invitee_user {
+        Ok(invitee_user) => (invitee_user.user_id, UserStatus::Active, None),
+        Err(e) if e.current_context().is_db_not_found() => {
+            let invitee_name = domain::UserName::new(request.name)?;
+            let hashed_password = utils::user::password::generate_password_hash(
+                utils::user::password::get_temp_password(),
+            )?;
+
+            let invitee_user = state
+                .store
+                .insert_user(storage_user::UserNew {
+                    user_id: uuid::Uuid::new_v4().to_string(),
+                    email: invitee_email.clone().into_inner(),
+                    name: invitee_name.clone().get_secret(),
+                    password: hashed_password,
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| {
+                    if e.current_context().is_db_unique_violation() {
+                        return e.change_context(UserErrors::UserExists);
+                    }
+                    e.change_context(UserErrors::InternalServerError)
+                })?;
+
+            (
+                invitee_user.user_id,
+                UserStatus::InvitationSent,
+                Some(invitee_name),
+            )
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_user_role(UserRoleNew {
+            user_id: invitee_user_id.clone(),
+            merchant_id: user_from_token.merchant_id,
+            role_id: request.role_id,
+            org_id: user_from_token.org_id,
+            status: user_status,
+            created_by: user_from_token.user_id.clone(),
+            last_modified_by: user_from_token.user_id,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                return e.change_context(UserErrors::UserExists);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    let is_email_sent = match invitee_name {
+        Some(user_name) => {
+            let email_contents = email_types::InviteUser {
+                recipient_email: invitee_email,
+                user_name,
+                settings: state.conf.clone(),
+                subject: "You have been invited to join Hyperswitch Community!",
+            };
+            let send_email_result = state
+                .email_client
+                .compose_and_send_email(
+                    Box::new(email_contents),
+                    state.conf.proxy.https_url.as_ref(),
+                )
+                .await;
+            logger::info!(?send_email_result);
+            send_email_result.is_ok()
+        }
+        None => false,
+    };
+
+    Ok(ApplicationResponse::Json(user_api::InviteUserResponse {
+        user_id: invitee_user_id,
+        is_email_sent,
+    }))
+}
+
+/// Sets the password of an invited user and activates their invitation. The invitation link can
+/// only be used once.
+#[cfg(feature = "email")]
+pub async fn accept_invite_from_email(
+    state: AppState,
+    request: user_api::AcceptInviteRequest,
+) -> UserResponse<()> {
+    let token = request.token.expose();
+    let user =
+        get_user_from_email_token(&state, &token, email_types::EmailTokenPurpose::AcceptInvite)
+            .await?;
+    let password = domain::UserPassword::new(request.password)?;
+
+    let user_role = state
+        .store
+        .list_user_roles_by_user_id(user.get_user_id())
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .find(|user_role| user_role.status == UserStatus::InvitationSent)
+        .ok_or(UserErrors::LinkInvalid)
+        .into_report()
+        .attach_printable("No pending invitation for the user")?;
+
+    state
+        .store
+        .update_user_by_user_id(
+            user.get_user_id(),
+            storage_user::UserUpdate::AccountUpdate {
+                name: None,
+                password: Some(utils::user::password::generate_password_hash(
+                    password.get_secret(),
+                )?),
+                is_verified: Some(true),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    state
+        .store
+        .update_user_role_by_user_id_merchant_id(
+            user.get_user_id(),
+            &user_role.merchant_id,
+            UserRoleUpdate::UpdateStatus {
+                status: UserStatus::Active,
+                modified_by: user.get_user_id().to_string(),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    auth::blacklist::insert_email_token_in_blacklist(&state, &token).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Sends a password reset link to the user. The response does not reveal whether an account
+/// exists with the email.
+#[cfg(feature = "email")]
+pub async fn forgot_password(
+    state: AppState,
+    request: user_api::ForgotPasswordRequest,
+) -> UserResponse<()> {
+    let user_email = domain::UserEmail::from_pii_email(request.email)?;
+
+    let user_from_db = match state
+        .store
+        .find_user_by_email(user_email.clone().get_secret().expose().as_str())
+        .await
+    {
+        Ok(user) => domain::UserFromStorage::from(user),
+        Err(e) if e.current_context().is_db_not_found() => {
+            logger::info!("Password reset requested for an email without an account");
+            return Ok(ApplicationResponse::StatusOk);
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    let email_contents = email_types::ResetPassword {
+        recipient_email: user_email,
+        user_name: domain::UserName::new(user_from_db.get_name())?,
+        settings: state.conf.clone(),
+        subject: "Get back to Hyperswitch - Reset Your Password Now",
+    };
+
+    state
+        .email_client
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Sets a new password using the link sent by `forgot_password`. The link can only be used once,
+/// and the tokens issued to the user before the reset are revoked.
+#[cfg(feature = "email")]
+pub async fn reset_password(
+    state: AppState,
+    request: user_api::ResetPasswordRequest,
+) -> UserResponse<()> {
+    let token = request.token.expose();
+    let user = get_user_from_email_token(
+        &state,
+        &token,
+        email_types::EmailTokenPurpose::ResetPassword,
+    )
+    .await?;
+    let password = domain::UserPassword::new(request.password)?;
+
+    state
+        .store
+        .update_user_by_user_id(
+            user.get_user_id(),
+            storage_user::UserUpdate::AccountUpdate {
+                name: None,
+                password: Some(utils::user::password::generate_password_hash(
+                    password.get_secret(),
+                )?),
+                is_verified: Some(true),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    auth::blacklist::insert_email_token_in_blacklist(&state, &token).await?;
+    auth::blacklist::insert_user_in_blacklist(&state, user.get_user_id()).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Validates the signature, expiry, purpose and single use of an email token and returns its user
+#[cfg(feature = "email")]
+async fn get_user_from_email_token(
+    state: &AppState,
+    token: &str,
+    purpose: email_types::EmailTokenPurpose,
+) -> UserResult<domain::UserFromStorage> {
+    let email_token = auth::decode_jwt::<email_types::EmailToken>(token, state)
+        .await
+        .change_context(UserErrors::LinkInvalid)?;
+    email_token.validate_purpose(purpose)?;
+    auth::blacklist::check_email_token_in_blacklist(state, token).await?;
+
+    state
+        .store
+        .find_user_by_email(email_token.get_email()?.expose().expose().as_str())
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::LinkInvalid);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })
+        .map(Into::into)
+}
+
 pub async fn create_internal_user(
     state: AppState,
     request: user_api::CreateInternalUserRequest,
@@ -289,6 +613,7 @@ pub async fn switch_merchant_id(
             user_id: user.get_user_id().to_string(),
             verification_days_left: None,
             user_role: user_role.role_id,
+            totp_challenge: None,
         },
     ))
 }
@@ -338,6 +663,19 @@ pub async fn get_users_for_merchant_account(
     state: AppState,
     user_from_token: auth::UserFromToken,
 ) -> UserResponse<user_api::GetUsersResponse> {
+    let custom_role_names: HashMap<String, String> = state
+        .store
+        .list_all_roles(
+            user_from_token.merchant_id.as_str(),
+            user_from_token.org_id.as_str(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to list the custom roles of the merchant")?
+        .into_iter()
+        .map(|role| (role.role_id, role.role_name))
+        .collect();
+
     let users = state
         .store
         .find_users_and_roles_by_merchant_id(user_from_token.merchant_id.as_str())
@@ -345,7 +683,12 @@ pub async fn get_users_for_merchant_account(
         .change_context(UserErrors::InternalServerError)
         .attach_printable("No users for given merchant id")?
         .into_iter()
-        .filter_map(|(user, role)| domain::UserAndRoleJoined(user, role).try_into().ok())
+        .filter_map(|(user, role)| {
+            let custom_role_name = custom_role_names.get(&role.role_id).cloned();
+            domain::UserAndRoleJoined(user, role, custom_role_name)
+                .try_into()
+                .ok()
+        })
         .collect();
 
     Ok(ApplicationResponse::Json(user_api::GetUsersResponse(users)))
@@ -0,0 +1,310 @@
+use api_models::user as user_api;
+use common_utils::crypto;
+use diesel_models::{
+    enums::UserStatus, organization::OrganizationUpdate, user as storage_user,
+    user_role::UserRoleNew,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+
+use super::get_signin_response;
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResponse, UserResult},
+    routes::AppState,
+    services::{authentication as auth, ApplicationResponse},
+    types::domain,
+    utils::{
+        self,
+        user::{password, sso as sso_utils},
+    },
+};
+
+/// Configures the OpenID Connect provider of the organization. The provider is discovered before
+/// the configuration is stored, so that a wrong issuer is rejected right away.
+pub async fn update_sso_config(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_api::UpdateSsoConfigRequest,
+) -> UserResponse<()> {
+    if user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Only organization admins can configure SSO");
+    }
+
+    let oidc_config = req.oidc_config;
+    let org_id = user_from_token.org_id;
+    validate_sso_merchant(&state, &oidc_config.merchant_id, &org_id).await?;
+    for role_id in
+        std::iter::once(&oidc_config.default_role_id).chain(oidc_config.role_mapping.values())
+    {
+        utils::user_role::validate_role_id(&state, role_id, &oidc_config.merchant_id, &org_id)
+            .await?;
+    }
+
+    let provider_metadata = sso_utils::get_provider_metadata(&state, &oidc_config.issuer).await?;
+    if provider_metadata.issuer.trim_end_matches('/') != oidc_config.issuer.trim_end_matches('/') {
+        return Err(UserErrors::SsoAuthenticationFailed.into())
+            .attach_printable("Issuer of the discovery document does not match the configuration");
+    }
+
+    let sso_config = serde_json::to_value(&oidc_config)
+        .into_report()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to serialize the SSO config")?;
+    let sso_client_secret = sso_utils::encrypt_client_secret(&state, req.client_secret).await?;
+
+    state
+        .store
+        .update_organization_by_org_id(
+            &org_id,
+            OrganizationUpdate::SsoConfigUpdate {
+                sso_config,
+                sso_client_secret,
+                enforce_sso: req.enforce_sso,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Starts the authorization code flow with PKCE, returning the URL of the provider the user signs
+/// in at
+pub async fn sso_authorize(
+    state: AppState,
+    req: user_api::SsoAuthorizeRequest,
+) -> UserResponse<user_api::SsoAuthorizeResponse> {
+    let (oidc_config, _) = get_sso_config(&state, &req.org_id).await?;
+    let provider_metadata = sso_utils::get_provider_metadata(&state, &oidc_config.issuer).await?;
+
+    let (code_verifier, code_challenge) = sso_utils::generate_pkce_pair()?;
+    let nonce =
+        crypto::generate_cryptographically_secure_random_string(consts::user::SSO_STATE_LENGTH);
+    let sso_state = sso_utils::store_sso_state(
+        state.store.as_ref(),
+        &sso_utils::SsoState {
+            org_id: req.org_id,
+            nonce: nonce.clone(),
+            code_verifier,
+        },
+    )
+    .await?;
+
+    let authorization_url = sso_utils::get_authorization_url(
+        &provider_metadata,
+        &oidc_config,
+        &sso_state,
+        &nonce,
+        &code_challenge,
+    )?;
+
+    Ok(ApplicationResponse::Json(user_api::SsoAuthorizeResponse {
+        authorization_url,
+    }))
+}
+
+/// Completes the authorization code flow and signs the user in. Users signing in for the first
+/// time are provisioned in the merchant of the SSO config, with the role mapped from their claims.
+/// The second factor is left to the provider, so SSO sign ins skip the TOTP challenge.
+pub async fn sso_callback(
+    state: AppState,
+    req: user_api::SsoCallbackRequest,
+) -> UserResponse<user_api::ConnectAccountResponse> {
+    let sso_state = sso_utils::consume_sso_state(state.store.as_ref(), &req.state).await?;
+    let (oidc_config, client_secret) = get_sso_config(&state, &sso_state.org_id).await?;
+    let provider_metadata = sso_utils::get_provider_metadata(&state, &oidc_config.issuer).await?;
+
+    let id_token = sso_utils::exchange_authorization_code(
+        &state,
+        &provider_metadata,
+        &oidc_config,
+        client_secret.clone(),
+        req.code,
+        sso_state.code_verifier,
+    )
+    .await?;
+    let json_web_key_set = match provider_metadata.jwks_uri.as_deref() {
+        Some(jwks_uri) => Some(sso_utils::get_json_web_key_set(&state, jwks_uri).await?),
+        None => None,
+    };
+    let claims = sso_utils::validate_id_token(
+        &id_token,
+        &provider_metadata.issuer,
+        &oidc_config,
+        &client_secret,
+        json_web_key_set.as_ref(),
+        &sso_state.nonce,
+    )?;
+
+    let email = domain::UserEmail::new(Secret::new(
+        claims
+            .email
+            .clone()
+            .ok_or(UserErrors::SsoAuthenticationFailed)
+            .into_report()
+            .attach_printable("ID token does not contain the email of the user")?,
+    ))?;
+
+    let user = match state.store.find_user_by_email(email.peek().as_str()).await {
+        Ok(user) => {
+            let user: domain::UserFromStorage = user.into();
+            let user_role = user.get_role_from_db(state.clone()).await?;
+            if user_role.org_id != sso_state.org_id {
+                return Err(UserErrors::SsoAuthenticationFailed.into())
+                    .attach_printable("User belongs to another organization");
+            }
+            user
+        }
+        Err(e) if e.current_context().is_db_not_found() => {
+            provision_user(&state, &oidc_config, &sso_state.org_id, email, &claims).await?
+        }
+        Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+    };
+
+    get_signin_response(&state, &user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Rejects the password sign in of users whose organization enforces SSO
+pub async fn validate_password_sign_in(state: &AppState, org_id: &str) -> UserResult<()> {
+    let organization = state
+        .store
+        .find_organization_by_org_id(org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if organization.enforce_sso {
+        return Err(UserErrors::SsoEnforced.into());
+    }
+
+    Ok(())
+}
+
+async fn provision_user(
+    state: &AppState,
+    oidc_config: &user_api::OidcConfig,
+    org_id: &str,
+    email: domain::UserEmail,
+    claims: &sso_utils::IdTokenClaims,
+) -> UserResult<domain::UserFromStorage> {
+    let name = match claims.name.clone() {
+        Some(name) => domain::UserName::new(Secret::new(name))
+            .or_else(|_| domain::UserName::try_from(email.clone().into_inner()))?,
+        None => domain::UserName::try_from(email.clone().into_inner())?,
+    };
+    let hashed_password = password::generate_password_hash(password::get_temp_password())?;
+    let user_id = uuid::Uuid::new_v4().to_string();
+
+    let user = state
+        .store
+        .insert_user(storage_user::UserNew {
+            user_id: user_id.clone(),
+            email: email.into_inner(),
+            name: name.get_secret(),
+            password: hashed_password,
+            is_verified: true,
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_unique_violation() {
+                return e.change_context(UserErrors::UserExists);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .insert_user_role(UserRoleNew {
+            user_id: user_id.clone(),
+            merchant_id: oidc_config.merchant_id.clone(),
+            role_id: sso_utils::get_role_id_from_claims(oidc_config, claims),
+            org_id: org_id.to_string(),
+            status: UserStatus::Active,
+            created_by: user_id.clone(),
+            last_modified_by: user_id,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(user.into())
+}
+
+/// Returns the OpenID Connect config of the organization along with its client secret
+async fn get_sso_config(
+    state: &AppState,
+    org_id: &str,
+) -> UserResult<(user_api::OidcConfig, Secret<String>)> {
+    let organization = state
+        .store
+        .find_organization_by_org_id(org_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::SsoNotConfigured);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    let oidc_config: user_api::OidcConfig = serde_json::from_value(
+        organization
+            .sso_config
+            .ok_or(UserErrors::SsoNotConfigured)
+            .into_report()?,
+    )
+    .into_report()
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to parse the SSO config")?;
+
+    let client_secret =
+        sso_utils::decrypt_client_secret(state, organization.sso_client_secret).await?;
+
+    Ok((oidc_config, client_secret))
+}
+
+/// Checks that the merchant users are provisioned in belongs to the organization
+async fn validate_sso_merchant(
+    state: &AppState,
+    merchant_id: &str,
+    org_id: &str,
+) -> UserResult<()> {
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::MerchantIdNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::MerchantIdNotFound)
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    if merchant_account.organization_id != org_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Merchant does not belong to the organization");
+    }
+
+    Ok(())
+}
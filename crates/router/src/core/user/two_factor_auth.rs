@@ -0,0 +1,386 @@
+use api_models::user as user_api;
+use diesel_models::{enums::TotpStatus, organization::OrganizationUpdate, user as storage_user};
+use error_stack::ResultExt;
+use masking::Secret;
+use totp_rs::TOTP;
+
+use super::get_signin_response;
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResponse, UserResult},
+    routes::AppState,
+    services::{authentication as auth, ApplicationResponse},
+    types::domain,
+    utils::user::two_factor_auth as tfa_utils,
+};
+
+/// Returns the TOTP step the user has to complete before being issued the dashboard token
+pub async fn get_totp_challenge(
+    state: &AppState,
+    user: &domain::UserFromStorage,
+    org_id: &str,
+) -> UserResult<Option<user_api::TotpChallenge>> {
+    if user.get_totp_status() == TotpStatus::Set {
+        return Ok(Some(user_api::TotpChallenge::Verify));
+    }
+
+    let organization = state
+        .store
+        .find_organization_by_org_id(org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(organization
+        .enforce_totp
+        .then_some(user_api::TotpChallenge::Enroll))
+}
+
+pub async fn begin_totp(
+    state: AppState,
+    user_from_token: auth::UserIdFromToken,
+) -> UserResponse<user_api::BeginTotpResponse> {
+    let user = find_user(&state, &user_from_token.user_id).await?;
+    if user.get_totp_status() == TotpStatus::Set {
+        return Err(UserErrors::TotpAlreadySetup.into());
+    }
+
+    let secret = tfa_utils::generate_totp_secret();
+    let totp = tfa_utils::generate_totp(secret.clone(), user.get_email())?;
+    let (recovery_codes, hashed_recovery_codes) = tfa_utils::generate_recovery_codes()?;
+
+    state
+        .store
+        .update_user_by_user_id(
+            user.get_user_id(),
+            storage_user::UserUpdate::TotpUpdate {
+                totp_status: Some(TotpStatus::InProgress),
+                totp_secret: Some(tfa_utils::encrypt_totp_secret(&state, secret).await?),
+                totp_recovery_codes: Some(hashed_recovery_codes),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(user_api::BeginTotpResponse {
+        secret: Secret::new(totp.get_secret_base32()),
+        provisioning_uri: Secret::new(totp.get_url()),
+        recovery_codes,
+    }))
+}
+
+/// Verifies the TOTP or a recovery code and issues the dashboard token. Failed attempts are
+/// counted per user, who is locked out for a while after too many of them. Once verified, the
+/// single purpose tokens issued to the user for the sign in are revoked.
+pub async fn verify_totp(
+    state: AppState,
+    user_from_token: auth::UserIdFromToken,
+    req: user_api::VerifyTotpRequest,
+) -> UserResponse<user_api::ConnectAccountResponse> {
+    let user = find_user(&state, &user_from_token.user_id).await?;
+    if user.get_totp_status() == TotpStatus::NotSet {
+        return Err(UserErrors::TotpNotSetup.into());
+    }
+
+    tfa_utils::consume_totp_attempt(state.store.as_ref(), user.get_user_id()).await?;
+
+    let totp = match req.totp {
+        Some(_) => {
+            let secret =
+                tfa_utils::decrypt_totp_secret(&state, user.get_encrypted_totp_secret()).await?;
+            Some(tfa_utils::generate_totp(secret, user.get_email())?)
+        }
+        None => None,
+    };
+    let user_update = get_totp_verification_update(
+        user.get_totp_status(),
+        totp.as_ref(),
+        user.get_hashed_recovery_codes(),
+        req,
+    )?;
+
+    let user = match user_update {
+        Some(user_update) => state
+            .store
+            .update_user_by_user_id(user.get_user_id(), user_update)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .into(),
+        None => user,
+    };
+
+    tfa_utils::clear_totp_attempts(state.store.as_ref(), user.get_user_id()).await?;
+    auth::blacklist::insert_single_purpose_tokens_in_blacklist(&state, user.get_user_id()).await?;
+
+    get_signin_response(&state, &user)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Checks the TOTP or recovery code, returning the update to apply to the user. Verifying a TOTP
+/// completes the enrollment if it is in progress, while recovery codes can only be used once the
+/// enrollment is complete and are consumed on use.
+fn get_totp_verification_update(
+    totp_status: TotpStatus,
+    totp: Option<&TOTP>,
+    mut hashed_recovery_codes: Vec<String>,
+    req: user_api::VerifyTotpRequest,
+) -> UserResult<Option<storage_user::UserUpdate>> {
+    match (totp_status, totp, req.totp, req.recovery_code) {
+        (TotpStatus::NotSet, _, _, _) => Err(UserErrors::TotpNotSetup.into()),
+        (totp_status, Some(totp), Some(candidate), _) => {
+            if !tfa_utils::is_valid_totp(totp, &candidate)? {
+                return Err(UserErrors::InvalidTotp.into());
+            }
+
+            Ok((totp_status == TotpStatus::InProgress).then_some(
+                storage_user::UserUpdate::TotpUpdate {
+                    totp_status: Some(TotpStatus::Set),
+                    totp_secret: None,
+                    totp_recovery_codes: None,
+                },
+            ))
+        }
+        (TotpStatus::Set, None, None, Some(recovery_code)) => {
+            let index = tfa_utils::find_recovery_code(&hashed_recovery_codes, &recovery_code)?
+                .ok_or(UserErrors::InvalidTotp)?;
+            hashed_recovery_codes.remove(index);
+
+            Ok(Some(storage_user::UserUpdate::TotpUpdate {
+                totp_status: None,
+                totp_secret: None,
+                totp_recovery_codes: Some(hashed_recovery_codes),
+            }))
+        }
+        _ => Err(UserErrors::InvalidTotp.into()),
+    }
+}
+
+/// Removes the TOTP of a user of the merchant, who then signs in with their password alone or
+/// sets up TOTP again if their organization enforces it
+pub async fn reset_totp(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_api::ResetTotpRequest,
+) -> UserResponse<()> {
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User resetting their own TOTP");
+    }
+
+    let user_role = state
+        .store
+        .find_user_role_by_user_id(&req.user_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::InvalidRoleOperation);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+    if user_role.merchant_id != user_from_token.merchant_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User does not belong to the merchant");
+    }
+
+    state
+        .store
+        .update_user_by_user_id(&req.user_id, storage_user::UserUpdate::TotpReset)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn update_totp_enforcement(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_api::UpdateTotpEnforcementRequest,
+) -> UserResponse<()> {
+    if user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Only organization admins can enforce TOTP");
+    }
+
+    state
+        .store
+        .update_organization_by_org_id(
+            &user_from_token.org_id,
+            OrganizationUpdate::TotpEnforcementUpdate {
+                enforce_totp: req.enforce_totp,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+async fn find_user(state: &AppState, user_id: &str) -> UserResult<domain::UserFromStorage> {
+    state
+        .store
+        .find_user_by_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::db::user::UserInterface;
+
+    fn verify_request(
+        totp: Option<&str>,
+        recovery_code: Option<&Secret<String>>,
+    ) -> user_api::VerifyTotpRequest {
+        user_api::VerifyTotpRequest {
+            totp: totp.map(|totp| Secret::new(totp.to_string())),
+            recovery_code: recovery_code.cloned(),
+        }
+    }
+
+    fn generate_totp() -> TOTP {
+        tfa_utils::generate_totp(
+            tfa_utils::generate_totp_secret(),
+            "user@example.com".to_string().try_into().unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_totp_verification_completes_enrollment() {
+        let totp = generate_totp();
+        let code = totp.generate_current().unwrap();
+
+        let enrollment_update = get_totp_verification_update(
+            TotpStatus::InProgress,
+            Some(&totp),
+            vec![],
+            verify_request(Some(&code), None),
+        )
+        .unwrap();
+        assert!(matches!(
+            enrollment_update,
+            Some(storage_user::UserUpdate::TotpUpdate {
+                totp_status: Some(TotpStatus::Set),
+                totp_secret: None,
+                totp_recovery_codes: None,
+            })
+        ));
+
+        let verification_update = get_totp_verification_update(
+            TotpStatus::Set,
+            Some(&totp),
+            vec![],
+            verify_request(Some(&code), None),
+        )
+        .unwrap();
+        assert!(verification_update.is_none());
+
+        let wrong_code = if code == "000000" { "111111" } else { "000000" };
+        let error = get_totp_verification_update(
+            TotpStatus::Set,
+            Some(&totp),
+            vec![],
+            verify_request(Some(wrong_code), None),
+        )
+        .unwrap_err();
+        assert!(matches!(error.current_context(), UserErrors::InvalidTotp));
+    }
+
+    #[test]
+    fn test_recovery_codes_are_single_use() {
+        let (recovery_codes, hashed_recovery_codes) = tfa_utils::generate_recovery_codes().unwrap();
+
+        // Recovery codes cannot complete the enrollment
+        let error = get_totp_verification_update(
+            TotpStatus::InProgress,
+            None,
+            hashed_recovery_codes.clone(),
+            verify_request(None, Some(&recovery_codes[0])),
+        )
+        .unwrap_err();
+        assert!(matches!(error.current_context(), UserErrors::InvalidTotp));
+
+        let Some(storage_user::UserUpdate::TotpUpdate {
+            totp_status: None,
+            totp_secret: None,
+            totp_recovery_codes: Some(remaining_recovery_codes),
+        }) = get_totp_verification_update(
+            TotpStatus::Set,
+            None,
+            hashed_recovery_codes.clone(),
+            verify_request(None, Some(&recovery_codes[0])),
+        )
+        .unwrap()
+        else {
+            panic!("Recovery code was not consumed");
+        };
+        assert_eq!(
+            remaining_recovery_codes.len(),
+            hashed_recovery_codes.len() - 1
+        );
+        assert!(!remaining_recovery_codes.contains(&hashed_recovery_codes[0]));
+
+        let error = get_totp_verification_update(
+            TotpStatus::Set,
+            None,
+            remaining_recovery_codes,
+            verify_request(None, Some(&recovery_codes[0])),
+        )
+        .unwrap_err();
+        assert!(matches!(error.current_context(), UserErrors::InvalidTotp));
+    }
+
+    #[tokio::test]
+    async fn test_totp_reset_requires_enrolling_again() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+
+        let (recovery_codes, hashed_recovery_codes) = tfa_utils::generate_recovery_codes().unwrap();
+        mockdb
+            .insert_user(storage_user::UserNew {
+                user_id: "user_1".to_string(),
+                email: "user@example.com".to_string().try_into().unwrap(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        mockdb
+            .update_user_by_user_id(
+                "user_1",
+                storage_user::UserUpdate::TotpUpdate {
+                    totp_status: Some(TotpStatus::Set),
+                    totp_secret: None,
+                    totp_recovery_codes: Some(hashed_recovery_codes),
+                },
+            )
+            .await
+            .unwrap();
+
+        let user: domain::UserFromStorage = mockdb
+            .update_user_by_user_id("user_1", storage_user::UserUpdate::TotpReset)
+            .await
+            .unwrap()
+            .into();
+        assert_eq!(user.get_totp_status(), TotpStatus::NotSet);
+        assert!(user.get_encrypted_totp_secret().is_none());
+        assert!(user.get_hashed_recovery_codes().is_empty());
+
+        let error = get_totp_verification_update(
+            user.get_totp_status(),
+            None,
+            user.get_hashed_recovery_codes(),
+            verify_request(None, Some(&recovery_codes[0])),
+        )
+        .unwrap_err();
+        assert!(matches!(error.current_context(), UserErrors::TotpNotSetup));
+    }
+}
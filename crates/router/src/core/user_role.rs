@@ -1,9 +1,20 @@
-use api_models::user_role as user_role_api;
-use diesel_models::user_role::UserRoleUpdate;
-use error_stack::ResultExt;
+use api_models::{
+    enums::{AuditAction, AuditResourceType},
+    user_role as user_role_api,
+};
+use diesel_models::{
+    enums::RoleScope,
+    role::{Role, RoleNew, RoleUpdate},
+    user_role::{UserRole, UserRoleUpdate},
+};
+use error_stack::{IntoReport, ResultExt};
 
 use crate::{
-    core::errors::{UserErrors, UserResponse},
+    consts,
+    core::{
+        audit_log,
+        errors::{UserErrors, UserResponse, UserResult},
+    },
     routes::AppState,
     services::{
         authentication::{self as auth},
@@ -26,41 +37,284 @@ pub async fn get_authorization_info(
     ))
 }
 
-pub async fn list_roles(_state: AppState) -> UserResponse<user_role_api::ListRolesResponse> {
-    Ok(ApplicationResponse::Json(user_role_api::ListRolesResponse(
+pub async fn list_roles(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_role_api::ListRolesResponse> {
+    let mut roles: Vec<user_role_api::RoleInfoResponse> =
         predefined_permissions::PREDEFINED_PERMISSIONS
             .iter()
             .filter_map(|(role_id, role_info)| {
                 utils::user_role::get_role_name_and_permission_response(role_info).map(
                     |(permissions, role_name)| user_role_api::RoleInfoResponse {
                         permissions,
-                        role_id,
-                        role_name,
+                        role_id: role_id.to_string(),
+                        role_name: role_name.to_string(),
+                        scope: None,
                     },
                 )
             })
-            .collect(),
+            .collect();
+
+    roles.extend(
+        state
+            .store
+            .list_all_roles(&user_from_token.merchant_id, &user_from_token.org_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+            .into_iter()
+            .map(utils::user_role::get_custom_role_info_response),
+    );
+
+    Ok(ApplicationResponse::Json(user_role_api::ListRolesResponse(
+        roles,
     )))
 }
 
 pub async fn get_role(
-    _state: AppState,
+    state: AppState,
+    user_from_token: auth::UserFromToken,
     role: user_role_api::GetRoleRequest,
 ) -> UserResponse<user_role_api::RoleInfoResponse> {
-    let info = predefined_permissions::PREDEFINED_PERMISSIONS
+    if let Some(info) = predefined_permissions::PREDEFINED_PERMISSIONS
         .get_key_value(role.role_id.as_str())
         .and_then(|(role_id, role_info)| {
             utils::user_role::get_role_name_and_permission_response(role_info).map(
                 |(permissions, role_name)| user_role_api::RoleInfoResponse {
                     permissions,
-                    role_id,
-                    role_name,
+                    role_id: role_id.to_string(),
+                    role_name: role_name.to_string(),
+                    scope: None,
                 },
             )
         })
-        .ok_or(UserErrors::InvalidRoleId)?;
+    {
+        return Ok(ApplicationResponse::Json(info));
+    }
 
-    Ok(ApplicationResponse::Json(info))
+    let custom_role = state
+        .store
+        .find_role_by_role_id_in_merchant_scope(
+            &role.role_id,
+            &user_from_token.merchant_id,
+            &user_from_token.org_id,
+        )
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::InvalidRoleId);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        utils::user_role::get_custom_role_info_response(custom_role),
+    ))
+}
+
+pub async fn create_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::CreateRoleRequest,
+) -> UserResponse<user_role_api::RoleInfoResponse> {
+    if req.scope == RoleScope::Organization
+        && user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+    {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Only organization admins can create organization scoped roles");
+    }
+
+    if req.permissions.is_empty() {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Role must have at least one permission");
+    }
+
+    utils::user_role::validate_permissions_grantable(&state, &user_from_token, &req.permissions)
+        .await?;
+
+    utils::user_role::validate_role_name(
+        &state,
+        &req.role_name,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let role = state
+        .store
+        .insert_role(RoleNew {
+            role_name: req.role_name.trim().to_string(),
+            role_id: common_utils::generate_id_with_default_len(
+                consts::user_role::CUSTOM_ROLE_ID_PREFIX,
+            ),
+            merchant_id: user_from_token.merchant_id,
+            org_id: user_from_token.org_id,
+            permissions: utils::user_role::get_permissions_for_storage(&req.permissions),
+            scope: req.scope,
+            created_at: now,
+            created_by: user_from_token.user_id.clone(),
+            last_modified_at: now,
+            last_modified_by: user_from_token.user_id,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to insert the custom role")?;
+
+    let merchant_id = role.merchant_id.clone();
+    let role = utils::user_role::get_custom_role_info_response(role);
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Create,
+        AuditResourceType::Role,
+        &role.role_id,
+        None,
+        Some(&role),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(role))
+}
+
+pub async fn update_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    role_id: &str,
+    req: user_role_api::UpdateRoleRequest,
+) -> UserResponse<user_role_api::RoleInfoResponse> {
+    let role = find_role_modifiable_by_user(&state, &user_from_token, role_id).await?;
+
+    if let Some(role_name) = req.role_name.as_ref() {
+        if !role_name.trim().eq_ignore_ascii_case(&role.role_name) {
+            utils::user_role::validate_role_name(
+                &state,
+                role_name,
+                &user_from_token.merchant_id,
+                &user_from_token.org_id,
+            )
+            .await?;
+        }
+    }
+
+    if let Some(permissions) = req.permissions.as_ref() {
+        if permissions.is_empty() {
+            return Err(UserErrors::InvalidRoleOperation.into())
+                .attach_printable("Role must have at least one permission");
+        }
+
+        utils::user_role::validate_permissions_grantable(&state, &user_from_token, permissions)
+            .await?;
+    }
+
+    let updated_role = state
+        .store
+        .update_role_by_role_id(
+            &role.role_id,
+            RoleUpdate::UpdateDetails {
+                role_name: req.role_name.map(|role_name| role_name.trim().to_string()),
+                permissions: req
+                    .permissions
+                    .as_deref()
+                    .map(utils::user_role::get_permissions_for_storage),
+                last_modified_by: user_from_token.user_id,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to update the custom role")?;
+
+    let role_id = role.role_id.clone();
+    let updated_role = utils::user_role::get_custom_role_info_response(updated_role);
+    audit_log::record(
+        &state,
+        Some(&user_from_token.merchant_id),
+        AuditAction::Update,
+        AuditResourceType::Role,
+        &role_id,
+        Some(&utils::user_role::get_custom_role_info_response(role)),
+        Some(&updated_role),
+    )
+    .await;
+
+    Ok(ApplicationResponse::Json(updated_role))
+}
+
+/// Deletes the custom role. Users who are still assigned the role lose access to the dashboard
+/// until they are assigned another role.
+pub async fn delete_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    role: user_role_api::GetRoleRequest,
+) -> UserResponse<()> {
+    let role = find_role_modifiable_by_user(&state, &user_from_token, &role.role_id).await?;
+
+    state
+        .store
+        .delete_role_by_role_id(&role.role_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to delete the custom role")?;
+
+    let role_id = role.role_id.clone();
+    audit_log::record(
+        &state,
+        Some(&user_from_token.merchant_id),
+        AuditAction::Delete,
+        AuditResourceType::Role,
+        &role_id,
+        Some(&utils::user_role::get_custom_role_info_response(role)),
+        None,
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Custom roles can only be modified by the merchant which created them, even if they are scoped
+/// to the organization. Organization scoped roles can only be modified by organization admins and
+/// users cannot modify the role they are assigned themselves.
+async fn find_role_modifiable_by_user(
+    state: &AppState,
+    user_from_token: &auth::UserFromToken,
+    role_id: &str,
+) -> UserResult<Role> {
+    let role = state
+        .store
+        .find_role_by_role_id(role_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::InvalidRoleId);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    validate_role_modifiable_by_user(&role, user_from_token)?;
+    Ok(role)
+}
+
+fn validate_role_modifiable_by_user(
+    role: &Role,
+    user_from_token: &auth::UserFromToken,
+) -> UserResult<()> {
+    if role.merchant_id != user_from_token.merchant_id {
+        return Err(UserErrors::InvalidRoleId.into())
+            .attach_printable("Role is not owned by the merchant");
+    }
+
+    if role.role_id == user_from_token.role_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User modifying their own role");
+    }
+
+    if role.scope == RoleScope::Organization
+        && user_from_token.role_id != consts::user_role::ROLE_ID_ORGANIZATION_ADMIN
+    {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Only organization admins can modify organization scoped roles");
+    }
+    Ok(())
 }
 
 pub async fn update_user_role(
@@ -68,16 +322,25 @@ pub async fn update_user_role(
     user_from_token: auth::UserFromToken,
     req: user_role_api::UpdateUserRoleRequest,
 ) -> UserResponse<()> {
-    let merchant_id = user_from_token.merchant_id;
+    let merchant_id = user_from_token.merchant_id.clone();
     let role_id = req.role_id.clone();
-    utils::user_role::validate_role_id(role_id.as_str())?;
+    utils::user_role::validate_role_id(
+        &state,
+        role_id.as_str(),
+        merchant_id.as_str(),
+        user_from_token.org_id.as_str(),
+    )
+    .await?;
+    utils::user_role::validate_role_grantable(&state, &user_from_token, &role_id).await?;
 
     if user_from_token.user_id == req.user_id {
         return Err(UserErrors::InvalidRoleOperation.into())
             .attach_printable("Admin User Changing their role");
     }
 
-    state
+    let user_role_before = find_user_role_in_merchant(&state, &req.user_id, &merchant_id).await?;
+
+    let user_role = state
         .store
         .update_user_role_by_user_id_merchant_id(
             req.user_id.as_str(),
@@ -97,5 +360,141 @@ pub async fn update_user_role(
             e.change_context(UserErrors::InternalServerError)
         })?;
 
+    auth::blacklist::insert_user_in_blacklist(&state, &req.user_id).await?;
+
+    audit_log::record(
+        &state,
+        Some(&merchant_id),
+        AuditAction::Update,
+        AuditResourceType::UserRole,
+        &req.user_id,
+        Some(&user_role_before),
+        Some(&user_role),
+    )
+    .await;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Removes a user from the merchant and revokes the tokens issued to them
+pub async fn delete_user_role(
+    state: AppState,
+    user_from_token: auth::UserFromToken,
+    req: user_role_api::DeleteUserRoleRequest,
+) -> UserResponse<()> {
+    if user_from_token.user_id == req.user_id {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("User deleting themself");
+    }
+
+    let user_role =
+        find_user_role_in_merchant(&state, &req.user_id, &user_from_token.merchant_id).await?;
+
+    if user_role.role_id == consts::user_role::ROLE_ID_ORGANIZATION_ADMIN {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Organization admins cannot be deleted");
+    }
+
+    state
+        .store
+        .delete_user_role_by_user_id_merchant_id(&req.user_id, &user_from_token.merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    auth::blacklist::insert_user_in_blacklist(&state, &req.user_id).await?;
+
+    audit_log::record(
+        &state,
+        Some(&user_from_token.merchant_id),
+        AuditAction::Delete,
+        AuditResourceType::UserRole,
+        &req.user_id,
+        Some(&user_role),
+        None,
+    )
+    .await;
+
     Ok(ApplicationResponse::StatusOk)
 }
+
+async fn find_user_role_in_merchant(
+    state: &AppState,
+    user_id: &str,
+    merchant_id: &str,
+) -> UserResult<UserRole> {
+    state
+        .store
+        .list_user_roles_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .find(|user_role| user_role.merchant_id == merchant_id)
+        .ok_or(UserErrors::InvalidRoleOperation)
+        .into_report()
+        .attach_printable("User does not belong to the merchant")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_role(role_id: &str, scope: RoleScope) -> Role {
+        let now = common_utils::date_time::now();
+        Role {
+            id: 1,
+            role_name: "Payments viewer".to_string(),
+            role_id: role_id.to_string(),
+            merchant_id: "merchant_1".to_string(),
+            org_id: "org_1".to_string(),
+            permissions: vec!["PaymentRead".to_string()],
+            scope,
+            created_at: now,
+            created_by: "user_1".to_string(),
+            last_modified_at: now,
+            last_modified_by: "user_1".to_string(),
+        }
+    }
+
+    fn user_from_token(role_id: &str, merchant_id: &str) -> auth::UserFromToken {
+        auth::UserFromToken {
+            user_id: "user_1".to_string(),
+            merchant_id: merchant_id.to_string(),
+            role_id: role_id.to_string(),
+            org_id: "org_1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validate_role_modifiable_by_user() {
+        let merchant_role = custom_role("role_custom_1", RoleScope::Merchant);
+        let organization_role = custom_role("role_custom_2", RoleScope::Organization);
+        let merchant_admin =
+            user_from_token(consts::user_role::ROLE_ID_MERCHANT_ADMIN, "merchant_1");
+        let organization_admin =
+            user_from_token(consts::user_role::ROLE_ID_ORGANIZATION_ADMIN, "merchant_1");
+
+        assert!(validate_role_modifiable_by_user(&merchant_role, &merchant_admin).is_ok());
+        assert!(validate_role_modifiable_by_user(&organization_role, &organization_admin).is_ok());
+
+        // Only organization admins can modify organization scoped roles
+        assert!(validate_role_modifiable_by_user(&organization_role, &merchant_admin).is_err());
+
+        // Roles can only be modified by the merchant which created them
+        assert!(validate_role_modifiable_by_user(
+            &merchant_role,
+            &user_from_token(consts::user_role::ROLE_ID_MERCHANT_ADMIN, "merchant_2"),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_user_cannot_modify_their_own_role() {
+        let role = custom_role("role_custom_1", RoleScope::Merchant);
+
+        assert!(validate_role_modifiable_by_user(
+            &role,
+            &user_from_token("role_custom_1", "merchant_1")
+        )
+        .is_err());
+    }
+}
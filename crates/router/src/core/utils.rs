@@ -233,6 +233,17 @@ pub async fn construct_refund_router_data<'a, F>(
 
     let (payment_amount, currency) = money;
 
+    // Payments made in another currency are refunded at the rate locked by their quote
+    let (refund_amount, payment_amount, currency) =
+        match helpers::get_fx_quote_of_payment(payment_intent)? {
+            Some(fx_quote) => (
+                helpers::convert_to_presentment_amount(&fx_quote, refund.refund_amount, currency)?,
+                helpers::convert_to_presentment_amount(&fx_quote, payment_amount, currency)?,
+                fx_quote.presentment_currency,
+            ),
+            None => (refund.refund_amount, payment_amount, currency),
+        };
+
     let payment_method_type = payment_attempt
         .payment_method
         .get_required_value("payment_method_type")
@@ -277,6 +288,31 @@ pub async fn construct_refund_router_data<'a, F>(
             field_name: "browser_info",
         })?;
 
+    let connector_capture_id = match refund.capture_id.as_ref() {
+        Some(capture_id) => state
+            .store
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                &payment_attempt.merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error while retrieving capture list")?
+            .into_iter()
+            .find(|capture| &capture.capture_id == capture_id)
+            .and_then(|capture| capture.connector_capture_id)
+            .map(Some)
+            // Refunding the payment as a whole instead could refund against another capture
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable_lazy(|| {
+                format!("Connector capture id not found for capture {capture_id}")
+            })?,
+        None => None,
+    };
+
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.merchant_id.clone(),
@@ -298,7 +334,7 @@ pub async fn construct_refund_router_data<'a, F>(
         request: types::RefundsData {
             refund_id: refund.refund_id.clone(),
             connector_transaction_id: refund.connector_transaction_id.clone(),
-            refund_amount: refund.refund_amount,
+            refund_amount,
             currency,
             payment_amount,
             webhook_url,
@@ -306,6 +342,7 @@ pub async fn construct_refund_router_data<'a, F>(
             reason: refund.refund_reason.clone(),
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
+            connector_capture_id,
         },
 
         response: Ok(types::RefundsResponseData {
@@ -389,6 +426,78 @@ pub fn validate_uuid(uuid: String, key: &str) -> Result<String, errors::ApiError
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_api_key_profile_cannot_be_overridden() {
+        let bound_profile_id = "pro_bound".to_string();
+        let other_profile_id = "pro_other".to_string();
+        let default_profile_id = "pro_default".to_string();
+
+        assert!(resolve_profile_id(
+            Some(&other_profile_id),
+            Some(&bound_profile_id),
+            Some(&default_profile_id),
+        )
+        .is_err());
+        assert_eq!(
+            resolve_profile_id(
+                Some(&bound_profile_id),
+                Some(&bound_profile_id),
+                Some(&default_profile_id),
+            )
+            .ok(),
+            Some(Some(&bound_profile_id))
+        );
+        assert_eq!(
+            resolve_profile_id(None, Some(&bound_profile_id), Some(&default_profile_id)).ok(),
+            Some(Some(&bound_profile_id))
+        );
+        assert_eq!(
+            resolve_profile_id(Some(&other_profile_id), None, Some(&default_profile_id)).ok(),
+            Some(Some(&other_profile_id))
+        );
+        assert_eq!(
+            resolve_profile_id(None, None, Some(&default_profile_id)).ok(),
+            Some(Some(&default_profile_id))
+        );
+    }
+
+    #[test]
+    fn test_api_key_profile_restricts_access() {
+        let bound_profile_id = "pro_bound".to_string();
+        let other_profile_id = "pro_other".to_string();
+
+        assert!(is_accessible_with_profile(
+            Some(&bound_profile_id),
+            Some(&bound_profile_id)
+        ));
+        assert!(!is_accessible_with_profile(
+            Some(&bound_profile_id),
+            Some(&other_profile_id)
+        ));
+        assert!(!is_accessible_with_profile(Some(&bound_profile_id), None));
+        assert!(is_accessible_with_profile(None, Some(&other_profile_id)));
+        assert!(is_accessible_with_profile(None, None));
+    }
+
+    #[test]
+    fn test_api_key_profile_restricts_list_filter() {
+        let bound_profile_id = "pro_bound".to_string();
+        let other_profile_id = "pro_other".to_string();
+
+        assert_eq!(resolve_profile_id(None, None, None).ok(), Some(None));
+        assert_eq!(
+            resolve_profile_id(None, Some(&bound_profile_id), None).ok(),
+            Some(Some(&bound_profile_id))
+        );
+        assert!(
+            resolve_profile_id(Some(&other_profile_id), Some(&bound_profile_id), None).is_err()
+        );
+        assert_eq!(
+            resolve_profile_id(Some(&other_profile_id), None, None).ok(),
+            Some(Some(&other_profile_id))
+        );
+    }
+
     #[test]
     fn validate_id_length_constraint() {
         let payment_id =
@@ -1013,7 +1122,8 @@ pub fn get_connector_label(
         })
 }
 
-/// If profile_id is not passed, use default profile if available, or
+/// If profile_id is not passed, use the profile the API key is bound to or the default profile if
+/// available, or
 /// If business_details (business_country and business_label) are passed, get the business_profile
 /// or return a `MissingRequiredField` error
 pub async fn get_profile_id_from_business_details(
@@ -1024,7 +1134,11 @@ pub async fn get_profile_id_from_business_details(
     db: &dyn StorageInterface,
     should_validate: bool,
 ) -> RouterResult<String> {
-    match request_profile_id.or(merchant_account.default_profile.as_ref()) {
+    match resolve_profile_id(
+        request_profile_id,
+        merchant_account.bound_profile_id.as_ref(),
+        merchant_account.default_profile.as_ref(),
+    )? {
         Some(profile_id) => {
             // Check whether this business profile belongs to the merchant
             if should_validate {
@@ -1059,6 +1173,68 @@ pub async fn get_profile_id_from_business_details(
     }
 }
 
+/// Requests made with an API key bound to a business profile cannot use any other profile
+fn resolve_profile_id<'a>(
+    request_profile_id: Option<&'a String>,
+    bound_profile_id: Option<&'a String>,
+    default_profile_id: Option<&'a String>,
+) -> RouterResult<Option<&'a String>> {
+    match (request_profile_id, bound_profile_id) {
+        (Some(request_profile_id), Some(bound_profile_id))
+            if request_profile_id != bound_profile_id =>
+        {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "profile_id does not match the business profile of the API key"
+                    .to_string(),
+            }))
+        }
+        _ => Ok(request_profile_id
+            .or(bound_profile_id)
+            .or(default_profile_id)),
+    }
+}
+
+/// Restricts the business profile filter of a list request made with an API key bound to a
+/// business profile to that profile
+pub fn get_profile_id_for_list(
+    merchant_account: &domain::MerchantAccount,
+    request_profile_id: Option<String>,
+) -> RouterResult<Option<String>> {
+    resolve_profile_id(
+        request_profile_id.as_ref(),
+        merchant_account.bound_profile_id.as_ref(),
+        None,
+    )
+    .map(|profile_id| profile_id.cloned())
+}
+
+/// Requests made with an API key bound to a business profile cannot access the objects of other
+/// profiles, which are reported as not found
+pub fn validate_profile_access(
+    merchant_account: &domain::MerchantAccount,
+    object_profile_id: Option<&String>,
+    not_found_error: errors::ApiErrorResponse,
+) -> RouterResult<()> {
+    if is_accessible_with_profile(
+        merchant_account.bound_profile_id.as_ref(),
+        object_profile_id,
+    ) {
+        Ok(())
+    } else {
+        Err(report!(not_found_error))
+            .attach_printable("The object belongs to another business profile than the API key")
+    }
+}
+
+fn is_accessible_with_profile(
+    bound_profile_id: Option<&String>,
+    object_profile_id: Option<&String>,
+) -> bool {
+    bound_profile_id.map_or(true, |bound_profile_id| {
+        object_profile_id == Some(bound_profile_id)
+    })
+}
+
 #[inline]
 pub fn get_flow_name<F>() -> RouterResult<String> {
     Ok(std::any::type_name::<F>()
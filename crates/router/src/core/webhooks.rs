@@ -530,6 +530,14 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             connector.id(),
         )
         .await?;
+        if dispute_object.dispute_stage == enums::DisputeStage::Dispute
+            && dispute_object.dispute_status == enums::DisputeStatus::DisputeOpened
+        {
+            super::disputes::add_dispute_deadline_task(&state, &business_profile, &dispute_object)
+                .await
+                .map_err(|error| logger::error!(dispute_deadline_task_error=?error))
+                .ok();
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -654,28 +662,49 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
     primary_object_id: String,
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let event_id = format!("{primary_object_id}_{}", event_type);
+    create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        event_id,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        content,
+    )
+    .await
+}
+
+/// Same as [`create_event_and_trigger_appropriate_outgoing_webhook`], for events of which more
+/// than one of the same type may be sent for an object, each of them identified by `event_id`
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    event_id: String,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
         Some(api_models::enums::Connector::Stripe) => {
-            create_event_and_trigger_outgoing_webhook::<stripe_webhooks::StripeOutgoingWebhook>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                event_type,
-                event_class,
-                intent_reference_id,
-                primary_object_id,
-                primary_object_type,
-                content,
-            )
-            .await
-        }
-        _ => {
-            create_event_and_trigger_outgoing_webhook::<api_models::webhooks::OutgoingWebhook>(
+            create_event_with_id_and_trigger_outgoing_webhook::<
+                stripe_webhooks::StripeOutgoingWebhook,
+            >(
                 state.clone(),
                 merchant_account,
                 business_profile,
+                event_id,
                 event_type,
                 event_class,
                 intent_reference_id,
@@ -685,6 +714,21 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
             )
             .await
         }
+        _ => create_event_with_id_and_trigger_outgoing_webhook::<
+            api_models::webhooks::OutgoingWebhook,
+        >(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            event_id,
+            event_type,
+            event_class,
+            intent_reference_id,
+            primary_object_id,
+            primary_object_type,
+            content,
+        )
+        .await,
     }
 }
 
@@ -702,6 +746,52 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let event_id = format!("{primary_object_id}_{}", event_type);
+    create_event_with_id_and_trigger_outgoing_webhook::<W>(
+        state,
+        merchant_account,
+        business_profile,
+        event_id,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        content,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn create_event_with_id_and_trigger_outgoing_webhook<W: types::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    event_id: String,
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    intent_reference_id: Option<String>,
+    primary_object_id: String,
+    primary_object_type: enums::EventObjectType,
+    content: api::OutgoingWebhookContent,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.merchant_id.clone(),
+        event_id: event_id.clone(),
+        event_type,
+        content,
+        timestamp: common_utils::date_time::now(),
+    };
+
+    // The event is stored along with the payload delivered to the merchant, in the format the
+    // merchant receives it, so that it can be retrieved later on
+    let event_content = Encode::<W>::encode_to_value(&W::from(outgoing_webhook.clone()))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode outgoing webhook content")?;
+
+    #[cfg(feature = "email")]
+    let notification_content = outgoing_webhook.content.clone();
+
     let new_event = storage::EventNew {
         event_id: event_id.clone(),
         event_type,
@@ -710,12 +800,14 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        merchant_id: Some(merchant_account.merchant_id.clone()),
+        content: Some(masking::Secret::new(event_content)),
     };
 
     let event_insert_result = state.store.insert_event(new_event).await;
 
-    let event = match event_insert_result {
-        Ok(event) => Ok(event),
+    match event_insert_result {
+        Ok(_) => Ok(()),
         Err(error) => {
             if error.current_context().is_db_unique_violation() {
                 logger::info!("Merchant already notified about the event {event_id}");
@@ -729,15 +821,28 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         }
     }?;
 
-    if state.conf.webhooks.outgoing_enabled {
-        let outgoing_webhook = api::OutgoingWebhook {
-            merchant_id: merchant_account.merchant_id.clone(),
-            event_id: event.event_id,
-            event_type: event.event_type,
-            content,
-            timestamp: event.created_at,
-        };
+    #[cfg(feature = "email")]
+    {
+        let state = state.clone();
+        let merchant_account = merchant_account.clone();
+        let business_profile = business_profile.clone();
+        tokio::spawn(async move {
+            let result = super::notifications::send_event_notifications(
+                &state,
+                &merchant_account,
+                &business_profile,
+                event_type,
+                &notification_content,
+            )
+            .await;
 
+            if let Err(e) = result {
+                logger::error!(notification_failure=?e);
+            }
+        });
+    }
+
+    if state.conf.webhooks.outgoing_enabled {
         // Using a tokio spawn here and not arbiter because not all caller of this function
         // may have an actix arbiter
         tokio::spawn(async move {
@@ -820,6 +925,15 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     );
     logger::debug!(outgoing_webhook_response=?response);
 
+    #[cfg(feature = "email")]
+    if !matches!(&response, Ok(res) if res.status().is_success()) {
+        let alert_result =
+            super::notifications::send_webhook_failure_alert(state, &business_profile).await;
+        if let Err(e) = alert_result {
+            logger::error!(webhook_failure_alert_failure=?e);
+        }
+    }
+
     match response {
         Err(e) => {
             // [#217]: Schedule webhook for retry.
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod business_profile;
 pub mod cache;
 pub mod capture;
@@ -11,6 +12,7 @@ pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod fraud_check;
 pub mod gsm;
 mod kafka_store;
@@ -26,6 +28,7 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod role;
 pub mod routing_algorithm;
 pub mod user;
 pub mod user_role;
@@ -66,6 +69,7 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + audit_log::AuditLogInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
@@ -74,6 +78,7 @@ pub trait StorageInterface:
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
+    + forex_rate_snapshot::ForexRateSnapshotInterface
     + FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
@@ -100,6 +105,7 @@ pub trait StorageInterface:
     + gsm::GsmInterface
     + user::UserInterface
     + user_role::UserRoleInterface
+    + role::RoleInterface
     + user::sample_data::BatchSampleDataInterface
     + 'static
 {
@@ -1,3 +1,4 @@
+use diesel_models::errors::DatabaseError;
 use error_stack::IntoReport;
 #[cfg(feature = "accounts_cache")]
 use storage_impl::redis::cache::CacheKind;
@@ -25,6 +26,17 @@ pub trait ApiKeyInterface {
         api_key: storage::ApiKeyUpdate,
     ) -> CustomResult<storage::ApiKey, errors::StorageError>;
 
+    /// Updates the API key only if its expiry and the key it was rotated to have not changed
+    /// since they were read
+    async fn update_api_key_if_unchanged(
+        &self,
+        merchant_id: String,
+        key_id: String,
+        current_expires_at: Option<time::PrimitiveDateTime>,
+        current_rotated_to: Option<String>,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -113,6 +125,62 @@ impl ApiKeyInterface for Store {
         }
     }
 
+    async fn update_api_key_if_unchanged(
+        &self,
+        merchant_id: String,
+        key_id: String,
+        current_expires_at: Option<time::PrimitiveDateTime>,
+        current_rotated_to: Option<String>,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let _merchant_id = merchant_id.clone();
+        let _key_id = key_id.clone();
+        let update_call = || async {
+            storage::ApiKey::update_if_unchanged_by_merchant_id_key_id(
+                &conn,
+                merchant_id,
+                key_id,
+                current_expires_at,
+                current_rotated_to,
+                api_key,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_call().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            use error_stack::report;
+
+            // The cached key is keyed by the HashedApiKey, as in `update_api_key`
+            let api_key = storage::ApiKey::find_optional_by_merchant_id_key_id(
+                &conn,
+                &_merchant_id,
+                &_key_id,
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()?
+            .ok_or(report!(errors::StorageError::ValueNotFound(format!(
+                "ApiKey of {_key_id} not found"
+            ))))?;
+
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(api_key.hashed_api_key.into_inner().into()),
+                update_call,
+            )
+            .await
+        }
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -234,6 +302,12 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            scopes: api_key.scopes,
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
+            rotated_from: api_key.rotated_from,
+            rotated_to: None,
+            original_expires_at: None,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -259,6 +333,8 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                scopes,
+                ip_allowlist,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -273,15 +349,60 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if scopes.is_some() {
+                    key_to_update.scopes = scopes;
+                }
+                if ip_allowlist.is_some() {
+                    key_to_update.ip_allowlist = ip_allowlist;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
             }
+            storage::ApiKeyUpdate::RotatedUpdate {
+                expires_at,
+                rotated_to,
+                original_expires_at,
+            } => {
+                key_to_update.expires_at = expires_at;
+                key_to_update.rotated_to = Some(rotated_to);
+                if original_expires_at.is_some() {
+                    key_to_update.original_expires_at = original_expires_at;
+                }
+            }
         }
 
         Ok(key_to_update.clone())
     }
 
+    async fn update_api_key_if_unchanged(
+        &self,
+        merchant_id: String,
+        key_id: String,
+        current_expires_at: Option<time::PrimitiveDateTime>,
+        current_rotated_to: Option<String>,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        {
+            let locked_api_keys = self.api_keys.lock().await;
+            // the update matches no key if the key was changed since it was read, like in the
+            // real db
+            if !locked_api_keys.iter().any(|k| {
+                k.merchant_id == merchant_id
+                    && k.key_id == key_id
+                    && k.expires_at == current_expires_at
+                    && k.rotated_to == current_rotated_to
+            }) {
+                return Err(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into(),
+                ))
+                .into_report();
+            }
+        }
+
+        self.update_api_key(merchant_id, key_id, api_key).await
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -391,9 +512,12 @@ mod tests {
     #[tokio::test]
     async fn test_mockdb_api_key_interface() {
         #[allow(clippy::expect_used)]
-        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-            .await
-            .expect("Failed to create Mock store");
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
 
         let key1 = mockdb
             .insert_api_key(storage::ApiKeyNew {
@@ -406,6 +530,10 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                scopes: None,
+                profile_id: None,
+                ip_allowlist: None,
+                rotated_from: None,
             })
             .await
             .unwrap();
@@ -421,6 +549,10 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                scopes: None,
+                profile_id: None,
+                ip_allowlist: None,
+                rotated_from: None,
             })
             .await
             .unwrap();
@@ -477,9 +609,12 @@ mod tests {
     #[tokio::test]
     async fn test_api_keys_cache() {
         #[allow(clippy::expect_used)]
-        let db = MockDb::new(&redis_interface::RedisSettings::default())
-            .await
-            .expect("Failed to create Mock store");
+        let db = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
 
         let redis_conn = db.get_redis_conn().unwrap();
         redis_conn
@@ -498,6 +633,10 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            scopes: None,
+            profile_id: None,
+            ip_allowlist: None,
+            rotated_from: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
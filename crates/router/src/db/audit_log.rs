@@ -0,0 +1,133 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, AuditLogDbExt},
+};
+
+/// The audit log is append-only, so entries can only be inserted and listed
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError>;
+
+    async fn list_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuditLog::filter_by_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let mut locked_audit_logs = self.audit_logs.lock().await;
+
+        let stored_audit_log = storage::AuditLog {
+            id: locked_audit_logs
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            audit_id: audit_log.audit_id,
+            merchant_id: audit_log.merchant_id,
+            actor_type: audit_log.actor_type,
+            actor_id: audit_log.actor_id,
+            action: audit_log.action,
+            resource_type: audit_log.resource_type,
+            resource_id: audit_log.resource_id,
+            diff: audit_log.diff,
+            request_id: audit_log.request_id,
+            ip_address: audit_log.ip_address,
+            created_at: audit_log.created_at,
+        };
+        locked_audit_logs.push(stored_audit_log.clone());
+
+        Ok(stored_audit_log)
+    }
+
+    async fn list_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let locked_audit_logs = self.audit_logs.lock().await;
+
+        Ok(locked_audit_logs
+            .iter()
+            .rev()
+            .filter(|audit_log| {
+                audit_log.merchant_id.as_deref() == Some(merchant_id)
+                    && constraints.resource_type.map_or(true, |resource_type| {
+                        resource_type == audit_log.resource_type
+                    })
+                    && constraints
+                        .resource_id
+                        .as_ref()
+                        .map_or(true, |resource_id| resource_id == &audit_log.resource_id)
+                    && constraints
+                        .actor_type
+                        .map_or(true, |actor_type| actor_type == audit_log.actor_type)
+                    && constraints.actor_id.as_ref().map_or(true, |actor_id| {
+                        Some(actor_id) == audit_log.actor_id.as_ref()
+                    })
+                    && constraints
+                        .action
+                        .map_or(true, |action| action == audit_log.action)
+                    && constraints.created_at_gte.map_or(true, |created_at_gte| {
+                        created_at_gte <= audit_log.created_at
+                    })
+                    && constraints.created_at_lte.map_or(true, |created_at_lte| {
+                        created_at_lte >= audit_log.created_at
+                    })
+            })
+            .skip(
+                constraints
+                    .offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or(0),
+            )
+            .take(
+                constraints
+                    .limit
+                    .and_then(|limit| usize::try_from(limit).ok())
+                    .unwrap_or(usize::MAX),
+            )
+            .cloned()
+            .collect())
+    }
+}
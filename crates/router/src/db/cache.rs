@@ -1,5 +1,5 @@
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use redis_interface::errors::RedisError;
 use storage_impl::redis::{
     cache::{Cache, CacheKind, Cacheable},
@@ -16,6 +16,7 @@ pub async fn get_or_populate_redis<T, F, Fut>(
     store: &dyn StorageInterface,
     key: impl AsRef<str>,
     fun: F,
+    cache: &Cache,
 ) -> CustomResult<T, errors::StorageError>
 where
     T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
@@ -34,7 +35,7 @@ where
     let get_data_set_redis = || async {
         let data = fun().await?;
         redis
-            .serialize_and_set_key(key, &data)
+            .serialize_and_set_key_with_expiry(key, &data, cache.redis_ttl())
             .await
             .change_context(errors::StorageError::KVError)?;
         Ok::<_, error_stack::Report<errors::StorageError>>(data)
@@ -42,13 +43,17 @@ where
     match redis_val {
         Err(err) => match err.current_context() {
             errors::RedisError::NotFound | errors::RedisError::JsonDeserializationFailed => {
+                cache.record_redis_lookup(false);
                 get_data_set_redis().await
             }
             _ => Err(err
                 .change_context(errors::StorageError::KVError)
                 .attach_printable(format!("Error while fetching cache for {type_name}"))),
         },
-        Ok(val) => Ok(val),
+        Ok(val) => {
+            cache.record_redis_lookup(true);
+            Ok(val)
+        }
     }
 }
 
@@ -63,14 +68,18 @@ where
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
-    let cache_val = cache.get_val::<T>(key);
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
-        let val = get_or_populate_redis(store, key, fun).await?;
-        cache.push(key.to_string(), val.clone()).await;
-        Ok(val)
-    }
+    cache
+        .get_or_populate(
+            key,
+            || get_or_populate_redis(store, key, fun, cache),
+            errors::StorageError::is_db_not_found,
+            || {
+                report!(errors::StorageError::DatabaseError(report!(
+                    errors::DatabaseError::NotFound
+                )))
+            },
+        )
+        .await
 }
 
 pub async fn redact_cache<T, F, Fut>(
@@ -84,7 +93,7 @@ where
     Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
 {
     let data = fun().await?;
-    in_memory.async_map(|cache| cache.invalidate(key)).await;
+    in_memory.async_map(|cache| cache.remove(key)).await;
 
     let redis_conn = store
         .get_redis_conn()
@@ -1,3 +1,4 @@
+use diesel_models::errors::DatabaseError;
 use router_env::{instrument, tracing};
 
 use super::MockDb;
@@ -178,6 +179,14 @@ impl CaptureInterface for MockDb {
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<types::Capture, errors::StorageError> {
         let mut captures = self.captures.lock().await;
+        if captures
+            .iter()
+            .any(|existing| existing.capture_id == capture.capture_id)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
         let capture = types::Capture {
             capture_id: capture.capture_id,
             payment_id: capture.payment_id,
@@ -196,6 +205,7 @@ impl CaptureInterface for MockDb {
             capture_sequence: capture.capture_sequence,
             connector_capture_id: capture.connector_capture_id,
             connector_response_reference_id: capture.connector_response_reference_id,
+            final_capture: capture.final_capture,
         };
         captures.push(capture.clone());
         Ok(capture)
@@ -213,12 +223,95 @@ impl CaptureInterface for MockDb {
     }
     async fn find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
         &self,
-        _merchant_id: &str,
-        _payment_id: &str,
-        _authorized_attempt_id: &str,
+        merchant_id: &str,
+        payment_id: &str,
+        authorized_attempt_id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<Vec<types::Capture>, errors::StorageError> {
-        //Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let captures = self.captures.lock().await;
+        Ok(captures
+            .iter()
+            .filter(|capture| {
+                capture.merchant_id == merchant_id
+                    && capture.payment_id == payment_id
+                    && capture.authorized_attempt_id == authorized_attempt_id
+            })
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use crate::{
+        db::{capture::CaptureInterface, MockDb},
+        types::storage::{self as types, enums},
+    };
+
+    fn capture_new(capture_sequence: i16) -> types::CaptureNew {
+        let now = common_utils::date_time::now();
+        types::CaptureNew {
+            capture_id: format!("pay_1_1_{capture_sequence}"),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            status: enums::CaptureStatus::Started,
+            amount: 100,
+            currency: Some(enums::Currency::USD),
+            connector: "checkout".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "pay_1_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence,
+            connector_response_reference_id: None,
+            final_capture: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_capture_is_inserted_once() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+        let storage_scheme = enums::MerchantStorageScheme::PostgresOnly;
+
+        mockdb
+            .insert_capture(capture_new(1), storage_scheme)
+            .await
+            .unwrap();
+        let duplicate = mockdb.insert_capture(capture_new(1), storage_scheme).await;
+        assert!(duplicate
+            .unwrap_err()
+            .current_context()
+            .is_db_unique_violation());
+        mockdb
+            .insert_capture(capture_new(2), storage_scheme)
+            .await
+            .unwrap();
+
+        let captures = mockdb
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                "merchant_1",
+                "pay_1",
+                "pay_1_1",
+                storage_scheme,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            captures
+                .iter()
+                .map(|capture| capture.capture_sequence)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
     }
 }
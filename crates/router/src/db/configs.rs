@@ -61,8 +61,13 @@ impl ConfigInterface for Store {
         &self,
         config: storage::ConfigNew,
     ) -> CustomResult<storage::Config, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        config.insert(&conn).await.map_err(Into::into).into_report()
+        let key = config.key.clone();
+        // A negative lookup of this key may have been cached
+        cache::publish_and_redact(self, CacheKind::Config(key.as_str().into()), || async {
+            let conn = connection::pg_connection_write(self).await?;
+            config.insert(&conn).await.map_err(Into::into).into_report()
+        })
+        .await
     }
 
     async fn update_config_in_database(
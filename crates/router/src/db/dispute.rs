@@ -219,6 +219,16 @@ impl DisputeInterface for MockDb {
         dispute_constraints: api_models::disputes::DisputeListConstraints,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
+        let now = common_utils::date_time::now();
+        let expiring_window =
+            dispute_constraints
+                .expiring_within_hours
+                .map(|expiring_within_hours| {
+                    (
+                        now,
+                        now.saturating_add(time::Duration::hours(i64::from(expiring_within_hours))),
+                    )
+                });
 
         Ok(locked_disputes
             .iter()
@@ -273,6 +283,18 @@ impl DisputeInterface for MockDb {
                         .as_ref()
                         .map(|received_time_gte| received_time_gte <= &d.created_at)
                         .unwrap_or(true)
+                    && expiring_window
+                        .map(|(expiring_after, expiring_before)| {
+                            d.dispute_stage == storage::enums::DisputeStage::Dispute
+                                && d.dispute_status == storage::enums::DisputeStatus::DisputeOpened
+                                && d.challenge_required_by
+                                    .map(|challenge_required_by| {
+                                        expiring_after <= challenge_required_by
+                                            && challenge_required_by <= expiring_before
+                                    })
+                                    .unwrap_or(false)
+                        })
+                        .unwrap_or(true)
             })
             .take(
                 dispute_constraints
@@ -413,9 +435,12 @@ mod tests {
         #[tokio::test]
         async fn test_insert_dispute() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&RedisSettings::default())
-                .await
-                .expect("Failed to create a mock DB");
+            let mockdb = MockDb::new(&RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create a mock DB");
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -444,9 +469,12 @@ mod tests {
         #[tokio::test]
         async fn test_find_by_merchant_id_payment_id_connector_dispute_id() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -487,9 +515,12 @@ mod tests {
         #[tokio::test]
         async fn test_find_dispute_by_merchant_id_dispute_id() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -524,9 +555,12 @@ mod tests {
         #[tokio::test]
         async fn test_find_disputes_by_merchant_id() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -565,6 +599,7 @@ mod tests {
                         received_time_lte: None,
                         received_time_gte: None,
                         profile_id: None,
+                        expiring_within_hours: None,
                     },
                 )
                 .await
@@ -578,9 +613,12 @@ mod tests {
         #[tokio::test]
         async fn test_find_disputes_by_merchant_id_payment_id() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
                 .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -634,9 +672,12 @@ mod tests {
             #[tokio::test]
             async fn test_update_dispute_update() {
                 #[allow(clippy::expect_used)]
-                let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                    .await
-                    .expect("Failed to create Mock store");
+                let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                    in_memory: true,
+                    ..Default::default()
+                })
+                .await
+                .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -714,9 +755,12 @@ mod tests {
             #[tokio::test]
             async fn test_update_dispute_update_status() {
                 #[allow(clippy::expect_used)]
-                let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                    .await
-                    .expect("Failed to create Mock store");
+                let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                    in_memory: true,
+                    ..Default::default()
+                })
+                .await
+                .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -789,9 +833,12 @@ mod tests {
             #[tokio::test]
             async fn test_update_dispute_update_evidence() {
                 #[allow(clippy::expect_used)]
-                let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                    .await
-                    .expect("Failed to create Mock store");
+                let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                    in_memory: true,
+                    ..Default::default()
+                })
+                .await
+                .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
                     .insert_dispute(create_dispute_new(DisputeNewIds {
@@ -4,7 +4,7 @@ use super::{MockDb, Store};
 use crate::{
     connection,
     core::errors::{self, CustomResult},
-    types::storage,
+    types::storage::{self, EventDbExt},
 };
 
 #[async_trait::async_trait]
@@ -18,6 +18,16 @@ pub trait EventInterface {
         event_id: String,
         event: storage::EventUpdate,
     ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError>;
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -40,6 +50,28 @@ impl EventInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::find_by_merchant_id_event_id(&conn, merchant_id, event_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::filter_by_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -65,6 +97,8 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            merchant_id: event.merchant_id,
+            content: event.content,
         };
 
         locked_events.push(stored_event.clone());
@@ -94,6 +128,58 @@ impl EventInterface for MockDb {
 
         Ok(event_to_update.clone())
     }
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        locked_events
+            .iter()
+            .find(|event| {
+                event.merchant_id.as_deref() == Some(merchant_id) && event.event_id == event_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No event available with event_id = {event_id}"
+                ))
+                .into(),
+            )
+    }
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let limit = usize::try_from(constraints.limit)
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+
+        let mut events: Vec<_> = locked_events
+            .iter()
+            .filter(|event| event.merchant_id.as_deref() == Some(merchant_id))
+            .filter(|event| {
+                constraints
+                    .starting_after_id
+                    .map_or(true, |starting_after_id| event.id < starting_after_id)
+                    && constraints
+                        .ending_before_id
+                        .map_or(true, |ending_before_id| event.id > ending_before_id)
+            })
+            .cloned()
+            .collect();
+
+        events.sort_by(|a, b| b.id.cmp(&a.id));
+        if constraints.ending_before_id.is_some() {
+            let skip = events.len().saturating_sub(limit);
+            Ok(events.into_iter().skip(skip).collect())
+        } else {
+            events.truncate(limit);
+            Ok(events)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -109,9 +195,12 @@ mod tests {
     #[tokio::test]
     async fn test_mockdb_event_interface() {
         #[allow(clippy::expect_used)]
-        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-            .await
-            .expect("Failed to create Mock store");
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
 
         let event1 = mockdb
             .insert_event(storage::EventNew {
@@ -122,6 +211,8 @@ mod tests {
                 intent_reference_id: Some("test".into()),
                 primary_object_id: "primary_object_tet".into(),
                 primary_object_type: enums::EventObjectType::PaymentDetails,
+                merchant_id: Some("merchant_1".into()),
+                content: None,
             })
             .await
             .unwrap();
@@ -141,5 +232,16 @@ mod tests {
         assert!(updated_event.is_webhook_notified);
         assert_eq!(updated_event.primary_object_id, "primary_object_tet");
         assert_eq!(updated_event.id, 0);
+
+        let found_event = mockdb
+            .find_event_by_merchant_id_event_id("merchant_1", "test_event_id")
+            .await
+            .unwrap();
+
+        assert_eq!(found_event.event_id, "test_event_id");
+        assert!(mockdb
+            .find_event_by_merchant_id_event_id("merchant_2", "test_event_id")
+            .await
+            .is_err());
     }
 }
@@ -0,0 +1,130 @@
+use diesel_models::errors::DatabaseError;
+use error_stack::IntoReport;
+use time::Date;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ForexRateSnapshotInterface {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+
+    async fn update_forex_rate_snapshot(
+        &self,
+        rate_date: Date,
+        snapshot: storage::ForexRateSnapshotUpdate,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+
+    async fn find_latest_forex_rate_snapshot_on_or_before(
+        &self,
+        rate_date: Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for Store {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        snapshot
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_forex_rate_snapshot(
+        &self,
+        rate_date: Date,
+        snapshot: storage::ForexRateSnapshotUpdate,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ForexRateSnapshot::update_by_rate_date(&conn, rate_date, snapshot)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_latest_forex_rate_snapshot_on_or_before(
+        &self,
+        rate_date: Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ForexRateSnapshot::find_latest_on_or_before(&conn, rate_date)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for MockDb {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let mut snapshots = self.forex_rate_snapshots.lock().await;
+        if snapshots
+            .iter()
+            .any(|existing| existing.rate_date == snapshot.rate_date)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))?
+        }
+        let created = storage::ForexRateSnapshot {
+            rate_date: snapshot.rate_date,
+            provider: snapshot.provider,
+            base_currency: snapshot.base_currency,
+            rates: snapshot.rates,
+            rates_timestamp: snapshot.rates_timestamp,
+            created_at: snapshot.created_at,
+            last_modified: snapshot.last_modified,
+        };
+        snapshots.push(created.clone());
+        Ok(created)
+    }
+
+    async fn update_forex_rate_snapshot(
+        &self,
+        rate_date: Date,
+        snapshot: storage::ForexRateSnapshotUpdate,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        let mut snapshots = self.forex_rate_snapshots.lock().await;
+        let existing = snapshots
+            .iter_mut()
+            .find(|existing| existing.rate_date == rate_date)
+            .ok_or(errors::StorageError::DatabaseError(
+                DatabaseError::NotFound.into(),
+            ))?;
+        existing.provider = snapshot.provider;
+        existing.base_currency = snapshot.base_currency;
+        existing.rates = snapshot.rates;
+        existing.rates_timestamp = snapshot.rates_timestamp;
+        existing.last_modified = snapshot.last_modified;
+        Ok(existing.clone())
+    }
+
+    async fn find_latest_forex_rate_snapshot_on_or_before(
+        &self,
+        rate_date: Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.forex_rate_snapshots
+            .lock()
+            .await
+            .iter()
+            .filter(|snapshot| snapshot.rate_date <= rate_date)
+            .max_by_key(|snapshot| snapshot.rate_date)
+            .cloned()
+            .ok_or(errors::StorageError::DatabaseError(DatabaseError::NotFound.into()).into())
+    }
+}
@@ -10,10 +10,10 @@ use diesel_models::{
     enums::ProcessTrackerStatus,
     ephemeral_key::{EphemeralKey, EphemeralKeyNew},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
-    user_role as user_storage,
+    role as role_storage, user_role as user_storage,
 };
 use masking::Secret;
-use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId};
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId, StreamGroupInfo};
 use router_env::logger;
 use scheduler::{
     db::{process_tracker::ProcessTrackerInterface, queue::QueueInterface},
@@ -24,6 +24,7 @@ use time::PrimitiveDateTime;
 
 use super::{
     dashboard_metadata::DashboardMetadataInterface,
+    role::RoleInterface,
     user::{sample_data::BatchSampleDataInterface, UserInterface},
     user_role::UserRoleInterface,
 };
@@ -32,6 +33,7 @@ use crate::{
     db::{
         address::AddressInterface,
         api_keys::ApiKeyInterface,
+        audit_log::AuditLogInterface,
         business_profile::BusinessProfileInterface,
         capture::CaptureInterface,
         cards_info::CardsInfoInterface,
@@ -41,6 +43,7 @@ use crate::{
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
+        forex_rate_snapshot::ForexRateSnapshotInterface,
         gsm::GsmInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
@@ -189,6 +192,25 @@ impl ApiKeyInterface for KafkaStore {
             .await
     }
 
+    async fn update_api_key_if_unchanged(
+        &self,
+        merchant_id: String,
+        key_id: String,
+        current_expires_at: Option<time::PrimitiveDateTime>,
+        current_rotated_to: Option<String>,
+        api_key: storage::ApiKeyUpdate,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        self.diesel_store
+            .update_api_key_if_unchanged(
+                merchant_id,
+                key_id,
+                current_expires_at,
+                current_rotated_to,
+                api_key,
+            )
+            .await
+    }
+
     async fn revoke_api_key(
         &self,
         merchant_id: &str,
@@ -470,6 +492,75 @@ impl EventInterface for KafkaStore {
     ) -> CustomResult<storage::Event, errors::StorageError> {
         self.diesel_store.update_event(event_id, event).await
     }
+
+    async fn find_event_by_merchant_id_event_id(
+        &self,
+        merchant_id: &str,
+        event_id: &str,
+    ) -> CustomResult<storage::Event, errors::StorageError> {
+        self.diesel_store
+            .find_event_by_merchant_id_event_id(merchant_id, event_id)
+            .await
+    }
+
+    async fn list_events_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: storage::EventListConstraints,
+    ) -> CustomResult<Vec<storage::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_events_by_merchant_id(merchant_id, constraints)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        self.diesel_store.insert_audit_log(audit_log).await
+    }
+
+    async fn list_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_audit_logs_by_merchant_id(merchant_id, constraints)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ForexRateSnapshotInterface for KafkaStore {
+    async fn insert_forex_rate_snapshot(
+        &self,
+        snapshot: storage::ForexRateSnapshotNew,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store.insert_forex_rate_snapshot(snapshot).await
+    }
+
+    async fn update_forex_rate_snapshot(
+        &self,
+        rate_date: time::Date,
+        snapshot: storage::ForexRateSnapshotUpdate,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .update_forex_rate_snapshot(rate_date, snapshot)
+            .await
+    }
+
+    async fn find_latest_forex_rate_snapshot_on_or_before(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::ForexRateSnapshot, errors::StorageError> {
+        self.diesel_store
+            .find_latest_forex_rate_snapshot_on_or_before(rate_date)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -586,6 +677,16 @@ impl PaymentLinkInterface for KafkaStore {
             .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
             .await
     }
+
+    async fn update_payment_link_by_payment_link_id(
+        &self,
+        payment_link_id: &str,
+        payment_link_update: storage::PaymentLinkUpdate,
+    ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
+        self.diesel_store
+            .update_payment_link_by_payment_link_id(payment_link_id, payment_link_update)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -891,6 +992,17 @@ impl QueueInterface for KafkaStore {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.diesel_store.get_key(key).await
     }
+
+    async fn stream_get_length(&self, stream: &str) -> CustomResult<usize, RedisError> {
+        self.diesel_store.stream_get_length(stream).await
+    }
+
+    async fn consumer_group_info(
+        &self,
+        stream: &str,
+    ) -> CustomResult<Vec<StreamGroupInfo>, RedisError> {
+        self.diesel_store.consumer_group_info(stream).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -938,6 +1050,32 @@ impl PaymentAttemptInterface for KafkaStore {
         Ok(attempt)
     }
 
+    async fn update_payment_attempt_with_attempt_id_and_status(
+        &self,
+        this: storage::PaymentAttempt,
+        payment_attempt: storage::PaymentAttemptUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PaymentAttempt, errors::DataStorageError> {
+        let attempt = self
+            .diesel_store
+            .update_payment_attempt_with_attempt_id_and_status(
+                this.clone(),
+                payment_attempt,
+                storage_scheme,
+            )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_attempt(&attempt, Some(this))
+            .await
+        {
+            logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+        }
+
+        Ok(attempt)
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         connector_transaction_id: &str,
@@ -1393,6 +1531,19 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_filters(
+        &self,
+        runner: Option<String>,
+        status: Option<ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_filters(runner, status, business_status, limit, offset)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1919,8 +2070,14 @@ impl UserRoleInterface for KafkaStore {
             .update_user_role_by_user_id_merchant_id(user_id, merchant_id, update)
             .await
     }
-    async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
-        self.diesel_store.delete_user_role(user_id).await
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<user_storage::UserRole, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_by_user_id_merchant_id(user_id, merchant_id)
+            .await
     }
     async fn list_user_roles_by_user_id(
         &self,
@@ -1930,6 +2087,54 @@ impl UserRoleInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl RoleInterface for KafkaStore {
+    async fn insert_role(
+        &self,
+        role: role_storage::RoleNew,
+    ) -> CustomResult<role_storage::Role, errors::StorageError> {
+        self.diesel_store.insert_role(role).await
+    }
+    async fn find_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<role_storage::Role, errors::StorageError> {
+        self.diesel_store.find_role_by_role_id(role_id).await
+    }
+    async fn find_role_by_role_id_in_merchant_scope(
+        &self,
+        role_id: &str,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<role_storage::Role, errors::StorageError> {
+        self.diesel_store
+            .find_role_by_role_id_in_merchant_scope(role_id, merchant_id, org_id)
+            .await
+    }
+    async fn update_role_by_role_id(
+        &self,
+        role_id: &str,
+        role_update: role_storage::RoleUpdate,
+    ) -> CustomResult<role_storage::Role, errors::StorageError> {
+        self.diesel_store
+            .update_role_by_role_id(role_id, role_update)
+            .await
+    }
+    async fn delete_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store.delete_role_by_role_id(role_id).await
+    }
+    async fn list_all_roles(
+        &self,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<Vec<role_storage::Role>, errors::StorageError> {
+        self.diesel_store.list_all_roles(merchant_id, org_id).await
+    }
+}
+
 #[async_trait::async_trait]
 impl DashboardMetadataInterface for KafkaStore {
     async fn insert_metadata(
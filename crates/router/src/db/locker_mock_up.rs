@@ -164,9 +164,12 @@ mod tests {
         #[tokio::test]
         async fn find_locker_by_card_id() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -195,9 +198,12 @@ mod tests {
         #[tokio::test]
         async fn insert_locker_mock_up() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -225,9 +231,12 @@ mod tests {
         #[tokio::test]
         async fn delete_locker_mock_up() {
             #[allow(clippy::expect_used)]
-            let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
-                .await
-                .expect("Failed to create Mock store");
+            let mockdb = MockDb::new(&redis_interface::RedisSettings {
+                in_memory: true,
+                ..Default::default()
+            })
+            .await
+            .expect("Failed to create Mock store");
 
             let created_locker = mockdb
                 .insert_locker_mock_up(create_locker_mock_up_new(LockerMockUpIds {
@@ -776,9 +776,12 @@ mod merchant_connector_account_cache_tests {
     #[tokio::test]
     async fn test_connector_profile_id_cache() {
         #[allow(clippy::expect_used)]
-        let db = MockDb::new(&redis_interface::RedisSettings::default())
-            .await
-            .expect("Failed to create Mock store");
+        let db = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
 
         let redis_conn = db.get_redis_conn().unwrap();
         let master_key = db.get_master_key();
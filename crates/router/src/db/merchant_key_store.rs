@@ -210,9 +210,12 @@ mod tests {
     #[tokio::test]
     async fn test_mock_db_merchant_key_store_interface() {
         #[allow(clippy::expect_used)]
-        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
-            .await
-            .expect("Failed to create mock DB");
+        let mock_db = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create mock DB");
         let master_key = mock_db.get_master_key();
         let merchant_id = "merchant1";
 
@@ -82,6 +82,10 @@ impl OrganizationInterface for super::MockDb {
         let org = storage::Organization {
             org_id: organization.org_id.clone(),
             org_name: organization.org_name,
+            enforce_totp: false,
+            sso_config: None,
+            sso_client_secret: None,
+            enforce_sso: false,
         };
         organizations.push(org.clone());
         Ok(org)
@@ -120,6 +124,22 @@ impl OrganizationInterface for super::MockDb {
                     org_name: org_name.clone(),
                     ..org.to_owned()
                 },
+                storage::OrganizationUpdate::TotpEnforcementUpdate { enforce_totp } => {
+                    storage::Organization {
+                        enforce_totp: *enforce_totp,
+                        ..org.to_owned()
+                    }
+                }
+                storage::OrganizationUpdate::SsoConfigUpdate {
+                    sso_config,
+                    sso_client_secret,
+                    enforce_sso,
+                } => storage::Organization {
+                    sso_config: Some(sso_config.clone()),
+                    sso_client_secret: Some(sso_client_secret.clone()),
+                    enforce_sso: *enforce_sso,
+                    ..org.to_owned()
+                },
             })
             .ok_or(
                 errors::StorageError::ValueNotFound(format!(
@@ -369,6 +369,7 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        capture_id: new.capture_id.clone(),
                     };
 
                     let field = format!(
@@ -798,6 +799,7 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            capture_id: new.capture_id,
         };
         refunds.push(refund.clone());
         Ok(refund)
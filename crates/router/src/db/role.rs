@@ -0,0 +1,457 @@
+use diesel_models::{errors::DatabaseError, role as storage};
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "accounts_cache")]
+use storage_impl::redis::cache::{CacheKind, ACCOUNTS_CACHE};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+/// Custom roles are looked up on every request authenticated with a JWT, so they are cached by
+/// their role_id and redacted whenever they are updated or deleted
+#[cfg(feature = "accounts_cache")]
+fn get_role_cache_key(role_id: &str) -> String {
+    format!("role_{role_id}")
+}
+
+#[async_trait::async_trait]
+pub trait RoleInterface {
+    async fn insert_role(
+        &self,
+        role: storage::RoleNew,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
+    async fn find_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
+    async fn find_role_by_role_id_in_merchant_scope(
+        &self,
+        role_id: &str,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
+    async fn update_role_by_role_id(
+        &self,
+        role_id: &str,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError>;
+
+    async fn delete_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn list_all_roles(
+        &self,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoleInterface for Store {
+    async fn insert_role(
+        &self,
+        role: storage::RoleNew,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        role.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let fetch_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Role::find_by_role_id(&conn, role_id.to_owned())
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            fetch_func().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::get_or_populate_in_memory(
+                self,
+                &get_role_cache_key(role_id),
+                fetch_func,
+                &ACCOUNTS_CACHE,
+            )
+            .await
+        }
+    }
+
+    async fn find_role_by_role_id_in_merchant_scope(
+        &self,
+        role_id: &str,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Role::find_by_role_id_in_merchant_scope(
+                &conn,
+                role_id.to_owned(),
+                merchant_id.to_owned(),
+                org_id.to_owned(),
+            )
+            .await
+            .map_err(Into::into)
+            .into_report()
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            let role = self.find_role_by_role_id(role_id).await?;
+            if is_role_in_merchant_scope(&role, merchant_id, org_id) {
+                Ok(role)
+            } else {
+                Err(errors::StorageError::DatabaseError(
+                    DatabaseError::NotFound.into(),
+                ))
+                .into_report()
+                .attach_printable(format!(
+                    "No role available in the scope of merchant_id = {merchant_id} for role_id = {role_id}"
+                ))
+            }
+        }
+    }
+
+    async fn update_role_by_role_id(
+        &self,
+        role_id: &str,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let update_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Role::update_by_role_id(&conn, role_id.to_owned(), role_update)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            update_func().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(get_role_cache_key(role_id).into()),
+                update_func,
+            )
+            .await
+        }
+    }
+
+    async fn delete_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let delete_func = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Role::delete_by_role_id(&conn, role_id.to_owned())
+                .await
+                .map_err(Into::into)
+                .into_report()
+        };
+
+        #[cfg(not(feature = "accounts_cache"))]
+        {
+            delete_func().await
+        }
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            super::cache::publish_and_redact(
+                self,
+                CacheKind::Accounts(get_role_cache_key(role_id).into()),
+                delete_func,
+            )
+            .await
+        }
+    }
+
+    async fn list_all_roles(
+        &self,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::Role::list_roles(&conn, merchant_id.to_owned(), org_id.to_owned())
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl RoleInterface for MockDb {
+    async fn insert_role(
+        &self,
+        role: storage::RoleNew,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let mut roles = self.roles.lock().await;
+        if roles
+            .iter()
+            .any(|role_inner| role_inner.role_id == role.role_id)
+        {
+            Err(errors::StorageError::DatabaseError(
+                DatabaseError::UniqueViolation.into(),
+            ))
+            .into_report()
+            .attach_printable(format!(
+                "Role already exists for role_id = {}",
+                role.role_id
+            ))?
+        }
+        let role = storage::Role {
+            id: roles
+                .len()
+                .try_into()
+                .into_report()
+                .change_context(errors::StorageError::MockDbError)?,
+            role_name: role.role_name,
+            role_id: role.role_id,
+            merchant_id: role.merchant_id,
+            org_id: role.org_id,
+            permissions: role.permissions,
+            scope: role.scope,
+            created_at: role.created_at,
+            created_by: role.created_by,
+            last_modified_at: role.last_modified_at,
+            last_modified_by: role.last_modified_by,
+        };
+        roles.push(role.clone());
+        Ok(role)
+    }
+
+    async fn find_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let roles = self.roles.lock().await;
+        roles
+            .iter()
+            .find(|role| role.role_id == role_id)
+            .cloned()
+            .ok_or(errors::StorageError::DatabaseError(
+                DatabaseError::NotFound.into(),
+            ))
+            .into_report()
+            .attach_printable(format!("No role available for role_id = {role_id}"))
+    }
+
+    async fn find_role_by_role_id_in_merchant_scope(
+        &self,
+        role_id: &str,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let roles = self.roles.lock().await;
+        roles
+            .iter()
+            .find(|role| role.role_id == role_id && is_role_in_merchant_scope(role, merchant_id, org_id))
+            .cloned()
+            .ok_or(errors::StorageError::DatabaseError(DatabaseError::NotFound.into()))
+            .into_report()
+            .attach_printable(format!(
+                "No role available in the scope of merchant_id = {merchant_id} for role_id = {role_id}"
+            ))
+    }
+
+    async fn update_role_by_role_id(
+        &self,
+        role_id: &str,
+        role_update: storage::RoleUpdate,
+    ) -> CustomResult<storage::Role, errors::StorageError> {
+        let mut roles = self.roles.lock().await;
+        roles
+            .iter_mut()
+            .find(|role| role.role_id == role_id)
+            .map(|role| {
+                *role = match &role_update {
+                    storage::RoleUpdate::UpdateDetails {
+                        role_name,
+                        permissions,
+                        last_modified_by,
+                    } => storage::Role {
+                        role_name: role_name
+                            .clone()
+                            .unwrap_or_else(|| role.role_name.to_owned()),
+                        permissions: permissions
+                            .clone()
+                            .unwrap_or_else(|| role.permissions.to_owned()),
+                        last_modified_by: last_modified_by.to_owned(),
+                        last_modified_at: common_utils::date_time::now(),
+                        ..role.to_owned()
+                    },
+                };
+                role.to_owned()
+            })
+            .ok_or(errors::StorageError::DatabaseError(
+                DatabaseError::NotFound.into(),
+            ))
+            .into_report()
+            .attach_printable(format!("No role available for role_id = {role_id}"))
+    }
+
+    async fn delete_role_by_role_id(
+        &self,
+        role_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut roles = self.roles.lock().await;
+        let role_index = roles
+            .iter()
+            .position(|role| role.role_id == role_id)
+            .ok_or(errors::StorageError::DatabaseError(
+                DatabaseError::NotFound.into(),
+            ))
+            .into_report()
+            .attach_printable(format!("No role available for role_id = {role_id}"))?;
+        roles.remove(role_index);
+        Ok(true)
+    }
+
+    async fn list_all_roles(
+        &self,
+        merchant_id: &str,
+        org_id: &str,
+    ) -> CustomResult<Vec<storage::Role>, errors::StorageError> {
+        let roles = self.roles.lock().await;
+
+        Ok(roles
+            .iter()
+            .filter(|role| is_role_in_merchant_scope(role, merchant_id, org_id))
+            .cloned()
+            .collect())
+    }
+}
+
+fn is_role_in_merchant_scope(role: &storage::Role, merchant_id: &str, org_id: &str) -> bool {
+    role.merchant_id == merchant_id
+        || (role.org_id == org_id && role.scope == diesel_models::enums::RoleScope::Organization)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use diesel_models::{enums::RoleScope, role as storage};
+
+    use crate::db::{role::RoleInterface, MockDb};
+
+    fn role_new(role_id: &str, merchant_id: &str, scope: RoleScope) -> storage::RoleNew {
+        let now = common_utils::date_time::now();
+        storage::RoleNew {
+            role_name: format!("{role_id} name"),
+            role_id: role_id.to_string(),
+            merchant_id: merchant_id.to_string(),
+            org_id: "org_1".to_string(),
+            permissions: vec!["PaymentRead".to_string()],
+            scope,
+            created_at: now,
+            created_by: "user_1".to_string(),
+            last_modified_at: now,
+            last_modified_by: "user_1".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mockdb_custom_role_crud() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+
+        mockdb
+            .insert_role(role_new("role_1", "merchant_1", RoleScope::Merchant))
+            .await
+            .unwrap();
+        mockdb
+            .insert_role(role_new("role_2", "merchant_1", RoleScope::Organization))
+            .await
+            .unwrap();
+        let duplicate = mockdb
+            .insert_role(role_new("role_1", "merchant_1", RoleScope::Merchant))
+            .await;
+        assert!(duplicate
+            .unwrap_err()
+            .current_context()
+            .is_db_unique_violation());
+
+        // Organization scoped roles can be assigned in the other merchants of the organization
+        let roles = mockdb.list_all_roles("merchant_2", "org_1").await.unwrap();
+        assert_eq!(
+            roles
+                .iter()
+                .map(|role| role.role_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["role_2"]
+        );
+        assert!(mockdb
+            .find_role_by_role_id_in_merchant_scope("role_1", "merchant_2", "org_1")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+
+        let updated_role = mockdb
+            .update_role_by_role_id(
+                "role_1",
+                storage::RoleUpdate::UpdateDetails {
+                    role_name: Some("Payments viewer".to_string()),
+                    permissions: None,
+                    last_modified_by: "user_2".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated_role.role_name, "Payments viewer");
+        assert_eq!(updated_role.permissions, vec!["PaymentRead".to_string()]);
+        assert_eq!(updated_role.last_modified_by, "user_2");
+
+        assert!(mockdb.delete_role_by_role_id("role_1").await.unwrap());
+        assert!(mockdb
+            .find_role_by_role_id("role_1")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert!(mockdb
+            .update_role_by_role_id(
+                "role_1",
+                storage::RoleUpdate::UpdateDetails {
+                    role_name: None,
+                    permissions: None,
+                    last_modified_by: "user_2".to_string(),
+                },
+            )
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+        assert!(mockdb
+            .delete_role_by_role_id("role_1")
+            .await
+            .unwrap_err()
+            .current_context()
+            .is_db_not_found());
+    }
+}
@@ -1,4 +1,4 @@
-use diesel_models::{user as storage, user_role::UserRole};
+use diesel_models::{enums as storage_enums, user as storage, user_role::UserRole};
 use error_stack::{IntoReport, ResultExt};
 use masking::Secret;
 
@@ -145,6 +145,9 @@ impl UserInterface for MockDb {
             is_verified: user_data.is_verified,
             created_at: user_data.created_at.unwrap_or(time_now),
             last_modified_at: user_data.created_at.unwrap_or(time_now),
+            totp_status: Default::default(),
+            totp_secret: None,
+            totp_recovery_codes: None,
         };
         users.push(user.clone());
         Ok(user)
@@ -213,6 +216,24 @@ impl UserInterface for MockDb {
                         is_verified: is_verified.unwrap_or(user.is_verified),
                         ..user.to_owned()
                     },
+                    storage::UserUpdate::TotpUpdate {
+                        totp_status,
+                        totp_secret,
+                        totp_recovery_codes,
+                    } => storage::User {
+                        totp_status: totp_status.unwrap_or(user.totp_status),
+                        totp_secret: totp_secret.clone().or(user.totp_secret.clone()),
+                        totp_recovery_codes: totp_recovery_codes
+                            .clone()
+                            .or(user.totp_recovery_codes.clone()),
+                        ..user.to_owned()
+                    },
+                    storage::UserUpdate::TotpReset => storage::User {
+                        totp_status: storage_enums::TotpStatus::NotSet,
+                        totp_secret: None,
+                        totp_recovery_codes: None,
+                        ..user.to_owned()
+                    },
                 };
                 user.to_owned()
             })
@@ -24,7 +24,11 @@ pub trait UserRoleInterface {
         merchant_id: &str,
         update: storage::UserRoleUpdate,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
-    async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError>;
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
     async fn list_user_roles_by_user_id(
         &self,
@@ -75,12 +79,20 @@ impl UserRoleInterface for Store {
         .into_report()
     }
 
-    async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
         let conn = connection::pg_connection_write(self).await?;
-        storage::UserRole::delete_by_user_id(&conn, user_id.to_owned())
-            .await
-            .map_err(Into::into)
-            .into_report()
+        storage::UserRole::delete_by_user_id_merchant_id(
+            &conn,
+            user_id.to_owned(),
+            merchant_id.to_owned(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
     }
 
     async fn list_user_roles_by_user_id(
@@ -187,16 +199,21 @@ impl UserRoleInterface for MockDb {
             )
     }
 
-    async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
         let mut user_roles = self.user_roles.lock().await;
         let user_role_index = user_roles
             .iter()
-            .position(|user_role| user_role.user_id == user_id)
+            .position(|user_role| {
+                user_role.user_id == user_id && user_role.merchant_id == merchant_id
+            })
             .ok_or(errors::StorageError::ValueNotFound(format!(
-                "No user available for user_id = {user_id}"
+                "No user role available for user_id = {user_id} and merchant_id = {merchant_id}"
             )))?;
-        user_roles.remove(user_role_index);
-        Ok(true)
+        Ok(user_roles.remove(user_role_index))
     }
 
     async fn list_user_roles_by_user_id(
@@ -243,8 +260,14 @@ impl UserRoleInterface for super::KafkaStore {
     ) -> CustomResult<storage::UserRole, errors::StorageError> {
         self.diesel_store.find_user_role_by_user_id(user_id).await
     }
-    async fn delete_user_role(&self, user_id: &str) -> CustomResult<bool, errors::StorageError> {
-        self.diesel_store.delete_user_role(user_id).await
+    async fn delete_user_role_by_user_id_merchant_id(
+        &self,
+        user_id: &str,
+        merchant_id: &str,
+    ) -> CustomResult<storage::UserRole, errors::StorageError> {
+        self.diesel_store
+            .delete_user_role_by_user_id_merchant_id(user_id, merchant_id)
+            .await
     }
     async fn list_user_roles_by_user_id(
         &self,
@@ -144,6 +144,8 @@ pub fn mk_app(
             .service(routes::Routing::server(state.clone()))
             .service(routes::LockerMigrate::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
+            .service(routes::ProcessTracker::server(state.clone()))
+            .service(routes::AuditLog::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
             .service(routes::User::server(state.clone()))
     }
@@ -86,6 +86,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payments::payments_update,
         crate::routes::payments::payments_confirm,
         crate::routes::payments::payments_capture,
+        crate::routes::payments::payments_list_captures,
         crate::routes::payments::payments_connector_session,
     // crate::routes::payments::payments_redirect_response,
         crate::routes::payments::payments_cancel,
@@ -106,6 +107,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // crate::routes::api_keys::api_key_retrieve,
         // crate::routes::api_keys::api_key_update,
         // crate::routes::api_keys::api_key_revoke,
+        // crate::routes::api_keys::api_key_rotate,
         // crate::routes::api_keys::api_key_list,
         crate::routes::disputes::retrieve_disputes_list,
         crate::routes::disputes::retrieve_dispute,
@@ -115,6 +117,9 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payouts::payouts_retrieve,
         crate::routes::payouts::payouts_update,
         crate::routes::payment_link::payment_link_retrieve,
+        crate::routes::payment_link::payment_link_deactivate,
+        crate::routes::payment_link::payment_link_qr_code,
+        crate::routes::payment_link::payment_link_invoice,
         crate::routes::gsm::create_gsm_rule,
         crate::routes::gsm::get_gsm_rule,
         crate::routes::gsm::update_gsm_rule,
@@ -147,6 +152,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
         api_models::enums::RoutingAlgorithm,
+        api_models::enums::ApiKeyResource,
+        api_models::enums::ApiKeyVerb,
         api_models::enums::PaymentType,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
@@ -314,7 +321,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
         api_models::payments::PaymentAttemptResponse,
+        api_models::payments::FxQuote,
         api_models::payments::CaptureResponse,
+        api_models::payments::CaptureListResponse,
         api_models::payment_methods::RequiredFieldInfo,
         api_models::payment_methods::MaskedBankDetails,
         api_models::refunds::RefundListRequest,
@@ -352,16 +361,21 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
         crate::types::api::api_keys::ApiKeyExpiration,
+        crate::types::api::api_keys::ApiKeyScope,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
+        crate::types::api::api_keys::RotateApiKeyRequest,
         crate::types::api::api_keys::RevokeApiKeyResponse,
         crate::types::api::api_keys::UpdateApiKeyRequest,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
         api_models::payments::PaymentLinkInitiateRequest,
-        api_models::payments::PaymentLinkObject
+        api_models::payments::PaymentLinkObject,
+        api_models::enums::PaymentLinkType,
+        api_models::enums::PaymentLinkAmountType,
+        api_models::enums::PaymentLinkStatus,
     )),
     modifiers(&SecurityAddon)
 )]
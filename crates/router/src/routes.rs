@@ -1,6 +1,8 @@
 pub mod admin;
 pub mod api_keys;
 pub mod app;
+#[cfg(feature = "olap")]
+pub mod audit_log;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
@@ -22,6 +24,8 @@ pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
@@ -42,8 +46,6 @@ pub use self::app::DummyConnector;
 pub use self::app::Forex;
 #[cfg(feature = "payouts")]
 pub use self::app::Payouts;
-#[cfg(feature = "olap")]
-pub use self::app::Routing;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub use self::app::Verify;
 pub use self::app::{
@@ -51,6 +53,8 @@ pub use self::app::{
     Files, Gsm, Health, LockerMigrate, Mandates, MerchantAccount, MerchantConnectorAccount,
     PaymentLink, PaymentMethods, Payments, Refunds, User, Webhooks,
 };
+#[cfg(feature = "olap")]
+pub use self::app::{AuditLog, ProcessTracker, Routing};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
@@ -4,7 +4,7 @@ use actix_web::{web, Scope};
 #[cfg(all(feature = "kms", feature = "olap"))]
 use analytics::AnalyticsConfig;
 #[cfg(feature = "email")]
-use external_services::email::{ses::AwsSes, EmailService};
+use external_services::email::{ses::AwsSes, smtp::SmtpServer, EmailService};
 #[cfg(feature = "kms")]
 use external_services::kms::{self, decrypt::KmsDecrypt};
 #[cfg(all(feature = "olap", feature = "kms"))]
@@ -20,8 +20,6 @@ use super::currency;
 use super::dummy_connector::*;
 #[cfg(feature = "payouts")]
 use super::payouts::*;
-#[cfg(feature = "olap")]
-use super::routing as cloud_routing;
 #[cfg(all(feature = "olap", feature = "kms"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
@@ -29,6 +27,8 @@ use super::{
     admin::*, api_keys::*, disputes::*, files::*, gsm::*, locker_migration, payment_link::*,
     user::*, user_role::*,
 };
+#[cfg(feature = "olap")]
+use super::{audit_log, process_tracker, routing as cloud_routing};
 use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
@@ -38,6 +38,7 @@ use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::routes::verify_connector::payment_connector_verify;
 pub use crate::{
     configs::settings,
+    core::audit_log::AuditContext,
     db::{StorageImpl, StorageInterface},
     events::EventsHandler,
     routes::cards_info::card_iin_info,
@@ -57,6 +58,8 @@ pub struct AppState {
     pub api_client: Box<dyn crate::services::ApiClient>,
     #[cfg(feature = "olap")]
     pub pool: crate::analytics::AnalyticsProvider,
+    pub audit_context: AuditContext,
+    pub client_ip: Option<String>,
 }
 
 impl scheduler::SchedulerAppState for AppState {
@@ -74,7 +77,11 @@ pub trait AppStateInfo {
     fn add_request_id(&mut self, request_id: RequestId);
     fn add_merchant_id(&mut self, merchant_id: Option<String>);
     fn add_flow_name(&mut self, flow_name: String);
+    fn add_audit_context(&mut self, audit_context: AuditContext);
+    fn add_client_ip(&mut self, client_ip: Option<String>);
     fn get_request_id(&self) -> Option<String>;
+    fn get_flow_name(&self) -> String;
+    fn get_client_ip(&self) -> Option<String>;
 }
 
 impl AppStateInfo for AppState {
@@ -100,11 +107,24 @@ impl AppStateInfo for AppState {
         self.api_client.add_merchant_id(merchant_id);
     }
     fn add_flow_name(&mut self, flow_name: String) {
-        self.api_client.add_flow_name(flow_name);
+        self.api_client.add_flow_name(flow_name.clone());
+        self.flow_name = flow_name;
+    }
+    fn add_audit_context(&mut self, audit_context: AuditContext) {
+        self.audit_context = audit_context;
+    }
+    fn add_client_ip(&mut self, client_ip: Option<String>) {
+        self.client_ip = client_ip;
     }
     fn get_request_id(&self) -> Option<String> {
         self.api_client.get_request_id()
     }
+    fn get_flow_name(&self) -> String {
+        self.flow_name.clone()
+    }
+    fn get_client_ip(&self) -> Option<String> {
+        self.client_ip.clone()
+    }
 }
 
 impl AsRef<Self> for AppState {
@@ -113,11 +133,20 @@ impl AsRef<Self> for AppState {
     }
 }
 
+/// # Panics
+///
+/// Panics if the SMTP email client is selected but its configuration is missing
 #[cfg(feature = "email")]
-pub async fn create_email_client(settings: &settings::Settings) -> impl EmailService {
+pub async fn create_email_client(settings: &settings::Settings) -> Arc<dyn EmailService> {
     match settings.email.active_email_client {
         external_services::email::AvailableEmailClients::SES => {
-            AwsSes::create(&settings.email, settings.proxy.https_url.to_owned()).await
+            Arc::new(AwsSes::create(&settings.email, settings.proxy.https_url.to_owned()).await)
+        }
+        external_services::email::AvailableEmailClients::SMTP => {
+            #[allow(clippy::expect_used)]
+            let smtp_server =
+                SmtpServer::create(&settings.email).expect("Failed to create SMTP email client");
+            Arc::new(smtp_server)
         }
     }
 }
@@ -125,15 +154,22 @@ pub async fn create_email_client(settings: &settings::Settings) -> impl EmailSer
 impl AppState {
     /// # Panics
     ///
-    /// Panics if Store can't be created or JWE decryption fails
+    /// Panics if Store can't be created, JWE decryption fails or the configuration secrets can't
+    /// be decrypted
     pub async fn with_storage(
-        #[cfg_attr(not(all(feature = "olap", feature = "kms")), allow(unused_mut))]
-        mut conf: settings::Settings,
+        conf: settings::Settings,
         storage_impl: StorageImpl,
         shut_down_signal: oneshot::Sender<()>,
         api_client: Box<dyn crate::services::ApiClient>,
     ) -> Self {
         Box::pin(async move {
+            #[allow(clippy::expect_used)]
+            #[cfg_attr(not(all(feature = "olap", feature = "kms")), allow(unused_mut))]
+            let mut conf = conf
+                .decrypt_secrets()
+                .await
+                .expect("Failed to decrypt the configuration secrets");
+            storage_impl::redis::cache::set_cache_settings(conf.cache.clone());
             #[cfg(feature = "kms")]
             let kms_client = kms::get_kms_client(&conf.kms).await;
             let testable = storage_impl == StorageImpl::PostgresqlTest;
@@ -198,7 +234,7 @@ impl AppState {
             .expect("Failed while performing KMS decryption");
 
             #[cfg(feature = "email")]
-            let email_client = Arc::new(create_email_client(&conf).await);
+            let email_client = create_email_client(&conf).await;
 
             Self {
                 flow_name: String::from("default"),
@@ -212,6 +248,8 @@ impl AppState {
                 event_handler,
                 #[cfg(feature = "olap")]
                 pool,
+                audit_context: AuditContext::default(),
+                client_ip: None,
             }
         })
         .await
@@ -324,6 +362,9 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/captures").route(web::get().to(payments_list_captures)),
+                )
                 .service(
                     web::resource("/redirect/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -362,6 +403,10 @@ impl Forex {
             .service(
                 web::resource("/convert_from_minor").route(web::get().to(currency::convert_forex)),
             )
+            .service(
+                web::resource("/convert_at_date")
+                    .route(web::get().to(currency::convert_forex_at_date)),
+            )
     }
 }
 
@@ -683,6 +728,7 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
     }
 }
 
@@ -704,6 +750,10 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/assemble")
+                    .route(web::post().to(assemble_dispute_evidence)),
+            )
             .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
     }
 }
@@ -754,10 +804,26 @@ impl PaymentLink {
             .service(
                 web::resource("/{payment_link_id}").route(web::get().to(payment_link_retrieve)),
             )
+            .service(
+                web::resource("/{payment_link_id}/deactivate")
+                    .route(web::post().to(payment_link_deactivate)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/qr_code")
+                    .route(web::get().to(payment_link_qr_code)),
+            )
+            .service(
+                web::resource("/{payment_link_id}/invoice")
+                    .route(web::get().to(payment_link_invoice)),
+            )
             .service(
                 web::resource("{merchant_id}/{payment_id}")
                     .route(web::get().to(initiate_payment_link)),
             )
+            .service(
+                web::resource("{merchant_id}/{payment_id}/payments")
+                    .route(web::post().to(payment_link_create_payment)),
+            )
     }
 }
 
@@ -796,6 +862,48 @@ impl Gsm {
     }
 }
 
+pub struct AuditLog;
+
+#[cfg(feature = "olap")]
+impl AuditLog {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_log::list_audit_logs)))
+    }
+}
+
+pub struct ProcessTracker;
+
+#[cfg(feature = "olap")]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/tasks").route(web::get().to(process_tracker::list_processes)))
+            .service(
+                web::resource("/stream_stats")
+                    .route(web::get().to(process_tracker::retrieve_stream_stats)),
+            )
+            .service(
+                web::resource("/tasks/{process_id}")
+                    .route(web::get().to(process_tracker::retrieve_process)),
+            )
+            .service(
+                web::resource("/tasks/{process_id}/reschedule")
+                    .route(web::post().to(process_tracker::reschedule_process)),
+            )
+            .service(
+                web::resource("/tasks/{process_id}/force_run")
+                    .route(web::post().to(process_tracker::force_run_process)),
+            )
+            .service(
+                web::resource("/tasks/{process_id}/cancel")
+                    .route(web::post().to(process_tracker::cancel_process)),
+            )
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub struct Verify;
 
@@ -841,11 +949,45 @@ impl User {
             )
             .service(web::resource("/switch/list").route(web::get().to(list_merchant_ids_for_user)))
             .service(web::resource("/user/list").route(web::get().to(get_user_details)))
+            .service(
+                web::scope("/totp")
+                    .service(web::resource("/begin").route(web::get().to(totp_begin)))
+                    .service(web::resource("/verify").route(web::post().to(totp_verify)))
+                    .service(web::resource("/reset").route(web::post().to(totp_reset)))
+                    .service(
+                        web::resource("/enforce").route(web::post().to(totp_enforcement_update)),
+                    ),
+            )
+            .service(
+                web::scope("/sso")
+                    .service(web::resource("/config").route(web::post().to(sso_config_update)))
+                    .service(web::resource("/authorize").route(web::get().to(sso_authorize)))
+                    .service(web::resource("/callback").route(web::post().to(sso_callback))),
+            )
             // User Role APIs
             .service(web::resource("/permission_info").route(web::get().to(get_authorization_info)))
             .service(web::resource("/user/update_role").route(web::post().to(update_user_role)))
+            .service(web::resource("/user/delete").route(web::delete().to(delete_user_role)))
+            .service(web::resource("/role").route(web::post().to(create_role)))
             .service(web::resource("/role/list").route(web::get().to(list_roles)))
-            .service(web::resource("/role/{role_id}").route(web::get().to(get_role)));
+            .service(
+                web::resource("/role/{role_id}")
+                    .route(web::get().to(get_role))
+                    .route(web::post().to(update_role))
+                    .route(web::delete().to(delete_role)),
+            );
+
+        #[cfg(feature = "email")]
+        {
+            route = route
+                .service(web::resource("/user/invite").route(web::post().to(invite_user)))
+                .service(
+                    web::resource("/user/invite/accept")
+                        .route(web::post().to(accept_invite_from_email)),
+                )
+                .service(web::resource("/forgot_password").route(web::post().to(forgot_password)))
+                .service(web::resource("/reset_password").route(web::post().to(reset_password)));
+        }
 
         #[cfg(feature = "dummy_connector")]
         {
@@ -0,0 +1,32 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::audit_log as audit_log_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, audit_log},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogList))]
+pub async fn list_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<audit_log_api::AuditLogListConstraints>,
+) -> impl Responder {
+    let flow = Flow::AuditLogList;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        |state, user: auth::UserFromToken, req| {
+            audit_log::list_audit_logs(state, user.merchant_id, req)
+        },
+        &auth::JWTAuth(Permission::AuditLogRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -56,3 +56,34 @@ pub async fn convert_forex(
     ))
     .await
 }
+
+pub async fn convert_forex_at_date(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    params: web::Query<api_models::currency::CurrencyConversionAtDateParams>,
+) -> HttpResponse {
+    let flow = Flow::RetrieveForexFlow;
+    let params = params.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        params,
+        |state, _, params| {
+            currency::convert_forex_at_date(
+                state,
+                params.amount,
+                params.to_currency,
+                params.from_currency,
+                params.date,
+            )
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::ForexRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
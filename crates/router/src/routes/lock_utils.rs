@@ -1,6 +1,6 @@
 use router_env::Flow;
 
-#[derive(Clone, Debug, strum::Display)]
+#[derive(Clone, Debug, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "snake_case")]
 pub enum ApiIdentifier {
     Payments,
@@ -28,6 +28,8 @@ pub enum ApiIdentifier {
     Gsm,
     User,
     UserRole,
+    ProcessTracker,
+    AuditLog,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -83,6 +85,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
+            | Flow::PaymentMethodsAttach
             | Flow::ValidatePaymentMethod => Self::PaymentMethods,
 
             Flow::PaymentsCreate
@@ -90,12 +93,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
+            | Flow::PaymentsListCaptures
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::ChargesRetrieve
+            | Flow::ChargesList
             | Flow::PaymentsRedirect => Self::Payments,
 
             Flow::PayoutsCreate
@@ -110,19 +116,23 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsUpdate
             | Flow::RefundsList => Self::Refunds,
 
-            Flow::IncomingWebhookReceive => Self::Webhooks,
+            Flow::IncomingWebhookReceive | Flow::EventsRetrieve | Flow::EventsList => {
+                Self::Webhooks
+            }
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
             | Flow::ApiKeyRevoke
+            | Flow::ApiKeyRotate
             | Flow::ApiKeyList => Self::ApiKeys,
 
             Flow::DisputesRetrieve
             | Flow::DisputesList
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
-            | Flow::RetrieveDisputeEvidence => Self::Disputes,
+            | Flow::RetrieveDisputeEvidence
+            | Flow::AssembleDisputeEvidence => Self::Disputes,
 
             Flow::CardsInfo => Self::CardsInfo,
 
@@ -136,9 +146,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileDelete
             | Flow::BusinessProfileList => Self::Business,
 
-            Flow::PaymentLinkRetrieve | Flow::PaymentLinkInitiate | Flow::PaymentLinkList => {
-                Self::PaymentLink
-            }
+            Flow::PaymentLinkRetrieve
+            | Flow::PaymentLinkInitiate
+            | Flow::PaymentLinkList
+            | Flow::PaymentLinkDeactivate
+            | Flow::PaymentLinkQrCode
+            | Flow::PaymentLinkInvoice
+            | Flow::PaymentLinkCreatePayment => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -159,11 +173,36 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GenerateSampleData
             | Flow::DeleteSampleData
             | Flow::UserMerchantAccountList
-            | Flow::GetUserDetails => Self::User,
-
-            Flow::ListRoles | Flow::GetRole | Flow::UpdateUserRole | Flow::GetAuthorizationInfo => {
-                Self::UserRole
-            }
+            | Flow::GetUserDetails
+            | Flow::TotpBegin
+            | Flow::TotpVerify
+            | Flow::TotpReset
+            | Flow::TotpEnforcementUpdate
+            | Flow::SsoConfigUpdate
+            | Flow::SsoAuthorize
+            | Flow::SsoCallback
+            | Flow::InviteUser
+            | Flow::AcceptInviteFromEmail
+            | Flow::ForgotPassword
+            | Flow::ResetPassword => Self::User,
+
+            Flow::ListRoles
+            | Flow::GetRole
+            | Flow::UpdateUserRole
+            | Flow::GetAuthorizationInfo
+            | Flow::CreateRole
+            | Flow::UpdateRole
+            | Flow::DeleteRole
+            | Flow::DeleteUserRole => Self::UserRole,
+
+            Flow::ProcessTrackerList
+            | Flow::ProcessTrackerRetrieve
+            | Flow::ProcessTrackerReschedule
+            | Flow::ProcessTrackerForceRun
+            | Flow::ProcessTrackerCancel
+            | Flow::SchedulerStreamStats => Self::ProcessTracker,
+
+            Flow::AuditLogList => Self::AuditLog,
         }
     }
 }
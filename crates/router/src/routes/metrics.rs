@@ -50,6 +50,11 @@ counter_metric!(CUSTOMER_REDACTED, GLOBAL_METER);
 counter_metric!(API_KEY_CREATED, GLOBAL_METER);
 counter_metric!(API_KEY_REVOKED, GLOBAL_METER);
 
+// Rate limiting metrics
+counter_metric!(RATE_LIMIT_ALLOWED, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_EXCEEDED, GLOBAL_METER);
+counter_metric!(RATE_LIMIT_LOCAL_FALLBACK, GLOBAL_METER); // Redis was not available
+
 counter_metric!(MCA_CREATE, GLOBAL_METER);
 
 // Flow Specific Metrics
@@ -81,6 +81,35 @@ pub async fn initiate_payment_link(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkCreatePayment))]
+pub async fn payment_link_create_payment(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::payments::PaymentLinkPaymentRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkCreatePayment;
+    let (merchant_id, payment_id) = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, payload| {
+            create_payment_link_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id.clone(),
+                payload,
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payment Link - List
 ///
 /// To list the payment links
@@ -123,3 +152,121 @@ pub async fn payments_link_list(
     )
     .await
 }
+
+/// Payment Link - Deactivate
+///
+/// To deactivate a Payment Link, after which it can no longer be used for payments
+#[utoipa::path(
+    post,
+    path = "/payment_link/{payment_link_id}/deactivate",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Payment link deactivated", body = RetrievePaymentLinkResponse),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Deactivate a Payment Link",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkDeactivate))]
+pub async fn payment_link_deactivate(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkDeactivate;
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth, payment_link_id| {
+            deactivate_payment_link(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Payment Link - QR Code
+///
+/// To retrieve a QR code image, in PNG format, that opens the Payment Link when scanned
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}/qr_code",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "QR code of the payment link", content_type = "image/png"),
+        (status = 404, description = "No payment link found")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link QR Code",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkQrCode))]
+pub async fn payment_link_qr_code(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkQrCode;
+    let payment_link_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth, payment_link_id| {
+            retrieve_payment_link_qr_code(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Payment Link - Invoice
+///
+/// To retrieve a printable PDF invoice for a Payment Link, or a receipt once it has been paid
+#[utoipa::path(
+    get,
+    path = "/payment_link/{payment_link_id}/invoice",
+    params(
+        ("payment_link_id" = String, Path, description = "The identifier for payment link")
+    ),
+    responses(
+        (status = 200, description = "Invoice of the payment link", content_type = "application/pdf"),
+        (status = 404, description = "No payment link found"),
+        (status = 412, description = "The payment link is expired or deactivated")
+    ),
+    tag = "Payment Link",
+    operation_id = "Retrieve a Payment Link Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkInvoice))]
+pub async fn payment_link_invoice(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkInvoice;
+    let payment_link_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_link_id,
+        |state, auth, payment_link_id| {
+            retrieve_payment_link_invoice(state, auth.merchant_account, payment_link_id)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
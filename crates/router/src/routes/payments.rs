@@ -543,6 +543,48 @@ pub async fn payments_capture(
     ))
     .await
 }
+/// Payments - List Captures
+///
+/// To list all the captures made against a payment, for payments captured in multiple parts
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/captures",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Captures of the payment retrieved", body = CaptureListResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "List Captures of a Payment",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsListCaptures))]
+// #[get("/{payment_id}/captures")]
+pub async fn payments_list_captures(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsListCaptures;
+    let payment_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth, payment_id| payments::list_captures(state, auth.merchant_account, payment_id),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Payments - Session token
 ///
 /// To create the session object or to get session token for wallets
@@ -0,0 +1,125 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker as pt_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, process_tracker},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn list_processes(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<pt_types::ProcessTrackerListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerList;
+    let payload = query_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, constraints| process_tracker::list_processes(state, constraints),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn retrieve_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRetrieve;
+    let process_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &process_id,
+        |state, _, process_id| process_tracker::retrieve_process(state, process_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerReschedule))]
+pub async fn reschedule_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<pt_types::ProcessTrackerRescheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerReschedule;
+    let process_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request| process_tracker::reschedule_process(state, &process_id, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerForceRun))]
+pub async fn force_run_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerForceRun;
+    let process_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &process_id,
+        |state, _, process_id| process_tracker::force_run_process(state, process_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerCancel))]
+pub async fn cancel_process(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerCancel;
+    let process_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &process_id,
+        |state, _, process_id| process_tracker::cancel_process(state, process_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SchedulerStreamStats))]
+pub async fn retrieve_stream_stats(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::SchedulerStreamStats;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _| process_tracker::retrieve_stream_stats(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -31,6 +31,7 @@ use crate::{
     consts,
     core::{
         api_locking,
+        audit_log::AuditContext,
         errors::{self, CustomResult},
         payments,
     },
@@ -792,6 +793,18 @@ where
 
     let mut event_type = payload.get_api_event_type();
 
+    // The flow and the client address are needed to authenticate restricted API keys
+    request_state.add_flow_name(flow.to_string());
+    let client_ip = get_client_ip(
+        request.peer_addr(),
+        request
+            .headers()
+            .get_all(crate::headers::X_FORWARDED_FOR)
+            .filter_map(|value| value.to_str().ok()),
+        &request_state.conf().server.trusted_proxies,
+    );
+    request_state.add_client_ip(client_ip.clone());
+
     // Currently auth failures are not recorded as API events
     let (auth_out, auth_type) = api_auth
         .authenticate_and_fetch(request.headers(), &request_state)
@@ -805,10 +818,20 @@ where
 
     request_state.add_merchant_id(Some(merchant_id.clone()));
 
-    request_state.add_flow_name(flow.to_string());
+    request_state.add_audit_context(AuditContext::new(&auth_type, client_ip));
 
     tracing::Span::current().record("merchant_id", &merchant_id);
 
+    // Currently rate limited requests are not recorded as API events either
+    super::rate_limit::check_rate_limits(
+        &request_state,
+        &request_state.conf().rate_limit,
+        &flow.to_string(),
+        &auth_type,
+    )
+    .await
+    .switch()?;
+
     let output = {
         lock_action
             .clone()
@@ -1450,12 +1473,111 @@ pub fn build_redirection_form(
     }
 }
 
+/// Returns the IP address of the client. This is the address of the peer, unless the peer is one of
+/// the trusted proxies, in which case it is the last address in `X-Forwarded-For` that is not a
+/// trusted proxy. The forwarded addresses are ignored for other peers, as clients can set them
+/// freely.
+pub fn get_client_ip<'a>(
+    peer_addr: Option<std::net::SocketAddr>,
+    forwarded_for: impl Iterator<Item = &'a str>,
+    trusted_proxies: &[String],
+) -> Option<String> {
+    let is_trusted_proxy =
+        |ip: &str| super::authentication::api_key_scope::is_ip_allowed(trusted_proxies, ip);
+
+    let peer_ip = peer_addr?.ip().to_string();
+    if !is_trusted_proxy(&peer_ip) {
+        return Some(peer_ip);
+    }
+
+    let forwarded_ips = forwarded_for
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    let mut client_ip = peer_ip;
+    for forwarded_ip in forwarded_ips.into_iter().rev() {
+        // An address that cannot be parsed leaves the client unknown rather than attributing the
+        // request to the proxy
+        forwarded_ip.parse::<std::net::IpAddr>().ok()?;
+        client_ip = forwarded_ip.to_string();
+        if !is_trusted_proxy(forwarded_ip) {
+            break;
+        }
+    }
+    Some(client_ip)
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_client_ip_is_only_forwarded_by_trusted_proxies() {
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+        let client = "203.0.113.7:52341".parse().ok();
+        let proxy = "10.0.0.2:52341".parse().ok();
+
+        // Clients cannot choose the address they are attributed to
+        assert_eq!(
+            get_client_ip(client, ["198.51.100.1"].into_iter(), &trusted_proxies).as_deref(),
+            Some("203.0.113.7")
+        );
+        assert_eq!(
+            get_client_ip(client, std::iter::empty(), &[]).as_deref(),
+            Some("203.0.113.7")
+        );
+
+        // The addresses added by the trusted proxies are skipped, while the ones sent by the client
+        // before them are not trusted
+        assert_eq!(
+            get_client_ip(
+                proxy,
+                ["198.51.100.1, 203.0.113.7", "10.0.0.1"].into_iter(),
+                &trusted_proxies
+            )
+            .as_deref(),
+            Some("203.0.113.7")
+        );
+        assert_eq!(
+            get_client_ip(proxy, ["not an ip"].into_iter(), &trusted_proxies),
+            None
+        );
+        assert_eq!(
+            get_client_ip(proxy, std::iter::empty(), &trusted_proxies).as_deref(),
+            Some("10.0.0.2")
+        );
+    }
+
+    #[test]
+    fn test_ip_allowlist_rejects_spoofed_forwarded_for() {
+        let ip_allowlist = vec!["198.51.100.0/24".to_string()];
+        let trusted_proxies = vec!["10.0.0.0/8".to_string()];
+        let is_allowed = |peer_addr: &str, forwarded_for: &str| {
+            get_client_ip(
+                peer_addr.parse().ok(),
+                [forwarded_for].into_iter(),
+                &trusted_proxies,
+            )
+            .map_or(false, |client_ip| {
+                crate::services::authentication::api_key_scope::is_ip_allowed(
+                    &ip_allowlist,
+                    &client_ip,
+                )
+            })
+        };
+
+        // A client outside the allowlist claiming an allowed address, directly or through a proxy
+        assert!(!is_allowed("203.0.113.7:52341", "198.51.100.1"));
+        assert!(!is_allowed("10.0.0.2:52341", "198.51.100.1, 203.0.113.7"));
+
+        assert!(is_allowed("198.51.100.1:52341", "203.0.113.7"));
+        assert!(is_allowed("10.0.0.2:52341", "203.0.113.7, 198.51.100.1"));
+    }
 }
 
 pub fn build_payment_link_html(
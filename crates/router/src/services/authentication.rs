@@ -29,6 +29,9 @@ use crate::{
     utils::OptionExt,
 };
 
+pub mod api_key_scope;
+pub mod blacklist;
+
 #[derive(Clone, Debug)]
 pub struct AuthenticationData {
     pub merchant_account: domain::MerchantAccount,
@@ -45,6 +48,7 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: String,
         key_id: String,
+        scopes: Option<Vec<String>>,
     },
     AdminApiKey,
     MerchantJwt {
@@ -60,16 +64,17 @@ pub enum AuthenticationType {
     WebhookAuth {
         merchant_id: String,
     },
+    SinglePurposeJwt {
+        user_id: String,
+        purpose: TokenPurpose,
+    },
     NoAuth,
 }
 
 impl AuthenticationType {
     pub fn get_merchant_id(&self) -> Option<&str> {
         match self {
-            Self::ApiKey {
-                merchant_id,
-                key_id: _,
-            }
+            Self::ApiKey { merchant_id, .. }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
             | Self::MerchantJwt {
@@ -77,7 +82,7 @@ impl AuthenticationType {
                 user_id: _,
             }
             | Self::WebhookAuth { merchant_id } => Some(merchant_id.as_ref()),
-            Self::AdminApiKey | Self::NoAuth => None,
+            Self::AdminApiKey | Self::SinglePurposeJwt { .. } | Self::NoAuth => None,
         }
     }
 }
@@ -113,6 +118,46 @@ impl AuthToken {
     }
 }
 
+/// The step of the sign in flow a single purpose token can be used for
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenPurpose {
+    Totp,
+}
+
+/// A short lived token which only authorizes a single step of the sign in flow. It does not
+/// carry a merchant or role and is therefore rejected by the dashboard authentication.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct SinglePurposeToken {
+    pub user_id: String,
+    pub purpose: TokenPurpose,
+    pub exp: u64,
+}
+
+#[cfg(feature = "olap")]
+impl SinglePurposeToken {
+    pub async fn new_token(
+        user_id: String,
+        purpose: TokenPurpose,
+        settings: &settings::Settings,
+    ) -> UserResult<String> {
+        let exp_duration =
+            std::time::Duration::from_secs(consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS);
+        let exp = jwt::generate_exp(exp_duration)?.as_secs();
+        let token_payload = Self {
+            user_id,
+            purpose,
+            exp,
+        };
+        jwt::generate_jwt(&token_payload, settings).await
+    }
+}
+
+#[derive(Clone)]
+pub struct UserIdFromToken {
+    pub user_id: String,
+}
+
 #[derive(Clone)]
 pub struct UserFromToken {
     pub user_id: String,
@@ -188,15 +233,13 @@ where
         }
 
         let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            api_keys::get_hash_key(
-                &config.api_keys,
-                #[cfg(feature = "kms")]
-                kms::get_kms_client(&config.kms).await,
-            )
-            .await?
-        };
+        let config = state.conf();
+        let hash_key = api_keys::get_hash_key(
+            &config.api_keys,
+            #[cfg(feature = "kms")]
+            kms::get_kms_client(&config.kms).await,
+        )
+        .await?;
         let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
         let stored_api_key = state
@@ -208,11 +251,7 @@ where
             .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
             .attach_printable("Merchant not authenticated")?;
 
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
+        if api_keys::is_api_key_expired(&stored_api_key, date_time::now()) {
             return Err(report!(errors::ApiErrorResponse::Unauthorized))
                 .attach_printable("API key has expired");
         }
@@ -227,12 +266,43 @@ where
             .change_context(errors::ApiErrorResponse::Unauthorized)
             .attach_printable("Failed to fetch merchant key store for the merchant id")?;
 
-        let merchant = state
+        if let Some(scopes) = &stored_api_key.scopes {
+            if !api_key_scope::is_flow_allowed(scopes, &state.get_flow_name()) {
+                return Err(report!(errors::ApiErrorResponse::AccessForbidden {
+                    resource: state.get_flow_name(),
+                }))
+                .attach_printable("API key is not allowed to perform this operation");
+            }
+        }
+
+        if let Some(ip_allowlist) = &stored_api_key.ip_allowlist {
+            let is_ip_allowed = state
+                .get_client_ip()
+                .map(|client_ip| api_key_scope::is_ip_allowed(ip_allowlist, &client_ip))
+                .unwrap_or(false);
+            if !is_ip_allowed {
+                return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                    .attach_printable("API key is not allowed from the client IP address");
+            }
+        }
+
+        let mut merchant = state
             .store()
             .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        api_keys::record_api_key_usage(
+            state.store().as_ref(),
+            &config.api_keys,
+            &stored_api_key,
+            date_time::now(),
+        )
+        .await;
+
+        // Requests made with an API key bound to a business profile can only use that profile
+        merchant.bound_profile_id = stored_api_key.profile_id;
+
         let auth = AuthenticationData {
             merchant_account: merchant,
             key_store,
@@ -242,6 +312,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.merchant_id.clone(),
                 key_id: stored_api_key.key_id,
+                scopes: stored_api_key.scopes,
             },
         ))
     }
@@ -418,8 +489,14 @@ where
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions(
+            state,
+            &payload.role_id,
+            &payload.merchant_id,
+            &payload.org_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
 
         Ok((
             (),
@@ -444,8 +521,14 @@ where
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions(
+            state,
+            &payload.role_id,
+            &payload.merchant_id,
+            &payload.org_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
 
         Ok((
             UserFromToken {
@@ -479,8 +562,14 @@ where
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.required_permission, permissions)?;
+        let permissions = authorization::get_permissions(
+            state,
+            &payload.role_id,
+            &payload.merchant_id,
+            &payload.org_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.required_permission, &permissions)?;
 
         // Check if token has access to MerchantId that has been requested through query param
         if payload.merchant_id != self.merchant_id {
@@ -498,19 +587,63 @@ where
 
 pub async fn parse_jwt_payload<A, T>(headers: &HeaderMap, state: &A) -> RouterResult<T>
 where
-    T: serde::de::DeserializeOwned,
+    T: serde::de::DeserializeOwned + UserTokenPayload,
     A: AppStateInfo + Sync,
 {
     let token = get_jwt_from_authorization_header(headers)?;
-    let payload = decode_jwt(token, state).await?;
+    decode_user_jwt(token, state).await
+}
+
+/// Decodes a token issued on a completed sign in, rejecting it if the tokens of its user were
+/// revoked after it was issued
+async fn decode_user_jwt<A, T>(token: &str, state: &A) -> RouterResult<T>
+where
+    T: serde::de::DeserializeOwned + UserTokenPayload,
+    A: AppStateInfo + Sync,
+{
+    let payload = decode_jwt::<T>(token, state).await?;
+
+    if blacklist::check_user_in_blacklist(state, payload.get_user_id(), payload.get_exp()).await? {
+        return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+    }
 
     Ok(payload)
 }
 
+/// The claims of the tokens issued on a completed sign in, which are checked against the revoked
+/// tokens of the user
+pub trait UserTokenPayload {
+    fn get_user_id(&self) -> &str;
+    fn get_exp(&self) -> u64;
+}
+
+impl UserTokenPayload for AuthToken {
+    fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    fn get_exp(&self) -> u64 {
+        self.exp
+    }
+}
+
+impl UserTokenPayload for JwtAuthPayloadFetchMerchantAccount {
+    fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    fn get_exp(&self) -> u64 {
+        self.exp
+    }
+}
+
 #[derive(serde::Deserialize)]
 struct JwtAuthPayloadFetchMerchantAccount {
+    user_id: String,
+    exp: u64,
     merchant_id: String,
     role_id: String,
+    org_id: String,
 }
 
 #[async_trait]
@@ -527,8 +660,14 @@ where
             parse_jwt_payload::<A, JwtAuthPayloadFetchMerchantAccount>(request_headers, state)
                 .await?;
 
-        let permissions = authorization::get_permissions(&payload.role_id)?;
-        authorization::check_authorization(&self.0, permissions)?;
+        let permissions = authorization::get_permissions(
+            state,
+            &payload.role_id,
+            &payload.merchant_id,
+            &payload.org_id,
+        )
+        .await?;
+        authorization::check_authorization(&self.0, &permissions)?;
 
         let key_store = state
             .store()
@@ -560,6 +699,69 @@ where
     }
 }
 
+/// Authenticates the requests made with a single purpose token. When `allow_login_token` is set,
+/// the token issued on a completed sign in is accepted as well.
+pub struct SinglePurposeJWTAuth {
+    pub purpose: TokenPurpose,
+    pub allow_login_token: bool,
+}
+
+#[cfg(feature = "olap")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<UserIdFromToken, A> for SinglePurposeJWTAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(UserIdFromToken, AuthenticationType)> {
+        let token = get_jwt_from_authorization_header(request_headers)?;
+
+        if let Ok(payload) = decode_jwt::<SinglePurposeToken>(token, state).await {
+            if payload.purpose != self.purpose {
+                return Err(report!(errors::ApiErrorResponse::InvalidJwtToken))
+                    .attach_printable("Token was issued for a different purpose");
+            }
+            if blacklist::check_single_purpose_token_in_blacklist(
+                state,
+                &payload.user_id,
+                payload.exp,
+            )
+            .await?
+            {
+                return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+            }
+            return Ok((
+                UserIdFromToken {
+                    user_id: payload.user_id.clone(),
+                },
+                AuthenticationType::SinglePurposeJwt {
+                    user_id: payload.user_id,
+                    purpose: payload.purpose,
+                },
+            ));
+        }
+
+        if !self.allow_login_token {
+            return Err(report!(errors::ApiErrorResponse::InvalidJwtToken));
+        }
+
+        let payload = decode_user_jwt::<A, AuthToken>(token, state).await?;
+
+        Ok((
+            UserIdFromToken {
+                user_id: payload.user_id.clone(),
+            },
+            AuthenticationType::MerchantJwt {
+                merchant_id: payload.merchant_id,
+                user_id: Some(payload.user_id),
+            },
+        ))
+    }
+}
+
 pub struct DashboardNoPermissionAuth;
 
 #[cfg(feature = "olap")]
@@ -601,7 +803,7 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
-        parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
 
         Ok(((), AuthenticationType::NoAuth))
     }
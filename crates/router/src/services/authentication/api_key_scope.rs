@@ -0,0 +1,282 @@
+use std::{net::IpAddr, str::FromStr};
+
+use api_models::api_keys::ApiKeyScope;
+use common_enums::{ApiKeyResource, ApiKeyVerb};
+use router_env::{logger, Flow};
+
+/// Returns the resource and the operation that a flow performs, for the flows that restricted API
+/// Keys can be granted access to. Restricted API Keys are not allowed to perform any other flow.
+pub fn get_flow_scope(flow: &Flow) -> Option<(ApiKeyResource, ApiKeyVerb)> {
+    use ApiKeyResource as Resource;
+    use ApiKeyVerb as Verb;
+
+    let scope = match flow {
+        Flow::PaymentsCreate | Flow::PaymentsSessionToken => (Resource::Payments, Verb::Create),
+        Flow::PaymentsRetrieve
+        | Flow::PaymentsList
+        | Flow::PaymentsListCaptures
+        | Flow::ChargesRetrieve
+        | Flow::ChargesList => (Resource::Payments, Verb::Read),
+        Flow::PaymentsUpdate
+        | Flow::PaymentsConfirm
+        | Flow::PaymentsCapture
+        | Flow::PaymentsCancel
+        | Flow::PaymentsApprove
+        | Flow::PaymentsReject => (Resource::Payments, Verb::Update),
+
+        Flow::RefundsCreate => (Resource::Refunds, Verb::Create),
+        Flow::RefundsRetrieve | Flow::RefundsList => (Resource::Refunds, Verb::Read),
+        Flow::RefundsUpdate => (Resource::Refunds, Verb::Update),
+
+        Flow::CustomersCreate => (Resource::Customers, Verb::Create),
+        Flow::CustomersRetrieve | Flow::CustomersList | Flow::CustomersGetMandates => {
+            (Resource::Customers, Verb::Read)
+        }
+        Flow::CustomersUpdate => (Resource::Customers, Verb::Update),
+        Flow::CustomersDelete => (Resource::Customers, Verb::Delete),
+
+        Flow::PaymentMethodsCreate | Flow::ValidatePaymentMethod => {
+            (Resource::PaymentMethods, Verb::Create)
+        }
+        Flow::PaymentMethodsList
+        | Flow::CustomerPaymentMethodsList
+        | Flow::PaymentMethodsRetrieve => (Resource::PaymentMethods, Verb::Read),
+        Flow::PaymentMethodsUpdate | Flow::PaymentMethodsAttach => {
+            (Resource::PaymentMethods, Verb::Update)
+        }
+        Flow::PaymentMethodsDelete => (Resource::PaymentMethods, Verb::Delete),
+
+        Flow::MandatesRetrieve | Flow::MandatesList => (Resource::Mandates, Verb::Read),
+        Flow::MandatesRevoke => (Resource::Mandates, Verb::Delete),
+
+        Flow::DisputesRetrieve | Flow::DisputesList | Flow::RetrieveDisputeEvidence => {
+            (Resource::Disputes, Verb::Read)
+        }
+        Flow::DisputesEvidenceSubmit
+        | Flow::AttachDisputeEvidence
+        | Flow::AssembleDisputeEvidence => (Resource::Disputes, Verb::Update),
+
+        Flow::CreateFile => (Resource::Files, Verb::Create),
+        Flow::RetrieveFile => (Resource::Files, Verb::Read),
+        Flow::DeleteFile => (Resource::Files, Verb::Delete),
+
+        Flow::PayoutsCreate => (Resource::Payouts, Verb::Create),
+        Flow::PayoutsRetrieve | Flow::PayoutsAccounts => (Resource::Payouts, Verb::Read),
+        Flow::PayoutsUpdate | Flow::PayoutsCancel | Flow::PayoutsFulfill => {
+            (Resource::Payouts, Verb::Update)
+        }
+
+        Flow::EventsRetrieve | Flow::EventsList => (Resource::Events, Verb::Read),
+
+        Flow::RoutingCreateConfig | Flow::DecisionManagerUpsertConfig => {
+            (Resource::Routing, Verb::Create)
+        }
+        Flow::RoutingRetrieveConfig
+        | Flow::RoutingRetrieveActiveConfig
+        | Flow::RoutingRetrieveDefaultConfig
+        | Flow::RoutingRetrieveDictionary
+        | Flow::DecisionManagerRetrieveConfig => (Resource::Routing, Verb::Read),
+        Flow::RoutingLinkConfig
+        | Flow::RoutingUnlinkConfig
+        | Flow::RoutingUpdateConfig
+        | Flow::RoutingUpdateDefaultConfig => (Resource::Routing, Verb::Update),
+        Flow::RoutingDeleteConfig | Flow::DecisionManagerDeleteConfig => {
+            (Resource::Routing, Verb::Delete)
+        }
+
+        Flow::PaymentLinkRetrieve | Flow::PaymentLinkList => (Resource::PaymentLinks, Verb::Read),
+        Flow::PaymentLinkDeactivate => (Resource::PaymentLinks, Verb::Update),
+
+        Flow::MerchantsAccountRetrieve => (Resource::MerchantAccount, Verb::Read),
+        Flow::MerchantsAccountUpdate => (Resource::MerchantAccount, Verb::Update),
+
+        Flow::MerchantConnectorsCreate => (Resource::MerchantConnectors, Verb::Create),
+        Flow::MerchantConnectorsRetrieve | Flow::MerchantConnectorsList => {
+            (Resource::MerchantConnectors, Verb::Read)
+        }
+        Flow::MerchantConnectorsUpdate => (Resource::MerchantConnectors, Verb::Update),
+        Flow::MerchantConnectorsDelete => (Resource::MerchantConnectors, Verb::Delete),
+
+        Flow::EphemeralKeyCreate => (Resource::EphemeralKeys, Verb::Create),
+        Flow::EphemeralKeyDelete => (Resource::EphemeralKeys, Verb::Delete),
+
+        _ => return None,
+    };
+
+    Some(scope)
+}
+
+/// Returns whether the stored scopes of an API Key allow it to perform the flow. A flow that is
+/// unknown or that cannot be granted to restricted API Keys is never allowed.
+pub fn is_flow_allowed(stored_scopes: &[String], flow_name: &str) -> bool {
+    let Some((resource, verb)) = Flow::from_str(flow_name)
+        .ok()
+        .as_ref()
+        .and_then(get_flow_scope)
+    else {
+        return false;
+    };
+
+    let scope = to_stored_scope(resource, verb);
+    stored_scopes
+        .iter()
+        .any(|stored_scope| *stored_scope == scope)
+}
+
+/// Returns whether the client address, either an IP address or an `ip:port` pair, is within one
+/// of the IP addresses or CIDR ranges of the allowlist
+pub fn is_ip_allowed(ip_allowlist: &[String], client_ip: &str) -> bool {
+    let Some(client_ip) = parse_client_ip(client_ip) else {
+        return false;
+    };
+
+    ip_allowlist
+        .iter()
+        .filter_map(|entry| parse_ip_range(entry))
+        .any(|(network, prefix_length)| is_ip_in_range(client_ip, network, prefix_length))
+}
+
+/// Returns whether the allowlist entry is an IP address or a CIDR range
+pub fn is_valid_ip_range(entry: &str) -> bool {
+    parse_ip_range(entry).is_some()
+}
+
+/// Converts the scopes of an API Key into the `resource:verb` entries it is stored as
+pub fn to_stored_scopes(scopes: &[ApiKeyScope]) -> Vec<String> {
+    let mut stored_scopes = Vec::new();
+    for scope in scopes {
+        for verb in &scope.verbs {
+            let stored_scope = to_stored_scope(scope.resource, *verb);
+            if !stored_scopes.contains(&stored_scope) {
+                stored_scopes.push(stored_scope);
+            }
+        }
+    }
+    stored_scopes
+}
+
+/// Converts the `resource:verb` entries an API Key is stored with back into its scopes, grouping
+/// the operations by resource
+pub fn from_stored_scopes(stored_scopes: &[String]) -> Vec<ApiKeyScope> {
+    let mut scopes: Vec<ApiKeyScope> = Vec::new();
+    for stored_scope in stored_scopes {
+        let Some((resource, verb)) = parse_stored_scope(stored_scope) else {
+            logger::warn!(%stored_scope, "Ignoring an invalid API key scope");
+            continue;
+        };
+
+        match scopes.iter_mut().find(|scope| scope.resource == resource) {
+            Some(scope) => scope.verbs.push(verb),
+            None => scopes.push(ApiKeyScope {
+                resource,
+                verbs: vec![verb],
+            }),
+        }
+    }
+    scopes
+}
+
+fn to_stored_scope(resource: ApiKeyResource, verb: ApiKeyVerb) -> String {
+    format!("{resource}:{verb}")
+}
+
+fn parse_stored_scope(stored_scope: &str) -> Option<(ApiKeyResource, ApiKeyVerb)> {
+    let (resource, verb) = stored_scope.split_once(':')?;
+    Some((
+        ApiKeyResource::from_str(resource).ok()?,
+        ApiKeyVerb::from_str(verb).ok()?,
+    ))
+}
+
+fn parse_client_ip(client_ip: &str) -> Option<IpAddr> {
+    IpAddr::from_str(client_ip)
+        .ok()
+        .or_else(|| {
+            std::net::SocketAddr::from_str(client_ip)
+                .ok()
+                .map(|socket_address| socket_address.ip())
+        })
+        .map(|ip| match ip {
+            IpAddr::V6(ipv6) => ipv6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            IpAddr::V4(_) => ip,
+        })
+}
+
+fn parse_ip_range(entry: &str) -> Option<(IpAddr, u32)> {
+    let (address, prefix_length) = match entry.split_once('/') {
+        Some((address, prefix_length)) => (address, Some(prefix_length)),
+        None => (entry, None),
+    };
+    let network = IpAddr::from_str(address.trim()).ok()?;
+    let max_prefix_length = if network.is_ipv4() { 32 } else { 128 };
+    let prefix_length = match prefix_length {
+        Some(prefix_length) => prefix_length.trim().parse::<u32>().ok()?,
+        None => max_prefix_length,
+    };
+
+    (prefix_length <= max_prefix_length).then_some((network, prefix_length))
+}
+
+fn is_ip_in_range(ip: IpAddr, network: IpAddr, prefix_length: u32) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix_length).unwrap_or(0);
+            u32::from(ip) & mask == u32::from(network) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix_length).unwrap_or(0);
+            u128::from(ip) & mask == u128::from(network) & mask
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_allowlist() {
+        let ip_allowlist = vec![
+            "203.0.113.7".to_string(),
+            "198.51.100.0/24".to_string(),
+            "2001:db8::/32".to_string(),
+        ];
+
+        assert!(is_ip_allowed(&ip_allowlist, "203.0.113.7"));
+        assert!(is_ip_allowed(&ip_allowlist, "203.0.113.7:52341"));
+        assert!(is_ip_allowed(&ip_allowlist, "198.51.100.255"));
+        assert!(is_ip_allowed(&ip_allowlist, "[2001:db8::1]:443"));
+        assert!(!is_ip_allowed(&ip_allowlist, "203.0.113.8"));
+        assert!(!is_ip_allowed(&ip_allowlist, "198.51.101.1"));
+        assert!(!is_ip_allowed(&ip_allowlist, "not an ip"));
+        assert!(!is_valid_ip_range("198.51.100.0/33"));
+    }
+
+    #[test]
+    fn test_scopes_round_trip_and_flow_check() {
+        let scopes = vec![ApiKeyScope {
+            resource: ApiKeyResource::Payments,
+            verbs: vec![ApiKeyVerb::Read, ApiKeyVerb::Read],
+        }];
+        let stored_scopes = to_stored_scopes(&scopes);
+
+        assert_eq!(stored_scopes, vec!["payments:read".to_string()]);
+        assert_eq!(
+            from_stored_scopes(&stored_scopes)[0].verbs,
+            vec![ApiKeyVerb::Read]
+        );
+        assert!(is_flow_allowed(
+            &stored_scopes,
+            &Flow::PaymentsRetrieve.to_string()
+        ));
+        assert!(!is_flow_allowed(
+            &stored_scopes,
+            &Flow::PaymentsCreate.to_string()
+        ));
+        assert!(!is_flow_allowed(
+            &stored_scopes,
+            &Flow::ApiKeyCreate.to_string()
+        ));
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::RedisConnectionPool;
+
+#[cfg(feature = "olap")]
+use crate::consts;
+use crate::{
+    consts::{JWT_TOKEN_TIME_IN_SECS, USER_BLACKLIST_PREFIX},
+    core::errors::{ApiErrorResponse, RouterResult},
+    routes::app::AppStateInfo,
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{UserErrors, UserResult},
+    routes::AppState,
+};
+
+/// Revokes the tokens issued to the user so far, by recording the time of revocation for as long
+/// as the tokens can be valid
+#[cfg(feature = "olap")]
+pub async fn insert_user_in_blacklist(state: &AppState, user_id: &str) -> UserResult<()> {
+    let user_blacklist_key = format!("{}{}", USER_BLACKLIST_PREFIX, user_id);
+    let expiry =
+        expiry_to_i64(JWT_TOKEN_TIME_IN_SECS).change_context(UserErrors::InternalServerError)?;
+    let redis_conn = get_redis_connection(state).change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_key_with_expiry(
+            user_blacklist_key.as_str(),
+            common_utils::date_time::now_unix_timestamp(),
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Revokes the single purpose tokens issued to the user so far, once the sign in they were issued
+/// for is complete
+#[cfg(feature = "olap")]
+pub async fn insert_single_purpose_tokens_in_blacklist(
+    state: &AppState,
+    user_id: &str,
+) -> UserResult<()> {
+    let token_blacklist_key = format!(
+        "{}{}",
+        consts::SINGLE_PURPOSE_TOKEN_BLACKLIST_PREFIX,
+        user_id
+    );
+    let expiry = expiry_to_i64(consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS)
+        .change_context(UserErrors::InternalServerError)?;
+    let redis_conn = get_redis_connection(state).change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_key_with_expiry(
+            token_blacklist_key.as_str(),
+            common_utils::date_time::now_unix_timestamp(),
+            expiry,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Marks the email token as used, for as long as the token can be valid
+#[cfg(all(feature = "olap", feature = "email"))]
+pub async fn insert_email_token_in_blacklist(state: &AppState, token: &str) -> UserResult<()> {
+    let email_token_blacklist_key = format!("{}{}", consts::EMAIL_TOKEN_BLACKLIST_PREFIX, token);
+    let expiry = expiry_to_i64(consts::EMAIL_TOKEN_TIME_IN_SECS)
+        .change_context(UserErrors::InternalServerError)?;
+    let redis_conn = get_redis_connection(state).change_context(UserErrors::InternalServerError)?;
+    redis_conn
+        .set_key_with_expiry(email_token_blacklist_key.as_str(), true, expiry)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+#[cfg(all(feature = "olap", feature = "email"))]
+pub async fn check_email_token_in_blacklist(state: &AppState, token: &str) -> UserResult<()> {
+    let email_token_blacklist_key = format!("{}{}", consts::EMAIL_TOKEN_BLACKLIST_PREFIX, token);
+    let redis_conn = get_redis_connection(state).change_context(UserErrors::InternalServerError)?;
+    let key_exists = redis_conn
+        .exists::<&str>(email_token_blacklist_key.as_str())
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if key_exists {
+        return Err(UserErrors::LinkInvalid).into_report();
+    }
+    Ok(())
+}
+
+/// Checks whether the token was issued before the tokens of the user were revoked
+pub async fn check_user_in_blacklist<A: AppStateInfo>(
+    state: &A,
+    user_id: &str,
+    token_expiry: u64,
+) -> RouterResult<bool> {
+    let user_blacklist_key = format!("{}{}", USER_BLACKLIST_PREFIX, user_id);
+    let token_issued_at = expiry_to_i64(token_expiry.saturating_sub(JWT_TOKEN_TIME_IN_SECS))?;
+    let redis_conn = get_redis_connection(state)?;
+    redis_conn
+        .get_key::<Option<i64>>(user_blacklist_key.as_str())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .map(|revoked_at| revoked_at.map_or(false, |revoked_at| revoked_at > token_issued_at))
+}
+
+/// Checks whether the single purpose token was issued before the single purpose tokens of the user
+/// were revoked. Tokens issued in the second of the revocation are rejected as well, as the token
+/// completing the sign in is usually issued shortly before.
+#[cfg(feature = "olap")]
+pub async fn check_single_purpose_token_in_blacklist<A: AppStateInfo>(
+    state: &A,
+    user_id: &str,
+    token_expiry: u64,
+) -> RouterResult<bool> {
+    let token_blacklist_key = format!(
+        "{}{}",
+        consts::SINGLE_PURPOSE_TOKEN_BLACKLIST_PREFIX,
+        user_id
+    );
+    let token_issued_at =
+        expiry_to_i64(token_expiry.saturating_sub(consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS))?;
+    let redis_conn = get_redis_connection(state)?;
+    redis_conn
+        .get_key::<Option<i64>>(token_blacklist_key.as_str())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .map(|revoked_at| revoked_at.map_or(false, |revoked_at| revoked_at >= token_issued_at))
+}
+
+fn get_redis_connection<A: AppStateInfo>(state: &A) -> RouterResult<Arc<RedisConnectionPool>> {
+    state
+        .store()
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+fn expiry_to_i64(expiry: u64) -> RouterResult<i64> {
+    i64::try_from(expiry)
+        .into_report()
+        .change_context(ApiErrorResponse::InternalServerError)
+}
@@ -1,14 +1,52 @@
-use crate::core::errors::{ApiErrorResponse, RouterResult};
+use std::str::FromStr;
+
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
+    routes::app::AppStateInfo,
+};
 
 pub mod info;
 pub mod permissions;
 pub mod predefined_permissions;
 
-pub fn get_permissions(role: &str) -> RouterResult<&Vec<permissions::Permission>> {
-    predefined_permissions::PREDEFINED_PERMISSIONS
-        .get(role)
-        .map(|role_info| role_info.get_permissions())
-        .ok_or(ApiErrorResponse::InvalidJwtToken.into())
+/// Returns the permissions of the role, which is either one of the predefined roles or a custom
+/// role which can be assigned within the merchant
+pub async fn get_permissions<A>(
+    state: &A,
+    role_id: &str,
+    merchant_id: &str,
+    org_id: &str,
+) -> RouterResult<Vec<permissions::Permission>>
+where
+    A: AppStateInfo + Sync,
+{
+    if let Some(role_info) = predefined_permissions::PREDEFINED_PERMISSIONS.get(role_id) {
+        return Ok(role_info.get_permissions().to_vec());
+    }
+
+    let role = state
+        .store()
+        .find_role_by_role_id_in_merchant_scope(role_id, merchant_id, org_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::InvalidJwtToken)
+        .attach_printable("Failed to fetch the custom role")?;
+
+    Ok(parse_permissions(&role.permissions))
+}
+
+/// Parses the permissions of a custom role, skipping the ones which are no longer supported
+pub fn parse_permissions(permissions: &[String]) -> Vec<permissions::Permission> {
+    permissions
+        .iter()
+        .filter_map(|permission| {
+            permissions::Permission::from_str(permission)
+                .map_err(|error| logger::error!(?error, %permission, "Invalid permission in role"))
+                .ok()
+        })
+        .collect()
 }
 
 pub fn check_authorization(
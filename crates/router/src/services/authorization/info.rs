@@ -42,6 +42,7 @@ pub enum PermissionModule {
     Files,
     ThreeDsDecisionManager,
     SurchargeDecisionManager,
+    AuditLogs,
 }
 
 impl PermissionModule {
@@ -58,7 +59,8 @@ impl PermissionModule {
             Self::Disputes => "Everything related to disputes - like creating and viewing dispute related information are within this module",
             Self::Files => "Permissions for uploading, deleting and viewing files for disputes",
             Self::ThreeDsDecisionManager => "View and configure 3DS decision rules configured for a merchant",
-            Self::SurchargeDecisionManager =>"View and configure surcharge decision rules configured for a merchant"
+            Self::SurchargeDecisionManager =>"View and configure surcharge decision rules configured for a merchant",
+            Self::AuditLogs => "View the trail of changes made to the accounts, connectors, routing, API keys and users of a merchant"
         }
     }
 }
@@ -163,6 +165,11 @@ impl ModuleInfo {
                     Permission::SurchargeDecisionManagerRead,
                 ]),
             },
+            PermissionModule::AuditLogs => Self {
+                module: module_name,
+                description,
+                permissions: PermissionInfo::new(&[Permission::AuditLogRead]),
+            },
         }
     }
 }
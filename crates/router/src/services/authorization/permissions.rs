@@ -1,6 +1,6 @@
-use strum::Display;
+use strum::{Display, EnumString};
 
-#[derive(PartialEq, Display, Clone, Debug)]
+#[derive(PartialEq, Display, EnumString, Clone, Debug)]
 pub enum Permission {
     PaymentRead,
     PaymentWrite,
@@ -28,6 +28,7 @@ pub enum Permission {
     SurchargeDecisionManagerRead,
     UsersRead,
     UsersWrite,
+    AuditLogRead,
     MerchantAccountCreate,
 }
 
@@ -68,6 +69,7 @@ impl Permission {
             Self::SurchargeDecisionManagerRead => Some("View all the surcharge decision rules"),
             Self::UsersRead => Some("View all the users for a merchant"),
             Self::UsersWrite => Some("Invite users, assign and update roles"),
+            Self::AuditLogRead => Some("View the audit log of changes made to the merchant"),
             Self::MerchantAccountCreate => None,
         }
     }
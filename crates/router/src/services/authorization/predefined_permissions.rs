@@ -57,6 +57,7 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::Analytics,
                 Permission::UsersRead,
                 Permission::UsersWrite,
+                Permission::AuditLogRead,
                 Permission::MerchantAccountCreate,
             ],
             name: None,
@@ -81,6 +82,7 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::MandateRead,
                 Permission::FileRead,
                 Permission::UsersRead,
+                Permission::AuditLogRead,
             ],
             name: None,
             is_invitable: false,
@@ -117,6 +119,7 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::Analytics,
                 Permission::UsersRead,
                 Permission::UsersWrite,
+                Permission::AuditLogRead,
                 Permission::MerchantAccountCreate,
             ],
             name: Some("Organization Admin"),
@@ -155,6 +158,7 @@ pub static PREDEFINED_PERMISSIONS: Lazy<HashMap<&'static str, RoleInfo>> = Lazy:
                 Permission::Analytics,
                 Permission::UsersRead,
                 Permission::UsersWrite,
+                Permission::AuditLogRead,
             ],
             name: Some("Admin"),
             is_invitable: true,
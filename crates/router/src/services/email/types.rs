@@ -1,5 +1,6 @@
-use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use api_models::admin::{EmailTemplates, MerchantAlertType};
+use common_utils::{errors::CustomResult, pii};
+use error_stack::{IntoReport, ResultExt};
 use external_services::email::{EmailContents, EmailData, EmailError};
 use masking::ExposeInterface;
 
@@ -8,14 +9,38 @@ use crate::{configs, consts};
 use crate::{core::errors::UserErrors, services::jwt, types::domain};
 
 pub enum EmailBody {
-    Verify { link: String },
-    Reset { link: String, user_name: String },
-    MagicLink { link: String, user_name: String },
-    InviteUser { link: String, user_name: String },
+    Verify {
+        link: String,
+    },
+    Reset {
+        link: String,
+        user_name: String,
+    },
+    MagicLink {
+        link: String,
+        user_name: String,
+    },
+    InviteUser {
+        link: String,
+        user_name: String,
+    },
+    PaymentReceipt {
+        merchant_name: String,
+        payment_id: String,
+        amount: String,
+        currency: String,
+        footer: String,
+    },
+    MerchantAlert {
+        title: String,
+        message: String,
+        profile_name: String,
+        footer: String,
+    },
 }
 
 pub mod html {
-    use crate::services::email::types::EmailBody;
+    use crate::services::email::types::{escape_html, EmailBody};
 
     pub fn get_html_body(email_body: EmailBody) -> String {
         match email_body {
@@ -43,26 +68,84 @@ pub mod html {
                     link = link
                 )
             }
+            // The footer is HTML provided by the merchant, the other values are escaped
+            EmailBody::PaymentReceipt {
+                merchant_name,
+                payment_id,
+                amount,
+                currency,
+                footer,
+            } => {
+                format!(
+                    include_str!("assets/payment_receipt.html"),
+                    merchant_name = escape_html(&merchant_name),
+                    payment_id = escape_html(&payment_id),
+                    amount = escape_html(&amount),
+                    currency = escape_html(&currency),
+                    footer = footer
+                )
+            }
+            EmailBody::MerchantAlert {
+                title,
+                message,
+                profile_name,
+                footer,
+            } => {
+                format!(
+                    include_str!("assets/merchant_alert.html"),
+                    title = escape_html(&title),
+                    message = escape_html(&message),
+                    profile_name = escape_html(&profile_name),
+                    footer = footer
+                )
+            }
         }
     }
 }
 
+/// The flow an email token is sent for, so that a link sent for one flow cannot be used in
+/// another
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTokenPurpose {
+    VerifyEmail,
+    ResetPassword,
+    MagicLink,
+    AcceptInvite,
+}
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct EmailToken {
     email: String,
-    expiration: u64,
+    purpose: EmailTokenPurpose,
+    exp: u64,
 }
 
 impl EmailToken {
+    pub fn get_email(&self) -> CustomResult<pii::Email, UserErrors> {
+        pii::Email::try_from(self.email.clone()).change_context(UserErrors::InternalServerError)
+    }
+
+    pub fn validate_purpose(&self, purpose: EmailTokenPurpose) -> CustomResult<(), UserErrors> {
+        if self.purpose != purpose {
+            return Err(UserErrors::LinkInvalid)
+                .into_report()
+                .attach_printable("Email token was issued for a different purpose");
+        }
+        Ok(())
+    }
+
     pub async fn new_token(
         email: domain::UserEmail,
+        purpose: EmailTokenPurpose,
         settings: &configs::settings::Settings,
     ) -> CustomResult<String, UserErrors> {
         let expiration_duration = std::time::Duration::from_secs(consts::EMAIL_TOKEN_TIME_IN_SECS);
-        let expiration = jwt::generate_exp(expiration_duration)?.as_secs();
+        let exp = jwt::generate_exp(expiration_duration)?.as_secs();
         let token_payload = Self {
             email: email.get_secret().expose(),
-            expiration,
+            purpose,
+            exp,
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -86,9 +169,13 @@ pub struct VerifyEmail {
 #[async_trait::async_trait]
 impl EmailData for VerifyEmail {
     async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
-        let token = EmailToken::new_token(self.recipient_email.clone(), &self.settings)
-            .await
-            .change_context(EmailError::TokenGenerationFailure)?;
+        let token = EmailToken::new_token(
+            self.recipient_email.clone(),
+            EmailTokenPurpose::VerifyEmail,
+            &self.settings,
+        )
+        .await
+        .change_context(EmailError::TokenGenerationFailure)?;
 
         let verify_email_link =
             get_link_with_token(&self.settings.server.base_url, token, "verify_email");
@@ -115,9 +202,13 @@ pub struct ResetPassword {
 #[async_trait::async_trait]
 impl EmailData for ResetPassword {
     async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
-        let token = EmailToken::new_token(self.recipient_email.clone(), &self.settings)
-            .await
-            .change_context(EmailError::TokenGenerationFailure)?;
+        let token = EmailToken::new_token(
+            self.recipient_email.clone(),
+            EmailTokenPurpose::ResetPassword,
+            &self.settings,
+        )
+        .await
+        .change_context(EmailError::TokenGenerationFailure)?;
 
         let reset_password_link =
             get_link_with_token(&self.settings.server.base_url, token, "set_password");
@@ -145,9 +236,13 @@ pub struct MagicLink {
 #[async_trait::async_trait]
 impl EmailData for MagicLink {
     async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
-        let token = EmailToken::new_token(self.recipient_email.clone(), &self.settings)
-            .await
-            .change_context(EmailError::TokenGenerationFailure)?;
+        let token = EmailToken::new_token(
+            self.recipient_email.clone(),
+            EmailTokenPurpose::MagicLink,
+            &self.settings,
+        )
+        .await
+        .change_context(EmailError::TokenGenerationFailure)?;
 
         let magic_link_login = get_link_with_token(&self.settings.server.base_url, token, "login");
 
@@ -174,14 +269,21 @@ pub struct InviteUser {
 #[async_trait::async_trait]
 impl EmailData for InviteUser {
     async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
-        let token = EmailToken::new_token(self.recipient_email.clone(), &self.settings)
-            .await
-            .change_context(EmailError::TokenGenerationFailure)?;
+        let token = EmailToken::new_token(
+            self.recipient_email.clone(),
+            EmailTokenPurpose::AcceptInvite,
+            &self.settings,
+        )
+        .await
+        .change_context(EmailError::TokenGenerationFailure)?;
 
-        let invite_user_link =
-            get_link_with_token(&self.settings.server.base_url, token, "set_password");
+        let invite_user_link = get_link_with_token(
+            &self.settings.server.base_url,
+            token,
+            "accept_invite_from_email",
+        );
 
-        let body = html::get_html_body(EmailBody::MagicLink {
+        let body = html::get_html_body(EmailBody::InviteUser {
             link: invite_user_link,
             user_name: self.user_name.clone().get_secret().expose(),
         });
@@ -193,3 +295,231 @@ impl EmailData for InviteUser {
         })
     }
 }
+
+/// Escapes the characters of a value that have a meaning in HTML, so that it is shown as text
+pub fn escape_html(value: &str) -> String {
+    value.chars().fold(
+        String::with_capacity(value.len()),
+        |mut escaped, character| {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#x27;"),
+                character => escaped.push(character),
+            }
+            escaped
+        },
+    )
+}
+
+/// Replaces the `{{placeholder}}` occurrences in a merchant provided HTML template with the
+/// HTML-escaped values
+pub fn fill_template_placeholders(template: &str, values: &[(&str, &str)]) -> String {
+    let escaped_values = values
+        .iter()
+        .map(|(placeholder, value)| (*placeholder, escape_html(value)))
+        .collect::<Vec<_>>();
+    replace_placeholders(template, &escaped_values)
+}
+
+fn replace_placeholders<V: AsRef<str>>(template: &str, values: &[(&str, V)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |filled, (placeholder, value)| {
+            filled.replace(&format!("{{{{{placeholder}}}}}"), value.as_ref())
+        })
+}
+
+pub struct PaymentReceipt {
+    pub recipient_email: pii::Email,
+    pub merchant_name: String,
+    pub profile_name: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub templates: Option<EmailTemplates>,
+}
+
+impl PaymentReceipt {
+    fn placeholder_values(&self) -> [(&str, &str); 5] {
+        [
+            ("merchant_name", self.merchant_name.as_str()),
+            ("profile_name", self.profile_name.as_str()),
+            ("payment_id", self.payment_id.as_str()),
+            ("amount", self.amount.as_str()),
+            ("currency", self.currency.as_str()),
+        ]
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentReceipt {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let values = self.placeholder_values();
+        let templates = self.templates.clone().unwrap_or_default();
+
+        // The subject is plain text, so its values are not escaped
+        let subject = templates
+            .receipt_subject
+            .map(|subject| replace_placeholders(&subject, &values))
+            .unwrap_or_else(|| format!("Your receipt from {}", self.merchant_name));
+
+        let body = templates
+            .receipt_body
+            .map(|body| fill_template_placeholders(&body, &values))
+            .unwrap_or_else(|| {
+                html::get_html_body(EmailBody::PaymentReceipt {
+                    merchant_name: self.merchant_name.clone(),
+                    payment_id: self.payment_id.clone(),
+                    amount: self.amount.clone(),
+                    currency: self.currency.clone(),
+                    footer: templates
+                        .footer
+                        .map(|footer| fill_template_placeholders(&footer, &values))
+                        .unwrap_or_default(),
+                })
+            });
+
+        Ok(EmailContents {
+            subject,
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct MerchantAlert {
+    pub recipient_email: pii::Email,
+    pub alert_type: MerchantAlertType,
+    pub message: String,
+    pub profile_name: String,
+    pub footer: Option<String>,
+}
+
+impl MerchantAlert {
+    fn get_title(&self) -> &'static str {
+        match self.alert_type {
+            MerchantAlertType::DisputeOpened => "A dispute was opened",
+            MerchantAlertType::HighRefundVolume => "Unusually high refund volume",
+            MerchantAlertType::WebhookEndpointFailing => "Your webhook endpoint is failing",
+            MerchantAlertType::DisputeDeadlineApproaching => {
+                "The evidence of a dispute is due soon"
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailData for MerchantAlert {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let title = self.get_title();
+
+        let body = html::get_html_body(EmailBody::MerchantAlert {
+            title: title.to_string(),
+            message: self.message.clone(),
+            profile_name: self.profile_name.clone(),
+            footer: self.footer.clone().unwrap_or_default(),
+        });
+
+        Ok(EmailContents {
+            subject: format!("[{}] {title}", self.profile_name),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn payment_receipt(templates: Option<EmailTemplates>) -> PaymentReceipt {
+        PaymentReceipt {
+            recipient_email: pii::Email::from_str("customer@example.com").unwrap(),
+            merchant_name: "Fish & Chips <Ltd>".to_string(),
+            profile_name: "default".to_string(),
+            payment_id: "pay_123".to_string(),
+            amount: "10.00".to_string(),
+            currency: "USD".to_string(),
+            templates,
+        }
+    }
+
+    #[test]
+    fn test_fill_template_placeholders() {
+        let filled = fill_template_placeholders(
+            "<p>{{amount}} {{currency}} paid to {{merchant_name}}, {{amount}} in total. \
+             {{unknown}}</p>",
+            &[
+                ("amount", "10.00"),
+                ("currency", "USD"),
+                ("merchant_name", "<script>alert('x')</script>"),
+            ],
+        );
+
+        assert_eq!(
+            filled,
+            "<p>10.00 USD paid to &lt;script&gt;alert(&#x27;x&#x27;)&lt;/script&gt;, 10.00 in \
+             total. {{unknown}}</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_payment_receipt_uses_merchant_templates() {
+        let receipt = payment_receipt(Some(EmailTemplates {
+            receipt_subject: Some("Receipt from {{merchant_name}}".to_string()),
+            receipt_body: Some("<p>{{merchant_name}} received {{amount}}</p>".to_string()),
+            footer: None,
+        }));
+
+        let email_contents = receipt.get_email_data().await.unwrap();
+
+        assert_eq!(email_contents.subject, "Receipt from Fish & Chips <Ltd>");
+        assert_eq!(
+            email_contents.body.into_inner(),
+            "<p>Fish &amp; Chips &lt;Ltd&gt; received 10.00</p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_payment_receipt_escapes_values() {
+        let email_contents = payment_receipt(None).get_email_data().await.unwrap();
+        let body = email_contents.body.into_inner();
+
+        assert!(body.contains("Fish &amp; Chips &lt;Ltd&gt;"));
+        assert!(!body.contains("<Ltd>"));
+    }
+
+    #[test]
+    fn test_email_token_is_rejected_in_other_flows() {
+        let email_token = EmailToken {
+            email: "user@example.com".to_string(),
+            purpose: EmailTokenPurpose::AcceptInvite,
+            exp: 0,
+        };
+
+        assert!(email_token
+            .validate_purpose(EmailTokenPurpose::AcceptInvite)
+            .is_ok());
+        for purpose in [
+            EmailTokenPurpose::VerifyEmail,
+            EmailTokenPurpose::ResetPassword,
+            EmailTokenPurpose::MagicLink,
+        ] {
+            assert!(email_token.validate_purpose(purpose).is_err());
+        }
+
+        // Tokens issued without a purpose are not accepted in any flow
+        assert!(serde_json::from_value::<EmailToken>(serde_json::json!({
+            "email": "user@example.com",
+            "exp": 0,
+        }))
+        .is_err());
+    }
+}
@@ -0,0 +1,401 @@
+//! Token bucket rate limiting of the requests of merchants and API keys.
+//!
+//! Each bucket holds up to `capacity` tokens and is refilled at `refill_rate` tokens a second, and
+//! every request takes a token from each of the buckets it counts towards, provided that all of
+//! them have one. The buckets are kept in Redis so that they are shared by all the servers, and in
+//! the memory of the server when Redis is not available, so that an outage of Redis does not
+//! disable rate limiting altogether.
+
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use error_stack::{report, ResultExt};
+use once_cell::sync::Lazy;
+use router_env::{logger, Flow};
+
+use super::authentication::AuthenticationType;
+use crate::{
+    configs::settings::{RateLimitSettings, TokenBucketSettings},
+    core::errors::{self, RouterResult},
+    routes::{app::AppStateInfo, lock_utils::ApiIdentifier, metrics},
+};
+
+const RATE_LIMIT_PREFIX: &str = "RATE_LIMIT";
+
+/// Number of buckets kept in memory above which the buckets that have been refilled are dropped
+const MAX_LOCAL_BUCKETS: usize = 10_000;
+
+/// Tokens are counted in thousandths, so that a bucket refilled at `refill_rate` tokens a second
+/// gains `refill_rate` of them every millisecond
+const MILLIS_PER_TOKEN: u64 = 1000;
+
+/// Refills the buckets, then takes a token from each of them if all of them have one, so that a
+/// rejected request does not use up the tokens of the other buckets. Returns whether the request
+/// is allowed followed by the thousandths of a token left in each bucket. Mirrors [`take_tokens`].
+const TAKE_TOKENS_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local tokens = {}
+local allowed = 1
+for i, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[2 * i]) * 1000
+    local refill_rate = tonumber(ARGV[2 * i + 1])
+    local bucket = redis.call('HMGET', key, 'tokens', 'updated_at')
+    local bucket_tokens = tonumber(bucket[1]) or capacity
+    local updated_at = tonumber(bucket[2]) or now
+    tokens[i] = math.min(capacity, bucket_tokens + math.max(0, now - updated_at) * refill_rate)
+    if tokens[i] < 1000 then
+        allowed = 0
+    end
+end
+local result = { allowed }
+for i, key in ipairs(KEYS) do
+    local capacity = tonumber(ARGV[2 * i]) * 1000
+    local refill_rate = tonumber(ARGV[2 * i + 1])
+    if allowed == 1 then
+        tokens[i] = tokens[i] - 1000
+    end
+    redis.call('HSET', key, 'tokens', tokens[i], 'updated_at', now)
+    redis.call('PEXPIRE', key, math.ceil(capacity / refill_rate))
+    result[i + 1] = tokens[i]
+end
+return result
+"#;
+
+static LOCAL_BUCKETS: Lazy<Mutex<HashMap<String, LocalBucket>>> = Lazy::new(Default::default);
+
+#[derive(Clone, Copy, Debug)]
+struct LocalBucket {
+    /// Thousandths of a token left in the bucket
+    tokens: u64,
+    updated_at: u64,
+    /// Time at which the bucket will have been refilled, after which it is equivalent to a missing
+    /// one
+    refilled_at: u64,
+}
+
+/// Takes a token from each of the buckets the request counts towards, and fails with
+/// [`errors::ApiErrorResponse::RateLimitExceeded`] if any of them is empty. Requests that are not
+/// made on behalf of a merchant are not limited.
+pub async fn check_rate_limits<A: AppStateInfo>(
+    state: &A,
+    settings: &RateLimitSettings,
+    flow_name: &str,
+    auth_type: &AuthenticationType,
+) -> RouterResult<()> {
+    if !settings.enabled {
+        return Ok(());
+    }
+    let Some(merchant_id) = auth_type.get_merchant_id() else {
+        return Ok(());
+    };
+
+    // Flows of the analytics routes are not grouped
+    let route_group = Flow::from_str(flow_name)
+        .ok()
+        .map(|flow| ApiIdentifier::from(flow).to_string());
+    // The keys of the buckets of a merchant share a hash tag, so that they can be evaluated by a
+    // single script on a Redis cluster
+    let mut buckets = Vec::new();
+    if let Some(bucket) = settings.merchant {
+        buckets.push(("merchant", format!("{{{merchant_id}}}_merchant"), bucket));
+    }
+    if let (Some(bucket), AuthenticationType::ApiKey { key_id, .. }) = (settings.api_key, auth_type)
+    {
+        buckets.push((
+            "api_key",
+            format!("{{{merchant_id}}}_api_key_{key_id}"),
+            bucket,
+        ));
+    }
+    if let Some((route_group, bucket)) = route_group.as_ref().and_then(|route_group| {
+        settings
+            .route_groups
+            .get(route_group)
+            .map(|bucket| (route_group, bucket))
+    }) {
+        buckets.push((
+            route_group.as_str(),
+            format!("{{{merchant_id}}}_{route_group}"),
+            *bucket,
+        ));
+    }
+    if buckets.is_empty() {
+        return Ok(());
+    }
+
+    let (allowed, tokens) = take_tokens_from_buckets(
+        state,
+        buckets
+            .iter()
+            .map(|(_, key, bucket)| (key.as_str(), *bucket))
+            .collect(),
+    )
+    .await;
+
+    let mut retry_after = None;
+    for ((kind, _, bucket), tokens) in buckets.into_iter().zip(tokens) {
+        let attributes = [metrics::request::add_attributes("bucket", kind.to_owned())];
+        if allowed {
+            metrics::RATE_LIMIT_ALLOWED.add(&metrics::CONTEXT, 1, &attributes);
+        } else if tokens < MILLIS_PER_TOKEN {
+            metrics::RATE_LIMIT_EXCEEDED.add(&metrics::CONTEXT, 1, &attributes);
+            retry_after = retry_after.max(Some(seconds_until_next_token(tokens, bucket)));
+        }
+    }
+
+    match retry_after {
+        Some(retry_after) => Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+            retry_after
+        }))
+        .attach_printable_lazy(|| format!("Rate limit exceeded for merchant {merchant_id}")),
+        None => Ok(()),
+    }
+}
+
+/// Takes a token from a single bucket that is not tied to the authentication of the request, such
+/// as the one of the payments created from a payment link, and fails with
+/// [`errors::ApiErrorResponse::RateLimitExceeded`] if it is empty
+pub async fn check_rate_limit<A: AppStateInfo>(
+    state: &A,
+    kind: &'static str,
+    key: &str,
+    bucket: TokenBucketSettings,
+) -> RouterResult<()> {
+    let attributes = [metrics::request::add_attributes("bucket", kind)];
+    let (allowed, tokens) = take_tokens_from_buckets(state, vec![(key, bucket)]).await;
+    if allowed {
+        metrics::RATE_LIMIT_ALLOWED.add(&metrics::CONTEXT, 1, &attributes);
+        Ok(())
+    } else {
+        metrics::RATE_LIMIT_EXCEEDED.add(&metrics::CONTEXT, 1, &attributes);
+        let retry_after = tokens
+            .first()
+            .map_or(1, |tokens| seconds_until_next_token(*tokens, bucket));
+        Err(report!(errors::ApiErrorResponse::RateLimitExceeded {
+            retry_after
+        }))
+        .attach_printable_lazy(|| format!("Rate limit exceeded for {key}"))
+    }
+}
+
+/// Takes a token from each of the buckets if all of them have one, returning whether the tokens
+/// were taken and the thousandths of a token left in each bucket
+async fn take_tokens_from_buckets<A: AppStateInfo>(
+    state: &A,
+    buckets: Vec<(&str, TokenBucketSettings)>,
+) -> (bool, Vec<u64>) {
+    let buckets = buckets
+        .into_iter()
+        .map(|(key, bucket)| (format!("{RATE_LIMIT_PREFIX}_{key}"), bucket))
+        .collect::<Vec<_>>();
+    let now = now_in_millis();
+
+    let result = match state.store().get_redis_conn() {
+        Ok(redis_conn) => {
+            let keys = buckets.iter().map(|(key, _)| key.clone()).collect();
+            let args = std::iter::once(now.to_string())
+                .chain(buckets.iter().flat_map(|(_, bucket)| {
+                    [bucket.capacity.to_string(), bucket.refill_rate.to_string()]
+                }))
+                .collect();
+            redis_conn
+                .evaluate_script::<Vec<i64>>(TAKE_TOKENS_SCRIPT, keys, args)
+                .await
+                .map_err(|error| {
+                    logger::warn!(?error, "Failed to evaluate the rate limit in Redis")
+                })
+                .ok()
+                .and_then(|result| match result.split_first() {
+                    Some((allowed, tokens)) if tokens.len() == buckets.len() => Some((
+                        *allowed == 1,
+                        tokens
+                            .iter()
+                            .map(|tokens| u64::try_from(*tokens).unwrap_or(0))
+                            .collect(),
+                    )),
+                    _ => None,
+                })
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to get redis connection");
+            None
+        }
+    };
+
+    result.unwrap_or_else(|| {
+        metrics::RATE_LIMIT_LOCAL_FALLBACK.add(&metrics::CONTEXT, 1, &[]);
+        take_tokens_from_local_buckets(&buckets, now)
+    })
+}
+
+fn take_tokens_from_local_buckets(
+    buckets: &[(String, TokenBucketSettings)],
+    now: u64,
+) -> (bool, Vec<u64>) {
+    let mut local_buckets = LOCAL_BUCKETS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if local_buckets.len() > MAX_LOCAL_BUCKETS {
+        prune_local_buckets(&mut local_buckets, now);
+    }
+
+    let (allowed, tokens) = take_tokens(
+        buckets
+            .iter()
+            .map(|(key, bucket)| (local_buckets.get(key).copied(), *bucket)),
+        now,
+    );
+    for ((key, bucket), tokens) in buckets.iter().zip(tokens.iter()) {
+        local_buckets.insert(key.to_owned(), LocalBucket::new(*tokens, *bucket, now));
+    }
+
+    (allowed, tokens)
+}
+
+/// Drops the buckets that have been refilled, as they are equivalent to missing ones
+fn prune_local_buckets(local_buckets: &mut HashMap<String, LocalBucket>, now: u64) {
+    local_buckets.retain(|_, local_bucket| local_bucket.refilled_at > now);
+}
+
+impl LocalBucket {
+    fn new(tokens: u64, bucket: TokenBucketSettings, now: u64) -> Self {
+        let capacity = u64::from(bucket.capacity) * MILLIS_PER_TOKEN;
+        let refill_rate = u64::from(bucket.refill_rate);
+        let refill_time = (capacity.saturating_sub(tokens) + refill_rate - 1) / refill_rate;
+        Self {
+            tokens,
+            updated_at: now,
+            refilled_at: now.saturating_add(refill_time),
+        }
+    }
+}
+
+/// Refills the buckets for the time elapsed since they were last updated, then takes a token from
+/// each of them if all of them have one. Returns whether the tokens were taken and the thousandths
+/// of a token left in each bucket.
+fn take_tokens(
+    buckets: impl Iterator<Item = (Option<LocalBucket>, TokenBucketSettings)>,
+    now: u64,
+) -> (bool, Vec<u64>) {
+    let tokens = buckets
+        .map(|(local_bucket, bucket)| {
+            let capacity = u64::from(bucket.capacity) * MILLIS_PER_TOKEN;
+            local_bucket.map_or(capacity, |local_bucket| {
+                let elapsed_in_millis = now.saturating_sub(local_bucket.updated_at);
+                capacity.min(local_bucket.tokens.saturating_add(
+                    elapsed_in_millis.saturating_mul(u64::from(bucket.refill_rate)),
+                ))
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if tokens.iter().all(|tokens| *tokens >= MILLIS_PER_TOKEN) {
+        (
+            true,
+            tokens
+                .into_iter()
+                .map(|tokens| tokens - MILLIS_PER_TOKEN)
+                .collect(),
+        )
+    } else {
+        (false, tokens)
+    }
+}
+
+fn seconds_until_next_token(tokens: u64, bucket: TokenBucketSettings) -> u64 {
+    let refill_per_second = u64::from(bucket.refill_rate) * MILLIS_PER_TOKEN;
+    let missing_tokens = MILLIS_PER_TOKEN.saturating_sub(tokens);
+    ((missing_tokens + refill_per_second - 1) / refill_per_second).max(1)
+}
+
+fn now_in_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn take_token(
+        local_bucket: Option<LocalBucket>,
+        bucket: TokenBucketSettings,
+        now: u64,
+    ) -> (bool, u64) {
+        let (allowed, tokens) = take_tokens(std::iter::once((local_bucket, bucket)), now);
+        (allowed, tokens[0])
+    }
+
+    #[test]
+    fn test_token_bucket_allows_bursts_and_refills() {
+        let bucket = TokenBucketSettings {
+            capacity: 2,
+            refill_rate: 1,
+        };
+
+        let (allowed, tokens) = take_token(None, bucket, 0);
+        assert!(allowed);
+        let (allowed, tokens) = take_token(Some(LocalBucket::new(tokens, bucket, 0)), bucket, 0);
+        assert!(allowed);
+        let (allowed, tokens) = take_token(Some(LocalBucket::new(tokens, bucket, 0)), bucket, 500);
+        assert!(!allowed);
+        assert_eq!(seconds_until_next_token(tokens, bucket), 1);
+
+        let (allowed, _) = take_token(Some(LocalBucket::new(tokens, bucket, 500)), bucket, 1000);
+        assert!(allowed);
+    }
+
+    #[test]
+    fn test_rejected_request_does_not_take_tokens_from_other_buckets() {
+        let full_bucket = TokenBucketSettings {
+            capacity: 2,
+            refill_rate: 1,
+        };
+        let empty_bucket = TokenBucketSettings {
+            capacity: 1,
+            refill_rate: 1,
+        };
+
+        let (allowed, tokens) = take_tokens(
+            [
+                (None, full_bucket),
+                (Some(LocalBucket::new(0, empty_bucket, 0)), empty_bucket),
+            ]
+            .into_iter(),
+            0,
+        );
+        assert!(!allowed);
+        assert_eq!(tokens, vec![2 * MILLIS_PER_TOKEN, 0]);
+    }
+
+    #[test]
+    fn test_pruning_keeps_buckets_that_are_being_refilled() {
+        let fast_bucket = TokenBucketSettings {
+            capacity: 10,
+            refill_rate: 10,
+        };
+        let slow_bucket = TokenBucketSettings {
+            capacity: 10,
+            refill_rate: 1,
+        };
+        let mut local_buckets = HashMap::from([
+            ("fast".to_string(), LocalBucket::new(0, fast_bucket, 0)),
+            ("slow".to_string(), LocalBucket::new(0, slow_bucket, 0)),
+        ]);
+
+        // The fast bucket is refilled after a second, the slow one after ten
+        prune_local_buckets(&mut local_buckets, 5000);
+        assert!(!local_buckets.contains_key("fast"));
+        assert!(local_buckets.contains_key("slow"));
+
+        prune_local_buckets(&mut local_buckets, 10_000);
+        assert!(local_buckets.is_empty());
+    }
+}
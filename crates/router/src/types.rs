@@ -400,6 +400,8 @@ pub struct PaymentsCaptureData {
 pub struct MultipleCaptureRequestData {
     pub capture_sequence: i16,
     pub capture_reference: String,
+    /// Whether this capture is the last one, after which no more captures are allowed
+    pub final_capture: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -754,6 +756,8 @@ pub struct RefundsData {
     /// Arbitrary metadata required for refund
     pub connector_metadata: Option<serde_json::Value>,
     pub browser_info: Option<BrowserInformation>,
+    /// Connector's id of the capture against which this refund is issued, for payments captured in multiple parts
+    pub connector_capture_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
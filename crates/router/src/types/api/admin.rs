@@ -1,11 +1,11 @@
 pub use api_models::admin::{
     payout_routing_algorithm, BusinessProfileCreate, BusinessProfileResponse,
-    BusinessProfileUpdate, MerchantAccountCreate, MerchantAccountDeleteResponse,
-    MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorCreate,
-    MerchantConnectorDeleteResponse, MerchantConnectorDetails, MerchantConnectorDetailsWrap,
-    MerchantConnectorId, MerchantConnectorResponse, MerchantDetails, MerchantId,
-    PaymentMethodsEnabled, PayoutRoutingAlgorithm, PayoutStraightThroughAlgorithm, ToggleKVRequest,
-    ToggleKVResponse, WebhookDetails,
+    BusinessProfileUpdate, DisputeSettings, EmailNotificationSettings, MerchantAccountCreate,
+    MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+    MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
+    MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
+    MerchantId, PaymentMethodsEnabled, PayoutRoutingAlgorithm, PayoutStraightThroughAlgorithm,
+    ToggleKVRequest, ToggleKVResponse, WebhookDetails,
 };
 use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
@@ -72,6 +72,15 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
             frm_routing_algorithm: item.frm_routing_algorithm,
             payout_routing_algorithm: item.payout_routing_algorithm,
             applepay_verified_domains: item.applepay_verified_domains,
+            delayed_capture_hours: item.delayed_capture_hours,
+            email_settings: item
+                .email_settings
+                .map(|email_settings| email_settings.parse_value("EmailNotificationSettings"))
+                .transpose()?,
+            dispute_settings: item
+                .dispute_settings
+                .map(|dispute_settings| dispute_settings.parse_value("DisputeSettings"))
+                .transpose()?,
         })
     }
 }
@@ -100,6 +109,32 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
             })
             .transpose()?;
 
+        let email_settings = request
+            .email_settings
+            .as_ref()
+            .map(|email_settings| {
+                common_utils::ext_traits::Encode::<EmailNotificationSettings>::encode_to_value(
+                    email_settings,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "email_settings",
+                })
+            })
+            .transpose()?;
+
+        let dispute_settings = request
+            .dispute_settings
+            .as_ref()
+            .map(|dispute_settings| {
+                common_utils::ext_traits::Encode::<DisputeSettings>::encode_to_value(
+                    dispute_settings,
+                )
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "dispute_settings",
+                })
+            })
+            .transpose()?;
+
         let payment_response_hash_key = request
             .payment_response_hash_key
             .or(merchant_account.payment_response_hash_key)
@@ -140,6 +175,9 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .or(merchant_account.payout_routing_algorithm),
             is_recon_enabled: merchant_account.is_recon_enabled,
             applepay_verified_domains: request.applepay_verified_domains,
+            delayed_capture_hours: request.delayed_capture_hours.map(i32::from),
+            email_settings,
+            dispute_settings,
         })
     }
 }
@@ -34,6 +34,9 @@ pub struct DisputeEvidence {
     pub invoice_showing_distinct_transactions: Option<String>,
     pub recurring_transaction_agreement: Option<String>,
     pub uncategorized_file: Option<String>,
+    /// Summary document rendered when the evidence was last assembled, which is replaced when the
+    /// evidence is assembled again
+    pub summary_file: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
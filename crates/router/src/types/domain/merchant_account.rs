@@ -46,6 +46,9 @@ pub struct MerchantAccount {
     pub default_profile: Option<String>,
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
+    /// The business profile the API key of the request is bound to, which the request cannot
+    /// override. It is set on authentication and not stored.
+    pub bound_profile_id: Option<String>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -224,6 +227,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 default_profile: item.default_profile,
                 recon_status: item.recon_status,
                 payment_link_config: item.payment_link_config,
+                bound_profile_id: None,
             })
         }
         .await
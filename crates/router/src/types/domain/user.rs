@@ -5,7 +5,8 @@ use api_models::{
 };
 use common_utils::pii;
 use diesel_models::{
-    enums::UserStatus,
+    encryption::Encryption,
+    enums::{TotpStatus, UserStatus},
     organization as diesel_org,
     organization::Organization,
     user as storage_user,
@@ -582,6 +583,18 @@ impl UserFromStorage {
         self.0.email.clone()
     }
 
+    pub fn get_totp_status(&self) -> TotpStatus {
+        self.0.totp_status
+    }
+
+    pub fn get_encrypted_totp_secret(&self) -> Option<Encryption> {
+        self.0.totp_secret.clone()
+    }
+
+    pub fn get_hashed_recovery_codes(&self) -> Vec<String> {
+        self.0.totp_recovery_codes.clone().unwrap_or_default()
+    }
+
     pub async fn get_jwt_auth_token(&self, state: AppState, org_id: String) -> UserResult<String> {
         let role_id = self.get_role_from_db(state.clone()).await?.role_id;
         let merchant_id = state
@@ -657,6 +670,7 @@ impl From<info::PermissionModule> for user_role_api::PermissionModule {
             info::PermissionModule::Files => Self::Files,
             info::PermissionModule::ThreeDsDecisionManager => Self::ThreeDsDecisionManager,
             info::PermissionModule::SurchargeDecisionManager => Self::SurchargeDecisionManager,
+            info::PermissionModule::AuditLogs => Self::AuditLogs,
         }
     }
 }
@@ -672,7 +686,8 @@ impl TryFrom<info::PermissionInfo> for user_role_api::PermissionInfo {
     }
 }
 
-pub struct UserAndRoleJoined(pub storage_user::User, pub UserRole);
+/// The user, their role in the merchant and the name of the role if it is a custom role
+pub struct UserAndRoleJoined(pub storage_user::User, pub UserRole, pub Option<String>);
 
 impl TryFrom<UserAndRoleJoined> for user_api::UserDetails {
     type Error = ();
@@ -684,8 +699,9 @@ impl TryFrom<UserAndRoleJoined> for user_api::UserDetails {
 
         let role_id = user_and_role.1.role_id;
         let role_name = predefined_permissions::get_role_name_from_id(role_id.as_str())
-            .ok_or(())?
-            .to_string();
+            .map(ToString::to_string)
+            .or(user_and_role.2)
+            .ok_or(())?;
 
         Ok(Self {
             user_id: user_and_role.0.user_id,
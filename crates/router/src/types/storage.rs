@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
@@ -11,6 +12,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod forex_rate_snapshot;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
@@ -28,6 +30,7 @@ use std::collections::HashMap;
 pub use diesel_models::{ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate};
 pub use scheduler::db::process_tracker;
 pub mod reverse_lookup;
+pub mod role;
 
 pub mod payout_attempt;
 pub mod payouts;
@@ -43,11 +46,12 @@ pub use data_models::payments::{
 };
 
 pub use self::{
-    address::*, api_keys::*, capture::*, cards_info::*, configs::*, customers::*,
-    dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*, file::*, gsm::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    merchant_key_store::*, payment_link::*, payment_method::*, payout_attempt::*, payouts::*,
-    process_tracker::*, refund::*, reverse_lookup::*, routing_algorithm::*, user::*, user_role::*,
+    address::*, api_keys::*, audit_log::*, capture::*, cards_info::*, configs::*, customers::*,
+    dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*, file::*,
+    forex_rate_snapshot::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
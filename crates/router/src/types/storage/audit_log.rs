@@ -0,0 +1,70 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::audit_log::{AuditLog, AuditLogNew};
+use diesel_models::{errors, query::generics::db_metrics, schema::audit_log::dsl};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait AuditLogDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        audit_log_list_constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogDbExt for AuditLog {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        audit_log_list_constraints: api_models::audit_log::AuditLogListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(resource_type) = audit_log_list_constraints.resource_type {
+            filter = filter.filter(dsl::resource_type.eq(resource_type));
+        }
+        if let Some(resource_id) = audit_log_list_constraints.resource_id {
+            filter = filter.filter(dsl::resource_id.eq(resource_id));
+        }
+        if let Some(actor_type) = audit_log_list_constraints.actor_type {
+            filter = filter.filter(dsl::actor_type.eq(actor_type));
+        }
+        if let Some(actor_id) = audit_log_list_constraints.actor_id {
+            filter = filter.filter(dsl::actor_id.eq(actor_id));
+        }
+        if let Some(action) = audit_log_list_constraints.action {
+            filter = filter.filter(dsl::action.eq(action));
+        }
+        if let Some(created_at_gte) = audit_log_list_constraints.created_at_gte {
+            filter = filter.filter(dsl::created_at.ge(created_at_gte));
+        }
+        if let Some(created_at_lte) = audit_log_list_constraints.created_at_lte {
+            filter = filter.filter(dsl::created_at.le(created_at_lte));
+        }
+        if let Some(limit) = audit_log_list_constraints.limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = audit_log_list_constraints.offset {
+            filter = filter.offset(offset);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable_lazy(|| "Error filtering audit log by predicate")
+    }
+}
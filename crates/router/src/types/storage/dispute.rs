@@ -2,7 +2,12 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
-use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
+use diesel_models::{
+    enums::{DisputeStage, DisputeStatus},
+    errors,
+    query::generics::db_metrics,
+    schema::dispute::dsl,
+};
 use error_stack::{IntoReport, ResultExt};
 
 use crate::{connection::PgPooledConn, logger};
@@ -58,6 +63,16 @@ impl DisputeDbExt for Dispute {
         if let Some(dispute_status) = dispute_list_constraints.dispute_status {
             filter = filter.filter(dsl::dispute_status.eq(dispute_status));
         }
+        if let Some(expiring_within_hours) = dispute_list_constraints.expiring_within_hours {
+            let now = common_utils::date_time::now();
+            let expiring_before =
+                now.saturating_add(time::Duration::hours(i64::from(expiring_within_hours)));
+            filter = filter
+                .filter(dsl::dispute_stage.eq(DisputeStage::Dispute))
+                .filter(dsl::dispute_status.eq(DisputeStatus::DisputeOpened))
+                .filter(dsl::challenge_required_by.ge(now))
+                .filter(dsl::challenge_required_by.le(expiring_before));
+        }
         if let Some(limit) = dispute_list_constraints.limit {
             filter = filter.limit(limit);
         }
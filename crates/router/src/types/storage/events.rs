@@ -1 +1,78 @@
-pub use diesel_models::events::{Event, EventNew, EventUpdate};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::{
+    events::{Event, EventNew, EventUpdate},
+    schema::events::dsl,
+};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    connection::PgPooledConn,
+    core::errors::{self, CustomResult},
+    logger,
+};
+
+/// Cursor based constraints for listing the events of a merchant, newest first
+#[derive(Clone, Debug)]
+pub struct EventListConstraints {
+    /// Only list events created before the event with this internal id
+    pub starting_after_id: Option<i32>,
+    /// Only list events created after the event with this internal id
+    pub ending_before_id: Option<i32>,
+    pub limit: i64,
+}
+
+#[async_trait::async_trait]
+pub trait EventDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError>;
+}
+
+#[async_trait::async_trait]
+impl EventDbExt for Event {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        constraints: EventListConstraints,
+    ) -> CustomResult<Vec<Self>, errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(starting_after_id) = constraints.starting_after_id {
+            filter = filter.filter(dsl::id.lt(starting_after_id));
+        }
+
+        // Paging backwards walks the events in ascending order, so that the page closest to the
+        // cursor is returned; the page is put back in descending order afterwards
+        let is_paging_backwards = constraints.ending_before_id.is_some();
+        if let Some(ending_before_id) = constraints.ending_before_id {
+            filter = filter
+                .filter(dsl::id.gt(ending_before_id))
+                .order(dsl::id.asc());
+        } else {
+            filter = filter.order(dsl::id.desc());
+        }
+        filter = filter.limit(constraints.limit);
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            // The query built here returns an empty Vec when no records are found, and if any error does occur,
+            // it would be an internal database error, due to which we are raising a DatabaseError::Unknown error
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering events by specified constraints")
+            .map(|mut events: Vec<Self>| {
+                if is_paging_backwards {
+                    events.reverse();
+                }
+                events
+            })
+    }
+}
@@ -12,6 +12,7 @@ pub trait PaymentAttemptExt {
         &self,
         capture_amount: i64,
         capture_status: enums::CaptureStatus,
+        final_capture: bool,
     ) -> RouterResult<CaptureNew>;
 
     fn get_next_capture_id(&self) -> String;
@@ -24,6 +25,7 @@ impl PaymentAttemptExt for PaymentAttempt {
         &self,
         capture_amount: i64,
         capture_status: enums::CaptureStatus,
+        final_capture: bool,
     ) -> RouterResult<CaptureNew> {
         let capture_sequence = self.multiple_capture_count.unwrap_or_default() + 1;
         let now = common_utils::date_time::now();
@@ -52,6 +54,7 @@ impl PaymentAttemptExt for PaymentAttempt {
             capture_sequence,
             connector_capture_id: None,
             connector_response_reference_id: None,
+            final_capture,
         })
     }
     fn get_next_capture_id(&self) -> String {
@@ -14,7 +14,7 @@ use masking::{ExposeInterface, PeekInterface};
 use super::domain;
 use crate::{
     core::errors,
-    services::authentication::get_header_value_by_key,
+    services::authentication::{self, get_header_value_by_key},
     types::{api as api_types, api::routing as routing_types, storage},
 };
 
@@ -103,6 +103,7 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             | storage_enums::AttemptStatus::CaptureFailed
             | storage_enums::AttemptStatus::Failure => Self::Failed,
             storage_enums::AttemptStatus::Voided => Self::Cancelled,
+            storage_enums::AttemptStatus::Expired => Self::Expired,
         }
     }
 }
@@ -138,6 +139,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::PaymentMethodAwaited
             | storage_enums::AttemptStatus::ConfirmationAwaited
             | storage_enums::AttemptStatus::DeviceDataCollectionPending
+            | storage_enums::AttemptStatus::Expired
             | storage_enums::AttemptStatus::PartialChargedAndChargeable=> {
                 Err(errors::ApiErrorResponse::PreconditionFailed {
                     message: "AttemptStatus must be one of these for multiple partial captures [Charged, PartialCharged, Pending, CaptureInitiated, Failure, CaptureFailed]".into(),
@@ -346,6 +348,7 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
+            api_enums::IntentStatus::Expired => Some(storage_enums::EventType::PaymentExpired),
             api_enums::IntentStatus::RequiresPaymentMethod
             | api_enums::IntentStatus::RequiresConfirmation
             | api_enums::IntentStatus::RequiresCapture
@@ -605,6 +608,13 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .as_deref()
+                .map(authentication::api_key_scope::from_stored_scopes),
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
+            rotated_from: api_key.rotated_from,
         }
     }
 }
@@ -619,6 +629,15 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            scopes: api_key
+                .scopes
+                .as_deref()
+                .map(authentication::api_key_scope::from_stored_scopes),
+            profile_id: api_key.profile_id,
+            ip_allowlist: api_key.ip_allowlist,
+            rotated_from: api_key.rotated_from,
+            rotated_to: api_key.rotated_to,
+            last_used: api_key.last_used,
         }
     }
 }
@@ -632,6 +651,11 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            scopes: api_key
+                .scopes
+                .as_deref()
+                .map(authentication::api_key_scope::to_stored_scopes),
+            ip_allowlist: api_key.ip_allowlist,
         }
     }
 }
@@ -812,6 +836,9 @@ impl ForeignFrom<storage::PaymentAttempt> for api_models::payments::PaymentAttem
             reference_id: payment_attempt.connector_response_reference_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            fx_quote: payment_attempt
+                .fx_quote
+                .and_then(|fx_quote| fx_quote.parse_value("FxQuote").ok()),
         }
     }
 }
@@ -831,6 +858,7 @@ impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
             error_code: capture.error_code,
             error_reason: capture.error_reason,
             reference_id: capture.connector_response_reference_id,
+            final_capture: capture.final_capture,
         }
     }
 }
@@ -924,10 +952,12 @@ impl
     }
 }
 
-impl ForeignFrom<(storage::PaymentLink, String)>
+impl ForeignFrom<(storage::PaymentLink, storage_enums::PaymentLinkStatus)>
     for api_models::payments::RetrievePaymentLinkResponse
 {
-    fn foreign_from((payment_link_object, status): (storage::PaymentLink, String)) -> Self {
+    fn foreign_from(
+        (payment_link_object, status): (storage::PaymentLink, storage_enums::PaymentLinkStatus),
+    ) -> Self {
         Self {
             payment_link_id: payment_link_object.payment_link_id,
             merchant_id: payment_link_object.merchant_id,
@@ -938,6 +968,13 @@ impl ForeignFrom<(storage::PaymentLink, String)>
             description: payment_link_object.description,
             currency: payment_link_object.currency,
             status,
+            link_type: payment_link_object.link_type,
+            amount_type: payment_link_object.amount_type,
+            custom_domain: payment_link_object.custom_domain,
+            locale: payment_link_object.locale,
+            success_url: payment_link_object.success_url,
+            failure_url: payment_link_object.failure_url,
+            tax_amount: payment_link_object.tax_amount,
         }
     }
 }
@@ -1014,3 +1051,22 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
         }
     }
 }
+
+impl ForeignFrom<storage::ProcessTracker> for api_models::process_tracker::ProcessTrackerResponse {
+    fn foreign_from(value: storage::ProcessTracker) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            tag: value.tag,
+            runner: value.runner,
+            status: value.status,
+            business_status: value.business_status,
+            retry_count: value.retry_count,
+            schedule_time: value.schedule_time,
+            tracking_data: value.tracking_data,
+            event: value.event,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod currency;
 pub mod custom_serde;
 pub mod db_utils;
 pub mod ext_traits;
+pub mod pdf;
 #[cfg(feature = "kv_store")]
 pub mod storage_partitioning;
 #[cfg(feature = "olap")]
@@ -49,7 +50,7 @@ use crate::{
             types::{encrypt_optional, AsyncLift},
         },
         storage,
-        transformers::{ForeignTryFrom, ForeignTryInto},
+        transformers::{ForeignInto, ForeignTryFrom, ForeignTryInto},
     },
 };
 
@@ -170,6 +171,22 @@ impl QrImage {
     pub fn new_from_data(
         data: String,
     ) -> Result<Self, error_stack::Report<common_utils::errors::QrCodeError>> {
+        let image_bytes = Self::png_from_data(&data)?;
+
+        let image_data_source = format!(
+            "{},{}",
+            consts::QR_IMAGE_DATA_SOURCE_STRING,
+            consts::BASE64_ENGINE.encode(image_bytes)
+        );
+        Ok(Self {
+            data: image_data_source,
+        })
+    }
+
+    /// Renders the data into a QR code, encoded as a PNG image
+    pub fn png_from_data(
+        data: &str,
+    ) -> Result<Vec<u8>, error_stack::Report<common_utils::errors::QrCodeError>> {
         let qr_code = qrcode::QrCode::new(data.as_bytes())
             .into_report()
             .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
@@ -181,27 +198,90 @@ impl QrImage {
         let mut image_bytes = Vec::new();
 
         // Encodes qrcode_dynamic_image and write it to image_bytes
-        let _ = qrcode_dynamic_image.write_to(&mut image_bytes, image::ImageOutputFormat::Png);
+        qrcode_dynamic_image
+            .write_to(&mut image_bytes, image::ImageOutputFormat::Png)
+            .into_report()
+            .change_context(common_utils::errors::QrCodeError::FailedToCreateQrCode)?;
 
-        let image_data_source = format!(
-            "{},{}",
-            consts::QR_IMAGE_DATA_SOURCE_STRING,
-            consts::BASE64_ENGINE.encode(image_bytes)
-        );
-        Ok(Self {
-            data: image_data_source,
-        })
+        Ok(image_bytes)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils;
+    #![allow(clippy::expect_used)]
+    use crate::{types::storage, utils};
     #[test]
     fn test_image_data_source_url() {
         let qr_image_data_source_url = utils::QrImage::new_from_data("Hyperswitch".to_string());
         assert!(qr_image_data_source_url.is_ok());
     }
+
+    #[test]
+    fn test_qr_png_from_data() {
+        let png = utils::QrImage::png_from_data("https://example.com/payment_link/merchant/pay")
+            .expect("Failed to render QR code");
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    fn capture(merchant_id: &str, status: storage::enums::CaptureStatus) -> storage::Capture {
+        let now = common_utils::date_time::now();
+        storage::Capture {
+            capture_id: "pay_1_1_1".to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: merchant_id.to_string(),
+            status,
+            amount: 100,
+            currency: Some(storage::enums::Currency::USD),
+            connector: "checkout".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "pay_1_1".to_string(),
+            connector_capture_id: Some("act_1".to_string()),
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+            final_capture: false,
+        }
+    }
+
+    #[test]
+    fn test_capture_event_is_sent_once_the_capture_completes() {
+        use storage::enums::{CaptureStatus, EventType};
+
+        assert_eq!(
+            utils::get_capture_event(&capture("merchant_1", CaptureStatus::Started)),
+            None
+        );
+        assert_eq!(
+            utils::get_capture_event(&capture("merchant_1", CaptureStatus::Pending)),
+            None
+        );
+        assert_eq!(
+            utils::get_capture_event(&capture("merchant_1", CaptureStatus::Charged))
+                .map(|(_, event_type)| event_type),
+            Some(EventType::CaptureSucceeded)
+        );
+        assert_eq!(
+            utils::get_capture_event(&capture("merchant_1", CaptureStatus::Failed))
+                .map(|(_, event_type)| event_type),
+            Some(EventType::CaptureFailed)
+        );
+    }
+
+    #[test]
+    fn test_capture_event_id_is_stable_and_scoped_to_the_merchant() {
+        let charged = storage::enums::CaptureStatus::Charged;
+        let event_id = |merchant_id| {
+            utils::get_capture_event(&capture(merchant_id, charged)).map(|(event_id, _)| event_id)
+        };
+
+        assert_eq!(event_id("merchant_1"), event_id("merchant_1"));
+        assert_ne!(event_id("merchant_1"), event_id("merchant_2"));
+    }
 }
 
 pub async fn find_payment_intent_from_payment_id_type(
@@ -697,6 +777,8 @@ impl ForeignTryFrom<enums::IntentStatus> for enums::EventType {
             enums::IntentStatus::Succeeded => Ok(Self::PaymentSucceeded),
             enums::IntentStatus::Failed => Ok(Self::PaymentFailed),
             enums::IntentStatus::Processing => Ok(Self::PaymentProcessing),
+            enums::IntentStatus::Cancelled => Ok(Self::PaymentCancelled),
+            enums::IntentStatus::Expired => Ok(Self::PaymentExpired),
             enums::IntentStatus::RequiresMerchantAction
             | enums::IntentStatus::RequiresCustomerAction => Ok(Self::ActionRequired),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
@@ -706,6 +788,34 @@ impl ForeignTryFrom<enums::IntentStatus> for enums::EventType {
     }
 }
 
+/// Returns the id and type of the event notifying the outcome of a capture, once it has one. The
+/// event is inserted only once for a given id, which keeps a capture from being notified again on
+/// every later retrieval of the payment
+fn get_capture_event(capture: &storage::Capture) -> Option<(String, enums::EventType)> {
+    let event_type = match capture.status {
+        enums::CaptureStatus::Charged => enums::EventType::CaptureSucceeded,
+        enums::CaptureStatus::Failed => enums::EventType::CaptureFailed,
+        enums::CaptureStatus::Started | enums::CaptureStatus::Pending => return None,
+    };
+    // Capture ids are derived from payment ids, which are only unique for a merchant
+    let event_id = format!(
+        "{}_{}_{}",
+        capture.merchant_id, capture.capture_id, event_type
+    );
+    Some((event_id, event_type))
+}
+
+/// Whether the merchant is sent an outgoing webhook when a payment reaches `status`
+pub fn is_payment_outcome_notified(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::Failed
+            | enums::IntentStatus::Cancelled
+            | enums::IntentStatus::Expired
+    )
+}
+
 pub async fn trigger_payments_webhook<F, Req, Op>(
     merchant_account: domain::MerchantAccount,
     business_profile: diesel_models::business_profile::BusinessProfile,
@@ -732,10 +842,41 @@ where
                 .collect()
         });
 
-    if matches!(
-        status,
-        enums::IntentStatus::Succeeded | enums::IntentStatus::Failed
-    ) {
+    for capture in captures.iter().flatten() {
+        let Some((event_id, event_type)) = get_capture_event(capture) else {
+            continue;
+        };
+        let m_state = state.clone();
+        let m_merchant_account = merchant_account.clone();
+        let m_business_profile = business_profile.clone();
+        let capture_id = capture.capture_id.clone();
+        let m_payment_id = payment_id.clone();
+        let capture_response: payments::CaptureResponse = capture.clone().foreign_into();
+        tokio::spawn(
+            async move {
+                Box::pin(
+                    webhooks_core::create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+                        m_state,
+                        m_merchant_account,
+                        m_business_profile,
+                        event_id,
+                        event_type,
+                        diesel_models::enums::EventClass::Payments,
+                        Some(m_payment_id),
+                        capture_id,
+                        diesel_models::enums::EventObjectType::CaptureDetails,
+                        webhooks::OutgoingWebhookContent::CaptureDetails(Box::new(
+                            capture_response,
+                        )),
+                    ),
+                )
+                .await
+            }
+            .in_current_span(),
+        );
+    }
+
+    if is_payment_outcome_notified(status) {
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             req,
             payment_data,
@@ -1,4 +1,6 @@
-use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc, time::Duration};
+pub mod providers;
+
+use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
 
 use api_models::enums;
 use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
@@ -6,25 +8,21 @@ use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "kms")]
 use external_services::kms;
-use masking::PeekInterface;
 use once_cell::sync::Lazy;
 use redis_interface::DelReply;
-use rust_decimal::Decimal;
-use strum::IntoEnumIterator;
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use tokio::{sync::RwLock, time::sleep};
 
 use crate::{
+    db::StorageInterface,
     logger,
     routes::app::settings::{Conversion, DefaultExchangeRates},
-    services, AppState,
+    types::storage,
+    AppState,
 };
 const REDIX_FOREX_CACHE_KEY: &str = "{forex_cache}_lock";
 const REDIX_FOREX_CACHE_DATA: &str = "{forex_cache}_data";
 const FOREX_API_TIMEOUT: u64 = 5;
-const FOREX_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
-const FOREX_BASE_CURRENCY: &str = "&base=USD";
-const FALLBACK_FOREX_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
-const FALLBACK_FOREX_API_CURRENCY_PREFIX: &str = "USD";
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct FxExchangeRatesCacheEntry {
@@ -73,27 +71,10 @@ pub enum ForexCacheError {
     RedisWriteError,
     #[error("Not able to acquire write lock")]
     WriteLockNotAcquired,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct ForexResponse {
-    pub rates: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
-struct FallbackForexResponse {
-    pub quotes: HashMap<String, FloatDecimal>,
-}
-
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
-#[serde(transparent)]
-struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
-
-impl Deref for FloatDecimal {
-    type Target = Decimal;
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
+    #[error("Error accessing forex rate snapshots")]
+    DatabaseError,
+    #[error("No forex rates recorded for the requested date")]
+    SnapshotNotFound,
 }
 
 impl FxExchangeRatesCacheEntry {
@@ -166,10 +147,7 @@ impl TryFrom<DefaultExchangeRates> for ExchangeRates {
         let base_curr = enums::Currency::from_str(value.base_currency.as_str())
             .into_report()
             .change_context(ForexCacheError::ConversionError)?;
-        Ok(Self {
-            base_currency: base_curr,
-            conversion: conversion_usable,
-        })
+        Ok(Self::new(base_curr, conversion_usable).with_timestamp(value.timestamp))
     }
 }
 
@@ -268,7 +246,7 @@ async fn successive_fetch_and_save_forex(
             if !lock_acquired {
                 return stale_redis_data.ok_or(ForexCacheError::CouldNotAcquireLock.into());
             }
-            let api_rates = fetch_forex_rates(
+            let api_rates = fetch_forex_rates_from_providers(
                 state,
                 #[cfg(feature = "kms")]
                 kms_config,
@@ -276,23 +254,10 @@ async fn successive_fetch_and_save_forex(
             .await;
             match api_rates {
                 Ok(rates) => successive_save_data_to_redis_local(state, rates).await,
-                Err(err) => {
-                    // API not able to fetch data call secondary service
+                Err(err) => stale_redis_data.ok_or({
                     logger::error!(?err);
-                    let secondary_api_rates = fallback_fetch_forex_rates(
-                        state,
-                        #[cfg(feature = "kms")]
-                        kms_config,
-                    )
-                    .await;
-                    match secondary_api_rates {
-                        Ok(rates) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-                        Err(err) => stale_redis_data.ok_or({
-                            logger::error!(?err);
-                            ForexCacheError::ApiUnresponsive.into()
-                        }),
-                    }
-                }
+                    ForexCacheError::ApiUnresponsive.into()
+                }),
             }
         }
         Err(e) => stale_redis_data.ok_or({
@@ -389,145 +354,96 @@ async fn handler_local_expired(
     }
 }
 
-async fn fetch_forex_rates(
+/// Fetches rates from the configured providers in order, falling through to the next provider
+/// on failure
+async fn fetch_forex_rates_from_providers(
     state: &AppState,
     #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
-) -> Result<FxExchangeRatesCacheEntry, error_stack::Report<ForexCacheError>> {
-    #[cfg(feature = "kms")]
-    let forex_api_key = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(state.conf.forex_api.api_key.peek())
-        .await
-        .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-    #[cfg(not(feature = "kms"))]
-    let forex_api_key = state.conf.forex_api.api_key.peek();
-
-    let forex_url: String = format!("{}{}{}", FOREX_BASE_URL, forex_api_key, FOREX_BASE_CURRENCY);
-    let forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&forex_url)
-        .build();
-
-    logger::info!(?forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let forex_response = response
-        .json::<ForexResponse>()
-        .await
-        .into_report()
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", forex_response);
-
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match forex_response.rates.get(&enum_curr.to_string()) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
-            }
-        };
-    }
+) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
+    fetch_rates_from_providers(
+        state,
+        #[cfg(feature = "kms")]
+        kms_config,
+    )
+    .await
+    .map(|(_, rates)| FxExchangeRatesCacheEntry::new(rates))
+}
 
-    Ok(FxExchangeRatesCacheEntry::new(ExchangeRates::new(
-        enums::Currency::USD,
-        conversions,
-    )))
+/// Fetches the rates from the configured providers and records them as the snapshot of the day
+/// they were published for, run daily by the scheduler
+pub async fn record_forex_rate_snapshot(
+    state: &AppState,
+) -> CustomResult<storage::ForexRateSnapshot, ForexCacheError> {
+    let (provider, rates) = fetch_rates_from_providers(
+        state,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+    save_forex_rate_snapshot(&*state.store, provider, &rates).await
 }
 
-pub async fn fallback_fetch_forex_rates(
+/// Returns the rates of the first of the configured providers that responds, along with its name
+async fn fetch_rates_from_providers(
     state: &AppState,
     #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
-) -> CustomResult<FxExchangeRatesCacheEntry, ForexCacheError> {
-    #[cfg(feature = "kms")]
-    let fallback_forex_api_key = kms::get_kms_client(kms_config)
-        .await
-        .decrypt(state.conf.forex_api.fallback_api_key.peek())
-        .await
-        .change_context(ForexCacheError::KmsDecryptionFailed)?;
-
-    #[cfg(not(feature = "kms"))]
-    let fallback_forex_api_key = state.conf.forex_api.fallback_api_key.peek();
-
-    let fallback_forex_url: String =
-        format!("{}{}", FALLBACK_FOREX_BASE_URL, fallback_forex_api_key,);
-    let fallback_forex_request = services::RequestBuilder::new()
-        .method(services::Method::Get)
-        .url(&fallback_forex_url)
-        .build();
-
-    logger::info!(?fallback_forex_request);
-    let response = state
-        .api_client
-        .send_request(
-            &state.clone(),
-            fallback_forex_request,
-            Some(FOREX_API_TIMEOUT),
-            false,
-        )
-        .await
-        .change_context(ForexCacheError::ApiUnresponsive)?;
-    let fallback_forex_response = response
-        .json::<FallbackForexResponse>()
-        .await
-        .into_report()
-        .change_context(ForexCacheError::ParsingError)?;
-
-    logger::info!("{:?}", fallback_forex_response);
-    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
-    for enum_curr in enums::Currency::iter() {
-        match fallback_forex_response.quotes.get(
-            format!(
-                "{}{}",
-                FALLBACK_FOREX_API_CURRENCY_PREFIX,
-                &enum_curr.to_string()
+) -> CustomResult<(&'static str, ExchangeRates), ForexCacheError> {
+    for provider in providers::get_forex_providers(&state.conf.forex_api) {
+        match provider
+            .fetch_rates(
+                state,
+                #[cfg(feature = "kms")]
+                kms_config,
             )
-            .as_str(),
-        ) {
-            Some(rate) => {
-                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
-                    Some(rate) => rate,
-                    None => {
-                        logger::error!("Rates for {} not received from API", &enum_curr);
-                        continue;
-                    }
-                };
-                let currency_factors = CurrencyFactors::new(**rate, from_factor);
-                conversions.insert(enum_curr, currency_factors);
-            }
-            None => {
-                logger::error!("Rates for {} not received from API", &enum_curr);
+            .await
+        {
+            Ok(rates) => return Ok((provider.name(), rates)),
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    provider = provider.name(),
+                    "Failed to fetch forex rates"
+                );
             }
-        };
+        }
     }
+    Err(ForexCacheError::ApiUnresponsive.into())
+}
 
-    let rates =
-        FxExchangeRatesCacheEntry::new(ExchangeRates::new(enums::Currency::USD, conversions));
-    match acquire_redis_lock(state).await {
-        Ok(_) => Ok(successive_save_data_to_redis_local(state, rates).await?),
-        Err(e) => {
-            logger::error!(?e);
-            Ok(rates)
-        }
+/// Records the rates as the snapshot of the day the provider published them for, replacing any
+/// earlier snapshot of that day
+async fn save_forex_rate_snapshot(
+    db: &dyn StorageInterface,
+    provider: &str,
+    rates: &ExchangeRates,
+) -> CustomResult<storage::ForexRateSnapshot, ForexCacheError> {
+    let now = date_time::now();
+    let rates_timestamp = rates
+        .timestamp
+        .unwrap_or_else(|| now.assume_utc().unix_timestamp());
+    let rate_date = time::OffsetDateTime::from_unix_timestamp(rates_timestamp)
+        .into_report()
+        .change_context(ForexCacheError::ParsingError)
+        .attach_printable("Invalid timestamp of forex rates")?
+        .date();
+    let snapshot = storage::ForexRateSnapshotNew {
+        rate_date,
+        provider: provider.to_string(),
+        base_currency: rates.base_currency,
+        rates: serde_json::to_value(&rates.conversion)
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?,
+        rates_timestamp,
+        created_at: now,
+        last_modified: now,
+    };
+
+    match db.insert_forex_rate_snapshot(snapshot.clone()).await {
+        Err(error) if error.current_context().is_db_unique_violation() => db
+            .update_forex_rate_snapshot(rate_date, snapshot.into())
+            .await
+            .change_context(ForexCacheError::DatabaseError),
+        result => result.change_context(ForexCacheError::DatabaseError),
     }
 }
 
@@ -639,3 +555,222 @@ pub async fn convert_currency(
         currency: to_currency.to_string(),
     })
 }
+
+/// Converts `amount` at the rates recorded on `rate_date`, or on the latest day before it for which
+/// rates were recorded
+pub async fn convert_currency_at_date(
+    state: &AppState,
+    amount: i64,
+    to_currency: String,
+    from_currency: String,
+    rate_date: time::Date,
+) -> CustomResult<api_models::currency::HistoricalCurrencyConversionResponse, ForexCacheError> {
+    let to_currency = api_models::enums::Currency::from_str(to_currency.as_str())
+        .into_report()
+        .change_context(ForexCacheError::CurrencyNotAcceptable)?;
+
+    let from_currency = api_models::enums::Currency::from_str(from_currency.as_str())
+        .into_report()
+        .change_context(ForexCacheError::CurrencyNotAcceptable)?;
+
+    let snapshot = state
+        .store
+        .find_latest_forex_rate_snapshot_on_or_before(rate_date)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(ForexCacheError::SnapshotNotFound)
+            } else {
+                error.change_context(ForexCacheError::DatabaseError)
+            }
+        })?;
+    let conversion: HashMap<enums::Currency, CurrencyFactors> =
+        serde_json::from_value(snapshot.rates)
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+    let rates = ExchangeRates::new(snapshot.base_currency, conversion)
+        .with_timestamp(snapshot.rates_timestamp);
+
+    let converted_amount =
+        currency_conversion::conversion::convert(&rates, from_currency, to_currency, amount)
+            .into_report()
+            .change_context(ForexCacheError::ConversionError)?;
+
+    Ok(api_models::currency::HistoricalCurrencyConversionResponse {
+        converted_amount: converted_amount.to_string(),
+        currency: to_currency.to_string(),
+        rate_date: snapshot.rate_date.to_string(),
+        provider: snapshot.provider,
+    })
+}
+
+/// Locks a quote for converting `amount` of `currency` to `presentment_currency`, with the
+/// configured markup applied on the market rate
+pub async fn get_fx_quote(
+    state: &AppState,
+    amount: i64,
+    currency: enums::Currency,
+    presentment_currency: enums::Currency,
+) -> CustomResult<api_models::payments::FxQuote, ForexCacheError> {
+    let rates = get_forex_rates(
+        state,
+        state.conf.forex_api.call_delay,
+        state.conf.forex_api.local_fetch_retry_delay,
+        state.conf.forex_api.local_fetch_retry_count,
+        #[cfg(feature = "kms")]
+        &state.conf.kms,
+    )
+    .await?;
+
+    let market_rate = get_market_rate(&rates.data, currency, presentment_currency)
+        .into_report()
+        .change_context(ForexCacheError::CurrencyNotAcceptable)?;
+    let markup_basis_points = state.conf.multi_currency.markup_basis_points;
+    let exchange_rate = Decimal::new(i64::from(markup_basis_points), 4)
+        .checked_add(Decimal::ONE)
+        .and_then(|markup| market_rate.checked_mul(markup))
+        .ok_or(ForexCacheError::ConversionError)
+        .into_report()?;
+    let presentment_amount =
+        convert_at_rate(amount, currency, presentment_currency, exchange_rate)?;
+
+    Ok(api_models::payments::FxQuote {
+        presentment_currency,
+        presentment_amount,
+        market_rate,
+        markup_basis_points,
+        exchange_rate,
+        expires_at: date_time::now().saturating_add(time::Duration::seconds(
+            state.conf.multi_currency.quote_validity,
+        )),
+    })
+}
+
+/// Converts `amount` in the lowest denomination of `from_currency` to the lowest denomination of
+/// `to_currency`, `rate` being the units of `to_currency` for one unit of `from_currency`
+pub fn convert_at_rate(
+    amount: i64,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+    rate: Decimal,
+) -> CustomResult<i64, ForexCacheError> {
+    Decimal::new(amount, minor_unit_exponent(from_currency))
+        .checked_mul(rate)
+        .and_then(|converted| {
+            converted.checked_mul(Decimal::from(10_i64.pow(minor_unit_exponent(to_currency))))
+        })
+        .and_then(|converted| {
+            converted
+                .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+                .to_i64()
+        })
+        .ok_or(ForexCacheError::ConversionError)
+        .into_report()
+}
+
+fn get_market_rate(
+    rates: &ExchangeRates,
+    from_currency: enums::Currency,
+    to_currency: enums::Currency,
+) -> Result<Decimal, currency_conversion::error::CurrencyConversionError> {
+    let base_currency = rates.base_currency;
+    let base_amount = if from_currency == base_currency {
+        Decimal::ONE
+    } else {
+        rates.forward_conversion(Decimal::ONE, from_currency)?
+    };
+    if to_currency == base_currency {
+        Ok(base_amount)
+    } else {
+        rates.backward_conversion(base_amount, to_currency)
+    }
+}
+
+fn minor_unit_exponent(currency: enums::Currency) -> u32 {
+    if currency.is_zero_decimal_currency() {
+        0
+    } else if currency.is_three_decimal_currency() {
+        3
+    } else {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::db::forex_rate_snapshot::ForexRateSnapshotInterface;
+
+    #[test]
+    fn convert_at_rate_between_exponents() {
+        let rate = Decimal::new(15025, 2);
+        // 10.00 USD to JPY
+        assert_eq!(
+            convert_at_rate(1000, enums::Currency::USD, enums::Currency::JPY, rate).unwrap(),
+            1503
+        );
+        // 1503 JPY to USD
+        let rate = Decimal::ONE / rate;
+        assert_eq!(
+            convert_at_rate(1503, enums::Currency::JPY, enums::Currency::USD, rate).unwrap(),
+            1000
+        );
+        // 10.00 USD to KWD
+        let rate = Decimal::new(3075, 4);
+        assert_eq!(
+            convert_at_rate(1000, enums::Currency::USD, enums::Currency::KWD, rate).unwrap(),
+            3075
+        );
+    }
+
+    #[tokio::test]
+    async fn forex_rate_snapshot_is_replaced_within_a_day() {
+        #[allow(clippy::expect_used)]
+        let mockdb = crate::db::MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+
+        let rates = |eur: i64| {
+            ExchangeRates::new(
+                enums::Currency::USD,
+                HashMap::from([(
+                    enums::Currency::EUR,
+                    CurrencyFactors::new(Decimal::new(eur, 2), Decimal::ONE),
+                )]),
+            )
+            .with_timestamp(1_701_388_800)
+        };
+
+        save_forex_rate_snapshot(&mockdb, "static_file", &rates(90))
+            .await
+            .unwrap();
+        let snapshot = save_forex_rate_snapshot(&mockdb, "api_layer", &rates(92))
+            .await
+            .unwrap();
+        assert_eq!(snapshot.provider, "api_layer");
+
+        // The snapshot is recorded for the day the rates were published, not the day they were
+        // fetched
+        assert_eq!(snapshot.rate_date, time::macros::date!(2023 - 12 - 01));
+        assert!(mockdb
+            .find_latest_forex_rate_snapshot_on_or_before(time::macros::date!(2023 - 11 - 30))
+            .await
+            .is_err());
+
+        let found = mockdb
+            .find_latest_forex_rate_snapshot_on_or_before(time::macros::date!(2023 - 12 - 01))
+            .await
+            .unwrap();
+        assert_eq!(found, snapshot);
+        let conversion: HashMap<enums::Currency, CurrencyFactors> =
+            serde_json::from_value(found.rates).unwrap();
+        assert_eq!(
+            conversion.get(&enums::Currency::EUR).unwrap().to_factor,
+            Decimal::new(92, 2)
+        );
+    }
+}
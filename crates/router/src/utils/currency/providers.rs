@@ -0,0 +1,252 @@
+use std::{collections::HashMap, ops::Deref, path::PathBuf};
+
+use api_models::enums;
+use common_utils::errors::CustomResult;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "kms")]
+use external_services::kms;
+use masking::PeekInterface;
+use rust_decimal::Decimal;
+use strum::IntoEnumIterator;
+
+use super::{ForexCacheError, FOREX_API_TIMEOUT};
+use crate::{
+    logger,
+    routes::app::settings::{DefaultExchangeRates, ForexApi, ForexProviderKind},
+    services, AppState,
+};
+
+const OPEN_EXCHANGE_RATES_BASE_URL: &str = "https://openexchangerates.org/api/latest.json?app_id=";
+const OPEN_EXCHANGE_RATES_BASE_CURRENCY: &str = "&base=USD";
+const API_LAYER_BASE_URL: &str = "http://apilayer.net/api/live?access_key=";
+const API_LAYER_CURRENCY_PREFIX: &str = "USD";
+
+/// A source of exchange rates
+#[async_trait::async_trait]
+pub trait ForexProvider: Send + Sync {
+    /// Name recorded against the rate snapshots taken from this provider
+    fn name(&self) -> &'static str;
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+        #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    ) -> CustomResult<ExchangeRates, ForexCacheError>;
+}
+
+/// Builds the configured providers, in the order they are to be tried
+pub fn get_forex_providers(conf: &ForexApi) -> Vec<Box<dyn ForexProvider>> {
+    conf.providers
+        .iter()
+        .filter_map(|kind| -> Option<Box<dyn ForexProvider>> {
+            match kind {
+                ForexProviderKind::OpenExchangeRates => Some(Box::new(OpenExchangeRates)),
+                ForexProviderKind::ApiLayer => Some(Box::new(ApiLayer)),
+                ForexProviderKind::StaticFile => conf
+                    .static_rates_file
+                    .clone()
+                    .map(|path| Box::new(StaticFile { path }) as Box<dyn ForexProvider>),
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct OpenExchangeRatesResponse {
+    pub timestamp: i64,
+    pub rates: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ApiLayerResponse {
+    pub timestamp: i64,
+    pub quotes: HashMap<String, FloatDecimal>,
+}
+
+#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+struct FloatDecimal(#[serde(with = "rust_decimal::serde::float")] Decimal);
+
+impl Deref for FloatDecimal {
+    type Target = Decimal;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Rates from openexchangerates.org, with USD as the base currency
+pub struct OpenExchangeRates;
+
+#[async_trait::async_trait]
+impl ForexProvider for OpenExchangeRates {
+    fn name(&self) -> &'static str {
+        "open_exchange_rates"
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+        #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        #[cfg(feature = "kms")]
+        let forex_api_key = kms::get_kms_client(kms_config)
+            .await
+            .decrypt(state.conf.forex_api.api_key.peek())
+            .await
+            .change_context(ForexCacheError::KmsDecryptionFailed)?;
+
+        #[cfg(not(feature = "kms"))]
+        let forex_api_key = state.conf.forex_api.api_key.peek();
+
+        let forex_url: String = format!(
+            "{}{}{}",
+            OPEN_EXCHANGE_RATES_BASE_URL, forex_api_key, OPEN_EXCHANGE_RATES_BASE_CURRENCY
+        );
+        let forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&forex_url)
+            .build();
+
+        logger::info!(?forex_request);
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexCacheError::ApiUnresponsive)?;
+        let forex_response = response
+            .json::<OpenExchangeRatesResponse>()
+            .await
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", forex_response);
+
+        Ok(ExchangeRates::new(
+            enums::Currency::USD,
+            usd_conversions(|currency| forex_response.rates.get(&currency.to_string())),
+        )
+        .with_timestamp(forex_response.timestamp))
+    }
+}
+
+/// Rates from apilayer.net, with USD as the base currency
+pub struct ApiLayer;
+
+#[async_trait::async_trait]
+impl ForexProvider for ApiLayer {
+    fn name(&self) -> &'static str {
+        "api_layer"
+    }
+
+    async fn fetch_rates(
+        &self,
+        state: &AppState,
+        #[cfg(feature = "kms")] kms_config: &kms::KmsConfig,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        #[cfg(feature = "kms")]
+        let fallback_forex_api_key = kms::get_kms_client(kms_config)
+            .await
+            .decrypt(state.conf.forex_api.fallback_api_key.peek())
+            .await
+            .change_context(ForexCacheError::KmsDecryptionFailed)?;
+
+        #[cfg(not(feature = "kms"))]
+        let fallback_forex_api_key = state.conf.forex_api.fallback_api_key.peek();
+
+        let fallback_forex_url: String =
+            format!("{}{}", API_LAYER_BASE_URL, fallback_forex_api_key);
+        let fallback_forex_request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&fallback_forex_url)
+            .build();
+
+        logger::info!(?fallback_forex_request);
+        let response = state
+            .api_client
+            .send_request(
+                &state.clone(),
+                fallback_forex_request,
+                Some(FOREX_API_TIMEOUT),
+                false,
+            )
+            .await
+            .change_context(ForexCacheError::ApiUnresponsive)?;
+        let fallback_forex_response = response
+            .json::<ApiLayerResponse>()
+            .await
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+
+        logger::info!("{:?}", fallback_forex_response);
+
+        Ok(ExchangeRates::new(
+            enums::Currency::USD,
+            usd_conversions(|currency| {
+                fallback_forex_response
+                    .quotes
+                    .get(format!("{}{}", API_LAYER_CURRENCY_PREFIX, currency).as_str())
+            }),
+        )
+        .with_timestamp(fallback_forex_response.timestamp))
+    }
+}
+
+/// Rates read from a local JSON file, for setups without access to a rates API and for tests
+pub struct StaticFile {
+    pub path: PathBuf,
+}
+
+#[async_trait::async_trait]
+impl ForexProvider for StaticFile {
+    fn name(&self) -> &'static str {
+        "static_file"
+    }
+
+    async fn fetch_rates(
+        &self,
+        _state: &AppState,
+        #[cfg(feature = "kms")] _kms_config: &kms::KmsConfig,
+    ) -> CustomResult<ExchangeRates, ForexCacheError> {
+        let contents = tokio::fs::read(&self.path)
+            .await
+            .into_report()
+            .change_context(ForexCacheError::LocalReadError)
+            .attach_printable_lazy(|| format!("Unable to read {}", self.path.display()))?;
+        let rates: DefaultExchangeRates = serde_json::from_slice(&contents)
+            .into_report()
+            .change_context(ForexCacheError::ParsingError)?;
+        ExchangeRates::try_from(rates)
+    }
+}
+
+/// Builds the conversion factors for all currencies from their rates against USD
+fn usd_conversions<'a>(
+    rate_of: impl Fn(enums::Currency) -> Option<&'a FloatDecimal>,
+) -> HashMap<enums::Currency, CurrencyFactors> {
+    let mut conversions: HashMap<enums::Currency, CurrencyFactors> = HashMap::new();
+    for enum_curr in enums::Currency::iter() {
+        match rate_of(enum_curr) {
+            Some(rate) => {
+                let from_factor = match Decimal::new(1, 0).checked_div(**rate) {
+                    Some(rate) => rate,
+                    None => {
+                        logger::error!("Rates for {} not received from API", &enum_curr);
+                        continue;
+                    }
+                };
+                let currency_factors = CurrencyFactors::new(**rate, from_factor);
+                conversions.insert(enum_curr, currency_factors);
+            }
+            None => {
+                logger::error!("Rates for {} not received from API", &enum_curr);
+            }
+        };
+    }
+    conversions
+}
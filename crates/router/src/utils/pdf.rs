@@ -0,0 +1,261 @@
+//! Minimal PDF writer for the documents that are generated, such as payment link invoices.
+//!
+//! The document is laid out on A4 pages using the standard Helvetica fonts, which every PDF
+//! reader provides, so that no fonts or images need to be embedded. QR codes are drawn as vector
+//! modules and links are clickable.
+
+use std::fmt::Write;
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::core::errors::{self, RouterResult};
+
+pub const PAGE_WIDTH: f32 = 595.0;
+pub const PAGE_HEIGHT: f32 = 842.0;
+pub const MARGIN: f32 = 50.0;
+const QR_CODE_SIZE: f32 = 110.0;
+
+#[derive(Clone, Copy)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+        }
+    }
+}
+
+struct LinkAnnotation {
+    rect: [f32; 4],
+    uri: String,
+}
+
+#[derive(Default)]
+struct Page {
+    content: Vec<u8>,
+    links: Vec<LinkAnnotation>,
+}
+
+pub struct PdfWriter {
+    finished_pages: Vec<Page>,
+    page: Page,
+    cursor: f32,
+}
+
+impl Default for PdfWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdfWriter {
+    pub fn new() -> Self {
+        Self {
+            finished_pages: Vec::new(),
+            page: Page::default(),
+            cursor: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Moves the cursor down by `height`, starting a new page when the bottom margin is reached
+    pub fn advance(&mut self, height: f32) {
+        if self.cursor - height < MARGIN {
+            self.finished_pages.push(std::mem::take(&mut self.page));
+            self.cursor = PAGE_HEIGHT - MARGIN;
+        }
+        self.cursor -= height;
+    }
+
+    fn text_at(&mut self, x: f32, y: f32, font: Font, size: f32, text: &str) {
+        let mut operations =
+            format!("BT /{} {size} Tf {x:.2} {y:.2} Td (", font.resource_name()).into_bytes();
+        operations.extend(encode_text(text));
+        operations.extend_from_slice(b") Tj ET\n");
+        self.page.content.extend(operations);
+    }
+
+    pub fn line(&mut self, font: Font, size: f32, text: &str) {
+        self.advance(size * 1.5);
+        let y = self.cursor;
+        self.text_at(MARGIN, y, font, size, text);
+    }
+
+    pub fn columns(&mut self, font: Font, size: f32, columns: &[(f32, &str)]) {
+        self.advance(size * 1.5);
+        let y = self.cursor;
+        for (x, text) in columns {
+            self.text_at(*x, y, font, size, text);
+        }
+    }
+
+    pub fn rule(&mut self) {
+        self.advance(8.0);
+        let y = self.cursor;
+        let operations = format!(
+            "0.5 w {MARGIN:.2} {y:.2} m {:.2} {y:.2} l S\n",
+            PAGE_WIDTH - MARGIN
+        );
+        self.page.content.extend(operations.into_bytes());
+    }
+
+    pub fn link(&mut self, font: Font, size: f32, text: &str, uri: &str) {
+        self.line(font, size, text);
+        let y = self.cursor;
+        let width = approximate_text_width(text, size);
+        self.page.links.push(LinkAnnotation {
+            rect: [MARGIN, y - 2.0, MARGIN + width, y + size],
+            uri: uri.to_string(),
+        });
+    }
+
+    /// Draws the QR code as filled squares, one for every dark module
+    // QR codes are at most 177 modules wide, which f32 represents exactly
+    #[allow(clippy::as_conversions)]
+    pub fn qr_code(&mut self, data: &str) -> RouterResult<()> {
+        let qr_code = qrcode::QrCode::new(data.as_bytes())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to create QR code for payment link")?;
+        let width = qr_code.width();
+        let module_size = QR_CODE_SIZE / width as f32;
+
+        self.advance(QR_CODE_SIZE + 10.0);
+        let bottom = self.cursor;
+        let mut operations = String::from("0 g\n");
+        for (index, color) in qr_code.to_colors().into_iter().enumerate() {
+            if color == qrcode::Color::Dark {
+                let x = MARGIN + (index % width) as f32 * module_size;
+                let y = bottom + QR_CODE_SIZE - ((index / width) + 1) as f32 * module_size;
+                let _ = writeln!(
+                    operations,
+                    "{x:.2} {y:.2} {module_size:.2} {module_size:.2} re f"
+                );
+            }
+        }
+        self.page.content.extend(operations.into_bytes());
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        self.finished_pages.push(self.page);
+
+        // Objects 1 and 2 are the catalog and the page tree, 3 and 4 the fonts, followed by the
+        // page, content stream and link annotations of every page
+        let mut objects: Vec<Vec<u8>> = Vec::new();
+        let mut page_ids = Vec::new();
+        let mut next_id = 5;
+        let mut page_objects = Vec::new();
+
+        for page in self.finished_pages {
+            let page_id = next_id;
+            let content_id = next_id + 1;
+            let annotation_ids: Vec<usize> = (0..page.links.len())
+                .map(|index| content_id + 1 + index)
+                .collect();
+            next_id = content_id + 1 + page.links.len();
+            page_ids.push(page_id);
+
+            let annotations = annotation_ids
+                .iter()
+                .map(|id| format!("{id} 0 R"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mut page_objects_for_page = vec![format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> \
+                 /Contents {content_id} 0 R /Annots [{annotations}] >>"
+            )
+            .into_bytes()];
+
+            let mut content_object =
+                format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+            content_object.extend(page.content);
+            content_object.extend_from_slice(b"\nendstream");
+            page_objects_for_page.push(content_object);
+
+            for link in page.links {
+                let [x1, y1, x2, y2] = link.rect;
+                let mut annotation = format!(
+                    "<< /Type /Annot /Subtype /Link /Rect [{x1:.2} {y1:.2} {x2:.2} {y2:.2}] \
+                     /Border [0 0 0] /A << /S /URI /URI ("
+                )
+                .into_bytes();
+                annotation.extend(encode_text(&link.uri));
+                annotation.extend_from_slice(b") >> >>");
+                page_objects_for_page.push(annotation);
+            }
+            page_objects.extend(page_objects_for_page);
+        }
+
+        let kids = page_ids
+            .iter()
+            .map(|id| format!("{id} 0 R"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        objects.push(
+            format!(
+                "<< /Type /Pages /Kids [{kids}] /Count {} >>",
+                page_ids.len()
+            )
+            .into_bytes(),
+        );
+        objects.push(
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+        objects.push(
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        );
+        objects.extend(page_objects);
+
+        let mut document = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.into_iter().enumerate() {
+            offsets.push(document.len());
+            document.extend(format!("{} 0 obj\n", index + 1).into_bytes());
+            document.extend(object);
+            document.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = document.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", offsets.len() + 1);
+        for offset in offsets.iter() {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            offsets.len() + 1
+        );
+        document.extend(trailer.into_bytes());
+        document
+    }
+}
+
+/// Encodes text as a PDF literal string in WinAnsi encoding, characters outside Latin-1 are
+/// replaced with `?`
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(text.len());
+    for character in text.chars() {
+        match u8::try_from(u32::from(character)).unwrap_or(b'?') {
+            byte @ (b'(' | b')' | b'\\') => encoded.extend_from_slice(&[b'\\', byte]),
+            b'\n' | b'\r' | b'\t' => encoded.push(b' '),
+            byte => encoded.push(byte),
+        }
+    }
+    encoded
+}
+
+/// Helvetica is proportional, half the font size per character is a close enough estimate for
+/// the clickable area of a link
+#[allow(clippy::as_conversions)]
+fn approximate_text_width(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * 0.5
+}
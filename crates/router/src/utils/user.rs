@@ -12,6 +12,8 @@ pub mod dashboard_metadata;
 pub mod password;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod sso;
+pub mod two_factor_auth;
 
 impl UserFromToken {
     pub async fn get_merchant_account(&self, state: AppState) -> UserResult<MerchantAccount> {
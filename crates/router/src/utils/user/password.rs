@@ -5,11 +5,11 @@ use argon2::{
     },
     Argon2,
 };
-use common_utils::errors::CustomResult;
+use common_utils::{crypto, errors::CustomResult};
 use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, Secret};
 
-use crate::core::errors::UserErrors;
+use crate::{consts, core::errors::UserErrors};
 
 pub fn generate_password_hash(
     password: Secret<String>,
@@ -41,3 +41,11 @@ pub fn is_correct_password(
     .into_report()
     .change_context(UserErrors::InternalServerError)
 }
+
+/// Generates the password of a user who has not set one yet, such as an invited user or a user
+/// provisioned through SSO. It is never shared with the user.
+pub fn get_temp_password() -> Secret<String> {
+    Secret::new(crypto::generate_cryptographically_secure_random_string(
+        consts::user::TEMP_PASSWORD_LENGTH,
+    ))
+}
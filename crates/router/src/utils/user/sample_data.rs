@@ -196,6 +196,9 @@ pub async fn generate_sample_data(
             surcharge_applicable: Default::default(),
             request_incremental_authorization: Default::default(),
             incremental_authorization_allowed: Default::default(),
+            presentment_currency: Default::default(),
+            presentment_amount: Default::default(),
+            fx_quote: Default::default(),
         };
         let payment_attempt = PaymentAttemptBatchNew {
             attempt_id: attempt_id.clone(),
@@ -272,6 +275,7 @@ pub async fn generate_sample_data(
                 profile_id: payment_intent.profile_id.clone(),
                 updated_by: merchant_from_db.storage_scheme.to_string(),
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                capture_id: None,
             })
         } else {
             None
@@ -0,0 +1,721 @@
+use std::collections::HashMap;
+
+use api_models::user::OidcConfig;
+use base64::Engine;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::Encode,
+};
+use diesel_models::encryption::Encryption;
+use error_stack::{IntoReport, ResultExt};
+use jsonwebtoken::{jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    db::StorageInterface,
+    headers,
+    routes::AppState,
+    services,
+    types::{self, domain::types as domain_types},
+    utils::ConnectorResponseExt,
+};
+
+/// The endpoints of an OpenID Connect provider, from its discovery document
+#[derive(Debug, serde::Deserialize)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: Option<String>,
+}
+
+/// The parameters of an authorization request, held until the provider redirects back
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SsoState {
+    pub org_id: String,
+    pub nonce: String,
+    pub code_verifier: Secret<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct TokenRequest<'a> {
+    grant_type: &'static str,
+    code: Secret<String>,
+    redirect_uri: &'a str,
+    client_id: &'a str,
+    client_secret: Secret<String>,
+    code_verifier: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct TokenResponse {
+    id_token: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct IdTokenClaims {
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub nonce: Option<String>,
+    #[serde(flatten)]
+    pub additional_claims: HashMap<String, serde_json::Value>,
+}
+
+/// Generates the PKCE code verifier and its S256 code challenge
+pub fn generate_pkce_pair() -> UserResult<(Secret<String>, String)> {
+    let code_verifier = crypto::generate_cryptographically_secure_random_string(
+        consts::user::PKCE_CODE_VERIFIER_LENGTH,
+    );
+    let code_challenge = crypto::Sha256
+        .generate_digest(code_verifier.as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to compute the PKCE code challenge")?;
+
+    Ok((
+        Secret::new(code_verifier),
+        consts::BASE64_ENGINE_URL_SAFE_NO_PAD.encode(code_challenge),
+    ))
+}
+
+/// Encrypts the client secret with the master key, as organizations do not have a key store
+pub async fn encrypt_client_secret(
+    state: &AppState,
+    client_secret: Secret<String>,
+) -> UserResult<Encryption> {
+    domain_types::encrypt(
+        Secret::<_, masking::WithType>::new(client_secret.expose().into_bytes()),
+        state.store.get_master_key(),
+    )
+    .await
+    .map(Into::into)
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to encrypt the SSO client secret")
+}
+
+pub async fn decrypt_client_secret(
+    state: &AppState,
+    encrypted_client_secret: Option<Encryption>,
+) -> UserResult<Secret<String>> {
+    let client_secret = domain_types::decrypt::<Vec<u8>, masking::WithType>(
+        encrypted_client_secret,
+        state.store.get_master_key(),
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to decrypt the SSO client secret")?
+    .ok_or(UserErrors::SsoNotConfigured)
+    .into_report()?;
+
+    String::from_utf8(client_secret.into_inner().expose())
+        .into_report()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to decode the SSO client secret")
+        .map(Secret::new)
+}
+
+fn get_sso_state_key(state: &str) -> String {
+    format!("{}_{}", consts::user::SSO_STATE_REDIS_PREFIX, state)
+}
+
+/// Stores the parameters of the authorization request, returning the state to be sent to the
+/// provider
+pub async fn store_sso_state(
+    store: &dyn StorageInterface,
+    sso_state: &SsoState,
+) -> UserResult<String> {
+    let state =
+        crypto::generate_cryptographically_secure_random_string(consts::user::SSO_STATE_LENGTH);
+    let redis_conn = store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_sso_state_key(&state),
+            sso_state,
+            consts::user::SSO_STATE_EXPIRY_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to store the SSO state")?;
+
+    Ok(state)
+}
+
+/// Fetches and deletes the parameters of the authorization request, so that a state can only be
+/// used once
+pub async fn consume_sso_state(store: &dyn StorageInterface, state: &str) -> UserResult<SsoState> {
+    let key = get_sso_state_key(state);
+    let redis_conn = store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let sso_state = redis_conn
+        .get_and_deserialize_key::<SsoState>(&key, "SsoState")
+        .await
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Invalid or expired SSO state")?;
+
+    match redis_conn
+        .delete_key(&key)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+    {
+        redis_interface::DelReply::KeyDeleted => Ok(sso_state),
+        redis_interface::DelReply::KeyNotDeleted => Err(UserErrors::SsoAuthenticationFailed)
+            .into_report()
+            .attach_printable("SSO state has already been used"),
+    }
+}
+
+pub async fn get_provider_metadata(
+    state: &AppState,
+    issuer: &str,
+) -> UserResult<OidcProviderMetadata> {
+    let discovery_url = format!(
+        "{}{}",
+        issuer.trim_end_matches('/'),
+        consts::user::OIDC_DISCOVERY_PATH
+    );
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(&discovery_url)
+        .attach_default_headers()
+        .build();
+
+    services::call_connector_api(state, request)
+        .await
+        .get_response_inner("OidcProviderMetadata")
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to fetch the OpenID Connect discovery document")
+}
+
+pub fn get_authorization_url(
+    provider_metadata: &OidcProviderMetadata,
+    oidc_config: &OidcConfig,
+    state: &str,
+    nonce: &str,
+    code_challenge: &str,
+) -> UserResult<String> {
+    let mut authorization_url = url::Url::parse(&provider_metadata.authorization_endpoint)
+        .into_report()
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Invalid authorization endpoint")?;
+
+    authorization_url
+        .query_pairs_mut()
+        .append_pair("response_type", "code")
+        .append_pair("client_id", &oidc_config.client_id)
+        .append_pair("redirect_uri", &oidc_config.redirect_uri)
+        .append_pair("scope", consts::user::OIDC_SCOPES)
+        .append_pair("state", state)
+        .append_pair("nonce", nonce)
+        .append_pair("code_challenge", code_challenge)
+        .append_pair("code_challenge_method", "S256");
+
+    Ok(authorization_url.to_string())
+}
+
+/// Exchanges the authorization code for the ID token of the user
+pub async fn exchange_authorization_code(
+    state: &AppState,
+    provider_metadata: &OidcProviderMetadata,
+    oidc_config: &OidcConfig,
+    client_secret: Secret<String>,
+    code: Secret<String>,
+    code_verifier: Secret<String>,
+) -> UserResult<Secret<String>> {
+    let token_request = TokenRequest {
+        grant_type: "authorization_code",
+        code,
+        redirect_uri: &oidc_config.redirect_uri,
+        client_id: &oidc_config.client_id,
+        client_secret,
+        code_verifier,
+    };
+    let body = types::RequestBody::log_and_get_request_body(
+        &token_request,
+        Encode::<TokenRequest<'_>>::url_encode,
+    )
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to encode the token request")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&provider_metadata.token_endpoint)
+        .attach_default_headers()
+        .headers(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            "application/x-www-form-urlencoded".to_string().into(),
+        )])
+        .body(Some(body))
+        .build();
+
+    services::call_connector_api(state, request)
+        .await
+        .get_response_inner::<TokenResponse>("TokenResponse")
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to exchange the authorization code")
+        .map(|token_response| token_response.id_token)
+}
+
+pub async fn get_json_web_key_set(state: &AppState, jwks_uri: &str) -> UserResult<JwkSet> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(jwks_uri)
+        .attach_default_headers()
+        .build();
+
+    services::call_connector_api(state, request)
+        .await
+        .get_response_inner("JwkSet")
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to fetch the JSON web key set of the provider")
+}
+
+/// Verifies the signature, issuer, audience, expiry and nonce of the ID token, and rejects emails
+/// the provider does not mark as verified, as they are matched against existing accounts. Tokens signed with HMAC are verified with the client secret,
+/// others with the key set published by the provider.
+pub fn validate_id_token(
+    id_token: &Secret<String>,
+    issuer: &str,
+    oidc_config: &OidcConfig,
+    client_secret: &Secret<String>,
+    json_web_key_set: Option<&JwkSet>,
+    nonce: &str,
+) -> UserResult<IdTokenClaims> {
+    let header = jsonwebtoken::decode_header(id_token.peek())
+        .into_report()
+        .change_context(UserErrors::SsoAuthenticationFailed)
+        .attach_printable("Failed to decode the ID token header")?;
+
+    let decoding_key = match header.alg {
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => {
+            DecodingKey::from_secret(client_secret.peek().as_bytes())
+        }
+        _ => {
+            let json_web_key_set = json_web_key_set
+                .ok_or(UserErrors::SsoAuthenticationFailed)
+                .into_report()
+                .attach_printable("The provider does not publish a JSON web key set")?;
+            let json_web_key = match header.kid.as_deref() {
+                Some(kid) => json_web_key_set.find(kid),
+                None => json_web_key_set.keys.first(),
+            }
+            .ok_or(UserErrors::SsoAuthenticationFailed)
+            .into_report()
+            .attach_printable("No key found to verify the ID token")?;
+
+            DecodingKey::from_jwk(json_web_key)
+                .into_report()
+                .change_context(UserErrors::SsoAuthenticationFailed)
+                .attach_printable("Invalid key in the JSON web key set")?
+        }
+    };
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[&oidc_config.client_id]);
+
+    let claims = jsonwebtoken::decode::<IdTokenClaims>(id_token.peek(), &decoding_key, &validation)
+        .map_err(|error| {
+            logger::error!(?error, "ID token validation failed");
+            error
+        })
+        .into_report()
+        .change_context(UserErrors::SsoAuthenticationFailed)?
+        .claims;
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(UserErrors::SsoAuthenticationFailed)
+            .into_report()
+            .attach_printable("ID token nonce mismatch");
+    }
+    if claims.email_verified != Some(true) {
+        return Err(UserErrors::SsoAuthenticationFailed)
+            .into_report()
+            .attach_printable("Email of the user is not verified by the provider");
+    }
+
+    Ok(claims)
+}
+
+/// Maps the role claim of the ID token to a role, falling back to the default role when the claim
+/// is missing or none of its values are mapped. The claim can be a string or a list of strings.
+pub fn get_role_id_from_claims(oidc_config: &OidcConfig, claims: &IdTokenClaims) -> String {
+    oidc_config
+        .role_claim
+        .as_ref()
+        .and_then(|role_claim| claims.additional_claims.get(role_claim))
+        .and_then(|claim_value| match claim_value {
+            serde_json::Value::String(value) => oidc_config.role_mapping.get(value).cloned(),
+            serde_json::Value::Array(values) => values
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .find_map(|value| oidc_config.role_mapping.get(value).cloned()),
+            _ => None,
+        })
+        .unwrap_or_else(|| oidc_config.default_role_id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use jsonwebtoken::{EncodingKey, Header};
+    use storage_impl::MockDb;
+
+    use super::*;
+
+    const ISSUER: &str = "https://idp.example.com";
+    const CLIENT_SECRET: &str = "mock_idp_client_secret";
+    const NONCE: &str = "nonce_123";
+
+    fn get_oidc_config() -> OidcConfig {
+        OidcConfig {
+            issuer: ISSUER.to_string(),
+            client_id: "hyperswitch_dashboard".to_string(),
+            redirect_uri: "https://dashboard.example.com/sso/callback".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            default_role_id: "merchant_view_only".to_string(),
+            role_claim: Some("groups".to_string()),
+            role_mapping: HashMap::from([(
+                "payments-admins".to_string(),
+                "merchant_admin".to_string(),
+            )]),
+        }
+    }
+
+    /// Issues an ID token the way a provider using client secret based signing would
+    fn issue_id_token(audience: &str, nonce: &str, groups: serde_json::Value) -> Secret<String> {
+        sign_id_token(get_id_token_claims(audience, nonce, groups))
+    }
+
+    fn get_id_token_claims(
+        audience: &str,
+        nonce: &str,
+        groups: serde_json::Value,
+    ) -> serde_json::Value {
+        let exp = common_utils::date_time::now_unix_timestamp() + 300;
+        serde_json::json!({
+            "iss": ISSUER,
+            "sub": "user_123",
+            "aud": audience,
+            "exp": exp,
+            "email": "user@example.com",
+            "email_verified": true,
+            "nonce": nonce,
+            "groups": groups,
+        })
+    }
+
+    fn sign_id_token(claims: serde_json::Value) -> Secret<String> {
+        Secret::new(
+            jsonwebtoken::encode(
+                &Header::new(Algorithm::HS256),
+                &claims,
+                &EncodingKey::from_secret(CLIENT_SECRET.as_bytes()),
+            )
+            .unwrap(),
+        )
+    }
+
+    /// The authorization request as the provider receives it when the user is sent to it
+    struct AuthorizationRequest {
+        state: String,
+        nonce: String,
+        code_challenge: String,
+    }
+
+    /// A provider which signs the user in as soon as they reach its authorization endpoint
+    struct MockIdp {
+        provider_metadata: OidcProviderMetadata,
+    }
+
+    impl MockIdp {
+        fn new() -> Self {
+            Self {
+                provider_metadata: OidcProviderMetadata {
+                    issuer: ISSUER.to_string(),
+                    authorization_endpoint: format!("{ISSUER}/authorize"),
+                    token_endpoint: format!("{ISSUER}/token"),
+                    jwks_uri: None,
+                },
+            }
+        }
+
+        fn authorize(&self, authorization_url: &str) -> AuthorizationRequest {
+            let authorization_url = url::Url::parse(authorization_url).unwrap();
+            assert!(authorization_url
+                .as_str()
+                .starts_with(&self.provider_metadata.authorization_endpoint));
+            let query: HashMap<_, _> = authorization_url.query_pairs().into_owned().collect();
+            let oidc_config = get_oidc_config();
+            assert_eq!(query["response_type"], "code");
+            assert_eq!(query["client_id"], oidc_config.client_id);
+            assert_eq!(query["redirect_uri"], oidc_config.redirect_uri);
+            assert_eq!(query["code_challenge_method"], "S256");
+
+            AuthorizationRequest {
+                state: query["state"].clone(),
+                nonce: query["nonce"].clone(),
+                code_challenge: query["code_challenge"].clone(),
+            }
+        }
+
+        /// Exchanges the code for an ID token once the code verifier matches the challenge of the
+        /// authorization request
+        fn exchange_code(
+            &self,
+            authorization_request: &AuthorizationRequest,
+            code_verifier: &Secret<String>,
+            claims: serde_json::Value,
+        ) -> Secret<String> {
+            let code_challenge = crypto::Sha256
+                .generate_digest(code_verifier.peek().as_bytes())
+                .unwrap();
+            assert_eq!(
+                consts::BASE64_ENGINE_URL_SAFE_NO_PAD.encode(code_challenge),
+                authorization_request.code_challenge
+            );
+            sign_id_token(claims)
+        }
+    }
+
+    async fn start_authorization(mockdb: &MockDb, idp: &MockIdp) -> AuthorizationRequest {
+        let (code_verifier, code_challenge) = generate_pkce_pair().unwrap();
+        let nonce =
+            crypto::generate_cryptographically_secure_random_string(consts::user::SSO_STATE_LENGTH);
+        let state = store_sso_state(
+            mockdb,
+            &SsoState {
+                org_id: "org_123".to_string(),
+                nonce: nonce.clone(),
+                code_verifier,
+            },
+        )
+        .await
+        .unwrap();
+        let authorization_url = get_authorization_url(
+            &idp.provider_metadata,
+            &get_oidc_config(),
+            &state,
+            &nonce,
+            &code_challenge,
+        )
+        .unwrap();
+
+        idp.authorize(&authorization_url)
+    }
+
+    async fn get_mock_db() -> MockDb {
+        MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store")
+    }
+
+    #[test]
+    fn test_validate_id_token() {
+        let oidc_config = get_oidc_config();
+        let id_token = issue_id_token(
+            &oidc_config.client_id,
+            NONCE,
+            serde_json::json!(["payments-admins"]),
+        );
+
+        let claims = validate_id_token(
+            &id_token,
+            ISSUER,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            NONCE,
+        )
+        .expect("Failed to validate the ID token");
+
+        assert_eq!(claims.email.as_deref(), Some("user@example.com"));
+        assert_eq!(
+            get_role_id_from_claims(&oidc_config, &claims),
+            "merchant_admin"
+        );
+    }
+
+    #[test]
+    fn test_validate_id_token_rejects_nonce_mismatch() {
+        let oidc_config = get_oidc_config();
+        let id_token = issue_id_token(&oidc_config.client_id, "other_nonce", serde_json::json!([]));
+
+        assert!(validate_id_token(
+            &id_token,
+            ISSUER,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            NONCE,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_id_token_rejects_other_audience() {
+        let oidc_config = get_oidc_config();
+        let id_token = issue_id_token("other_client", NONCE, serde_json::json!([]));
+
+        assert!(validate_id_token(
+            &id_token,
+            ISSUER,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            NONCE,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_role_id_falls_back_to_default_role() {
+        let oidc_config = get_oidc_config();
+        let id_token = issue_id_token(
+            &oidc_config.client_id,
+            NONCE,
+            serde_json::json!("unmapped-group"),
+        );
+        let claims = validate_id_token(
+            &id_token,
+            ISSUER,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            NONCE,
+        )
+        .unwrap();
+
+        assert_eq!(
+            get_role_id_from_claims(&oidc_config, &claims),
+            "merchant_view_only"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sso_authorize_and_callback() {
+        let mockdb = get_mock_db().await;
+        let idp = MockIdp::new();
+        let oidc_config = get_oidc_config();
+        let authorization_request = start_authorization(&mockdb, &idp).await;
+
+        let sso_state = consume_sso_state(&mockdb, &authorization_request.state)
+            .await
+            .unwrap();
+        assert_eq!(sso_state.org_id, "org_123");
+        let id_token = idp.exchange_code(
+            &authorization_request,
+            &sso_state.code_verifier,
+            get_id_token_claims(
+                &oidc_config.client_id,
+                &authorization_request.nonce,
+                serde_json::json!(["payments-admins"]),
+            ),
+        );
+        let claims = validate_id_token(
+            &id_token,
+            &idp.provider_metadata.issuer,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            &sso_state.nonce,
+        )
+        .unwrap();
+        assert_eq!(claims.email.as_deref(), Some("user@example.com"));
+
+        // The state can only be used once
+        let error = consume_sso_state(&mockdb, &authorization_request.state)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::SsoAuthenticationFailed
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sso_callback_rejects_state_mismatch() {
+        let mockdb = get_mock_db().await;
+        let idp = MockIdp::new();
+        start_authorization(&mockdb, &idp).await;
+
+        let error = consume_sso_state(&mockdb, "forged_state")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::SsoAuthenticationFailed
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sso_callback_rejects_nonce_mismatch() {
+        let mockdb = get_mock_db().await;
+        let idp = MockIdp::new();
+        let oidc_config = get_oidc_config();
+        let first_request = start_authorization(&mockdb, &idp).await;
+        let second_request = start_authorization(&mockdb, &idp).await;
+
+        // An ID token issued for the first authorization is replayed on the callback of the second
+        let sso_state = consume_sso_state(&mockdb, &second_request.state)
+            .await
+            .unwrap();
+        let id_token = idp.exchange_code(
+            &second_request,
+            &sso_state.code_verifier,
+            get_id_token_claims(
+                &oidc_config.client_id,
+                &first_request.nonce,
+                serde_json::json!([]),
+            ),
+        );
+        let error = validate_id_token(
+            &id_token,
+            ISSUER,
+            &oidc_config,
+            &Secret::new(CLIENT_SECRET.to_string()),
+            None,
+            &sso_state.nonce,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::SsoAuthenticationFailed
+        ));
+    }
+
+    #[test]
+    fn test_validate_id_token_requires_verified_email() {
+        let oidc_config = get_oidc_config();
+
+        for email_verified in [serde_json::json!(false), serde_json::Value::Null] {
+            let mut claims =
+                get_id_token_claims(&oidc_config.client_id, NONCE, serde_json::json!([]));
+            claims["email_verified"] = email_verified;
+
+            assert!(validate_id_token(
+                &sign_id_token(claims),
+                ISSUER,
+                &oidc_config,
+                &Secret::new(CLIENT_SECRET.to_string()),
+                None,
+                NONCE,
+            )
+            .is_err());
+        }
+    }
+}
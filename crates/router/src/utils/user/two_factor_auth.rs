@@ -0,0 +1,173 @@
+use common_utils::{crypto, pii};
+use diesel_models::encryption::Encryption;
+use error_stack::{IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use totp_rs::{Algorithm, TOTP};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    db::StorageInterface,
+    routes::AppState,
+    types::domain::types as domain_types,
+    utils::user::password,
+};
+
+pub fn generate_totp_secret() -> Vec<u8> {
+    crypto::generate_cryptographically_secure_random_bytes::<{ consts::user::TOTP_SECRET_LENGTH }>()
+        .to_vec()
+}
+
+pub fn generate_totp(secret: Vec<u8>, email: pii::Email) -> UserResult<TOTP> {
+    TOTP::new(
+        Algorithm::SHA1,
+        consts::user::TOTP_DIGITS,
+        consts::user::TOTP_TOLERANCE,
+        consts::user::TOTP_VALIDITY_DURATION_IN_SECONDS,
+        secret,
+        Some(consts::user::TOTP_ISSUER_NAME.to_string()),
+        email.expose().expose(),
+    )
+    .into_report()
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to construct the TOTP")
+}
+
+pub fn is_valid_totp(totp: &TOTP, candidate: &Secret<String>) -> UserResult<bool> {
+    totp.check_current(candidate.peek().trim())
+        .into_report()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to compute the current TOTP")
+}
+
+/// Encrypts the TOTP secret with the master key, as dashboard users do not have a key store
+pub async fn encrypt_totp_secret(state: &AppState, secret: Vec<u8>) -> UserResult<Encryption> {
+    domain_types::encrypt(
+        Secret::<_, masking::WithType>::new(secret),
+        state.store.get_master_key(),
+    )
+    .await
+    .map(Into::into)
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to encrypt the TOTP secret")
+}
+
+pub async fn decrypt_totp_secret(
+    state: &AppState,
+    encrypted_secret: Option<Encryption>,
+) -> UserResult<Vec<u8>> {
+    domain_types::decrypt::<Vec<u8>, masking::WithType>(
+        encrypted_secret,
+        state.store.get_master_key(),
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)
+    .attach_printable("Failed to decrypt the TOTP secret")?
+    .map(|secret| secret.into_inner().expose())
+    .ok_or(UserErrors::TotpNotSetup.into())
+}
+
+/// Generates the recovery codes, returning the codes to be shown to the user along with the
+/// argon2 hashes to be stored
+pub fn generate_recovery_codes() -> UserResult<(Vec<Secret<String>>, Vec<String>)> {
+    let recovery_codes: Vec<Secret<String>> = (0..consts::user::TOTP_RECOVERY_CODES_COUNT)
+        .map(|_| {
+            Secret::new(crypto::generate_cryptographically_secure_random_string(
+                consts::user::TOTP_RECOVERY_CODE_LENGTH,
+            ))
+        })
+        .collect();
+
+    let hashed_recovery_codes = recovery_codes
+        .iter()
+        .map(|code| password::generate_password_hash(code.clone()).map(ExposeInterface::expose))
+        .collect::<UserResult<Vec<String>>>()?;
+
+    Ok((recovery_codes, hashed_recovery_codes))
+}
+
+/// Returns the position of the stored hash matching the recovery code, if any
+pub fn find_recovery_code(
+    hashed_recovery_codes: &[String],
+    candidate: &Secret<String>,
+) -> UserResult<Option<usize>> {
+    for (index, hashed_code) in hashed_recovery_codes.iter().enumerate() {
+        let candidate = Secret::new(candidate.peek().trim().to_string());
+        if password::is_correct_password(candidate, Secret::new(hashed_code.clone()))? {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+/// Counts an attempt at the TOTP step of the user, failing once the user has run out of attempts
+/// until the lockout expires. The attempt is counted before the code is checked, so that concurrent
+/// requests cannot exceed the limit and locked out users cost no hashing.
+pub async fn consume_totp_attempt(store: &dyn StorageInterface, user_id: &str) -> UserResult<()> {
+    let attempts = store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .increment_key_with_expiry(
+            &get_totp_attempts_key(user_id),
+            consts::user::TOTP_LOCKOUT_DURATION_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if attempts > consts::user::TOTP_MAX_FAILED_ATTEMPTS {
+        return Err(UserErrors::TotpAttemptsExceeded.into());
+    }
+    Ok(())
+}
+
+pub async fn clear_totp_attempts(store: &dyn StorageInterface, user_id: &str) -> UserResult<()> {
+    store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_key(&get_totp_attempts_key(user_id))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+fn get_totp_attempts_key(user_id: &str) -> String {
+    format!(
+        "{}{}",
+        consts::user::TOTP_FAILED_ATTEMPTS_REDIS_PREFIX,
+        user_id
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+    use super::*;
+    use storage_impl::MockDb;
+
+    #[tokio::test]
+    async fn test_totp_attempts_lock_out_the_user() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings {
+            in_memory: true,
+            ..Default::default()
+        })
+        .await
+        .expect("Failed to create Mock store");
+
+        for _ in 0..consts::user::TOTP_MAX_FAILED_ATTEMPTS {
+            consume_totp_attempt(&mockdb, "user_1").await.unwrap();
+        }
+        let error = consume_totp_attempt(&mockdb, "user_1").await.unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            UserErrors::TotpAttemptsExceeded
+        ));
+
+        // Attempts are counted per user
+        consume_totp_attempt(&mockdb, "user_2").await.unwrap();
+
+        clear_totp_attempts(&mockdb, "user_1").await.unwrap();
+        consume_totp_attempt(&mockdb, "user_1").await.unwrap();
+    }
+}
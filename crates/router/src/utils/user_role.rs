@@ -1,5 +1,5 @@
 use api_models::user_role as user_role_api;
-use diesel_models::enums::UserStatus;
+use diesel_models::{enums::UserStatus, role::Role};
 use error_stack::ResultExt;
 use router_env::logger;
 
@@ -7,9 +7,13 @@ use crate::{
     consts,
     core::errors::{UserErrors, UserResult},
     routes::AppState,
-    services::authorization::{
-        permissions::Permission,
-        predefined_permissions::{self, RoleInfo},
+    services::{
+        authentication::UserFromToken,
+        authorization::{
+            self,
+            permissions::Permission,
+            predefined_permissions::{self, RoleInfo},
+        },
     },
 };
 
@@ -34,11 +38,153 @@ pub async fn get_merchant_ids_for_user(state: AppState, user_id: &str) -> UserRe
         .collect())
 }
 
-pub fn validate_role_id(role_id: &str) -> UserResult<()> {
+pub async fn validate_role_id(
+    state: &AppState,
+    role_id: &str,
+    merchant_id: &str,
+    org_id: &str,
+) -> UserResult<()> {
     if predefined_permissions::is_role_invitable(role_id) {
         return Ok(());
     }
-    Err(UserErrors::InvalidRoleId.into())
+
+    state
+        .store
+        .find_role_by_role_id_in_merchant_scope(role_id, merchant_id, org_id)
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                return e.change_context(UserErrors::InvalidRoleId);
+            }
+            e.change_context(UserErrors::InternalServerError)
+        })?;
+
+    Ok(())
+}
+
+/// Checks that the role name is not used by a predefined role or by another custom role which
+/// can be assigned within the merchant
+pub async fn validate_role_name(
+    state: &AppState,
+    role_name: &str,
+    merchant_id: &str,
+    org_id: &str,
+) -> UserResult<()> {
+    let role_name = role_name.trim();
+    if role_name.is_empty() {
+        return Err(UserErrors::InvalidRoleOperation.into())
+            .attach_printable("Role name must not be empty");
+    }
+
+    let is_predefined_role_name = predefined_permissions::PREDEFINED_PERMISSIONS
+        .values()
+        .filter_map(|role_info| role_info.get_name())
+        .any(|name| name.eq_ignore_ascii_case(role_name));
+
+    let is_custom_role_name = state
+        .store
+        .list_all_roles(merchant_id, org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .iter()
+        .any(|role| role.role_name.eq_ignore_ascii_case(role_name));
+
+    if is_predefined_role_name || is_custom_role_name {
+        return Err(UserErrors::RoleNameAlreadyExists.into());
+    }
+    Ok(())
+}
+
+/// Checks that the user holds every permission they are granting, so that custom roles cannot be
+/// used to escalate privileges
+pub async fn validate_permissions_grantable(
+    state: &AppState,
+    user_from_token: &UserFromToken,
+    permissions: &[user_role_api::Permission],
+) -> UserResult<()> {
+    let permissions: Vec<Permission> = permissions
+        .iter()
+        .map(|permission| Permission::from(*permission))
+        .collect();
+
+    check_permissions_grantable(
+        &get_user_permissions(state, user_from_token).await?,
+        &permissions,
+    )
+}
+
+/// Checks that the user holds every permission of the role they are assigning to another user
+pub async fn validate_role_grantable(
+    state: &AppState,
+    user_from_token: &UserFromToken,
+    role_id: &str,
+) -> UserResult<()> {
+    let role_permissions = authorization::get_permissions(
+        state,
+        role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    check_permissions_grantable(
+        &get_user_permissions(state, user_from_token).await?,
+        &role_permissions,
+    )
+}
+
+async fn get_user_permissions(
+    state: &AppState,
+    user_from_token: &UserFromToken,
+) -> UserResult<Vec<Permission>> {
+    authorization::get_permissions(
+        state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)
+}
+
+fn check_permissions_grantable(
+    user_permissions: &[Permission],
+    permissions: &[Permission],
+) -> UserResult<()> {
+    if let Some(permission) = permissions
+        .iter()
+        .find(|permission| !user_permissions.contains(*permission))
+    {
+        return Err(UserErrors::InvalidRoleOperation.into()).attach_printable(format!(
+            "User cannot grant the {permission} permission which they do not have"
+        ));
+    }
+    Ok(())
+}
+
+/// Converts the requested permissions into the form they are stored in, dropping the duplicates
+pub fn get_permissions_for_storage(permissions: &[user_role_api::Permission]) -> Vec<String> {
+    let mut stored_permissions: Vec<String> = Vec::with_capacity(permissions.len());
+    for permission in permissions {
+        let permission = Permission::from(*permission).to_string();
+        if !stored_permissions.contains(&permission) {
+            stored_permissions.push(permission);
+        }
+    }
+    stored_permissions
+}
+
+pub fn get_custom_role_info_response(role: Role) -> user_role_api::RoleInfoResponse {
+    user_role_api::RoleInfoResponse {
+        permissions: authorization::parse_permissions(&role.permissions)
+            .iter()
+            .filter_map(|permission| permission.try_into().ok())
+            .collect(),
+        role_id: role.role_id,
+        role_name: role.role_name,
+        scope: Some(role.scope),
+    }
 }
 
 pub fn get_role_name_and_permission_response(
@@ -83,6 +229,7 @@ impl TryFrom<&Permission> for user_role_api::Permission {
             Permission::SurchargeDecisionManagerRead => Ok(Self::SurchargeDecisionManagerRead),
             Permission::UsersRead => Ok(Self::UsersRead),
             Permission::UsersWrite => Ok(Self::UsersWrite),
+            Permission::AuditLogRead => Ok(Self::AuditLogRead),
 
             Permission::MerchantAccountCreate => {
                 logger::error!("Invalid use of internal permission");
@@ -91,3 +238,83 @@ impl TryFrom<&Permission> for user_role_api::Permission {
         }
     }
 }
+
+impl From<user_role_api::Permission> for Permission {
+    fn from(value: user_role_api::Permission) -> Self {
+        match value {
+            user_role_api::Permission::PaymentRead => Self::PaymentRead,
+            user_role_api::Permission::PaymentWrite => Self::PaymentWrite,
+            user_role_api::Permission::RefundRead => Self::RefundRead,
+            user_role_api::Permission::RefundWrite => Self::RefundWrite,
+            user_role_api::Permission::ApiKeyRead => Self::ApiKeyRead,
+            user_role_api::Permission::ApiKeyWrite => Self::ApiKeyWrite,
+            user_role_api::Permission::MerchantAccountRead => Self::MerchantAccountRead,
+            user_role_api::Permission::MerchantAccountWrite => Self::MerchantAccountWrite,
+            user_role_api::Permission::MerchantConnectorAccountRead => {
+                Self::MerchantConnectorAccountRead
+            }
+            user_role_api::Permission::MerchantConnectorAccountWrite => {
+                Self::MerchantConnectorAccountWrite
+            }
+            user_role_api::Permission::ForexRead => Self::ForexRead,
+            user_role_api::Permission::RoutingRead => Self::RoutingRead,
+            user_role_api::Permission::RoutingWrite => Self::RoutingWrite,
+            user_role_api::Permission::DisputeRead => Self::DisputeRead,
+            user_role_api::Permission::DisputeWrite => Self::DisputeWrite,
+            user_role_api::Permission::MandateRead => Self::MandateRead,
+            user_role_api::Permission::MandateWrite => Self::MandateWrite,
+            user_role_api::Permission::FileRead => Self::FileRead,
+            user_role_api::Permission::FileWrite => Self::FileWrite,
+            user_role_api::Permission::Analytics => Self::Analytics,
+            user_role_api::Permission::ThreeDsDecisionManagerWrite => {
+                Self::ThreeDsDecisionManagerWrite
+            }
+            user_role_api::Permission::ThreeDsDecisionManagerRead => {
+                Self::ThreeDsDecisionManagerRead
+            }
+            user_role_api::Permission::SurchargeDecisionManagerWrite => {
+                Self::SurchargeDecisionManagerWrite
+            }
+            user_role_api::Permission::SurchargeDecisionManagerRead => {
+                Self::SurchargeDecisionManagerRead
+            }
+            user_role_api::Permission::UsersRead => Self::UsersRead,
+            user_role_api::Permission::UsersWrite => Self::UsersWrite,
+            user_role_api::Permission::AuditLogRead => Self::AuditLogRead,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_permissions_grantable() {
+        let user_permissions = [
+            Permission::PaymentRead,
+            Permission::RefundRead,
+            Permission::UsersWrite,
+        ];
+
+        assert!(check_permissions_grantable(&user_permissions, &[]).is_ok());
+        assert!(check_permissions_grantable(
+            &user_permissions,
+            &[Permission::PaymentRead, Permission::RefundRead],
+        )
+        .is_ok());
+        assert!(check_permissions_grantable(
+            &user_permissions,
+            &[Permission::PaymentRead, Permission::PaymentWrite],
+        )
+        .is_err());
+        assert!(check_permissions_grantable(
+            &user_permissions,
+            &predefined_permissions::PREDEFINED_PERMISSIONS
+                .get(consts::user_role::ROLE_ID_ORGANIZATION_ADMIN)
+                .map(|role_info| role_info.get_permissions().to_vec())
+                .unwrap_or_default(),
+        )
+        .is_err());
+    }
+}
@@ -1,3 +1,6 @@
+pub mod authorization_expiry;
+pub mod dispute_deadline;
+pub mod forex_rate_snapshot;
 pub mod payment_sync;
 pub mod refund_router;
 pub mod tokenized_data;
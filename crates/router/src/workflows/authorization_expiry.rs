@@ -0,0 +1,410 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    configs::settings::AuthorizationExpiryAction,
+    core::{
+        errors::{DataStorageError, StorageErrorExt},
+        payment_methods::Oss,
+        payments::{self as payment_flows, operations, PaymentData},
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+/// Tracking data of the task acting on a payment still awaiting capture once it is due
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub action: AuthorizationExpiryAction,
+}
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &tracking_data.payment_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        // Nothing to do if the payment was captured, cancelled or retried in the meantime
+        let is_awaiting_capture = matches!(
+            payment_intent.status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        ) && payment_intent.active_attempt.get_id()
+            == tracking_data.attempt_id;
+
+        if is_awaiting_capture {
+            logger::info!(
+                payment_id = %tracking_data.payment_id,
+                action = ?tracking_data.action,
+                "Acting on payment awaiting capture"
+            );
+            match tracking_data.action {
+                AuthorizationExpiryAction::Capture => {
+                    capture_payment(state, merchant_account, key_store, &tracking_data).await?
+                }
+                AuthorizationExpiryAction::Void => {
+                    void_payment(state, merchant_account, key_store, &tracking_data).await?
+                }
+                AuthorizationExpiryAction::Expire => {
+                    expire_payment(
+                        state,
+                        merchant_account,
+                        key_store,
+                        payment_intent,
+                        &tracking_data,
+                    )
+                    .await?
+                }
+            }
+        }
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+async fn capture_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &tracking_data.payment_id,
+            &merchant_account.merchant_id,
+            &tracking_data.attempt_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Whatever is left of the authorization is captured, as the last capture on the payment
+    let request = api::PaymentsCaptureRequest {
+        payment_id: tracking_data.payment_id.clone(),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        amount_to_capture: Some(payment_attempt.amount_capturable),
+        final_capture: Some(true),
+        ..Default::default()
+    };
+
+    let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::Capture,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state,
+        merchant_account.clone(),
+        key_store,
+        operations::PaymentCapture,
+        request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    trigger_webhook(
+        state,
+        merchant_account,
+        payment_data,
+        customer,
+        operations::PaymentCapture,
+    )
+    .await
+}
+
+async fn void_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let request = api::PaymentsCancelRequest {
+        payment_id: tracking_data.payment_id.clone(),
+        cancellation_reason: Some("authorization_expired".to_string()),
+        ..Default::default()
+    };
+
+    let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::Void,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state,
+        merchant_account.clone(),
+        key_store,
+        operations::PaymentCancel,
+        request,
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    trigger_webhook(
+        state,
+        merchant_account,
+        payment_data,
+        customer,
+        operations::PaymentCancel,
+    )
+    .await
+}
+
+/// Marks the payment as expired without calling the connector, nothing is left to capture on it
+async fn expire_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &tracking_data.payment_id,
+            &merchant_account.merchant_id,
+            &tracking_data.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if !matches!(
+        payment_attempt.status,
+        enums::AttemptStatus::Authorized | enums::AttemptStatus::PartialChargedAndChargeable
+    ) {
+        return Ok(());
+    }
+
+    // A capture made since the attempt was read must not be overwritten, so the attempt is only
+    // expired if it is still awaiting capture
+    match db
+        .update_payment_attempt_with_attempt_id_and_status(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::AmountToCaptureUpdate {
+                status: enums::AttemptStatus::Expired,
+                amount_capturable: 0,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(_) => {}
+        Err(error) if matches!(error.current_context(), DataStorageError::ValueNotFound(_)) => {
+            logger::info!(
+                payment_id = %tracking_data.payment_id,
+                "Payment attempt is no longer awaiting capture, not expiring it"
+            );
+            return Ok(());
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to expire the payment attempt")?,
+    }
+
+    db.update_payment_intent(
+        payment_intent,
+        storage::PaymentIntentUpdate::PGStatusUpdate {
+            status: enums::IntentStatus::Expired,
+            incremental_authorization_allowed: Some(false),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+
+    let (payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state,
+        merchant_account.clone(),
+        key_store,
+        operations::PaymentStatus,
+        request,
+        payment_flows::CallConnectorAction::Avoid,
+        services::AuthFlow::Merchant,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    trigger_webhook(
+        state,
+        merchant_account,
+        payment_data,
+        customer,
+        operations::PaymentStatus,
+    )
+    .await
+}
+
+async fn trigger_webhook<F, Op>(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    payment_data: PaymentData<F>,
+    customer: Option<domain::Customer>,
+    operation: Op,
+) -> Result<(), sch_errors::ProcessTrackerError>
+where
+    F: Send + Clone + Sync,
+    Op: std::fmt::Debug,
+{
+    let profile_id = payment_data
+        .payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not find profile_id in payment intent")?;
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    Box::pin(utils::trigger_payments_webhook::<
+        _,
+        api_models::payments::PaymentsRequest,
+        _,
+    >(
+        merchant_account,
+        business_profile,
+        payment_data,
+        None,
+        customer,
+        state,
+        operation,
+    ))
+    .await
+    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::types::transformers::ForeignTryFrom;
+
+    #[test]
+    fn test_outcome_of_every_action_is_notified() {
+        // Capturing, voiding and expiring settle the payment in these statuses
+        let outcomes = [
+            (
+                AuthorizationExpiryAction::Capture,
+                enums::IntentStatus::Succeeded,
+                enums::EventType::PaymentSucceeded,
+            ),
+            (
+                AuthorizationExpiryAction::Void,
+                enums::IntentStatus::Cancelled,
+                enums::EventType::PaymentCancelled,
+            ),
+            (
+                AuthorizationExpiryAction::Expire,
+                enums::IntentStatus::Expired,
+                enums::EventType::PaymentExpired,
+            ),
+        ];
+
+        for (action, status, event_type) in outcomes {
+            assert!(
+                utils::is_payment_outcome_notified(status),
+                "{action:?} is not notified"
+            );
+            assert_eq!(
+                enums::EventType::foreign_try_from(status).unwrap(),
+                event_type
+            );
+        }
+    }
+}
@@ -0,0 +1,424 @@
+use std::str::FromStr;
+
+use api_models::admin::{DisputeAutoAcceptThreshold, DisputeSettings};
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        disputes,
+        errors::{RouterResult, StorageErrorExt},
+        metrics, webhooks,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// Tracking data of the task acting on an open dispute ahead of its evidence deadline
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DisputeDeadlineTrackingData {
+    pub merchant_id: String,
+    pub dispute_id: String,
+}
+
+/// An action taken ahead of the evidence deadline of a dispute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisputeDeadlineAction {
+    /// The merchant is reminded that the evidence is due within the given number of hours
+    Reminder { offset_in_hours: u16 },
+    /// The dispute is accepted if it is eligible for automatic acceptance
+    AutoAccept,
+}
+
+pub struct DisputeDeadlineWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: DisputeDeadlineTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlineTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &merchant_account.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+                dispute_id: tracking_data.dispute_id.clone(),
+            })?;
+
+        let profile_id = dispute
+            .profile_id
+            .as_ref()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not find profile_id in dispute")?;
+
+        let business_profile = db
+            .find_business_profile_by_profile_id(profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        // Nothing is left to do once the dispute is challenged, accepted or closed, or once its
+        // evidence is overdue
+        let now = common_utils::date_time::now();
+        let is_open = dispute.dispute_stage == enums::DisputeStage::Dispute
+            && dispute.dispute_status == enums::DisputeStatus::DisputeOpened;
+        let dispute_settings = disputes::get_dispute_settings(&business_profile)?;
+        let actions = match (&dispute_settings, dispute.challenge_required_by) {
+            (Some(dispute_settings), Some(challenge_required_by))
+                if is_open && now < challenge_required_by =>
+            {
+                get_deadline_actions(dispute_settings, challenge_required_by)
+            }
+            _ => Vec::new(),
+        };
+
+        // Only the latest of the actions that are due is taken, the earlier ones were taken on
+        // the previous runs or are stale by now
+        let due_at = actions
+            .iter()
+            .map(|(due_at, _)| *due_at)
+            .filter(|due_at| *due_at <= now)
+            .max();
+        for (_, action) in actions
+            .iter()
+            .filter(|(action_due_at, _)| Some(*action_due_at) == due_at)
+        {
+            match action {
+                DisputeDeadlineAction::Reminder { offset_in_hours } => {
+                    send_deadline_reminder(
+                        state,
+                        &merchant_account,
+                        &business_profile,
+                        &dispute,
+                        *offset_in_hours,
+                    )
+                    .await
+                }
+                DisputeDeadlineAction::AutoAccept => {
+                    let is_eligible = match dispute_settings.as_ref().and_then(|dispute_settings| {
+                        dispute_settings.auto_accept_threshold.as_ref()
+                    }) {
+                        Some(auto_accept_threshold) => {
+                            is_eligible_for_auto_accept(auto_accept_threshold, &dispute)?
+                        }
+                        None => false,
+                    };
+                    if is_eligible {
+                        auto_accept_dispute(
+                            state,
+                            merchant_account.clone(),
+                            key_store.clone(),
+                            &business_profile,
+                            &dispute,
+                        )
+                        .await
+                    }
+                }
+            }
+        }
+
+        let next_due_at = actions
+            .iter()
+            .map(|(due_at, _)| *due_at)
+            .find(|due_at| *due_at > now);
+        match next_due_at {
+            Some(schedule_time) => {
+                let updated_process_tracker_data = storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(schedule_time),
+                    tracking_data: None,
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                };
+                db.process_tracker_update_process_status_by_ids(
+                    vec![process.id.clone()],
+                    updated_process_tracker_data,
+                )
+                .await?;
+            }
+            None => {
+                let id = process.id.clone();
+                process
+                    .finish_with_status(
+                        state.get_db().as_scheduler(),
+                        format!("COMPLETED_BY_PT_{id}"),
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Returns the actions to take ahead of the evidence deadline of a dispute, in the order in which
+/// they are due
+pub fn get_deadline_actions(
+    dispute_settings: &DisputeSettings,
+    challenge_required_by: PrimitiveDateTime,
+) -> Vec<(PrimitiveDateTime, DisputeDeadlineAction)> {
+    let mut actions: Vec<_> = dispute_settings
+        .reminder_offsets_in_hours
+        .iter()
+        .map(|offset_in_hours| {
+            (
+                challenge_required_by
+                    .saturating_sub(time::Duration::hours(i64::from(*offset_in_hours))),
+                DisputeDeadlineAction::Reminder {
+                    offset_in_hours: *offset_in_hours,
+                },
+            )
+        })
+        .collect();
+    if dispute_settings.auto_accept_threshold.is_some() {
+        actions.push((
+            challenge_required_by.saturating_sub(time::Duration::hours(
+                consts::DISPUTE_AUTO_ACCEPT_OFFSET_IN_HOURS,
+            )),
+            DisputeDeadlineAction::AutoAccept,
+        ));
+    }
+    actions.sort_by_key(|(due_at, _)| *due_at);
+    actions.dedup();
+    actions
+}
+
+/// Returns whether the amount of the dispute is below the automatic acceptance threshold and no
+/// evidence is attached to it
+fn is_eligible_for_auto_accept(
+    auto_accept_threshold: &DisputeAutoAcceptThreshold,
+    dispute: &storage::Dispute,
+) -> RouterResult<bool> {
+    let api::DisputeEvidence {
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+        // The summary document is rendered from the payment, not attached by the merchant
+        summary_file: _,
+    } = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let has_evidence = [
+        cancellation_policy,
+        customer_communication,
+        customer_signature,
+        receipt,
+        refund_policy,
+        service_documentation,
+        shipping_documentation,
+        invoice_showing_distinct_transactions,
+        recurring_transaction_agreement,
+        uncategorized_file,
+    ]
+    .iter()
+    .any(Option::is_some);
+
+    let is_below_threshold = enums::Currency::from_str(&dispute.currency.to_uppercase()).ok()
+        == Some(auto_accept_threshold.currency)
+        && dispute
+            .amount
+            .parse::<i64>()
+            .map_or(false, |amount| amount < auto_accept_threshold.amount);
+
+    Ok(is_below_threshold && !has_evidence)
+}
+
+async fn send_deadline_reminder(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &storage::business_profile::BusinessProfile,
+    dispute: &storage::Dispute,
+    offset_in_hours: u16,
+) {
+    let event_type = enums::EventType::DisputeDeadlineApproaching;
+    // Each of the reminders of a dispute is an event of its own
+    let event_id = format!("{}_{event_type}_{offset_in_hours}h", dispute.dispute_id);
+    let result = webhooks::create_event_with_id_and_trigger_appropriate_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile.clone(),
+        event_id,
+        event_type,
+        enums::EventClass::Disputes,
+        None,
+        dispute.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute.clone().foreign_into())),
+    )
+    .await;
+
+    match result {
+        Ok(()) => metrics::DISPUTE_DEADLINE_REMINDER_SENT_METRIC.add(&metrics::CONTEXT, 1, &[]),
+        Err(error) => logger::warn!(dispute_deadline_reminder_error=?error),
+    }
+}
+
+/// Accepts the dispute on behalf of the merchant. A failure is only logged, so that the merchant
+/// is still reminded of the deadline and can act on the dispute themselves.
+async fn auto_accept_dispute(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    business_profile: &storage::business_profile::BusinessProfile,
+    dispute: &storage::Dispute,
+) {
+    logger::info!(dispute_id = %dispute.dispute_id, "Accepting dispute automatically");
+    let result = accept_dispute_and_trigger_webhook(
+        state,
+        merchant_account,
+        key_store,
+        business_profile,
+        dispute,
+    )
+    .await;
+
+    match result {
+        Ok(()) => metrics::DISPUTE_AUTO_ACCEPTED_METRIC.add(&metrics::CONTEXT, 1, &[]),
+        Err(error) => logger::error!(dispute_auto_accept_error=?error),
+    }
+}
+
+async fn accept_dispute_and_trigger_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    business_profile: &storage::business_profile::BusinessProfile,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    let accept_dispute_response = disputes::accept_dispute(
+        state.clone(),
+        merchant_account.clone(),
+        key_store,
+        api::DisputeId {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )
+    .await?;
+    let dispute_response = match accept_dispute_response {
+        services::ApplicationResponse::Json(dispute_response) => dispute_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response received from accept dispute core")?,
+    };
+
+    let event_type: enums::EventType = dispute_response.dispute_status.foreign_into();
+    webhooks::create_event_and_trigger_appropriate_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile.clone(),
+        event_type,
+        enums::EventClass::Disputes,
+        None,
+        dispute_response.dispute_id.clone(),
+        enums::EventObjectType::DisputeDetails,
+        api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+    )
+    .await
+    .map_err(|error| logger::warn!(dispute_outgoing_webhook_error=?error))
+    .ok();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_deadline_actions() {
+        let challenge_required_by = time::macros::datetime!(2023-12-20 12:00);
+        let dispute_settings = DisputeSettings {
+            reminder_offsets_in_hours: vec![2, 72, 24, 24],
+            auto_accept_threshold: Some(DisputeAutoAcceptThreshold {
+                amount: 1000,
+                currency: enums::Currency::USD,
+            }),
+        };
+
+        let actions = get_deadline_actions(&dispute_settings, challenge_required_by);
+
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    time::macros::datetime!(2023-12-17 12:00),
+                    DisputeDeadlineAction::Reminder {
+                        offset_in_hours: 72
+                    }
+                ),
+                (
+                    time::macros::datetime!(2023-12-19 12:00),
+                    DisputeDeadlineAction::Reminder {
+                        offset_in_hours: 24
+                    }
+                ),
+                (
+                    time::macros::datetime!(2023-12-20 10:00),
+                    DisputeDeadlineAction::Reminder { offset_in_hours: 2 }
+                ),
+                (
+                    time::macros::datetime!(2023-12-20 10:00),
+                    DisputeDeadlineAction::AutoAccept
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, utils as pt_utils, SchedulerAppState,
+};
+use time::PrimitiveDateTime;
+
+use crate::{consts, errors, routes::AppState, types::storage, utils::currency};
+
+const RUNNER: &str = "FOREX_RATE_SNAPSHOT_WORKFLOW";
+const TASK: &str = "FOREX_RATE_SNAPSHOT";
+
+/// Records the rates of the configured forex providers once a day, as the snapshot of the day the
+/// provider published them for
+pub struct ForexRateSnapshotWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ForexRateSnapshotWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let now = common_utils::date_time::now();
+        // The task never finishes, it is scheduled again for the next day, or retried later in
+        // the day when no provider could be reached
+        let schedule_time = match currency::record_forex_rate_snapshot(state).await {
+            Ok(snapshot) => {
+                logger::info!(
+                    rate_date = %snapshot.rate_date,
+                    provider = snapshot.provider,
+                    "Recorded forex rate snapshot"
+                );
+                get_next_schedule_time(now)
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to record forex rate snapshot");
+                now.saturating_add(time::Duration::minutes(
+                    consts::FOREX_RATE_SNAPSHOT_RETRY_DELAY_IN_MINUTES,
+                ))
+            }
+        };
+        process
+            .reset(state.get_db().as_scheduler(), schedule_time)
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Creates the daily forex rate snapshot task, due right away, unless it already exists
+pub async fn add_forex_rate_snapshot_task(
+    state: &AppState,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(RUNNER, TASK, "daily", "global");
+    let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+        process_tracker_id,
+        TASK,
+        RUNNER,
+        serde_json::json!({}),
+        common_utils::date_time::now(),
+    )?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+/// The start of the day after `now`, in UTC
+fn get_next_schedule_time(now: PrimitiveDateTime) -> PrimitiveDateTime {
+    now.date()
+        .saturating_add(time::Duration::days(1))
+        .midnight()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_schedule_time_is_the_start_of_the_next_day() {
+        assert_eq!(
+            get_next_schedule_time(datetime!(2023-12-01 00:00:00)),
+            datetime!(2023-12-02 00:00:00)
+        );
+        assert_eq!(
+            get_next_schedule_time(datetime!(2023-12-31 23:59:59)),
+            datetime!(2024-01-01 00:00:00)
+        );
+    }
+}
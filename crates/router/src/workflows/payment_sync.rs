@@ -88,6 +88,7 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
             enums::AttemptStatus::VoidFailed,
             enums::AttemptStatus::CaptureFailed,
             enums::AttemptStatus::Failure,
+            enums::AttemptStatus::Expired,
         ];
         match &payment_data.payment_attempt.status {
             status if terminal_status.contains(status) => {
@@ -128,6 +128,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             reason: None,
             connector_refund_id: None,
             browser_info: None,
+            connector_capture_id: None,
         },
         payment_method_id: None,
         response: Err(types::ErrorResponse::default()),
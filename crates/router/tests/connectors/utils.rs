@@ -400,6 +400,7 @@ pub trait ConnectorActions: Connector {
                 reason: None,
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
+                connector_capture_id: None,
             }),
             payment_info,
         );
@@ -983,6 +984,7 @@ impl Default for PaymentRefundType {
             reason: Some("Customer returned product".to_string()),
             connector_refund_id: None,
             browser_info: None,
+            connector_capture_id: None,
         };
         Self(data)
     }
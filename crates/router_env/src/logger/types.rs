@@ -52,7 +52,7 @@ pub enum Tag {
 }
 
 /// API Flow
-#[derive(Debug, Display, Clone, PartialEq, Eq)]
+#[derive(Debug, Display, EnumString, Clone, PartialEq, Eq)]
 pub enum Flow {
     /// Merchants account create flow.
     MerchantsAccountCreate,
@@ -114,6 +114,8 @@ pub enum Flow {
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Payment methods attach flow.
+    PaymentMethodsAttach,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -124,6 +126,8 @@ pub enum Flow {
     PaymentsConfirm,
     /// Payments capture flow.
     PaymentsCapture,
+    /// Payments list captures flow.
+    PaymentsListCaptures,
     /// Payments cancel flow.
     PaymentsCancel,
     /// Payments approve flow.
@@ -136,6 +140,10 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Charges retrieve flow.
+    ChargesRetrieve,
+    /// Charges list flow.
+    ChargesList,
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
@@ -187,6 +195,10 @@ pub enum Flow {
     RoutingDeleteConfig,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Events retrieve flow
+    EventsRetrieve,
+    /// Events list flow
+    EventsList,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -197,6 +209,8 @@ pub enum Flow {
     ApiKeyUpdate,
     /// API Key revoke flow
     ApiKeyRevoke,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key list flow
     ApiKeyList,
     /// Dispute Retrieve flow
@@ -219,6 +233,8 @@ pub enum Flow {
     AttachDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Assemble Dispute Evidence flow
+    AssembleDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -227,6 +243,14 @@ pub enum Flow {
     PaymentLinkInitiate,
     /// Payment Link List flow
     PaymentLinkList,
+    /// Payment Link Deactivate flow
+    PaymentLinkDeactivate,
+    /// Payment Link QR code flow
+    PaymentLinkQrCode,
+    /// Payment Link Invoice flow
+    PaymentLinkInvoice,
+    /// Payment Link Create Payment flow
+    PaymentLinkCreatePayment,
     /// Create a business profile
     BusinessProfileCreate,
     /// Update a business profile
@@ -277,6 +301,14 @@ pub enum Flow {
     GetRole,
     /// Update user role
     UpdateUserRole,
+    /// Create custom role
+    CreateRole,
+    /// Update custom role
+    UpdateRole,
+    /// Delete custom role
+    DeleteRole,
+    /// Remove a user from a merchant
+    DeleteUserRole,
     /// Create merchant account for user in a org
     UserMerchantAccountCreate,
     /// Generate Sample Data
@@ -287,6 +319,42 @@ pub enum Flow {
     UserMerchantAccountList,
     /// Get users for merchant account
     GetUserDetails,
+    /// Begin the TOTP enrollment of a user
+    TotpBegin,
+    /// Verify the TOTP or recovery code of a user
+    TotpVerify,
+    /// Reset the TOTP of a user
+    TotpReset,
+    /// Enforce TOTP for the users of an organization
+    TotpEnforcementUpdate,
+    /// Configure the SSO provider of an organization
+    SsoConfigUpdate,
+    /// Start the SSO sign in of a user
+    SsoAuthorize,
+    /// Complete the SSO sign in of a user
+    SsoCallback,
+    /// Invite a user to a merchant
+    InviteUser,
+    /// Accept an invitation from the invitation email
+    AcceptInviteFromEmail,
+    /// Send a password reset email
+    ForgotPassword,
+    /// Reset the password from the password reset email
+    ResetPassword,
+    /// List process tracker tasks
+    ProcessTrackerList,
+    /// Retrieve a process tracker task
+    ProcessTrackerRetrieve,
+    /// Reschedule a process tracker task
+    ProcessTrackerReschedule,
+    /// Force run a process tracker task
+    ProcessTrackerForceRun,
+    /// Cancel a process tracker task
+    ProcessTrackerCancel,
+    /// Retrieve scheduler stream statistics
+    SchedulerStreamStats,
+    /// List the audit log of a merchant
+    AuditLogList,
 }
 
 ///
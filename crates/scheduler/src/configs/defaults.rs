@@ -6,6 +6,8 @@ impl Default for super::settings::SchedulerSettings {
             consumer: super::settings::ConsumerSettings::default(),
             graceful_shutdown_interval: 60000,
             loop_interval: 5000,
+            default_lane_weight: 1,
+            lanes: Vec::new(),
         }
     }
 }
@@ -27,6 +29,7 @@ impl Default for super::settings::ConsumerSettings {
         Self {
             disabled: false,
             consumer_group: "SCHEDULER_GROUP".into(),
+            runner_concurrency: std::collections::HashMap::new(),
         }
     }
 }
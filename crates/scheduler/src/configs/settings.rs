@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 #[cfg(feature = "kms")]
 use external_services::kms;
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
@@ -15,6 +17,49 @@ pub struct SchedulerSettings {
     pub consumer: ConsumerSettings,
     pub loop_interval: u64,
     pub graceful_shutdown_interval: u64,
+    /// Weight of the default lane (`stream`), which carries the tasks of runners not assigned to
+    /// any of the configured `lanes`
+    pub default_lane_weight: u32,
+    pub lanes: Vec<LaneSettings>,
+}
+
+impl SchedulerSettings {
+    /// Name of the stream backing a priority lane
+    pub fn get_lane_stream_name(&self, lane: &LaneSettings) -> String {
+        format!("{}_{}", self.stream, lane.name)
+    }
+
+    /// Name of the stream that tasks of the specified runner are appended to
+    pub fn get_stream_name_for_runner(&self, runner: Option<&str>) -> String {
+        runner
+            .and_then(|runner| {
+                self.lanes
+                    .iter()
+                    .find(|lane| lane.runners.iter().any(|lane_runner| lane_runner == runner))
+            })
+            .map(|lane| self.get_lane_stream_name(lane))
+            .unwrap_or_else(|| self.stream.clone())
+    }
+
+    /// All streams consumed by the scheduler, along with the weights of their lanes
+    pub fn get_weighted_streams(&self) -> Vec<(String, u32)> {
+        std::iter::once((self.stream.clone(), self.default_lane_weight))
+            .chain(
+                self.lanes
+                    .iter()
+                    .map(|lane| (self.get_lane_stream_name(lane), lane.weight)),
+            )
+            .collect()
+    }
+}
+
+/// A priority lane has its own stream, consumers pick lanes in proportion to their weights
+#[derive(Debug, Clone, Deserialize)]
+pub struct LaneSettings {
+    pub name: String,
+    pub weight: u32,
+    /// Runners whose tasks are routed to this lane, e.g. `REFUND_WORKFLOW_ROUTER`
+    pub runners: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -33,4 +78,75 @@ pub struct ProducerSettings {
 pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
+    /// Maximum number of tasks of a runner a consumer executes at once, runners not listed here
+    /// are not limited
+    pub runner_concurrency: HashMap<String, usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_with_lanes() -> SchedulerSettings {
+        SchedulerSettings {
+            lanes: vec![
+                LaneSettings {
+                    name: "refunds".into(),
+                    weight: 3,
+                    runners: vec!["REFUND_WORKFLOW_ROUTER".into()],
+                },
+                LaneSettings {
+                    name: "payments".into(),
+                    weight: 2,
+                    runners: vec![
+                        "PAYMENTS_SYNC_WORKFLOW".into(),
+                        "AUTHORIZATION_EXPIRY_WORKFLOW".into(),
+                    ],
+                },
+            ],
+            ..SchedulerSettings::default()
+        }
+    }
+
+    #[test]
+    fn test_get_stream_name_for_runner() {
+        let settings = settings_with_lanes();
+
+        assert_eq!(
+            settings.get_stream_name_for_runner(Some("REFUND_WORKFLOW_ROUTER")),
+            "SCHEDULER_STREAM_refunds"
+        );
+        assert_eq!(
+            settings.get_stream_name_for_runner(Some("AUTHORIZATION_EXPIRY_WORKFLOW")),
+            "SCHEDULER_STREAM_payments"
+        );
+        assert_eq!(
+            settings.get_stream_name_for_runner(Some("API_KEY_EXPIRY_WORKFLOW")),
+            "SCHEDULER_STREAM"
+        );
+        assert_eq!(
+            settings.get_stream_name_for_runner(None),
+            "SCHEDULER_STREAM"
+        );
+        assert_eq!(
+            SchedulerSettings::default().get_stream_name_for_runner(Some("REFUND_WORKFLOW_ROUTER")),
+            "SCHEDULER_STREAM"
+        );
+    }
+
+    #[test]
+    fn test_get_weighted_streams() {
+        assert_eq!(
+            settings_with_lanes().get_weighted_streams(),
+            vec![
+                ("SCHEDULER_STREAM".to_string(), 1),
+                ("SCHEDULER_STREAM_refunds".to_string(), 3),
+                ("SCHEDULER_STREAM_payments".to_string(), 2),
+            ]
+        );
+        assert_eq!(
+            SchedulerSettings::default().get_weighted_streams(),
+            vec![("SCHEDULER_STREAM".to_string(), 1)]
+        );
+    }
 }
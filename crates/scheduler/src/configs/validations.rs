@@ -19,10 +19,72 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        when(self.default_lane_weight == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler default lane weight must be greater than zero".into(),
+            ))
+        })?;
+
+        let mut lane_names = std::collections::HashSet::new();
+        let mut lane_runners = std::collections::HashSet::new();
+        for lane in &self.lanes {
+            lane.validate()?;
+
+            when(!lane_names.insert(lane.name.as_str()), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "scheduler lane `{}` is configured more than once",
+                    lane.name
+                )))
+            })?;
+
+            for runner in &lane.runners {
+                when(!lane_runners.insert(runner.as_str()), || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "scheduler runner `{runner}` must not be assigned to more than one lane"
+                    )))
+                })?;
+            }
+        }
+
+        self.consumer.validate()?;
+
         Ok(())
     }
 }
 
+impl super::settings::LaneSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduler lane name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.weight == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "weight of scheduler lane `{}` must be greater than zero",
+                self.name
+            )))
+        })
+    }
+}
+
+impl super::settings::ConsumerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.runner_concurrency
+            .iter()
+            .try_for_each(|(runner, concurrency)| {
+                common_utils::fp_utils::when(*concurrency == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "concurrency limit of scheduler runner `{runner}` must be greater than zero"
+                    )))
+                })
+            })
+    }
+}
+
 impl super::settings::ProducerSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.lock_key.is_default_or_empty(), || {
@@ -51,6 +51,8 @@ pub async fn start_consumer<T: SchedulerAppState + 'static>(
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let runner_limiter =
+        types::RunnerConcurrencyLimiter::new(&settings.consumer.runner_concurrency);
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!("Signal Handler Error: {:?}", error);
@@ -78,6 +80,7 @@ pub async fn start_consumer<T: SchedulerAppState + 'static>(
                         logger::error!(%err);
                     },
                     sync::Arc::clone(&consumer_operation_counter),
+                    runner_limiter.clone(),
                     workflow_selector,
                 ));
             }
@@ -109,27 +112,51 @@ pub async fn start_consumer<T: SchedulerAppState + 'static>(
 pub async fn consumer_operations<T: SchedulerAppState + 'static>(
     state: &T,
     settings: &SchedulerSettings,
+    runner_limiter: types::RunnerConcurrencyLimiter,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
-    let stream_name = settings.stream.clone();
     let group_name = settings.consumer.consumer_group.clone();
     let consumer_name = format!("consumer_{}", Uuid::new_v4());
 
-    let group_created = &mut state
-        .get_db()
-        .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
-        .await;
-    if group_created.is_err() {
-        logger::info!("Consumer group already exists");
+    // Lanes are tried in a weighted random order, the first lane with pending tasks is consumed
+    let mut last_error = None;
+    let mut picked = None;
+    for stream_name in pt_utils::get_streams_by_priority(settings) {
+        let group_created = &mut state
+            .get_db()
+            .consumer_group_create(&stream_name, &group_name, &RedisEntryId::AfterLastID)
+            .await;
+        if group_created.is_err() {
+            logger::info!("Consumer group already exists");
+        }
+
+        match state
+            .get_db()
+            .as_scheduler()
+            .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
+            .await
+        {
+            Ok(tasks) if tasks.is_empty() => continue,
+            Ok(tasks) => {
+                picked = Some((stream_name, tasks));
+                break;
+            }
+            Err(error) => last_error = Some(error),
+        }
     }
 
-    let mut tasks = state
-        .get_db()
-        .as_scheduler()
-        .fetch_consumer_tasks(&stream_name, &group_name, &consumer_name)
-        .await?;
+    let (stream_name, mut tasks) = match (picked, last_error) {
+        (Some(picked), _) => picked,
+        (None, Some(error)) => return Err(error),
+        (None, None) => return Ok(()),
+    };
 
-    logger::info!("{} picked {} tasks", consumer_name, tasks.len());
+    logger::info!(
+        "{} picked {} tasks from {}",
+        consumer_name,
+        tasks.len(),
+        stream_name
+    );
     let mut handler = vec![];
 
     for task in tasks.iter_mut() {
@@ -139,12 +166,14 @@ pub async fn consumer_operations<T: SchedulerAppState + 'static>(
 
         metrics::TASK_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
         // let runner = workflow_selector(task)?.ok_or(errors::ProcessTrackerError::UnexpectedFlow)?;
-        handler.push(tokio::task::spawn(start_workflow(
-            state.clone(),
-            task.clone(),
-            pickup_time,
-            workflow_selector,
-        )))
+        let state = state.clone();
+        let task = task.clone();
+        let runner_limiter = runner_limiter.clone();
+        handler.push(tokio::task::spawn(async move {
+            // The permit is held until the workflow completes
+            let _permit = runner_limiter.acquire(task.runner.as_deref()).await;
+            start_workflow(state, task, pickup_time, workflow_selector).await
+        }))
     }
     future::join_all(handler).await;
 
@@ -1,4 +1,5 @@
 pub mod batch;
+pub mod limiter;
 pub mod process_data;
 
-pub use self::batch::ProcessTrackerBatch;
+pub use self::{batch::ProcessTrackerBatch, limiter::RunnerConcurrencyLimiter};
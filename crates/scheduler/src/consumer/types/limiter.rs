@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Bounds the number of tasks of each runner that a consumer executes at once
+#[derive(Debug, Clone, Default)]
+pub struct RunnerConcurrencyLimiter {
+    semaphores: Arc<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl RunnerConcurrencyLimiter {
+    pub fn new(runner_concurrency: &HashMap<String, usize>) -> Self {
+        Self {
+            semaphores: Arc::new(
+                runner_concurrency
+                    .iter()
+                    .map(|(runner, limit)| (runner.clone(), Arc::new(Semaphore::new(*limit))))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Waits until the runner has capacity to execute another task. The returned permit must be
+    /// held until the task completes. Runners without a configured limit are never throttled.
+    pub async fn acquire(&self, runner: Option<&str>) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self.semaphores.get(runner?)?;
+        Arc::clone(semaphore).acquire_owned().await.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[test]
+    fn test_runner_concurrency_limit() {
+        let limiter =
+            RunnerConcurrencyLimiter::new(&HashMap::from([("REFUND_WORKFLOW_ROUTER".into(), 2)]));
+
+        let first = limiter
+            .acquire(Some("REFUND_WORKFLOW_ROUTER"))
+            .now_or_never()
+            .expect("first permit should be available");
+        let second = limiter
+            .acquire(Some("REFUND_WORKFLOW_ROUTER"))
+            .now_or_never()
+            .expect("second permit should be available");
+        assert!(first.is_some() && second.is_some());
+
+        // The limit is reached, a third task has to wait for a running task to complete
+        assert!(limiter
+            .acquire(Some("REFUND_WORKFLOW_ROUTER"))
+            .now_or_never()
+            .is_none());
+
+        drop(first);
+        assert!(limiter
+            .acquire(Some("REFUND_WORKFLOW_ROUTER"))
+            .now_or_never()
+            .expect("released permit should be available")
+            .is_some());
+    }
+
+    #[test]
+    fn test_runners_without_limit_are_not_throttled() {
+        let limiter =
+            RunnerConcurrencyLimiter::new(&HashMap::from([("REFUND_WORKFLOW_ROUTER".into(), 1)]));
+
+        let permits = (0..10)
+            .map(|_| {
+                limiter
+                    .acquire(Some("PAYMENTS_SYNC_WORKFLOW"))
+                    .now_or_never()
+                    .expect("unlimited runner should never wait")
+            })
+            .collect::<Vec<_>>();
+        assert!(permits.iter().all(Option::is_none));
+
+        assert!(limiter.acquire(None).now_or_never().unwrap().is_none());
+    }
+}
@@ -50,6 +50,15 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_filters(
+        &self,
+        runner: Option<String>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -97,6 +106,28 @@ impl ProcessTrackerInterface for Store {
         .into_report()
     }
 
+    async fn find_processes_by_filters(
+        &self,
+        runner: Option<String>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_filters(
+            &conn,
+            runner,
+            status,
+            business_status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -182,6 +213,47 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_processes_by_filters(
+        &self,
+        runner: Option<String>,
+        status: Option<storage_enums::ProcessTrackerStatus>,
+        business_status: Option<String>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let offset: usize = offset
+            .unwrap_or_default()
+            .try_into()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?;
+        let limit = limit
+            .map(usize::try_from)
+            .transpose()
+            .into_report()
+            .change_context(errors::StorageError::MockDbError)?
+            .unwrap_or(usize::MAX);
+
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                runner
+                    .as_ref()
+                    .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+                    && status.map_or(true, |status| process.status == status)
+                    && business_status.as_ref().map_or(true, |business_status| {
+                        &process.business_status == business_status
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        processes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(processes.into_iter().skip(offset).take(limit).collect())
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
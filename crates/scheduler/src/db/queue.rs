@@ -1,6 +1,6 @@
 use common_utils::errors::CustomResult;
 use diesel_models::process_tracker as storage;
-use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply};
+use redis_interface::{errors::RedisError, RedisEntryId, SetnxReply, StreamGroupInfo};
 use router_env::logger;
 use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
 
@@ -40,6 +40,13 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    async fn stream_get_length(&self, stream: &str) -> CustomResult<usize, RedisError>;
+
+    async fn consumer_group_info(
+        &self,
+        stream: &str,
+    ) -> CustomResult<Vec<StreamGroupInfo>, RedisError>;
 }
 
 #[async_trait::async_trait]
@@ -132,6 +139,17 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.get_redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+
+    async fn stream_get_length(&self, stream: &str) -> CustomResult<usize, RedisError> {
+        self.get_redis_conn()?.stream_get_length(stream).await
+    }
+
+    async fn consumer_group_info(
+        &self,
+        stream: &str,
+    ) -> CustomResult<Vec<StreamGroupInfo>, RedisError> {
+        self.get_redis_conn()?.consumer_group_info(stream).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -187,4 +205,17 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
         Err(RedisError::RedisConnectionError.into())
     }
+
+    async fn stream_get_length(&self, _stream: &str) -> CustomResult<usize, RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Err(RedisError::GetLengthFailed)?
+    }
+
+    async fn consumer_group_info(
+        &self,
+        _stream: &str,
+    ) -> CustomResult<Vec<StreamGroupInfo>, RedisError> {
+        // [#172]: Implement function for `MockDb`
+        Err(RedisError::ConsumerGroupInfoFailed)?
+    }
 }
@@ -16,8 +16,11 @@ use super::{
     env::logger,
 };
 use crate::{
-    configs::settings::SchedulerSettings, consumer::types::ProcessTrackerBatch, errors,
-    flow::SchedulerFlow, metrics, SchedulerAppState, SchedulerInterface,
+    configs::settings::SchedulerSettings,
+    consumer::types::{ProcessTrackerBatch, RunnerConcurrencyLimiter},
+    errors,
+    flow::SchedulerFlow,
+    metrics, SchedulerAppState, SchedulerInterface,
 };
 
 pub async fn divide_and_append_tasks<T>(
@@ -152,24 +155,76 @@ pub fn divide_into_batches(
 ) -> Vec<ProcessTrackerBatch> {
     let batch_id = Uuid::new_v4().to_string();
 
-    tasks
-        .chunks(batch_size)
-        .fold(Vec::new(), |mut batches, item| {
-            let batch = ProcessTrackerBatch {
+    group_tasks_by_stream(tasks, conf).into_iter().fold(
+        Vec::new(),
+        |mut batches, (stream_name, tasks)| {
+            batches.extend(tasks.chunks(batch_size).map(|item| ProcessTrackerBatch {
                 id: batch_id.clone(),
                 group_name: conf.consumer.consumer_group.clone(),
-                stream_name: conf.stream.clone(),
+                stream_name: stream_name.clone(),
                 connection_name: String::new(),
                 created_time: batch_creation_time,
                 rule: String::new(), // is it required?
                 trackers: item.to_vec(),
-            };
-            batches.push(batch);
+            }));
 
             batches
+        },
+    )
+}
+
+/// Groups tasks by the stream of the priority lane their runner is assigned to, preserving the
+/// order in which the streams are first encountered
+fn group_tasks_by_stream(
+    tasks: Vec<storage::ProcessTracker>,
+    conf: &SchedulerSettings,
+) -> Vec<(String, Vec<storage::ProcessTracker>)> {
+    tasks
+        .into_iter()
+        .fold(Vec::new(), |mut groups: Vec<(String, Vec<_>)>, task| {
+            let stream_name = conf.get_stream_name_for_runner(task.runner.as_deref());
+            match groups.iter_mut().find(|(name, _)| *name == stream_name) {
+                Some((_, group)) => group.push(task),
+                None => groups.push((stream_name, vec![task])),
+            }
+
+            groups
         })
 }
 
+/// Orders the streams of all priority lanes for a consumer to read from, by sampling lanes
+/// without replacement in proportion to their weights
+pub fn get_streams_by_priority(conf: &SchedulerSettings) -> Vec<String> {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let mut streams = conf.get_weighted_streams();
+    let mut ordered_streams = Vec::with_capacity(streams.len());
+
+    while !streams.is_empty() {
+        let total_weight = streams
+            .iter()
+            .map(|(_, weight)| u64::from(*weight))
+            .sum::<u64>();
+        let mut pick = rng.gen_range(0..total_weight.max(1));
+        let index = streams
+            .iter()
+            .position(|(_, weight)| {
+                let weight = u64::from(*weight);
+                if pick < weight {
+                    true
+                } else {
+                    pick -= weight;
+                    false
+                }
+            })
+            .unwrap_or_default();
+        ordered_streams.push(streams.remove(index).0);
+    }
+
+    ordered_streams
+}
+
 pub async fn get_batches(
     conn: &RedisConnectionPool,
     stream_name: &str,
@@ -246,6 +301,7 @@ pub async fn consumer_operation_handler<E, T: Send + Sync + 'static>(
     settings: sync::Arc<SchedulerSettings>,
     error_handler_fun: E,
     consumer_operation_counter: sync::Arc<atomic::AtomicU64>,
+    runner_limiter: RunnerConcurrencyLimiter,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) where
     // Error handler function
@@ -255,7 +311,8 @@ pub async fn consumer_operation_handler<E, T: Send + Sync + 'static>(
     consumer_operation_counter.fetch_add(1, atomic::Ordering::Release);
     let start_time = std_time::Instant::now();
 
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(&state, &settings, runner_limiter, workflow_selector).await
+    {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -379,3 +436,112 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::configs::settings::LaneSettings;
+
+    fn settings_with_lanes(refunds_weight: u32) -> SchedulerSettings {
+        SchedulerSettings {
+            lanes: vec![LaneSettings {
+                name: "refunds".into(),
+                weight: refunds_weight,
+                runners: vec!["REFUND_WORKFLOW_ROUTER".into()],
+            }],
+            ..SchedulerSettings::default()
+        }
+    }
+
+    fn task(id: &str, runner: &str) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: id.to_string(),
+            name: None,
+            tag: Vec::new(),
+            runner: Some(runner.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::Value::Null,
+            business_status: String::new(),
+            status: ProcessTrackerStatus::New,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_divide_into_batches_by_lane() {
+        let settings = settings_with_lanes(1);
+        let tasks = vec![
+            task("1", "REFUND_WORKFLOW_ROUTER"),
+            task("2", "PAYMENTS_SYNC_WORKFLOW"),
+            task("3", "REFUND_WORKFLOW_ROUTER"),
+            task("4", "REFUND_WORKFLOW_ROUTER"),
+        ];
+
+        let batches = divide_into_batches(2, tasks, common_utils::date_time::now(), &settings);
+        let batches = batches
+            .iter()
+            .map(|batch| {
+                (
+                    batch.stream_name.as_str(),
+                    batch
+                        .trackers
+                        .iter()
+                        .map(|tracker| tracker.id.as_str())
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            batches,
+            vec![
+                ("SCHEDULER_STREAM_refunds", vec!["1", "3"]),
+                ("SCHEDULER_STREAM_refunds", vec!["4"]),
+                ("SCHEDULER_STREAM", vec!["2"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_streams_by_priority_includes_every_lane_once() {
+        let settings = settings_with_lanes(1);
+
+        for _ in 0..100 {
+            let mut streams = get_streams_by_priority(&settings);
+            streams.sort();
+            assert_eq!(
+                streams,
+                vec!["SCHEDULER_STREAM", "SCHEDULER_STREAM_refunds"]
+            );
+        }
+    }
+
+    #[test]
+    fn test_get_streams_by_priority_follows_weights() {
+        // The refunds lane carries 99% of the weight, so it is expected to come first in about
+        // 9900 out of 10000 orderings, the bound leaves a wide margin to keep the test stable
+        let settings = settings_with_lanes(99);
+
+        let refunds_first = (0..10_000)
+            .filter(|_| {
+                get_streams_by_priority(&settings)
+                    .first()
+                    .map_or(false, |stream| stream == "SCHEDULER_STREAM_refunds")
+            })
+            .count();
+
+        assert!(
+            refunds_first > 9_000,
+            "refunds lane came first {refunds_first} times"
+        );
+        assert!(
+            refunds_first < 10_000,
+            "default lane was never picked first"
+        );
+    }
+}
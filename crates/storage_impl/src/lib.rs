@@ -198,7 +198,8 @@ impl<T: DatabaseStore> KVRouterStore<T> {
     }
 
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
-        format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
+        // Example: {shard_5}_drainer_stream
+        redis_interface::hash_tagged_key(shard_key, &self.drainer_stream_name)
     }
 
     pub async fn push_to_drainer_stream<R>(
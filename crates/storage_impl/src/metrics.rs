@@ -8,3 +8,12 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // Metrics for KV
 counter_metric!(KV_OPERATION_SUCCESSFUL, GLOBAL_METER);
 counter_metric!(KV_OPERATION_FAILED, GLOBAL_METER);
+
+// Metrics for in-memory caches
+counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+
+// Metrics for the Redis tier backing the in-memory caches
+counter_metric!(REDIS_CACHE_HIT, GLOBAL_METER);
+counter_metric!(REDIS_CACHE_MISS, GLOBAL_METER);
@@ -29,10 +29,12 @@ pub struct MockDb {
     pub processes: Arc<Mutex<Vec<store::ProcessTracker>>>,
     pub redis: Arc<RedisStore>,
     pub api_keys: Arc<Mutex<Vec<store::ApiKey>>>,
+    pub audit_logs: Arc<Mutex<Vec<store::audit_log::AuditLog>>>,
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
+    pub forex_rate_snapshots: Arc<Mutex<Vec<store::forex_rate_snapshot::ForexRateSnapshot>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<crate::store::capture::Capture>>>,
@@ -43,6 +45,7 @@ pub struct MockDb {
     pub organizations: Arc<Mutex<Vec<store::organization::Organization>>>,
     pub users: Arc<Mutex<Vec<store::user::User>>>,
     pub user_roles: Arc<Mutex<Vec<store::user_role::UserRole>>>,
+    pub roles: Arc<Mutex<Vec<store::role::Role>>>,
     pub dashboard_metadata: Arc<Mutex<Vec<store::user::dashboard_metadata::DashboardMetadata>>>,
 }
 
@@ -65,10 +68,12 @@ impl MockDb {
                     .change_context(StorageError::InitializationError)?,
             ),
             api_keys: Default::default(),
+            audit_logs: Default::default(),
             ephemeral_keys: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
+            forex_rate_snapshots: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
@@ -79,6 +84,7 @@ impl MockDb {
             organizations: Default::default(),
             users: Default::default(),
             user_roles: Default::default(),
+            roles: Default::default(),
             dashboard_metadata: Default::default(),
         })
     }
@@ -146,6 +146,7 @@ impl PaymentAttemptInterface for MockDb {
             merchant_connector_id: payment_attempt.merchant_connector_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            fx_quote: payment_attempt.fx_quote,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -175,6 +176,31 @@ impl PaymentAttemptInterface for MockDb {
         Ok(item.clone())
     }
 
+    async fn update_payment_attempt_with_attempt_id_and_status(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        let mut payment_attempts = self.payment_attempts.lock().await;
+
+        let item = payment_attempts
+            .iter_mut()
+            .find(|item| item.attempt_id == this.attempt_id && item.status == this.status)
+            .ok_or(StorageError::ValueNotFound(format!(
+                "Payment attempt {} is no longer in {} status",
+                this.attempt_id, this.status
+            )))?;
+
+        *item = PaymentAttempt::from_storage_model(
+            payment_attempt
+                .to_storage_model()
+                .apply_changeset(this.to_storage_model()),
+        );
+
+        Ok(item.clone())
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         _connector_transaction_id: &str,
@@ -108,6 +108,9 @@ impl PaymentIntentInterface for MockDb {
             surcharge_applicable: new.surcharge_applicable,
             request_incremental_authorization: new.request_incremental_authorization,
             incremental_authorization_allowed: new.incremental_authorization_allowed,
+            presentment_currency: new.presentment_currency,
+            presentment_amount: new.presentment_amount,
+            fx_quote: new.fx_quote,
         };
         payment_intents.push(payment_intent.clone());
         Ok(payment_intent)
@@ -73,6 +73,24 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             .map(PaymentAttempt::from_storage_model)
     }
 
+    #[instrument(skip_all)]
+    async fn update_payment_attempt_with_attempt_id_and_status(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        this.to_storage_model()
+            .update_with_attempt_id_and_status(&conn, payment_attempt.to_storage_model())
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+            .map(PaymentAttempt::from_storage_model)
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         connector_transaction_id: &str,
@@ -387,6 +405,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                     unified_code: payment_attempt.unified_code.clone(),
                     unified_message: payment_attempt.unified_message.clone(),
+                    fx_quote: payment_attempt.fx_quote.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -554,6 +573,51 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn update_payment_attempt_with_attempt_id_and_status(
+        &self,
+        this: PaymentAttempt,
+        payment_attempt: PaymentAttemptUpdate,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentAttempt, errors::StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .update_payment_attempt_with_attempt_id_and_status(
+                        this,
+                        payment_attempt,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                // Fields of a Redis hash cannot be updated conditionally, so the status is checked
+                // against the latest attempt right before updating it
+                let latest_attempt = self
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        &this.payment_id,
+                        &this.merchant_id,
+                        &this.attempt_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                if latest_attempt.status != this.status {
+                    return Err(errors::StorageError::ValueNotFound(format!(
+                        "Payment attempt {} is no longer in {} status",
+                        this.attempt_id, this.status
+                    )))
+                    .into_report();
+                }
+                self.update_payment_attempt_with_attempt_id(
+                    latest_attempt,
+                    payment_attempt,
+                    storage_scheme,
+                )
+                .await
+            }
+        }
+    }
+
     async fn find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
         &self,
         connector_transaction_id: &str,
@@ -1042,6 +1106,7 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            fx_quote: self.fx_quote,
         }
     }
 
@@ -1096,6 +1161,7 @@ impl DataModelExt for PaymentAttempt {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            fx_quote: storage_model.fx_quote,
         }
     }
 }
@@ -1150,6 +1216,7 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: self.merchant_connector_id,
             unified_code: self.unified_code,
             unified_message: self.unified_message,
+            fx_quote: self.fx_quote,
         }
     }
 
@@ -1202,6 +1269,7 @@ impl DataModelExt for PaymentAttemptNew {
             merchant_connector_id: storage_model.merchant_connector_id,
             unified_code: storage_model.unified_code,
             unified_message: storage_model.unified_message,
+            fx_quote: storage_model.fx_quote,
         }
     }
 }
@@ -1227,6 +1295,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote,
             } => DieselPaymentAttemptUpdate::Update {
                 amount,
                 currency,
@@ -1243,6 +1312,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote,
             },
             Self::UpdateTrackers {
                 payment_token,
@@ -1291,6 +1361,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                fx_quote,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1312,6 +1383,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                fx_quote,
             },
             Self::VoidUpdate {
                 status,
@@ -1488,6 +1560,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote,
             } => Self::Update {
                 amount,
                 currency,
@@ -1504,6 +1577,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 updated_by,
+                fx_quote,
             },
             DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -1552,6 +1626,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                fx_quote,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1573,6 +1648,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                fx_quote,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
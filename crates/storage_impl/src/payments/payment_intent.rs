@@ -99,6 +99,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                     surcharge_applicable: new.surcharge_applicable,
                     request_incremental_authorization: new.request_incremental_authorization,
                     incremental_authorization_allowed: new.incremental_authorization_allowed,
+                    presentment_currency: new.presentment_currency,
+                    presentment_amount: new.presentment_amount,
+                    fx_quote: new.fx_quote.clone(),
                 };
                 let redis_entry = kv::TypedSql {
                     op: kv::DBOperation::Insert {
@@ -762,6 +765,9 @@ impl DataModelExt for PaymentIntentNew {
             surcharge_applicable: self.surcharge_applicable,
             request_incremental_authorization: self.request_incremental_authorization,
             incremental_authorization_allowed: self.incremental_authorization_allowed,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            fx_quote: self.fx_quote,
         }
     }
 
@@ -804,6 +810,9 @@ impl DataModelExt for PaymentIntentNew {
             surcharge_applicable: storage_model.surcharge_applicable,
             request_incremental_authorization: storage_model.request_incremental_authorization,
             incremental_authorization_allowed: storage_model.incremental_authorization_allowed,
+            presentment_currency: storage_model.presentment_currency,
+            presentment_amount: storage_model.presentment_amount,
+            fx_quote: storage_model.fx_quote,
         }
     }
 }
@@ -851,6 +860,9 @@ impl DataModelExt for PaymentIntent {
             surcharge_applicable: self.surcharge_applicable,
             request_incremental_authorization: self.request_incremental_authorization,
             incremental_authorization_allowed: self.incremental_authorization_allowed,
+            presentment_currency: self.presentment_currency,
+            presentment_amount: self.presentment_amount,
+            fx_quote: self.fx_quote,
         }
     }
 
@@ -894,6 +906,9 @@ impl DataModelExt for PaymentIntent {
             surcharge_applicable: storage_model.surcharge_applicable,
             request_incremental_authorization: storage_model.request_incremental_authorization,
             incremental_authorization_allowed: storage_model.incremental_authorization_allowed,
+            presentment_currency: storage_model.presentment_currency,
+            presentment_amount: storage_model.presentment_amount,
+            fx_quote: storage_model.fx_quote,
         }
     }
 }
@@ -976,6 +991,9 @@ impl DataModelExt for PaymentIntentUpdate {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency,
+                presentment_amount,
+                fx_quote,
             } => DieselPaymentIntentUpdate::Update {
                 amount,
                 currency,
@@ -994,6 +1012,9 @@ impl DataModelExt for PaymentIntentUpdate {
                 metadata,
                 payment_confirm_source,
                 updated_by,
+                presentment_currency,
+                presentment_amount,
+                fx_quote,
             },
             Self::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -4,8 +4,6 @@ pub mod pub_sub;
 
 use std::sync::{atomic, Arc};
 
-use error_stack::{IntoReport, ResultExt};
-use redis_interface::PubsubInterface;
 use router_env::logger;
 
 use self::{kv_store::RedisConnInterface, pub_sub::PubSubInterface};
@@ -44,14 +42,7 @@ impl RedisStore {
         &self,
         channel: &str,
     ) -> error_stack::Result<(), redis_interface::errors::RedisError> {
-        self.redis_conn.subscriber.manage_subscriptions();
-
-        self.redis_conn
-            .subscriber
-            .subscribe::<(), _>(channel)
-            .await
-            .into_report()
-            .change_context(redis_interface::errors::RedisError::SubscribeError)?;
+        self.redis_conn.subscribe_to_channel(channel).await?;
 
         let redis_clone = self.redis_conn.clone();
         tokio::spawn(async move {
@@ -6,12 +6,14 @@ use common_utils::{
 };
 use data_models::errors::StorageError;
 use dyn_clone::DynClone;
-use error_stack::{Report, ResultExt};
+use error_stack::{report, Report, ResultExt};
 use moka::future::Cache as MokaCache;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 use redis_interface::{errors::RedisError, RedisValue};
+use router_env::{logger, opentelemetry::KeyValue};
 
 use super::{kv_store::RedisConnInterface, pub_sub::PubSubInterface};
+use crate::metrics;
 
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
 
@@ -33,12 +35,84 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
-/// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
-pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI, None));
+/// Time to live of entries in Redis 5 mins
+const REDIS_CACHE_TTL: i64 = 5 * 60;
 
-/// Accounts cache with time_to_live as 30 mins and size limit
+/// Time to live of lookups which found nothing 1 min
+const NEGATIVE_CACHE_TTL: u64 = 60;
+
+/// Time to idle of the locks used to coalesce concurrent misses 1 min
+const IN_FLIGHT_LOCK_TTI: u64 = 60;
+
+static CACHE_SETTINGS: OnceCell<CacheSettings> = OnceCell::new();
+
+/// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins by default.
+pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| Cache::new("CONFIG", &cache_settings().config));
+
+/// Accounts cache with time_to_live as 30 mins and size limit by default
 pub static ACCOUNTS_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+    Lazy::new(|| Cache::new("ACCOUNTS", &cache_settings().accounts));
+
+/// Configuration of the caches of every [`CacheKind`]
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct CacheSettings {
+    pub config: CacheKindSettings,
+    pub accounts: CacheKindSettings,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            config: CacheKindSettings {
+                negative_ttl: NEGATIVE_CACHE_TTL,
+                ..Default::default()
+            },
+            accounts: CacheKindSettings {
+                max_capacity: Some(MAX_CAPACITY),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct CacheKindSettings {
+    /// Time in seconds an entry is kept in the in-memory cache
+    pub ttl: u64,
+    /// Time in seconds an entry is kept in the in-memory cache without being accessed
+    pub tti: u64,
+    /// Max size in MB's of the in-memory cache
+    pub max_capacity: Option<u64>,
+    /// Time in seconds an entry is kept in Redis
+    pub redis_ttl: i64,
+    /// Time in seconds a lookup which found nothing is remembered, `0` disables negative caching
+    pub negative_ttl: u64,
+}
+
+impl Default for CacheKindSettings {
+    fn default() -> Self {
+        Self {
+            ttl: CACHE_TTL,
+            tti: CACHE_TTI,
+            max_capacity: None,
+            redis_ttl: REDIS_CACHE_TTL,
+            negative_ttl: 0,
+        }
+    }
+}
+
+/// Configures the caches, which must happen before any of them is used.
+pub fn set_cache_settings(settings: CacheSettings) {
+    if CACHE_SETTINGS.set(settings).is_err() {
+        logger::warn!("Cache settings have already been set, ignoring the new settings");
+    }
+}
+
+fn cache_settings() -> &'static CacheSettings {
+    CACHE_SETTINGS.get_or_init(CacheSettings::default)
+}
 
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
@@ -91,7 +165,19 @@ where
 dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
+    name: &'static str,
     inner: MokaCache<String, Arc<dyn Cacheable>>,
+    /// Keys for which the last lookup found nothing
+    negative: Option<MokaCache<String, ()>>,
+    /// Locks held while populating a key, so that concurrent misses fetch the value only once
+    in_flight: MokaCache<String, Arc<tokio::sync::Mutex<()>>>,
+    redis_ttl: i64,
+}
+
+enum Lookup<T> {
+    Value(T),
+    NotFound,
+    Missing,
 }
 
 impl std::ops::Deref for Cache {
@@ -102,27 +188,69 @@ impl std::ops::Deref for Cache {
 }
 
 impl Cache {
-    /// With given `time_to_live` and `time_to_idle` creates a moka cache.
+    /// Creates a moka cache named `name` in metrics, with the given settings.
     ///
-    /// `time_to_live`: Time in seconds before an object is stored in a caching system before it’s deleted
-    /// `time_to_idle`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
+    /// `ttl`: Time in seconds before an object is stored in a caching system before it’s deleted
+    /// `tti`: Time in seconds before a `get` or `insert` operation an object is stored in a caching system before it's deleted
     /// `max_capacity`: Max size in MB's that the cache can hold
-    pub fn new(time_to_live: u64, time_to_idle: u64, max_capacity: Option<u64>) -> Self {
+    /// `negative_ttl`: Time in seconds a lookup which found nothing is remembered
+    pub fn new(name: &'static str, settings: &CacheKindSettings) -> Self {
         let mut cache_builder = MokaCache::builder()
-            .eviction_listener_with_queued_delivery_mode(|_, _, _| {})
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
-            .time_to_idle(std::time::Duration::from_secs(time_to_idle));
-
-        if let Some(capacity) = max_capacity {
+            .eviction_listener_with_queued_delivery_mode(move |_, _, cause| {
+                if cause.was_evicted() {
+                    metrics::IN_MEMORY_CACHE_EVICTION_COUNT.add(
+                        &metrics::CONTEXT,
+                        1,
+                        &[
+                            KeyValue::new("cache_type", name),
+                            KeyValue::new("removal_cause", format!("{cause:?}")),
+                        ],
+                    );
+                }
+            })
+            .time_to_live(std::time::Duration::from_secs(settings.ttl))
+            .time_to_idle(std::time::Duration::from_secs(settings.tti));
+
+        if let Some(capacity) = settings.max_capacity {
             cache_builder = cache_builder.max_capacity(capacity * 1024 * 1024);
         }
 
+        let negative = (settings.negative_ttl > 0).then(|| {
+            MokaCache::builder()
+                .time_to_live(std::time::Duration::from_secs(settings.negative_ttl))
+                .build()
+        });
+
         Self {
+            name,
             inner: cache_builder.build(),
+            negative,
+            in_flight: MokaCache::builder()
+                .time_to_idle(std::time::Duration::from_secs(IN_FLIGHT_LOCK_TTI))
+                .build(),
+            redis_ttl: settings.redis_ttl,
+        }
+    }
+
+    /// Time in seconds the entries of this cache are kept in Redis
+    pub fn redis_ttl(&self) -> i64 {
+        self.redis_ttl
+    }
+
+    /// Records whether a lookup in the Redis tier backing this cache found the value
+    pub fn record_redis_lookup(&self, hit: bool) {
+        let cache_type = [KeyValue::new("cache_type", self.name)];
+        if hit {
+            metrics::REDIS_CACHE_HIT.add(&metrics::CONTEXT, 1, &cache_type);
+        } else {
+            metrics::REDIS_CACHE_MISS.add(&metrics::CONTEXT, 1, &cache_type);
         }
     }
 
     pub async fn push<T: Cacheable>(&self, key: String, val: T) {
+        if let Some(negative) = &self.negative {
+            negative.invalidate(&key).await;
+        }
         self.insert(key, Arc::new(val)).await;
     }
 
@@ -132,14 +260,96 @@ impl Cache {
     }
 
     pub async fn remove(&self, key: &str) {
+        if let Some(negative) = &self.negative {
+            negative.invalidate(key).await;
+        }
         self.invalidate(key).await;
     }
+
+    fn lookup<T: Clone + Cacheable>(&self, key: &str) -> Lookup<T> {
+        match self.get_val::<T>(key) {
+            Some(val) => Lookup::Value(val),
+            None if self
+                .negative
+                .as_ref()
+                .map(|negative| negative.contains_key(key))
+                .unwrap_or(false) =>
+            {
+                Lookup::NotFound
+            }
+            None => Lookup::Missing,
+        }
+    }
+
+    /// Returns the cached value of `key`, calling `fun` to populate the cache on a miss.
+    ///
+    /// Concurrent misses for the same key are coalesced, only the first caller runs `fun` while
+    /// the others wait for it and read the populated value. If negative caching is enabled,
+    /// errors for which `is_not_found` holds are remembered and `not_found` is returned instead
+    /// of calling `fun` again until they expire.
+    pub async fn get_or_populate<T, E, F, Fut>(
+        &self,
+        key: &str,
+        fun: F,
+        is_not_found: fn(&E) -> bool,
+        not_found: fn() -> Report<E>,
+    ) -> error_stack::Result<T, E>
+    where
+        T: Cacheable + Clone,
+        E: error_stack::Context,
+        F: FnOnce() -> Fut + Send,
+        Fut: futures::Future<Output = error_stack::Result<T, E>> + Send,
+    {
+        let cache_type = [KeyValue::new("cache_type", self.name)];
+        match self.lookup::<T>(key) {
+            Lookup::Value(val) => {
+                metrics::IN_MEMORY_CACHE_HIT.add(&metrics::CONTEXT, 1, &cache_type);
+                return Ok(val);
+            }
+            Lookup::NotFound => {
+                metrics::IN_MEMORY_CACHE_HIT.add(&metrics::CONTEXT, 1, &cache_type);
+                return Err(not_found());
+            }
+            Lookup::Missing => {
+                metrics::IN_MEMORY_CACHE_MISS.add(&metrics::CONTEXT, 1, &cache_type);
+            }
+        }
+
+        let lock = self
+            .in_flight
+            .get_with(key.to_string(), async { Arc::default() })
+            .await;
+        let _guard = lock.lock().await;
+
+        // The cache may have been populated while waiting for the lock
+        match self.lookup::<T>(key) {
+            Lookup::Value(val) => return Ok(val),
+            Lookup::NotFound => return Err(not_found()),
+            Lookup::Missing => {}
+        }
+
+        match fun().await {
+            Ok(val) => {
+                self.push(key.to_string(), val.clone()).await;
+                Ok(val)
+            }
+            Err(err) => {
+                if let Some(negative) = &self.negative {
+                    if is_not_found(err.current_context()) {
+                        negative.insert(key.to_string(), ()).await;
+                    }
+                }
+                Err(err)
+            }
+        }
+    }
 }
 
 pub async fn get_or_populate_redis<T, F, Fut>(
     store: &(dyn RedisConnInterface + Send + Sync),
     key: impl AsRef<str>,
     fun: F,
+    cache: &Cache,
 ) -> CustomResult<T, StorageError>
 where
     T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
@@ -159,7 +369,7 @@ where
     let get_data_set_redis = || async {
         let data = fun().await?;
         redis
-            .serialize_and_set_key(key, &data)
+            .serialize_and_set_key_with_expiry(key, &data, cache.redis_ttl())
             .await
             .change_context(StorageError::KVError)?;
         Ok::<_, Report<StorageError>>(data)
@@ -167,13 +377,17 @@ where
     match redis_val {
         Err(err) => match err.current_context() {
             RedisError::NotFound | RedisError::JsonDeserializationFailed => {
+                cache.record_redis_lookup(false);
                 get_data_set_redis().await
             }
             _ => Err(err
                 .change_context(StorageError::KVError)
                 .attach_printable(format!("Error while fetching cache for {type_name}"))),
         },
-        Ok(val) => Ok(val),
+        Ok(val) => {
+            cache.record_redis_lookup(true);
+            Ok(val)
+        }
     }
 }
 
@@ -188,14 +402,18 @@ where
     F: FnOnce() -> Fut + Send,
     Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
 {
-    let cache_val = cache.get_val::<T>(key);
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
-        let val = get_or_populate_redis(store, key, fun).await?;
-        cache.push(key.to_string(), val.clone()).await;
-        Ok(val)
-    }
+    cache
+        .get_or_populate(
+            key,
+            || get_or_populate_redis(store, key, fun, cache),
+            |err| matches!(err, StorageError::ValueNotFound(_)),
+            || {
+                report!(StorageError::ValueNotFound(
+                    "cached value not found".to_string()
+                ))
+            },
+        )
+        .await
 }
 
 pub async fn redact_cache<T, F, Fut>(
@@ -209,7 +427,7 @@ where
     Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
 {
     let data = fun().await?;
-    in_memory.async_map(|cache| cache.invalidate(key)).await;
+    in_memory.async_map(|cache| cache.remove(key)).await;
 
     let redis_conn = store
         .get_redis_conn()
@@ -264,21 +482,43 @@ mod cache_tests {
 
     #[tokio::test]
     async fn construct_and_get_cache() {
-        let cache = Cache::new(1800, 1800, None);
+        let cache = Cache::new(
+            "TEST",
+            &CacheKindSettings {
+                ttl: 1800,
+                tti: 1800,
+                ..Default::default()
+            },
+        );
         cache.push("key".to_string(), "val".to_string()).await;
         assert_eq!(cache.get_val::<String>("key"), Some(String::from("val")));
     }
 
     #[tokio::test]
     async fn eviction_on_size_test() {
-        let cache = Cache::new(2, 2, Some(0));
+        let cache = Cache::new(
+            "TEST",
+            &CacheKindSettings {
+                ttl: 2,
+                tti: 2,
+                max_capacity: Some(0),
+                ..Default::default()
+            },
+        );
         cache.push("key".to_string(), "val".to_string()).await;
         assert_eq!(cache.get_val::<String>("key"), None);
     }
 
     #[tokio::test]
     async fn invalidate_cache_for_key() {
-        let cache = Cache::new(1800, 1800, None);
+        let cache = Cache::new(
+            "TEST",
+            &CacheKindSettings {
+                ttl: 1800,
+                tti: 1800,
+                ..Default::default()
+            },
+        );
         cache.push("key".to_string(), "val".to_string()).await;
 
         cache.remove("key").await;
@@ -288,9 +528,93 @@ mod cache_tests {
 
     #[tokio::test]
     async fn eviction_on_time_test() {
-        let cache = Cache::new(2, 2, None);
+        let cache = Cache::new(
+            "TEST",
+            &CacheKindSettings {
+                ttl: 2,
+                tti: 2,
+                ..Default::default()
+            },
+        );
         cache.push("key".to_string(), "val".to_string()).await;
         tokio::time::sleep(std::time::Duration::from_secs(3)).await;
         assert_eq!(cache.get_val::<String>("key"), None);
     }
+
+    #[tokio::test]
+    async fn negative_lookup_is_cached() {
+        let cache = Cache::new(
+            "TEST",
+            &CacheKindSettings {
+                negative_ttl: 1800,
+                ..Default::default()
+            },
+        );
+        let not_found = || report!(StorageError::ValueNotFound("key".to_string()));
+        let is_not_found = |err: &StorageError| matches!(err, StorageError::ValueNotFound(_));
+
+        let result = cache
+            .get_or_populate::<String, _, _, _>(
+                "key",
+                || async { Err(not_found()) },
+                is_not_found,
+                not_found,
+            )
+            .await;
+        assert!(result.is_err());
+
+        // The populating function must not be called while the negative entry is cached
+        let result = cache
+            .get_or_populate(
+                "key",
+                || async { Ok("val".to_string()) },
+                is_not_found,
+                not_found,
+            )
+            .await;
+        assert!(result.is_err());
+
+        cache.remove("key").await;
+        let result = cache
+            .get_or_populate(
+                "key",
+                || async { Ok("val".to_string()) },
+                is_not_found,
+                not_found,
+            )
+            .await;
+        assert_eq!(result.ok(), Some(String::from("val")));
+    }
+
+    #[tokio::test]
+    async fn concurrent_misses_are_coalesced() {
+        let cache = Arc::new(Cache::new("TEST", &CacheKindSettings::default()));
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let not_found = || report!(StorageError::ValueNotFound("key".to_string()));
+
+        let lookups = (0..10).map(|_| {
+            let cache = cache.clone();
+            let calls = calls.clone();
+            tokio::spawn(async move {
+                cache
+                    .get_or_populate(
+                        "key",
+                        || async {
+                            calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                            Ok("val".to_string())
+                        },
+                        |_| false,
+                        not_found,
+                    )
+                    .await
+                    .ok()
+            })
+        });
+
+        for lookup in futures::future::join_all(lookups).await {
+            assert_eq!(lookup.ok().flatten(), Some(String::from("val")));
+        }
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 }
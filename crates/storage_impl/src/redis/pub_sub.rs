@@ -1,5 +1,6 @@
-use error_stack::{IntoReport, ResultExt};
-use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
+use error_stack::ResultExt;
+use futures::StreamExt;
+use redis_interface::{errors as redis_errors, RedisValue};
 use router_env::logger;
 
 use crate::redis::cache::{CacheKind, ACCOUNTS_CACHE, CONFIG_CACHE};
@@ -21,14 +22,7 @@ pub trait PubSubInterface {
 impl PubSubInterface for redis_interface::RedisConnectionPool {
     #[inline]
     async fn subscribe(&self, channel: &str) -> error_stack::Result<(), redis_errors::RedisError> {
-        // Spawns a task that will automatically re-subscribe to any channels or channel patterns used by the client.
-        self.subscriber.manage_subscriptions();
-
-        self.subscriber
-            .subscribe(channel)
-            .await
-            .into_report()
-            .change_context(redis_errors::RedisError::SubscribeError)
+        self.subscribe_to_channel(channel).await
     }
 
     #[inline]
@@ -37,18 +31,16 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
         channel: &str,
         key: CacheKind<'a>,
     ) -> error_stack::Result<usize, redis_errors::RedisError> {
-        self.publisher
-            .publish(channel, RedisValue::from(key).into_inner())
+        self.publish_to_channel(channel, RedisValue::from(key).into_inner())
             .await
-            .into_report()
             .change_context(redis_errors::RedisError::SubscribeError)
     }
 
     #[inline]
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError> {
         logger::debug!("Started on message");
-        let mut rx = self.subscriber.on_message();
-        while let Ok(message) = rx.recv().await {
+        let mut messages = self.on_channel_message();
+        while let Some(message) = messages.next().await {
             logger::debug!("Invalidating {message:?}");
             let key: CacheKind<'_> = match RedisValue::new(message.value)
                 .try_into()
@@ -63,16 +55,16 @@ impl PubSubInterface for redis_interface::RedisConnectionPool {
 
             let key = match key {
                 CacheKind::Config(key) => {
-                    CONFIG_CACHE.invalidate(key.as_ref()).await;
+                    CONFIG_CACHE.remove(key.as_ref()).await;
                     key
                 }
                 CacheKind::Accounts(key) => {
-                    ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
+                    ACCOUNTS_CACHE.remove(key.as_ref()).await;
                     key
                 }
                 CacheKind::All(key) => {
-                    CONFIG_CACHE.invalidate(key.as_ref()).await;
-                    ACCOUNTS_CACHE.invalidate(key.as_ref()).await;
+                    CONFIG_CACHE.remove(key.as_ref()).await;
+                    ACCOUNTS_CACHE.remove(key.as_ref()).await;
                     key
                 }
             };
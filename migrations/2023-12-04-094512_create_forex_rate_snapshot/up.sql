@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS forex_rate_snapshot (
+    rate_date DATE PRIMARY KEY,
+    provider VARCHAR(64) NOT NULL,
+    base_currency "Currency" NOT NULL,
+    rates JSONB NOT NULL,
+    rates_timestamp BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_modified TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE captures DROP COLUMN IF EXISTS final_capture;
+
+ALTER TABLE refund DROP COLUMN IF EXISTS capture_id;
+
+-- Values added to the "EventObjectType" and "EventType" enums cannot be removed
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+ALTER TABLE captures ADD COLUMN IF NOT EXISTS final_capture BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS capture_id VARCHAR(64);
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'capture_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'capture_succeeded';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'capture_failed';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS delayed_capture_hours;
+
+-- Values added to the "IntentStatus", "AttemptStatus" and "EventType" enums cannot be removed
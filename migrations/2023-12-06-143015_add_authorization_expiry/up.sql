@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS delayed_capture_hours INTEGER;
+
+ALTER TYPE "IntentStatus" ADD VALUE IF NOT EXISTS 'expired';
+
+ALTER TYPE "AttemptStatus" ADD VALUE IF NOT EXISTS 'expired';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_expired';
@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_link
+DROP COLUMN IF EXISTS link_type,
+DROP COLUMN IF EXISTS amount_type,
+DROP COLUMN IF EXISTS status,
+DROP COLUMN IF EXISTS custom_domain,
+DROP COLUMN IF EXISTS locale,
+DROP COLUMN IF EXISTS success_url,
+DROP COLUMN IF EXISTS failure_url;
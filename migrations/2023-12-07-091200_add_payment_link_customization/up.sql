@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE payment_link
+ADD COLUMN IF NOT EXISTS link_type VARCHAR(32) NOT NULL DEFAULT 'single_use',
+ADD COLUMN IF NOT EXISTS amount_type VARCHAR(32) NOT NULL DEFAULT 'fixed',
+ADD COLUMN IF NOT EXISTS status VARCHAR(32) NOT NULL DEFAULT 'active',
+ADD COLUMN IF NOT EXISTS custom_domain VARCHAR(255),
+ADD COLUMN IF NOT EXISTS locale VARCHAR(32),
+ADD COLUMN IF NOT EXISTS success_url VARCHAR(255),
+ADD COLUMN IF NOT EXISTS failure_url VARCHAR(255);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE payment_link ADD COLUMN IF NOT EXISTS tax_amount BIGINT;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS events_merchant_id_id_index;
+
+ALTER TABLE events
+DROP COLUMN IF EXISTS merchant_id,
+DROP COLUMN IF EXISTS content;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS merchant_id VARCHAR(64),
+ADD COLUMN IF NOT EXISTS content JSONB;
+
+CREATE INDEX IF NOT EXISTS events_merchant_id_id_index ON events (merchant_id, id DESC);
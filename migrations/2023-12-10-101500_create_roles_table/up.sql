@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS roles (
+    id SERIAL PRIMARY KEY,
+    role_name VARCHAR(64) NOT NULL,
+    role_id VARCHAR(64) NOT NULL UNIQUE,
+    merchant_id VARCHAR(64) NOT NULL,
+    org_id VARCHAR(64) NOT NULL,
+    permissions TEXT[] NOT NULL,
+    scope VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    created_by VARCHAR(64) NOT NULL,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_by VARCHAR(64) NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS roles_merchant_org_index ON roles (merchant_id, org_id);
+CREATE INDEX IF NOT EXISTS roles_org_scope_index ON roles (org_id, scope);
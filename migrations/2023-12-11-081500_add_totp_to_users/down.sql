@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE users
+DROP COLUMN IF EXISTS totp_status,
+DROP COLUMN IF EXISTS totp_secret,
+DROP COLUMN IF EXISTS totp_recovery_codes;
+
+ALTER TABLE organization DROP COLUMN IF EXISTS enforce_totp;
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE users
+ADD COLUMN IF NOT EXISTS totp_status VARCHAR(64) NOT NULL DEFAULT 'not_set',
+ADD COLUMN IF NOT EXISTS totp_secret BYTEA DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS totp_recovery_codes TEXT[] DEFAULT NULL;
+
+ALTER TABLE organization
+ADD COLUMN IF NOT EXISTS enforce_totp BOOLEAN NOT NULL DEFAULT FALSE;
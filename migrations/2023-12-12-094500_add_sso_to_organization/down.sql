@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE organization
+DROP COLUMN IF EXISTS sso_config,
+DROP COLUMN IF EXISTS sso_client_secret,
+DROP COLUMN IF EXISTS enforce_sso;
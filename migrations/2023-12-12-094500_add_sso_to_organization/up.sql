@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE organization
+ADD COLUMN IF NOT EXISTS sso_config JSONB DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS sso_client_secret BYTEA DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS enforce_sso BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    id SERIAL PRIMARY KEY,
+    audit_id VARCHAR(64) NOT NULL UNIQUE,
+    merchant_id VARCHAR(64),
+    actor_type VARCHAR(64) NOT NULL,
+    actor_id VARCHAR(255),
+    action VARCHAR(64) NOT NULL,
+    resource_type VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    diff JSONB NOT NULL,
+    request_id VARCHAR(64),
+    ip_address VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
+CREATE INDEX IF NOT EXISTS audit_log_resource_index ON audit_log (resource_type, resource_id);
+
+-- The audit log is append-only, rows can neither be updated nor deleted
+CREATE OR REPLACE FUNCTION audit_log_reject_modification() RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER audit_log_append_only
+BEFORE UPDATE OR DELETE ON audit_log
+FOR EACH ROW EXECUTE FUNCTION audit_log_reject_modification();
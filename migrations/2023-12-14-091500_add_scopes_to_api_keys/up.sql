@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS scopes TEXT[] DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS profile_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS ip_allowlist TEXT[] DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE api_keys
+ADD COLUMN IF NOT EXISTS rotated_from VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS rotated_to VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS dispute_settings JSONB;
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'dispute_deadline_approaching';